@@ -0,0 +1,197 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Attributes carrying [cbindgen] annotations, as an alternative to
+//! `/// cbindgen:` doc comments.
+//!
+//! The attributes don't do anything when compiling: cbindgen reads them when
+//! parsing the crate. They are meant to be used under the `cbindgen` name,
+//! either by renaming the dependency or with `use cbindgen_macros as cbindgen;`:
+//!
+//! ```ignore
+//! #[cbindgen::section("Audio API")]
+//! #[cbindgen::returns_ownership("audio_buffer_free")]
+//! #[no_mangle]
+//! pub extern "C" fn audio_buffer_new(len: usize) -> *mut AudioBuffer { .. }
+//! ```
+//!
+//! Attribute names are annotation names with dashes replaced by underscores.
+//! A lone string literal is the annotation value, as it would be written in a
+//! doc comment, while identifiers and integers make up a list:
+//! `#[cbindgen::field_names(mHandle, mNamespace)]`.
+//!
+//! See the [annotation documentation] for what each attribute does. Since
+//! these are attribute macros, they can only be put on items, not on fields or
+//! enum variants, and they are gone from the output of `parse.expand`. There
+//! is no attribute for `cfg`, whose name is reserved.
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+//! [annotation documentation]: https://github.com/mozilla/cbindgen/blob/master/docs.md#annotations
+
+use proc_macro::TokenStream;
+
+macro_rules! annotations {
+    ($($(#[$doc:meta])* $name:ident,)*) => {
+        $(
+            $(#[$doc])*
+            #[proc_macro_attribute]
+            pub fn $name(_args: TokenStream, item: TokenStream) -> TokenStream {
+                item
+            }
+        )*
+    };
+}
+
+annotations! {
+    /// Makes cbindgen skip the item entirely.
+    ignore,
+    /// Makes cbindgen aware of the item without emitting it.
+    no_export,
+    /// Renames the item in the output, like an `export.rename` entry.
+    rename,
+    /// Emits the item as an opaque type.
+    opaque,
+    /// Raw code emitted before the item.
+    prefix,
+    /// Raw code emitted after the item.
+    postfix,
+    /// Raw code replacing the declaration of the item.
+    body,
+    /// Headers the item needs.
+    include,
+    /// Groups the function under a banner.
+    section,
+    field_names,
+    rename_all,
+    derive_constructor,
+    derive_eq,
+    derive_neq,
+    derive_lt,
+    derive_lte,
+    derive_gt,
+    derive_gte,
+    derive_ostream,
+    eq_attributes,
+    neq_attributes,
+    lt_attributes,
+    lte_attributes,
+    gt_attributes,
+    gte_attributes,
+    enum_trailing_values,
+    add_sentinel,
+    derive_helper_methods,
+    derive_const_casts,
+    derive_mut_casts,
+    derive_tagged_enum_destructor,
+    derive_tagged_enum_copy_constructor,
+    derive_tagged_enum_copy_assignment,
+    enum_class,
+    prefix_with_name,
+    private_default_tagged_enum_constructor,
+    destructor_attributes,
+    copy_constructor_attributes,
+    copy_assignment_attributes,
+    nonnull,
+    returns_ownership,
+    consumes,
+    ptrs_as_arrays,
+    /// Marks the function as callable from CUDA device code.
+    cuda,
+    /// Emits the struct as a table of function pointers with call helpers.
+    vtable,
+    /// Emits the vtable struct as a COM interface.
+    com,
+    /// The interface ID of a COM interface.
+    iid,
+    /// The symbol version of the function.
+    version,
+    /// Generates a function naming the variants of the enum.
+    derive_to_string,
+    /// Generates a wrapper checking the pointer arguments of the function.
+    null_check,
+    /// Puts the item in the group of the given name.
+    group,
+    /// Marks the result of the function as one not to ignore.
+    must_use,
+    /// Generates conversions to and from the wrapped type.
+    derive_transparent_conversions,
+    /// The function freeing the opaque type.
+    destructor,
+    /// The operators to overload for the type.
+    derive_ops,
+    /// The function implementing `+` for the type.
+    add_function,
+    /// The function implementing `-` for the type.
+    sub_function,
+    /// The function implementing `*` for the type.
+    mul_function,
+    /// The function implementing `/` for the type.
+    div_function,
+    /// The function implementing `%` for the type.
+    rem_function,
+    /// The function implementing `&` for the type.
+    bitand_function,
+    /// The function implementing `|` for the type.
+    bitor_function,
+    /// The function implementing `^` for the type.
+    bitxor_function,
+    /// The function implementing unary `-` for the type.
+    neg_function,
+    /// The function implementing `!` for the type.
+    not_function,
+    /// Declares the function `nogil` in Cython.
+    nogil,
+    /// The function creating the opaque type, for Cython wrapper classes.
+    constructor,
+    /// Declares the enum `cpdef` in Cython.
+    cpdef,
+    /// Adds SAL annotations to the function.
+    sal,
+    /// The pointer arguments which are only written to.
+    sal_out,
+    /// Adds GNU attributes to the function.
+    gnu_attributes,
+    /// The arguments giving the size of the returned allocation.
+    alloc_size,
+    /// The pointer arguments declared `restrict`.
+    restrict,
+    /// Emits the bodies of the variants as anonymous structs.
+    anonymous_variant_bodies,
+    /// Lowers a returned `Result` to an out-parameter or a tagged enum.
+    result,
+    /// The length of a static slice.
+    len,
+    /// Declares the function weak.
+    weak,
+    /// Other names the function is exported as.
+    alias,
+    /// Overrides `struct.initializer` for the struct.
+    initializer,
+    /// The value of the `DEFAULT` constant of the struct.
+    default,
+    /// Overrides `enum.fixed_type` for the enum.
+    fixed_type,
+    /// Declares the function `constexpr` in C++.
+    constexpr,
+    /// Overrides `enum.duplicate_values` for the enum.
+    duplicate_values,
+    /// Overrides `enum.value_gaps` for the enum.
+    value_gaps,
+    /// Overrides `enum.value_range` for the enum.
+    value_range,
+    /// The function cloning the enum.
+    clone,
+    /// Moves the item to the internal header.
+    internal,
+    /// Attributes of the constructors of the variants of the enum.
+    variant_constructor_attributes,
+    /// Attributes of the const casts to the variants of the enum.
+    variant_const_cast_attributes,
+    /// Attributes of the mutable casts to the variants of the enum.
+    variant_mut_cast_attributes,
+    /// Attributes of the `Is` methods of the variants of the enum.
+    variant_is_attributes,
+    /// Overrides `ptr.owned_box` for the item.
+    owned,
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+use std::fs;
+
+/// The names of the annotations documented in docs.md which can be put on
+/// items, as attribute names.
+fn documented_annotations() -> BTreeSet<String> {
+    let docs = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/../docs.md")).unwrap();
+    let start = docs.find("\n## Annotations\n").unwrap();
+    let end = start
+        + docs[start..]
+            .find("\n## Generating Swift Bindings\n")
+            .unwrap();
+
+    let mut names = BTreeSet::new();
+    let mut section = "";
+    for line in docs[start..end].lines() {
+        if let Some(heading) = line.strip_prefix("### ") {
+            section = heading;
+        }
+        // Attribute macros can't be put on fields. The enum variant
+        // annotations can be put on the enum, applying to all its variants.
+        if section == "Field Annotations" {
+            continue;
+        }
+        for (i, _) in line.match_indices("cbindgen:") {
+            names.insert(annotation_name(&line[i + "cbindgen:".len()..]));
+        }
+        if let Some(bullet) = line.strip_prefix("* ") {
+            for bullet in expand_braces(bullet) {
+                let name = annotation_name(&bullet);
+                let rest = &bullet[name.len()..];
+                if rest.is_empty() || rest.starts_with(['=', ':', ' ']) {
+                    names.insert(name);
+                }
+            }
+        }
+    }
+    // `cfg` is a reserved attribute name.
+    names.remove("cfg");
+    names.remove("");
+    names
+        .into_iter()
+        .map(|name| name.replace('-', "_"))
+        .collect()
+}
+
+/// Expands the `{a,b}` group at the start of the annotation name of `text`,
+/// as in `variant-{is,const-cast}-attributes`, into one text per name.
+fn expand_braces(text: &str) -> Vec<String> {
+    let prefix = annotation_name(text);
+    let group = match text[prefix.len()..].strip_prefix('{') {
+        Some(group) => group,
+        None => return vec![text.to_owned()],
+    };
+    let end = match group.find('}') {
+        Some(end) => end,
+        None => return vec![text.to_owned()],
+    };
+    group[..end]
+        .split(',')
+        .map(|alternative| format!("{}{}{}", prefix, alternative.trim(), &group[end + 1..]))
+        .collect()
+}
+
+fn annotation_name(text: &str) -> String {
+    text.chars()
+        .take_while(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')
+        .collect()
+}
+
+/// The attributes defined by the `annotations!` list of the crate.
+fn attributes() -> BTreeSet<String> {
+    let source = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/src/lib.rs")).unwrap();
+    let list = &source[source.find("\nannotations! {\n").unwrap()..];
+    list.lines()
+        .filter_map(|line| line.strip_prefix("    ")?.strip_suffix(','))
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn documented_annotations_have_attributes() {
+    let attributes = attributes();
+    let missing: Vec<_> = documented_annotations()
+        .into_iter()
+        .filter(|name| !attributes.contains(name))
+        .collect();
+    assert!(missing.is_empty(), "no attribute for {:?}", missing);
+}
+
+#[test]
+fn operator_function_annotations_have_attributes() {
+    let attributes = attributes();
+    let missing: Vec<_> = [
+        "add", "sub", "mul", "div", "rem", "bitand", "bitor", "bitxor", "neg", "not",
+    ]
+    .iter()
+    .map(|op| format!("{}_function", op))
+    .filter(|name| !attributes.contains(name))
+    .collect();
+    assert!(missing.is_empty(), "no attribute for {:?}", missing);
+}
+
+#[test]
+fn brace_groups_are_expanded() {
+    assert_eq!(
+        expand_braces("variant-{is,const-cast}-attributes: See"),
+        [
+            "variant-is-attributes: See",
+            "variant-const-cast-attributes: See"
+        ]
+    );
+    assert_eq!(expand_braces("prefix=value"), ["prefix=value"]);
+}
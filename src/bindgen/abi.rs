@@ -0,0 +1,345 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::cdecl;
+use crate::bindgen::ir::{Field, Item, ItemContainer, ReprAlign, Type, VariantBody, VariantValue};
+use crate::bindgen::writer::SourceWriter;
+
+/// The parts of a set of bindings that C code depends on at runtime: the
+/// signatures of the functions and globals, and the layout of the types.
+///
+/// Snapshots are written as JSON with `--abi-snapshot`, and checked against
+/// the current bindings with `--abi-check`, to catch unintentional ABI
+/// breaks before a release. Types are recorded as they're spelled in the
+/// header.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiSnapshot {
+    pub functions: BTreeMap<String, FunctionAbi>,
+    pub globals: BTreeMap<String, String>,
+    pub structs: BTreeMap<String, Vec<FieldAbi>>,
+    pub unions: BTreeMap<String, Vec<FieldAbi>>,
+    pub enums: BTreeMap<String, EnumAbi>,
+    pub typedefs: BTreeMap<String, String>,
+    /// The alignment of the structs and unions with a `#[repr(packed)]` or
+    /// `#[repr(align(N))]`, as `packed` or `align(N)`.
+    #[serde(default)]
+    pub alignments: BTreeMap<String, String>,
+    /// The opaque types, which C code only uses behind pointers.
+    #[serde(default)]
+    pub opaque: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionAbi {
+    pub ret: String,
+    pub args: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldAbi {
+    pub name: String,
+    pub ty: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumAbi {
+    /// The type of the tag, or `None` for a plain C enum.
+    pub repr: Option<String>,
+    pub variants: Vec<VariantAbi>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariantAbi {
+    pub name: String,
+    /// The value of the tag, as an expression when it isn't a literal.
+    pub value: String,
+    /// The fields of the variant's body, if any.
+    pub fields: Vec<FieldAbi>,
+}
+
+impl AbiSnapshot {
+    pub(crate) fn new(bindings: &Bindings) -> AbiSnapshot {
+        let config = &bindings.config;
+        let spell = |ty: &Type| {
+            let mut buffer = Vec::new();
+            cdecl::write_type(
+                &mut SourceWriter::new(&mut buffer as &mut dyn Write, bindings),
+                ty,
+                config,
+            );
+            String::from_utf8(buffer).unwrap()
+        };
+        let fields = |fields: &[Field]| {
+            fields
+                .iter()
+                .map(|field| FieldAbi {
+                    name: field.name.clone(),
+                    ty: spell(&field.ty),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut snapshot = AbiSnapshot::default();
+        for function in &bindings.functions {
+            snapshot.functions.insert(
+                function.path.name().to_owned(),
+                FunctionAbi {
+                    ret: spell(&function.ret),
+                    args: function.args.iter().map(|arg| spell(&arg.ty)).collect(),
+                },
+            );
+        }
+        for global in &bindings.globals {
+            snapshot
+                .globals
+                .insert(global.export_name().to_owned(), spell(&global.ty));
+        }
+        for item in &bindings.items {
+            match *item {
+                ItemContainer::Struct(ref x) if x.is_transparent => {
+                    snapshot
+                        .typedefs
+                        .insert(x.export_name().to_owned(), spell(&x.fields[0].ty));
+                }
+                ItemContainer::Struct(ref x) => {
+                    snapshot
+                        .structs
+                        .insert(x.export_name().to_owned(), fields(&x.fields));
+                    snapshot.add_alignment(x.export_name(), x.alignment);
+                }
+                ItemContainer::Union(ref x) => {
+                    snapshot
+                        .unions
+                        .insert(x.export_name().to_owned(), fields(&x.fields));
+                    snapshot.add_alignment(x.export_name(), x.alignment);
+                }
+                ItemContainer::OpaqueItem(ref x) => {
+                    snapshot.opaque.insert(x.export_name().to_owned());
+                }
+                ItemContainer::Typedef(ref x) => {
+                    snapshot
+                        .typedefs
+                        .insert(x.export_name().to_owned(), spell(&x.aliased));
+                }
+                ItemContainer::Enum(ref x) => {
+                    let mut variants = Vec::new();
+                    for (variant, value) in x.variant_values() {
+                        let value = match value {
+                            VariantValue::Integer(value) => value.to_string(),
+                            VariantValue::Offset(base, offset) => {
+                                let mut buffer = Vec::new();
+                                base.write(
+                                    config,
+                                    &mut SourceWriter::new(&mut buffer as &mut dyn Write, bindings),
+                                );
+                                let base = String::from_utf8(buffer).unwrap();
+                                match offset {
+                                    0 => base,
+                                    _ => format!("{} + {}", base, offset),
+                                }
+                            }
+                        };
+                        let variant_fields = match variant.body {
+                            VariantBody::Empty(..) => Vec::new(),
+                            VariantBody::Body { ref body, .. } => fields(&body.fields),
+                        };
+                        variants.push(VariantAbi {
+                            name: variant.export_name.clone(),
+                            value,
+                            fields: variant_fields,
+                        });
+                    }
+                    snapshot.enums.insert(
+                        x.export_name().to_owned(),
+                        EnumAbi {
+                            repr: x
+                                .repr
+                                .ty
+                                .map(|ty| ty.to_primitive().to_repr_c(config).to_owned()),
+                            variants,
+                        },
+                    );
+                }
+                ItemContainer::Constant(..) | ItemContainer::Static(..) => {}
+            }
+        }
+        snapshot
+    }
+
+    fn add_alignment(&mut self, name: &str, alignment: Option<ReprAlign>) {
+        let alignment = match alignment {
+            Some(ReprAlign::Packed) => "packed".to_owned(),
+            Some(ReprAlign::Align(n)) => format!("align({})", n),
+            None => return,
+        };
+        self.alignments.insert(name.to_owned(), alignment);
+    }
+
+    /// How the type `name`, which isn't a `kind` anymore, is gone.
+    fn removal(&self, kind: &str, name: &str) -> String {
+        if self.opaque.contains(name) {
+            format!("{} `{}` became opaque", kind, name)
+        } else {
+            format!("{} `{}` was removed", kind, name)
+        }
+    }
+
+    /// Lists the changes from this snapshot to `new` which break code built
+    /// against this one.
+    pub fn breaking_changes(&self, new: &AbiSnapshot) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        for (name, old) in &self.functions {
+            match new.functions.get(name) {
+                None => changes.push(format!("function `{}` was removed", name)),
+                Some(new) if new != old => changes.push(format!(
+                    "function `{}` changed from `{} ({})` to `{} ({})`",
+                    name,
+                    old.ret,
+                    old.args.join(", "),
+                    new.ret,
+                    new.args.join(", ")
+                )),
+                Some(_) => {}
+            }
+        }
+        compare_types(&mut changes, "global", &self.globals, &new.globals);
+        compare_types(&mut changes, "typedef", &self.typedefs, &new.typedefs);
+        for (name, old) in &self.structs {
+            match new.structs.get(name) {
+                None => changes.push(new.removal("struct", name)),
+                Some(new_fields) => {
+                    let what = format!("struct `{}`", name);
+                    self.compare_alignment(&mut changes, &what, name, new);
+                    compare_fields(&mut changes, &what, old, new_fields);
+                }
+            }
+        }
+        for (name, old) in &self.unions {
+            match new.unions.get(name) {
+                None => changes.push(new.removal("union", name)),
+                Some(new_fields) => {
+                    let what = format!("union `{}`", name);
+                    self.compare_alignment(&mut changes, &what, name, new);
+                    compare_fields(&mut changes, &what, old, new_fields);
+                }
+            }
+        }
+        for (name, old) in &self.enums {
+            let new = match new.enums.get(name) {
+                Some(new) => new,
+                None => {
+                    changes.push(new.removal("enum", name));
+                    continue;
+                }
+            };
+            if old.repr != new.repr {
+                changes.push(format!(
+                    "enum `{}` changed its tag type from `{}` to `{}`",
+                    name,
+                    old.repr.as_deref().unwrap_or("int"),
+                    new.repr.as_deref().unwrap_or("int")
+                ));
+            }
+            for old_variant in &old.variants {
+                let what = format!("variant `{}` of enum `{}`", old_variant.name, name);
+                match new.variants.iter().find(|v| v.name == old_variant.name) {
+                    None => changes.push(format!("{} was removed", what)),
+                    Some(new_variant) => {
+                        if old_variant.value != new_variant.value {
+                            changes.push(format!(
+                                "{} changed its value from `{}` to `{}`",
+                                what, old_variant.value, new_variant.value
+                            ));
+                        }
+                        compare_fields(
+                            &mut changes,
+                            &what,
+                            &old_variant.fields,
+                            &new_variant.fields,
+                        );
+                    }
+                }
+            }
+        }
+
+        for name in &self.opaque {
+            if !new.opaque.contains(name)
+                && !new.structs.contains_key(name)
+                && !new.unions.contains_key(name)
+                && !new.enums.contains_key(name)
+                && !new.typedefs.contains_key(name)
+            {
+                changes.push(format!("opaque type `{}` was removed", name));
+            }
+        }
+
+        changes
+    }
+
+    fn compare_alignment(
+        &self,
+        changes: &mut Vec<String>,
+        what: &str,
+        name: &str,
+        new: &AbiSnapshot,
+    ) {
+        let old = self.alignments.get(name).map_or("default", String::as_str);
+        let new = new.alignments.get(name).map_or("default", String::as_str);
+        if old != new {
+            changes.push(format!(
+                "{} changed its alignment from `{}` to `{}`",
+                what, old, new
+            ));
+        }
+    }
+}
+
+fn compare_types(
+    changes: &mut Vec<String>,
+    kind: &str,
+    old: &BTreeMap<String, String>,
+    new: &BTreeMap<String, String>,
+) {
+    for (name, old_ty) in old {
+        match new.get(name) {
+            None => changes.push(format!("{} `{}` was removed", kind, name)),
+            Some(new_ty) if new_ty != old_ty => changes.push(format!(
+                "{} `{}` changed its type from `{}` to `{}`",
+                kind, name, old_ty, new_ty
+            )),
+            Some(_) => {}
+        }
+    }
+}
+
+/// Fields are compared by position, since that's what the layout depends on.
+fn compare_fields(changes: &mut Vec<String>, what: &str, old: &[FieldAbi], new: &[FieldAbi]) {
+    for (i, old_field) in old.iter().enumerate() {
+        match new.get(i) {
+            None => changes.push(format!("{} lost field `{}`", what, old_field.name)),
+            Some(new_field) if new_field.name != old_field.name => {
+                changes.push(format!(
+                    "{} has field `{}` where `{}` used to be",
+                    what, new_field.name, old_field.name
+                ));
+            }
+            Some(new_field) if new_field.ty != old_field.ty => changes.push(format!(
+                "{} changed the type of field `{}` from `{}` to `{}`",
+                what, old_field.name, old_field.ty, new_field.ty
+            )),
+            Some(_) => {}
+        }
+    }
+    for new_field in new.iter().skip(old.len()) {
+        changes.push(format!(
+            "{} gained field `{}`, changing its size",
+            what, new_field.name
+        ));
+    }
+}
@@ -3,19 +3,43 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::borrow::Cow;
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::{self, Write};
 use std::path;
-use std::rc::Rc;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::abi::AbiSnapshot;
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{
+    is_raw_lines, source_module, CharType, Config, DocumentationStyle, DoxygenConfig, ExternC,
+    Language, Layout, SourceLocations,
+};
+use crate::bindgen::cython;
+use crate::bindgen::dependencies::ForwardDeclaration;
+use crate::bindgen::descriptor::{self, LayoutDescriptor};
+use crate::bindgen::externs;
+use crate::bindgen::fuzz;
+use crate::bindgen::includes::StandardIncludes;
 use crate::bindgen::ir::{
-    Constant, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct, Typedef,
+    cxx_operator, AnnotationSet, CallingConvention, Cfg, ConditionWrite, Constant, Documentation,
+    Enum, Function, GenericArgument, GenericPath, Item, ItemContainer, ItemMap, OpaqueItem,
+    Path as BindgenPath, ReprStyle, SourceLocation, Static, Struct, ToCondition, Type, Typedef,
+    VariantBody,
+};
+use crate::bindgen::language_backend::{
+    builtin_backend, clike, cython as cython_backend, BackendWriter, LanguageBackend,
 };
-use crate::bindgen::writer::{Source, SourceWriter};
+use crate::bindgen::layout::Layouts;
+use crate::bindgen::printers;
+use crate::bindgen::report::{self, SkippedItem};
+use crate::bindgen::shim::Helper;
+use crate::bindgen::symbols::{self, LibraryCheck, Symbol, SymbolKind};
+use crate::bindgen::wire::WireFormat;
+use crate::bindgen::writer::{Fork, Forked, SourceWriter};
 
 /// A bindings header that can be written.
 pub struct Bindings {
@@ -24,16 +48,55 @@ pub struct Bindings {
     /// transparent struct. This is needed to generate code for constants.
     struct_map: ItemMap<Struct>,
     typedef_map: ItemMap<Typedef>,
-    struct_fileds_memo: RefCell<HashMap<BindgenPath, Rc<Vec<String>>>>,
-    globals: Vec<Static>,
-    constants: Vec<Constant>,
-    items: Vec<ItemContainer>,
-    functions: Vec<Function>,
+    struct_fileds_memo: Mutex<HashMap<BindgenPath, Arc<Vec<String>>>>,
+    pub(crate) globals: Vec<Static>,
+    pub(crate) constants: Vec<Constant>,
+    pub(crate) items: Vec<ItemContainer>,
+    forward_declarations: Vec<ForwardDeclaration>,
+    pub(crate) functions: Vec<Function>,
     source_files: Vec<path::PathBuf>,
     /// Bindings are generated by a recursive call to cbindgen
     /// and shouldn't do anything when written anywhere.
     noop: bool,
     package_version: String,
+    package_name: String,
+    package_description: Option<String>,
+    /// The name of the library built from the crate, for the `.pc` file.
+    package_lib_name: Option<String>,
+    backend: Arc<dyn LanguageBackend>,
+    /// The layouts of the exported types, from `layout.probe`.
+    pub(crate) layouts: Layouts,
+    /// The declarations not annotated with `internal`, written to the header
+    /// when the ones annotated with it are written to `internal.path`.
+    public: Option<Box<Bindings>>,
+    /// The declarations annotated with `internal`, for `internal.path`.
+    internal: Option<Box<Bindings>>,
+    /// The items left out of the bindings, for `--emit report`.
+    skipped: Vec<SkippedItem>,
+    /// The structs in the byte order of the target, for `layout.wire_format`.
+    pub(crate) wire_format: WireFormat,
+    /// The types boxes point to, which get a `{T}Owned` typedef with
+    /// `ptr.owned_box = "typedef"`.
+    owned_boxes: BTreeSet<String>,
+    /// The generic types of the monomorphs, by their mangled path, for
+    /// `--emit rust-externs`.
+    pub(crate) unmangled_paths: HashMap<BindgenPath, GenericPath>,
+}
+
+/// A read-only view of the items that make up a set of bindings, in output
+/// order and after all of cbindgen's processing (exclusion, renaming,
+/// monomorphization, ...) has been applied, along with the layouts probed
+/// with `layout.probe`.
+#[cfg(feature = "serialize-ir")]
+#[derive(Debug, Serialize)]
+pub struct BindingsIr<'a> {
+    pub constants: &'a [Constant],
+    pub globals: &'a [Static],
+    pub items: &'a [ItemContainer],
+    pub functions: &'a [Function],
+    /// The layouts of the exported types according to rustc, empty unless
+    /// `layout.probe` is set.
+    pub layouts: &'a Layouts,
 }
 
 #[derive(PartialEq, Eq)]
@@ -42,6 +105,9 @@ enum NamespaceOperation {
     Close,
 }
 
+/// Includes required by items, along with the `cfg` they're needed under.
+type ItemIncludes = Vec<(String, Option<Cfg>)>;
+
 impl Bindings {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
@@ -51,11 +117,41 @@ impl Bindings {
         constants: Vec<Constant>,
         globals: Vec<Static>,
         items: Vec<ItemContainer>,
+        forward_declarations: Vec<ForwardDeclaration>,
         functions: Vec<Function>,
         source_files: Vec<path::PathBuf>,
         noop: bool,
         package_version: String,
+        package_name: String,
+        package_description: Option<String>,
     ) -> Bindings {
+        let backend = builtin_backend(config.language);
+        let mut owned_boxes = BTreeSet::new();
+        if config.language != Language::Cython {
+            let mut add = |ty: &Type| add_owned_boxes(ty, &config, &mut owned_boxes);
+            for item in &items {
+                match *item {
+                    ItemContainer::Struct(ref x) => x.fields.iter().for_each(|f| add(&f.ty)),
+                    ItemContainer::Union(ref x) => x.fields.iter().for_each(|f| add(&f.ty)),
+                    ItemContainer::Enum(ref x) => {
+                        for variant in &x.variants {
+                            if let VariantBody::Body { ref body, .. } = variant.body {
+                                body.fields.iter().for_each(|f| add(&f.ty));
+                            }
+                        }
+                    }
+                    ItemContainer::Typedef(ref x) => add(&x.aliased),
+                    ItemContainer::Constant(..)
+                    | ItemContainer::Static(..)
+                    | ItemContainer::OpaqueItem(..) => {}
+                }
+            }
+            for function in &functions {
+                add(&function.ret);
+                function.args.iter().for_each(|arg| add(&arg.ty));
+            }
+            globals.iter().for_each(|global| add(&global.ty));
+        }
         Bindings {
             config,
             struct_map,
@@ -64,10 +160,75 @@ impl Bindings {
             globals,
             constants,
             items,
+            forward_declarations,
             functions,
             source_files,
             noop,
             package_version,
+            package_name,
+            package_description,
+            package_lib_name: None,
+            backend,
+            layouts: Layouts::default(),
+            public: None,
+            internal: None,
+            skipped: Vec::new(),
+            wire_format: WireFormat::default(),
+            owned_boxes,
+            unmangled_paths: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn set_backend(&mut self, backend: Arc<dyn LanguageBackend>) {
+        for split in self.public.iter_mut().chain(self.internal.iter_mut()) {
+            split.set_backend(backend.clone());
+        }
+        self.backend = backend;
+    }
+
+    pub(crate) fn set_layouts(&mut self, layouts: Layouts) {
+        for split in self.public.iter_mut().chain(self.internal.iter_mut()) {
+            split.set_layouts(layouts.clone());
+        }
+        self.layouts = layouts;
+    }
+
+    /// Splits the declarations written to a file between `public` and
+    /// `internal`, for `internal.path`. Written anywhere else, the bindings
+    /// still declare everything.
+    pub(crate) fn set_internal(&mut self, public: Bindings, internal: Bindings) {
+        self.public = Some(Box::new(public));
+        self.internal = Some(Box::new(internal));
+    }
+
+    pub(crate) fn set_skipped(&mut self, skipped: Vec<SkippedItem>) {
+        self.skipped = skipped;
+    }
+
+    pub(crate) fn set_wire_format(&mut self, wire_format: WireFormat) {
+        self.wire_format = wire_format;
+    }
+
+    pub(crate) fn set_package_lib_name(&mut self, package_lib_name: Option<String>) {
+        self.package_lib_name = package_lib_name;
+    }
+
+    pub(crate) fn set_unmangled_paths(
+        &mut self,
+        unmangled_paths: HashMap<BindgenPath, GenericPath>,
+    ) {
+        self.unmangled_paths = unmangled_paths;
+    }
+
+    #[cfg(feature = "serialize-ir")]
+    #[allow(unused)]
+    pub fn ir(&self) -> BindingsIr<'_> {
+        BindingsIr {
+            constants: &self.constants,
+            globals: &self.globals,
+            items: &self.items,
+            functions: &self.functions,
+            layouts: &self.layouts,
         }
     }
 
@@ -105,8 +266,8 @@ impl Bindings {
         any
     }
 
-    pub fn struct_field_names(&self, path: &BindgenPath) -> Rc<Vec<String>> {
-        let mut memos = self.struct_fileds_memo.borrow_mut();
+    pub fn struct_field_names(&self, path: &BindgenPath) -> Arc<Vec<String>> {
+        let mut memos = self.struct_fileds_memo.lock().unwrap();
         if let Some(memo) = memos.get(path) {
             return memo.clone();
         }
@@ -126,7 +287,7 @@ impl Bindings {
             }
         });
 
-        let fields = Rc::new(fields);
+        let fields = Arc::new(fields);
         memos.insert(path.clone(), fields.clone());
         if let Cow::Owned(p) = resolved_path {
             memos.insert(p, fields.clone());
@@ -145,6 +306,7 @@ impl Bindings {
             .source_files
             .iter()
             .chain(self.config.config_path.as_ref())
+            .chain(self.config.text_files())
             .map(|p| p.canonicalize().unwrap())
             .collect();
         // Sorting makes testing easier by ensuring the output is ordered.
@@ -175,54 +337,631 @@ impl Bindings {
         depfile.flush().unwrap();
     }
 
+    /// Takes a snapshot of the ABI of the bindings, to check later versions
+    /// against with `AbiSnapshot::breaking_changes`.
+    pub fn abi_snapshot(&self) -> AbiSnapshot {
+        AbiSnapshot::new(self)
+    }
+
+    /// Describes the layout of the structs, unions and enums, as far as it's
+    /// known without a compiler.
+    pub fn layout_descriptor(&self) -> LayoutDescriptor {
+        LayoutDescriptor::new(self)
+    }
+
+    /// Writes a C or C++ source file including the header at `header_path`,
+    /// which defines a table of the types with their size, alignment and
+    /// fields, as computed by the compiler.
+    pub fn write_layout_table<F: Write>(&self, out: F, header_path: &str) -> io::Result<()> {
+        if self.noop {
+            return Ok(());
+        }
+
+        let header_name = path::Path::new(header_path)
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        descriptor::write_layout_table(self, out, &header_name)
+    }
+
+    /// Writes a Python file with GDB pretty-printers and LLDB summaries for
+    /// the enums with data, showing their active variant.
+    pub fn write_pretty_printers<F: Write>(&self, out: F) -> io::Result<()> {
+        printers::write_pretty_printers(self, out)
+    }
+
+    /// Writes a Rust file declaring the exported functions and globals in an
+    /// `extern "C"` block, and checking that the crate defines them with the
+    /// same types, to be included in a test of the crate.
+    pub fn write_rust_externs<F: Write>(&self, out: F) -> io::Result<()> {
+        externs::write_rust_externs(self, out)
+    }
+
+    /// Writes a Markdown reference of the exported items, with their
+    /// declarations and documentation, for use as standalone API docs.
+    pub fn write_markdown_docs<F: Write>(&self, mut out: F) -> io::Result<()> {
+        if self.noop {
+            return Ok(());
+        }
+
+        // The documentation is rendered as Markdown next to each declaration.
+        let mut config = self.config.clone();
+        config.documentation = false;
+        config.source_locations = SourceLocations::None;
+
+        let backend = &*self.backend;
+        let declaration = |write: &dyn Fn(&mut SourceWriter<&mut dyn Write>)| {
+            let mut buffer = Vec::new();
+            write(&mut SourceWriter::new(&mut buffer as &mut dyn Write, self));
+            String::from_utf8(buffer).unwrap()
+        };
+
+        writeln!(out, "# API reference")?;
+
+        let constants: Vec<_> = self
+            .constants
+            .iter()
+            .map(|constant| {
+                let decl = declaration(&|out| backend.write_constant(&config, out, constant));
+                (
+                    constant.export_name(),
+                    decl,
+                    &constant.documentation,
+                    vec![],
+                )
+            })
+            .collect();
+        self.write_markdown_section(&mut out, "Constants", &constants)?;
+
+        let types: Vec<_> = self
+            .items
+            .iter()
+            .filter(|item| {
+                !item
+                    .deref()
+                    .annotations()
+                    .bool("no-export")
+                    .unwrap_or(false)
+            })
+            .map(|item| match *item {
+                ItemContainer::Constant(..) | ItemContainer::Static(..) => unreachable!(),
+                ItemContainer::Enum(ref x) => {
+                    let decl = declaration(&|out| backend.write_enum(&config, out, x));
+                    let variants = x
+                        .variants
+                        .iter()
+                        .map(|variant| (variant.export_name.as_str(), &variant.documentation))
+                        .collect();
+                    (x.export_name(), decl, &x.documentation, variants)
+                }
+                ItemContainer::Struct(ref x) => {
+                    let decl = declaration(&|out| backend.write_struct(&config, out, x));
+                    let fields = x
+                        .fields
+                        .iter()
+                        .map(|field| (field.name.as_str(), &field.documentation))
+                        .collect();
+                    (x.export_name(), decl, &x.documentation, fields)
+                }
+                ItemContainer::Union(ref x) => {
+                    let decl = declaration(&|out| backend.write_union(&config, out, x));
+                    let fields = x
+                        .fields
+                        .iter()
+                        .map(|field| (field.name.as_str(), &field.documentation))
+                        .collect();
+                    (x.export_name(), decl, &x.documentation, fields)
+                }
+                ItemContainer::OpaqueItem(ref x) => {
+                    let decl = declaration(&|out| backend.write_opaque_item(&config, out, x));
+                    (x.export_name(), decl, &x.documentation, vec![])
+                }
+                ItemContainer::Typedef(ref x) => {
+                    let decl = declaration(&|out| backend.write_typedef(&config, out, x));
+                    (x.export_name(), decl, &x.documentation, vec![])
+                }
+            })
+            .collect();
+        self.write_markdown_section(&mut out, "Types", &types)?;
+
+        let globals: Vec<_> = self
+            .globals
+            .iter()
+            .map(|global| {
+                let decl = declaration(&|out| backend.write_static(&config, out, global));
+                (global.export_name(), decl, &global.documentation, vec![])
+            })
+            .collect();
+        self.write_markdown_section(&mut out, "Globals", &globals)?;
+
+        let functions: Vec<_> = self
+            .functions
+            .iter()
+            .map(|function| {
+                let decl = declaration(&|out| backend.write_function(&config, out, function));
+                (
+                    function.path().name(),
+                    decl,
+                    &function.documentation,
+                    vec![],
+                )
+            })
+            .collect();
+        self.write_markdown_section(&mut out, "Functions", &functions)?;
+
+        Ok(())
+    }
+
+    /// Lists the functions and globals the library is expected to export,
+    /// with their declarations in the bindings.
+    pub fn symbols(&self) -> Vec<Symbol> {
+        if self.noop {
+            return Vec::new();
+        }
+
+        let mut config = self.config.clone();
+        config.documentation = false;
+        config.source_locations = SourceLocations::None;
+        config.function.args = Layout::Horizontal;
+        config.extern_c = ExternC::Block;
+
+        let backend = &*self.backend;
+        let declaration = |write: &dyn Fn(&mut SourceWriter<&mut dyn Write>)| {
+            let mut buffer = Vec::new();
+            write(&mut SourceWriter::new(&mut buffer as &mut dyn Write, self));
+            String::from_utf8(buffer).unwrap()
+        };
+
+        let mut symbols = Vec::new();
+        for function in &self.functions {
+            let decl = declaration(&|out| backend.write_function(&config, out, function));
+            symbols.push(Symbol::new(
+                function.path().name(),
+                SymbolKind::Function,
+                &decl,
+            ));
+        }
+        for global in &self.globals {
+            let decl = declaration(&|out| backend.write_static(&config, out, global));
+            symbols.push(Symbol::new(global.export_name(), SymbolKind::Static, &decl));
+        }
+        symbols
+    }
+
+    /// Writes the symbols of `Bindings::symbols` as a plain list, one
+    /// `kind<TAB>name<TAB>signature` line per symbol.
+    pub fn write_symbols<F: Write>(&self, out: F) -> io::Result<()> {
+        symbols::write_symbol_list(out, &self.symbols())
+    }
+
+    /// Lists the items left out of the bindings or written as opaque types,
+    /// and the types the bindings refer to which couldn't be found.
+    pub fn skipped(&self) -> &[SkippedItem] {
+        &self.skipped
+    }
+
+    /// Writes the items of `Bindings::skipped` as a plain list, one
+    /// `location<TAB>kind<TAB>name<TAB>reason` line per item.
+    pub fn write_skipped<F: Write>(&self, out: F) -> io::Result<()> {
+        report::write_skipped_list(out, &self.skipped)
+    }
+
+    /// Compares the symbols of `Bindings::symbols` with those exported by the
+    /// built library at `path`, read with `nm`.
+    pub fn check_library<P: AsRef<path::Path>>(&self, path: P) -> io::Result<LibraryCheck> {
+        symbols::check_library(self.symbols(), path.as_ref())
+    }
+
+    /// Writes the pkg-config file describing the package the header at
+    /// `header_path` is part of, next to it, returning whether it changed.
+    fn write_pc_file(&self, header_path: &path::Path) -> bool {
+        let header_stem = header_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let package = &self.config.package;
+        let name = match package.name {
+            Some(ref name) => name.as_str(),
+            None if !self.package_name.is_empty() => &self.package_name,
+            None => &header_stem,
+        };
+        let description = package
+            .description
+            .as_deref()
+            .or(self.package_description.as_deref())
+            .unwrap_or(name);
+        let version = match package.version {
+            Some(ref version) => version.as_str(),
+            None if !self.package_version.is_empty() => &self.package_version,
+            None => "0.0.0",
+        };
+        let libs = match package.libs {
+            Some(ref libs) => Cow::Borrowed(libs.as_str()),
+            None => {
+                // Cargo names the library after the crate unless its `[lib]`
+                // target is renamed, with underscores.
+                let lib_name = match self.package_lib_name {
+                    Some(ref lib_name) => lib_name.as_str(),
+                    None if !self.package_name.is_empty() => &self.package_name,
+                    None => &header_stem,
+                };
+                Cow::Owned(format!("-L${{libdir}} -l{}", lib_name.replace('-', "_")))
+            }
+        };
+
+        let mut pc = String::new();
+        pc.push_str(&format!("prefix={}\n", package.prefix));
+        pc.push_str("exec_prefix=${prefix}\n");
+        pc.push_str("libdir=${exec_prefix}/lib\n");
+        pc.push_str("includedir=${prefix}/include\n");
+        pc.push('\n');
+        pc.push_str(&format!("Name: {}\n", name));
+        pc.push_str(&format!("Description: {}\n", description));
+        pc.push_str(&format!("Version: {}\n", version));
+        if !package.requires.is_empty() {
+            pc.push_str(&format!("Requires: {}\n", package.requires.join(", ")));
+        }
+        match package.cflags {
+            Some(ref cflags) => pc.push_str(&format!("Cflags: -I${{includedir}} {}\n", cflags)),
+            None => pc.push_str("Cflags: -I${includedir}\n"),
+        }
+        pc.push_str(&format!("Libs: {}\n", libs));
+        if let Some(ref libs_private) = package.libs_private {
+            pc.push_str(&format!("Libs.private: {}\n", libs_private));
+        }
+
+        let pc_path = header_path.with_file_name(format!("{}.pc", name));
+        write_if_changed(&pc_path, pc.as_bytes())
+    }
+
+    /// Writes a SWIG interface file wrapping the bindings, which are written
+    /// to `header_path`.
+    pub fn write_swig_interface<F: Write>(&self, mut out: F, header_path: &str) -> io::Result<()> {
+        if self.noop {
+            return Ok(());
+        }
+
+        if self.config.language == Language::Cython {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "SWIG interfaces can only include C or C++ headers",
+            ));
+        }
+
+        let header_path = path::Path::new(header_path);
+        let header = match self.config.swig.header {
+            Some(ref header) => header.clone(),
+            None => format!(
+                "\"{}\"",
+                header_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+            ),
+        };
+        let module = match self.config.swig.module {
+            Some(ref module) => module.clone(),
+            None => header_path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned(),
+        };
+
+        if let Some(ref f) = self.config.autogen_warning {
+            writeln!(out, "{}", f)?;
+            writeln!(out)?;
+        }
+        writeln!(out, "%module {}", module)?;
+        writeln!(out)?;
+        writeln!(out, "%{{")?;
+        writeln!(out, "#include {}", header)?;
+        writeln!(out, "%}}")?;
+        writeln!(out)?;
+        writeln!(out, "%include <stdint.i>")?;
+
+        // Opaque items are only ever handled through pointers, so don't let
+        // SWIG generate constructors and destructors for them. Those with a
+        // `destructor` annotation are destroyed by it instead, once owned by
+        // the target language.
+        for item in &self.items {
+            if let ItemContainer::OpaqueItem(ref opaque) = *item {
+                let name = opaque.export_name();
+                writeln!(out)?;
+                writeln!(out, "%nodefaultctor {};", name)?;
+                let destructor = match opaque.annotations.atom("destructor") {
+                    Some(Some(destructor))
+                        if self.functions.iter().any(|f| f.path.name() == destructor) =>
+                    {
+                        destructor
+                    }
+                    _ => {
+                        writeln!(out, "%nodefaultdtor {};", name)?;
+                        continue;
+                    }
+                };
+                writeln!(out, "%extend {} {{", name)?;
+                writeln!(out, "  ~{}() {{", name)?;
+                writeln!(out, "    {}($self);", destructor)?;
+                writeln!(out, "  }}")?;
+                writeln!(out, "}}")?;
+            }
+        }
+
+        // Hand the values returned with `returns-ownership` over to the
+        // target language, which releases them with the function it names,
+        // and take the first argument back from it when `consumes` lists it.
+        // SWIG can't disown the other arguments of a single function.
+        let mut ownership = Vec::new();
+        for function in &self.functions {
+            if let Some(deallocator) = function.returned_ownership() {
+                let name = function.path.name();
+                ownership.push(format!("%newobject {};", name));
+                if let Some(deallocator) = deallocator {
+                    let mut ret = Vec::new();
+                    cdecl::write_field(
+                        &mut SourceWriter::new(&mut ret as &mut dyn Write, self),
+                        &function.ret,
+                        name,
+                        &self.config,
+                    );
+                    ownership.push(format!(
+                        "%typemap(newfree) {} \"{}($1);\";",
+                        String::from_utf8(ret).unwrap(),
+                        deallocator
+                    ));
+                }
+            }
+            if function.consumed_args().contains(&0) {
+                ownership.push(format!("%delobject {};", function.path.name()));
+            }
+        }
+        if !ownership.is_empty() {
+            writeln!(out)?;
+            for line in ownership {
+                writeln!(out, "{}", line)?;
+            }
+        }
+
+        writeln!(out)?;
+        writeln!(out, "%include {}", header)?;
+        Ok(())
+    }
+
+    /// Writes a libFuzzer target to `dir` for each function taking a buffer,
+    /// including the bindings which are written to `header_path`, and
+    /// returns their paths.
+    pub fn write_fuzz_stubs<P: AsRef<path::Path>>(
+        &self,
+        dir: P,
+        header_path: &str,
+    ) -> io::Result<Vec<path::PathBuf>> {
+        if self.noop {
+            return Ok(Vec::new());
+        }
+
+        let header_name = path::Path::new(header_path)
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        fuzz::write_fuzz_stubs(self, dir.as_ref(), &header_name)
+    }
+
+    /// Writes a section of the Markdown reference: every entry is the name of
+    /// an item, its declaration, its documentation and its documented
+    /// variants or fields.
+    #[allow(clippy::type_complexity)]
+    fn write_markdown_section<F: Write>(
+        &self,
+        out: &mut F,
+        title: &str,
+        entries: &[(&str, String, &Documentation, Vec<(&str, &Documentation)>)],
+    ) -> io::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let code_language = match self.config.language {
+            Language::C => "c",
+            Language::Cxx => "cpp",
+            Language::Cython => "cython",
+        };
+
+        writeln!(out)?;
+        writeln!(out, "## {}", title)?;
+        for (name, declaration, documentation, members) in entries {
+            writeln!(out)?;
+            writeln!(out, "### `{}`", name)?;
+            writeln!(out)?;
+            writeln!(out, "```{}", code_language)?;
+            writeln!(out, "{}", declaration.trim_end())?;
+            writeln!(out, "```")?;
+            let doc_comment = documentation.lines_for_config(&self.config);
+            if !doc_comment.is_empty() {
+                writeln!(out)?;
+                for line in doc_comment.iter() {
+                    writeln!(out, "{}", line.strip_prefix(' ').unwrap_or(line))?;
+                }
+            }
+            if members.iter().any(|(_, doc)| !doc.doc_comment.is_empty()) {
+                writeln!(out)?;
+                for (member, doc) in members {
+                    let doc_comment = doc.lines_for_config(&self.config);
+                    let text: Vec<_> = doc_comment.iter().map(|line| line.trim()).collect();
+                    let text = text.join(" ");
+                    if text.is_empty() {
+                        writeln!(out, "- `{}`", member)?;
+                    } else {
+                        writeln!(out, "- `{}`: {}", member, text)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the bindings to `path`, along with the files which go with
+    /// them, returning whether any of them changed. Files which are already
+    /// up to date aren't rewritten.
     pub fn write_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
         if self.noop {
             return false;
         }
+        let mut changed = false;
+
+        let file_name = path
+            .as_ref()
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+
+        if self.config.package.emit_pc {
+            changed |= self.write_pc_file(path.as_ref());
+        }
+
+        // Without the internal declarations, when they go to their own header.
+        let public = self.public.as_deref().unwrap_or(self);
+
+        if let Some(ref shim_path) = self.config.shim.path {
+            let header_dir = path
+                .as_ref()
+                .parent()
+                .unwrap_or_else(|| path::Path::new(""));
+            let mut contents = Vec::new();
+            public.write_shim(&mut contents, file_name.as_deref().unwrap_or_default());
+            changed |= write_if_changed(&header_dir.join(shim_path), &contents);
+        }
 
-        // Don't compare files if we've never written this file before
-        if !path.as_ref().is_file() {
-            if let Some(parent) = path::Path::new(path.as_ref()).parent() {
-                fs::create_dir_all(parent).unwrap();
+        if let Some(ref wrapper_path) = self.config.cython.wrapper_path {
+            if self.config.language == Language::Cython {
+                let header_dir = path
+                    .as_ref()
+                    .parent()
+                    .unwrap_or_else(|| path::Path::new(""));
+                let module = path
+                    .as_ref()
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let mut contents = Vec::new();
+                self.write_cython_wrappers(&mut contents, &module);
+                changed |= write_if_changed(&header_dir.join(wrapper_path), &contents);
             }
-            self.write(File::create(path).unwrap());
-            return true;
+        }
+
+        if let (Some(internal), Some(internal_path)) =
+            (self.internal.as_ref(), self.config.internal.path.as_ref())
+        {
+            let header_dir = path
+                .as_ref()
+                .parent()
+                .unwrap_or_else(|| path::Path::new(""));
+            // The public header is included relative to the internal one.
+            let mut public_header = String::new();
+            if let Some(parent) = path::Path::new(internal_path).parent() {
+                for _ in parent.components() {
+                    public_header.push_str("../");
+                }
+            }
+            public_header.push_str(file_name.as_deref().unwrap_or_default());
+            let internal_path = header_dir.join(internal_path);
+            let mut contents = Vec::new();
+            internal.write_named(&mut contents, Some(&internal_path), Some(&public_header));
+            let contents = self.maybe_clang_format(contents, &internal_path);
+            changed |= write_if_changed(&internal_path, &contents);
         }
 
         let mut new_file_contents = Vec::new();
-        self.write(&mut new_file_contents);
+        public.write_named(&mut new_file_contents, Some(path.as_ref()), None);
+        let new_file_contents = self.maybe_clang_format(new_file_contents, path.as_ref());
 
-        let mut old_file_contents = Vec::new();
-        {
-            let mut old_file = File::open(&path).unwrap();
-            old_file.read_to_end(&mut old_file_contents).unwrap();
+        changed | write_if_changed(path.as_ref(), &new_file_contents)
+    }
+
+    /// Formats the bindings written to `path` with `clang_format.style`, if
+    /// it's set.
+    fn maybe_clang_format(&self, contents: Vec<u8>, path: &path::Path) -> Vec<u8> {
+        match self.config.clang_format.style {
+            Some(ref style) if self.config.language != Language::Cython => {
+                self.clang_format(contents, style, path)
+            }
+            _ => contents,
         }
+    }
 
-        if old_file_contents != new_file_contents {
-            let mut new_file = File::create(&path).unwrap();
-            new_file.write_all(&new_file_contents).unwrap();
-            true
-        } else {
-            false
+    /// Formats the bindings written to `path` with clang-format, returning
+    /// them unchanged if it fails.
+    fn clang_format(&self, contents: Vec<u8>, style: &str, path: &path::Path) -> Vec<u8> {
+        let program = self
+            .config
+            .clang_format
+            .path
+            .as_deref()
+            .unwrap_or("clang-format");
+        let child = Command::new(program)
+            .arg(format!("--style={}", style))
+            .arg(format!("--assume-filename={}", path.display()))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(err) => {
+                warn!("Couldn't run {}: {}.", program, err);
+                return contents;
+            }
+        };
+
+        // Feed the input from another thread, so that clang-format doesn't
+        // block on a full output pipe while we're still writing.
+        let mut stdin = child.stdin.take().unwrap();
+        let input = contents.clone();
+        let writer = thread::spawn(move || stdin.write_all(&input));
+        let output = child.wait_with_output();
+        let written = writer.join().unwrap();
+        match (output, written) {
+            (Ok(output), Ok(())) if output.status.success() => output.stdout,
+            (Ok(output), Ok(())) => {
+                warn!(
+                    "{} failed: {}",
+                    program,
+                    String::from_utf8_lossy(&output.stderr).trim_end()
+                );
+                contents
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                warn!("Couldn't run {}: {}.", program, err);
+                contents
+            }
         }
     }
 
+    /// Writes everything preceding the declarations, like the built-in backend
+    /// of `Config::language` does.
+    #[allow(unused)]
     pub fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
         if self.noop {
             return;
         }
 
+        match self.config.language {
+            Language::C | Language::Cxx => clike::write_headers(self, out),
+            Language::Cython => cython_backend::write_headers(self, out),
+        }
+    }
+
+    /// Writes the `header`, the include guard and the `autogen_warning`, along
+    /// with the package and cbindgen versions as comments written by
+    /// `comment`, and `#pragma once` if `pragma_once` is set.
+    pub(crate) fn write_preamble<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        comment: impl Fn(&mut SourceWriter<F>, &str),
+        pragma_once: bool,
+    ) {
         if self.config.package_version {
             out.new_line_if_not_start();
-            match self.config.language {
-                Language::C | Language::Cxx => {
-                    write!(out, "/* Package version: {} */", self.package_version);
-                }
-                Language::Cython => {
-                    write!(out, "''' Package version: {} '''", self.package_version);
-                }
-            }
-
+            comment(out, &format!("Package version: {}", self.package_version));
             out.new_line();
         }
 
@@ -231,37 +970,27 @@ impl Bindings {
             write!(out, "{}", f);
             out.new_line();
         }
-        if let Some(f) = self.config.include_guard() {
+        if let Some(f) = self.include_guard(out.file_name()) {
             out.new_line_if_not_start();
             write!(out, "#ifndef {}", f);
             out.new_line();
             write!(out, "#define {}", f);
             out.new_line();
         }
-        if self.config.pragma_once && self.config.language != Language::Cython {
+        if pragma_once {
             out.new_line_if_not_start();
             write!(out, "#pragma once");
             out.new_line();
         }
         if self.config.include_version {
             out.new_line_if_not_start();
-            match self.config.language {
-                Language::C | Language::Cxx => {
-                    write!(
-                        out,
-                        "/* Generated with cbindgen:{} */",
-                        crate::bindgen::config::VERSION
-                    );
-                }
-                Language::Cython => {
-                    write!(
-                        out,
-                        "''' Generated with cbindgen:{} '''",
-                        crate::bindgen::config::VERSION
-                    );
-                }
-            }
-
+            comment(
+                out,
+                &format!(
+                    "Generated with cbindgen:{}",
+                    crate::bindgen::config::VERSION
+                ),
+            );
             out.new_line();
         }
         if let Some(ref f) = self.config.autogen_warning {
@@ -269,218 +998,1726 @@ impl Bindings {
             write!(out, "{}", f);
             out.new_line();
         }
+    }
+
+    /// Writes the `#error` of `compiler_checks` and the macros used by the
+    /// declarations, like those of `version_macros` or of the calling
+    /// conventions.
+    pub(crate) fn write_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.compiler_checks {
+            self.write_language_requirements(out);
+        }
+
+        if let Some(ref prefix) = self.config.version_macros {
+            self.write_version_macros(out, prefix);
+        }
+
+        if self.uses_cuda_qualifiers() {
+            out.new_line_if_not_start();
+            self.write_cuda_macros(out);
+        }
 
-        if self.config.no_includes
-            && self.config.sys_includes().is_empty()
-            && self.config.includes().is_empty()
-            && (self.config.cython.cimports.is_empty() || self.config.language != Language::Cython)
-            && self.config.after_includes.is_none()
+        if self.config.cpp_compatible_c()
+            && self.config.extern_c == ExternC::Item
+            && (!self.functions.is_empty() || !self.globals.is_empty())
         {
-            return;
+            out.new_line_if_not_start();
+            self.write_extern_c_macro(out);
         }
 
-        out.new_line_if_not_start();
+        if self
+            .functions
+            .iter()
+            .any(|function| function.uses_gnu_attributes(&self.config))
+        {
+            out.new_line_if_not_start();
+            self.write_gnu_attribute_macros(out);
+        }
 
-        if !self.config.no_includes {
-            match self.config.language {
-                Language::C => {
-                    out.write("#include <stdarg.h>");
-                    out.new_line();
-                    out.write("#include <stdbool.h>");
-                    out.new_line();
-                    if self.config.usize_is_size_t {
-                        out.write("#include <stddef.h>");
-                        out.new_line();
-                    }
-                    out.write("#include <stdint.h>");
-                    out.new_line();
-                    out.write("#include <stdlib.h>");
-                    out.new_line();
-                }
-                Language::Cxx => {
-                    out.write("#include <cstdarg>");
-                    out.new_line();
-                    if self.config.usize_is_size_t {
-                        out.write("#include <cstddef>");
-                        out.new_line();
-                    }
-                    out.write("#include <cstdint>");
-                    out.new_line();
-                    out.write("#include <cstdlib>");
-                    out.new_line();
-                    out.write("#include <ostream>");
-                    out.new_line();
-                    out.write("#include <new>");
-                    out.new_line();
-                    if self.config.enumeration.cast_assert_name.is_none()
-                        && (self.config.enumeration.derive_mut_casts
-                            || self.config.enumeration.derive_const_casts)
-                    {
-                        out.write("#include <cassert>");
-                        out.new_line();
-                    }
-                }
-                Language::Cython => {
-                    out.write(
-                        "from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t",
-                    );
-                    out.new_line();
-                    out.write(
-                        "from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t",
-                    );
-                    out.new_line();
-                    out.write("cdef extern from *");
-                    out.open_brace();
-                    out.write("ctypedef bint bool");
-                    out.new_line();
-                    out.write("ctypedef struct va_list");
-                    out.new_line();
-                    out.close_brace(false);
-                }
-            }
+        let sal_macros = self.sal_macros();
+        if !sal_macros.is_empty() {
+            out.new_line_if_not_start();
+            self.write_sal_macros(out, &sal_macros);
         }
 
-        for include in self.config.sys_includes() {
-            write!(out, "#include <{}>", include);
-            out.new_line();
+        for calling_convention in StandardIncludes::new(self).calling_conventions {
+            out.new_line_if_not_start();
+            self.write_calling_convention_macro(out, calling_convention);
+        }
+    }
+
+    /// Whether there are includes to write, or `after_includes`.
+    pub(crate) fn has_includes(&self) -> bool {
+        let (item_sys_includes, item_includes) = self.item_includes();
+        !self.config.no_includes
+            || !self.config.sys_includes().is_empty()
+            || !self.config.includes().is_empty()
+            || !item_sys_includes.is_empty()
+            || !item_includes.is_empty()
+            || self.config.after_includes.is_some()
+    }
+
+    /// The standard headers to include: the ones the declarations use with
+    /// `prune_includes`, and all of them otherwise.
+    pub(crate) fn used_includes(&self) -> StandardIncludes {
+        if self.config.prune_includes {
+            return StandardIncludes::new(self);
+        }
+        StandardIncludes {
+            stdarg: true,
+            stdbool: self.config.writes_bool(),
+            stddef: [false, true]
+                .iter()
+                .any(|&signed| matches!(self.config.size_type(signed), "size_t" | "ptrdiff_t")),
+            stdint: true,
+            uchar: self.config.char_type == CharType::Char32,
+            ostream: true,
+            new: true,
+            calling_conventions: Default::default(),
+        }
+    }
+
+    /// Writes the `sys_includes` and `includes`, along with the ones of the
+    /// items, inlining the project headers with `amalgamate`.
+    pub(crate) fn write_includes<F: Write>(&self, out: &mut SourceWriter<F>, amalgamate: bool) {
+        let (item_sys_includes, item_includes) = self.item_includes();
+        let mut sys_includes: Vec<_> = self
+            .config
+            .sys_includes()
+            .iter()
+            .map(|x| (x, None))
+            .chain(item_sys_includes.iter().map(|(x, cfg)| (x, cfg.as_ref())))
+            .collect();
+        let mut includes: Vec<_> = self
+            .config
+            .includes()
+            .iter()
+            .map(|x| (x, None))
+            .chain(item_includes.iter().map(|(x, cfg)| (x, cfg.as_ref())))
+            .collect();
+        if self.config.deterministic {
+            sys_includes.sort_by_key(|(x, _)| *x);
+            includes.sort_by_key(|(x, _)| *x);
         }
 
-        for include in self.config.includes() {
-            write!(out, "#include \"{}\"", include);
+        for (include, cfg) in sys_includes {
+            let condition = cfg.and_then(|cfg| cfg.to_condition(&self.config));
+            condition.write_before(&self.config, out);
+            write!(out, "#include <{}>", include);
+            condition.write_after(&self.config, out);
             out.new_line();
         }
 
-        if self.config.language == Language::Cython {
-            for (module, names) in &self.config.cython.cimports {
-                write!(out, "from {} cimport {}", module, names.join(", "));
+        let mut inlined = HashSet::new();
+        for (include, cfg) in includes {
+            let condition = cfg.and_then(|cfg| cfg.to_condition(&self.config));
+            condition.write_before(&self.config, out);
+            let was_inlined = amalgamate && self.inline_include(out, include, None, &mut inlined);
+            if !was_inlined {
+                write!(out, "#include \"{}\"", include);
+            }
+            condition.write_after(&self.config, out);
+            if !was_inlined || condition.is_some() {
                 out.new_line();
             }
         }
+    }
 
+    /// Writes `after_includes`, following the includes.
+    pub(crate) fn write_after_includes<F: Write>(&self, out: &mut SourceWriter<F>) {
         if let Some(ref line) = self.config.after_includes {
             write!(out, "{}", line);
             out.new_line();
         }
     }
 
-    pub fn write<F: Write>(&self, file: F) {
-        if self.noop {
-            return;
-        }
-
-        let mut out = SourceWriter::new(file, self);
-
-        self.write_headers(&mut out);
-
-        self.open_namespaces(&mut out);
-
-        for constant in &self.constants {
-            if constant.uses_only_primitive_types() {
-                out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
-                out.new_line();
+    /// Writes the contents of a project header instead of including it,
+    /// along with those of the headers it includes in turn, returning whether
+    /// it was found. Each header is inlined once, and headers which aren't
+    /// found in `amalgamate.include_dirs` or next to the header including
+    /// them are left as `#include`s.
+    fn inline_include<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        include: &str,
+        including_dir: Option<&path::Path>,
+        inlined: &mut HashSet<path::PathBuf>,
+    ) -> bool {
+        let found = including_dir
+            .into_iter()
+            .chain(
+                self.config
+                    .amalgamate
+                    .include_dirs
+                    .iter()
+                    .map(path::Path::new),
+            )
+            .map(|dir| dir.join(include))
+            .find(|path| path.is_file());
+        let path = match found {
+            Some(path) => path,
+            None => {
+                warn!("Couldn't find {:?} to inline it.", include);
+                return false;
             }
+        };
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if !inlined.insert(canonical) {
+            return true;
         }
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) => {
+                warn!("Couldn't read {} to inline it: {}", path.display(), err);
+                return false;
+            }
+        };
 
-        for item in &self.items {
-            if item
-                .deref()
-                .annotations()
-                .bool("no-export")
-                .unwrap_or(false)
+        write!(out, "/* Begin of \"{}\" */", include);
+        out.new_line();
+        for line in contents.lines() {
+            let directive = line.trim_start();
+            if directive.starts_with("#pragma once") {
+                continue;
+            }
+            if let Some(nested) = quoted_include(directive) {
+                if self.inline_include(out, nested, path.parent(), inlined) {
+                    continue;
+                }
+            }
+            out.write_raw_block(line);
+            out.new_line();
+        }
+        write!(out, "/* End of \"{}\" */", include);
+        out.new_line();
+        true
+    }
+
+    /// Defines the standard types instead of including the standard headers,
+    /// which freestanding targets may not have.
+    pub(crate) fn write_freestanding_types<F: Write>(&self, out: &mut SourceWriter<F>) {
+        out.write("#ifndef CBINDGEN_FREESTANDING_TYPES");
+        out.new_line();
+        out.write("#define CBINDGEN_FREESTANDING_TYPES");
+        out.new_line();
+        let types = self.config.freestanding.types();
+        for (name, ty) in &types {
+            if *name != "bool" {
+                write!(out, "typedef {} {};", ty, name);
+                out.new_line();
+            }
+        }
+        // `bool` is a keyword in C++.
+        if let Some((_, ty)) = types.iter().find(|(name, _)| *name == "bool") {
+            if self.config.language == Language::C {
+                out.write("#ifndef __cplusplus");
+                out.new_line();
+                write!(out, "typedef {} bool;", ty);
+                out.new_line();
+                out.write("#define true 1");
+                out.new_line();
+                out.write("#define false 0");
+                out.new_line();
+                out.write("#endif");
+                out.new_line();
+            }
+        }
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Whether any function or C++ helper method has a CUDA qualifier.
+    fn uses_cuda_qualifiers(&self) -> bool {
+        let config = &self.config;
+        self.functions
+            .iter()
+            .any(|function| config.cuda_qualifier(&function.annotations).is_some())
+            || (config.language == Language::Cxx
+                && self
+                    .items
+                    .iter()
+                    .any(|item| config.cuda_qualifier(item.deref().annotations()).is_some()))
+    }
+
+    /// Defines the version of the package, for `version_macros`.
+    fn write_version_macros<F: Write>(&self, out: &mut SourceWriter<F>, prefix: &str) {
+        let version = &self.package_version;
+        // Pre-release and build metadata don't go in the numbers.
+        let numbers: Vec<_> = version
+            .split(['-', '+'])
+            .next()
+            .unwrap()
+            .split('.')
+            .map(str::parse::<u64>)
+            .collect();
+        let (major, minor, patch) = match numbers[..] {
+            [Ok(major), Ok(minor), Ok(patch)] => (major, minor, patch),
+            _ => {
+                warn!(
+                    "Can't write the version macros, the package version isn't known or \
+                     isn't major.minor.patch: '{}'.",
+                    version
+                );
+                return;
+            }
+        };
+
+        out.new_line_if_not_start();
+        write!(out, "#define {}_VERSION_MAJOR {}", prefix, major);
+        out.new_line();
+        write!(out, "#define {}_VERSION_MINOR {}", prefix, minor);
+        out.new_line();
+        write!(out, "#define {}_VERSION_PATCH {}", prefix, patch);
+        out.new_line();
+        write!(out, "#define {}_VERSION \"{}\"", prefix, version);
+        out.new_line();
+    }
+
+    /// Defines `CBINDGEN_EXTERN_C`, which gives the declarations C linkage
+    /// in C++ for `extern_c = "item"`, and is `extern` in C.
+    fn write_extern_c_macro<F: Write>(&self, out: &mut SourceWriter<F>) {
+        out.write("#if !defined(CBINDGEN_EXTERN_C)");
+        out.new_line();
+        out.write("#if defined(__cplusplus)");
+        out.new_line();
+        out.write("#define CBINDGEN_EXTERN_C extern \"C\"");
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        out.write("#define CBINDGEN_EXTERN_C extern");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Defines the macros expanding to CUDA qualifiers when compiling CUDA
+    /// code, and to nothing otherwise.
+    fn write_cuda_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let qualifiers = [
+            ("CBINDGEN_CUDA_HOST", "__host__"),
+            ("CBINDGEN_CUDA_DEVICE", "__device__"),
+            ("CBINDGEN_CUDA_HOST_DEVICE", "__host__ __device__"),
+        ];
+        out.write("#if !defined(CBINDGEN_CUDA_HOST_DEVICE)");
+        out.new_line();
+        out.write("#if defined(__CUDACC__)");
+        for (name, qualifier) in qualifiers {
+            out.new_line();
+            write!(out, "#define {} {}", name, qualifier);
+        }
+        out.new_line();
+        out.write("#else");
+        for (name, _) in qualifiers {
+            out.new_line();
+            write!(out, "#define {}", name);
+        }
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Defines the macros expanding to the function attributes of GCC and
+    /// Clang, and to nothing for other compilers. `access` is only known to
+    /// GCC 10 and later.
+    fn write_gnu_attribute_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let attributes = [
+            (
+                "CBINDGEN_NONNULL(...)",
+                "__attribute__((nonnull(__VA_ARGS__)))",
+            ),
+            (
+                "CBINDGEN_WARN_UNUSED_RESULT",
+                "__attribute__((warn_unused_result))",
+            ),
+            ("CBINDGEN_MALLOC", "__attribute__((malloc))"),
+            (
+                "CBINDGEN_ALLOC_SIZE(...)",
+                "__attribute__((alloc_size(__VA_ARGS__)))",
+            ),
+        ];
+        out.write("#if !defined(CBINDGEN_NONNULL)");
+        out.new_line();
+        out.write("#if defined(__GNUC__)");
+        for (name, attribute) in attributes {
+            out.new_line();
+            write!(out, "#define {} {}", name, attribute);
+        }
+        out.new_line();
+        out.write("#else");
+        for (name, _) in attributes {
+            out.new_line();
+            write!(out, "#define {}", name);
+        }
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        out.write("#if defined(__GNUC__) && !defined(__clang__) && __GNUC__ >= 10");
+        out.new_line();
+        out.write("#define CBINDGEN_ACCESS(...) __attribute__((access(__VA_ARGS__)))");
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        out.write("#define CBINDGEN_ACCESS(...)");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// The SAL annotations used by the functions, as the names of the macros
+    /// along with whether they take the length of a buffer.
+    fn sal_macros(&self) -> BTreeSet<(String, bool)> {
+        let config = &self.config;
+        let mut macros = BTreeSet::new();
+        for function in &self.functions {
+            let annotations = function
+                .sal_arg_annotations(config)
+                .into_iter()
+                .flatten()
+                .chain(function.sal_return_annotation(config).map(str::to_owned));
+            for annotation in annotations {
+                let name = match annotation.find('(') {
+                    Some(paren) => (annotation[..paren].to_owned(), true),
+                    None => (annotation, false),
+                };
+                macros.insert(name);
+            }
+        }
+        macros
+    }
+
+    /// Includes the SAL annotations for MSVC, and defines the ones used to
+    /// nothing for other compilers.
+    fn write_sal_macros<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        macros: &BTreeSet<(String, bool)>,
+    ) {
+        out.write("#if defined(_MSC_VER)");
+        out.new_line();
+        out.write("#include <sal.h>");
+        out.new_line();
+        out.write("#else");
+        for (name, takes_length) in macros {
+            out.new_line();
+            write!(out, "#if !defined({})", name);
+            out.new_line();
+            if *takes_length {
+                write!(out, "#define {}(size)", name);
+            } else {
+                write!(out, "#define {}", name);
+            }
+            out.new_line();
+            out.write("#endif");
+        }
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Defines the macro of a calling convention of the function pointers to
+    /// its qualifier where it isn't the one of `extern "C"`, and to nothing
+    /// otherwise. `extern "system"` is `stdcall` on Windows (which compilers
+    /// ignore for 64-bit targets), and `extern "stdcall"` also on 32-bit x86.
+    fn write_calling_convention_macro<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        calling_convention: CallingConvention,
+    ) {
+        let name = calling_convention.macro_name().unwrap();
+        write!(out, "#if !defined({})", name);
+        out.new_line();
+        out.write("#if defined(_WIN32)");
+        out.new_line();
+        write!(out, "#define {} __stdcall", name);
+        if calling_convention == CallingConvention::Stdcall {
+            out.new_line();
+            out.write("#elif defined(__GNUC__) && defined(__i386__)");
+            out.new_line();
+            write!(out, "#define {} __attribute__((stdcall))", name);
+        }
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        write!(out, "#define {}", name);
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// Collects the `cbindgen:include` requirements of the items that are
+    /// written, as (system, local) includes that aren't already part of the
+    /// configured ones, in order of first use. Each include comes with the
+    /// `cfg` it's needed under: none if an item without one requires it, and
+    /// any of those of the items requiring it otherwise.
+    fn item_includes(&self) -> (ItemIncludes, ItemIncludes) {
+        let mut sys_includes: Vec<(String, Option<Vec<Cfg>>)> = Vec::new();
+        let mut includes: Vec<(String, Option<Vec<Cfg>>)> = Vec::new();
+        if self.config.language == Language::Cython {
+            return (Vec::new(), Vec::new());
+        }
+
+        let requirements = self
+            .constants
+            .iter()
+            .map(|x| (&x.annotations, x.cfg.as_ref()))
+            .chain(
+                self.items
+                    .iter()
+                    .map(|x| (x.deref().annotations(), x.deref().cfg()))
+                    .filter(|(x, _)| !x.bool("no-export").unwrap_or(false)),
+            )
+            .chain(
+                self.globals
+                    .iter()
+                    .map(|x| (&x.annotations, x.cfg.as_ref())),
+            )
+            .chain(
+                self.functions
+                    .iter()
+                    .map(|x| (&x.annotations, x.cfg.as_ref())),
+            );
+
+        for (annotations, cfg) in requirements {
+            let requested = match annotations.list("include") {
+                Some(list) => list,
+                None => match annotations.atom("include") {
+                    Some(Some(include)) => vec![include],
+                    _ => continue,
+                },
+            };
+
+            for include in requested {
+                let (list, configured, name) =
+                    match include.strip_prefix('<').and_then(|x| x.strip_suffix('>')) {
+                        Some(name) => (&mut sys_includes, self.config.sys_includes(), name),
+                        None => (
+                            &mut includes,
+                            self.config.includes(),
+                            include.trim_matches('"'),
+                        ),
+                    };
+                if configured.iter().any(|x| x == name) {
+                    continue;
+                }
+                match list.iter_mut().find(|(x, _)| x == name) {
+                    Some((_, cfgs)) => match (cfgs.as_mut(), cfg) {
+                        (Some(cfgs), Some(cfg)) => {
+                            if !cfgs.iter().any(|x| x.to_string() == cfg.to_string()) {
+                                cfgs.push(cfg.clone());
+                            }
+                        }
+                        _ => *cfgs = None,
+                    },
+                    None => list.push((name.to_owned(), cfg.map(|cfg| vec![cfg.clone()]))),
+                }
+            }
+        }
+
+        let with_cfg = |list: Vec<(String, Option<Vec<Cfg>>)>| {
+            list.into_iter()
+                .map(|(name, cfgs)| {
+                    let cfg = cfgs.map(|mut cfgs| match cfgs.len() {
+                        1 => cfgs.remove(0),
+                        _ => Cfg::Any(cfgs),
+                    });
+                    (name, cfg)
+                })
+                .collect()
+        };
+        (with_cfg(sys_includes), with_cfg(includes))
+    }
+
+    pub fn write<F: Write>(&self, file: F) {
+        self.write_named(file, None, None);
+    }
+
+    /// Writes the bindings, knowing that they end up in the header at
+    /// `header_path`, which `#line` directives need to point back to. The
+    /// internal header includes the public one, `public_header`.
+    fn write_named<F: Write>(
+        &self,
+        mut file: F,
+        header_path: Option<&path::Path>,
+        public_header: Option<&str>,
+    ) {
+        if self.noop {
+            return;
+        }
+
+        if self.config.utf8_bom {
+            file.write_all(b"\xEF\xBB\xBF").unwrap();
+        }
+
+        let file_name = header_path
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy());
+        let header_path = header_path.map(|path| path.to_string_lossy());
+        let header_path = header_path.as_deref();
+        let backend = &*self.backend;
+        let mut out = SourceWriter::new(&mut file as &mut dyn Write, self)
+            .with_file_name(file_name.as_deref());
+
+        backend.write_headers(self, &mut out);
+
+        if let Some(public_header) = public_header {
+            out.new_line_if_not_start();
+            write!(out, "#include \"{}\"", public_header);
+            out.new_line();
+        }
+
+        if self.config.defensive_header {
+            self.write_extern_cxx(&mut out, true);
+        }
+
+        backend.open_namespaces(self, &mut out);
+
+        backend.write_doxygen_groups(self, &mut out);
+
+        for constant in &self.constants {
+            if constant.uses_only_primitive_types() {
+                out.new_line_if_not_start();
+                write_annotated(
+                    &mut out,
+                    &constant.annotations,
+                    true,
+                    constant.source_location.as_ref(),
+                    header_path,
+                    |out| backend.write_constant(&self.config, out, constant),
+                );
+                out.new_line();
+            }
+        }
+
+        if !self.forward_declarations.is_empty() {
+            out.new_line_if_not_start();
+            for declaration in &self.forward_declarations {
+                declaration.write(&self.config, &mut out);
+            }
+        }
+
+        let early_boxes: Vec<_> = self
+            .owned_boxes
+            .iter()
+            .filter_map(|name| {
+                let item = self
+                    .items
+                    .iter()
+                    .find(|item| item.deref().export_name() == name);
+                match item {
+                    Some(item) if !self.declares_owned_box_early(item) => None,
+                    _ => Some((item, name)),
+                }
+            })
+            .collect();
+        if !early_boxes.is_empty() {
+            out.new_line_if_not_start();
+            for (item, name) in early_boxes {
+                self.write_owned_box_typedef(&mut out, item, name);
+            }
+        }
+
+        if self.wire_format.has_helpers(&self.config) {
+            out.new_line_if_not_start();
+            self.wire_format.write_swap_bytes(&mut out);
+            out.new_line();
+        }
+
+        let items: Vec<&ItemContainer> = self
+            .items
+            .iter()
+            .filter(|item| {
+                !item
+                    .deref()
+                    .annotations()
+                    .bool("no-export")
+                    .unwrap_or(false)
+            })
+            .collect();
+        // `#line` directives need to know which line of the header they're
+        // on, so the items can only be written to separate buffers without
+        // them. Items start on a fresh line, except for the first one when
+        // nothing came before it.
+        let line_directives = self.config.source_locations == SourceLocations::Line
+            && header_path.is_some()
+            && self.config.language != Language::Cython;
+        let mut module = None;
+        if line_directives || out.line_number() == 1 || items.len() < 2 {
+            for item in items {
+                out.new_line_if_not_start();
+                self.write_module_change(&mut out, &mut module, item.deref().source_location());
+                self.write_item(&mut out, item, header_path);
+                out.new_line();
+            }
+        } else {
+            // Fork after the separator of the first item, which is where all
+            // of the items start.
+            out.new_line_if_not_start();
+            let start = out.fork();
+            let written = self.write_items_in_parallel(&start, &items, header_path);
+            for (i, (item, written)) in items.iter().zip(written).enumerate() {
+                if i > 0 {
+                    out.new_line_if_not_start();
+                }
+                self.write_module_change(&mut out, &mut module, item.deref().source_location());
+                out.append(written);
+                out.new_line();
+            }
+        }
+
+        for constant in &self.constants {
+            if !constant.uses_only_primitive_types() {
+                out.new_line_if_not_start();
+                write_annotated(
+                    &mut out,
+                    &constant.annotations,
+                    true,
+                    constant.source_location.as_ref(),
+                    header_path,
+                    |out| backend.write_constant(&self.config, out, constant),
+                );
+                out.new_line();
+            }
+        }
+
+        let helpers = self.helpers();
+        if !self.functions.is_empty() || !self.globals.is_empty() || !helpers.is_empty() {
+            backend.open_function_block(self, &mut out);
+
+            for global in &self.globals {
+                out.new_line_if_not_start();
+                write_annotated(
+                    &mut out,
+                    &global.annotations,
+                    true,
+                    global.source_location.as_ref(),
+                    header_path,
+                    |out| backend.write_static(&self.config, out, global),
+                );
+                out.new_line();
+            }
+
+            // Functions handle their prefix and postfix annotations themselves.
+            let mut section = None;
+            let mut module = None;
+            let lifecycle_functions = self.enum_lifecycle_functions();
+            for function in &self.functions {
+                if lifecycle_functions.contains(function.path.name()) {
+                    continue;
+                }
+                out.new_line_if_not_start();
+                if self.write_module_change(
+                    &mut out,
+                    &mut module,
+                    function.source_location.as_ref(),
+                ) {
+                    section = None;
+                }
+                if let Some(Some(function_section)) = function.annotations.atom("section") {
+                    if section.as_ref() != Some(&function_section) {
+                        backend.write_section_banner(self, &mut out, &function_section);
+                        out.new_line();
+                        out.new_line();
+                        section = Some(function_section);
+                    }
+                }
+                write_annotated(
+                    &mut out,
+                    &function.annotations,
+                    false,
+                    function.source_location.as_ref(),
+                    header_path,
+                    |out| backend.write_function(&self.config, out, function),
+                );
+                out.new_line();
+            }
+
+            for helper in &helpers {
+                out.new_line_if_not_start();
+                if self.config.shim.path.is_some() {
+                    helper.write_declaration(&self.config, &mut out);
+                } else {
+                    helper.write_definition(&self.config, &mut out, true);
+                }
+                out.new_line();
+            }
+
+            backend.close_function_block(self, &mut out);
+
+            backend.write_forwarding_operators(self, &mut out);
+            backend.write_owned_pointers(self, &mut out);
+
+            if self
+                .functions
+                .iter()
+                .any(|f| f.annotations.atom("version").is_some())
             {
+                backend.write_symbol_versions(self, &mut out);
+            }
+
+            if self.functions.iter().any(|f| !f.aliases().is_empty()) {
+                backend.write_function_aliases(self, &mut out);
+            }
+        }
+
+        backend.close_namespaces(self, &mut out);
+
+        if !self.config.export.macros.is_empty() {
+            backend.write_export_macros(self, &mut out);
+        }
+
+        backend.write_footers(self, &mut out);
+
+        if self.config.final_newline {
+            out.end_line();
+        }
+    }
+
+    fn write_item(
+        &self,
+        out: &mut SourceWriter<&mut dyn Write>,
+        item: &ItemContainer,
+        header_path: Option<&str>,
+    ) {
+        let backend = &*self.backend;
+        write_annotated(
+            out,
+            item.deref().annotations(),
+            true,
+            item.deref().source_location(),
+            header_path,
+            |out| {
+                match *item {
+                    ItemContainer::Constant(..) => unreachable!(),
+                    ItemContainer::Static(..) => unreachable!(),
+                    ItemContainer::Enum(ref x) => {
+                        // The C++ destructor and copy constructor call them.
+                        if self.config.language == Language::Cxx {
+                            self.write_enum_lifecycle_declarations(out, x, header_path);
+                        }
+                        backend.write_enum(&self.config, out, x);
+                        if self.config.language != Language::Cxx {
+                            self.write_enum_lifecycle_declarations(out, x, header_path);
+                        }
+                    }
+                    ItemContainer::Struct(ref x) => backend.write_struct(&self.config, out, x),
+                    ItemContainer::Union(ref x) => backend.write_union(&self.config, out, x),
+                    ItemContainer::OpaqueItem(ref x) => {
+                        backend.write_opaque_item(&self.config, out, x)
+                    }
+                    ItemContainer::Typedef(ref x) => backend.write_typedef(&self.config, out, x),
+                }
+                self.layouts.write_static_asserts(out, item);
+                self.wire_format.write_helper(out, item);
+                if !self.declares_owned_box_early(item) {
+                    self.write_owned_box_typedef(out, Some(item), item.deref().export_name());
+                }
+            },
+        );
+    }
+
+    /// Writes each item separately from `start`, spreading them over as many
+    /// threads as there are cores. They're returned in the order of `items`.
+    fn write_items_in_parallel(
+        &self,
+        start: &Fork,
+        items: &[&ItemContainer],
+        header_path: Option<&str>,
+    ) -> Vec<Forked> {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = (items.len() + threads - 1) / threads;
+        thread::scope(|scope| {
+            let chunks: Vec<_> = items
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|item| start.write(|out| self.write_item(out, item, header_path)))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            chunks
+                .into_iter()
+                .flat_map(|chunk| chunk.join().unwrap())
+                .collect()
+        })
+    }
+
+    /// Writes the banner of the module a declaration at `location` comes
+    /// from with `group_by_module`, when it isn't the module of the previous
+    /// declaration, and returns whether it did.
+    fn write_module_change(
+        &self,
+        out: &mut BackendWriter,
+        module: &mut Option<String>,
+        location: Option<&SourceLocation>,
+    ) -> bool {
+        if !self.config.group_by_module {
+            return false;
+        }
+        let next = source_module(location);
+        if next == *module {
+            return false;
+        }
+        let name = next.as_deref().unwrap_or("crate");
+        self.backend.write_module_banner(self, out, name);
+        out.new_line();
+        out.new_line();
+        *module = next;
+        true
+    }
+
+    /// The functions named by the `destructor` and `clone` annotations of
+    /// enums with fields, which are declared next to the enums rather than
+    /// with the other functions.
+    fn enum_lifecycle_functions(&self) -> HashSet<String> {
+        let mut functions = HashSet::new();
+        for item in &self.items {
+            if let ItemContainer::Enum(ref x) = *item {
+                functions.extend(x.destructor_function());
+                functions.extend(x.clone_function());
+            }
+        }
+        functions
+    }
+
+    /// Declares the functions named by the `destructor` and `clone`
+    /// annotations of an enum with fields, before it in C++, where its
+    /// destructor and copy constructor call them, and after it otherwise.
+    fn write_enum_lifecycle_declarations(
+        &self,
+        out: &mut SourceWriter<&mut dyn Write>,
+        x: &Enum,
+        header_path: Option<&str>,
+    ) {
+        let functions: Vec<&Function> = [x.destructor_function(), x.clone_function()]
+            .iter()
+            .flatten()
+            .filter_map(|name| self.functions.iter().find(|f| f.path.name() == name))
+            .collect();
+        if functions.is_empty() {
+            return;
+        }
+
+        let config = &self.config;
+        let extern_c_block = config.extern_c_item().is_none()
+            && (config.language == Language::Cxx || config.cpp_compatible_c());
+        let condition = x.cfg.to_condition(config);
+        if config.language == Language::Cxx {
+            condition.write_before(config, out);
+            write!(out, "{} {};", x.tagged_keyword(), x.export_name());
+            out.new_line();
+        } else {
+            out.new_line();
+            out.new_line();
+            condition.write_before(config, out);
+        }
+        if extern_c_block {
+            if config.language != Language::Cxx {
+                out.write("#ifdef __cplusplus");
+                out.new_line();
+            }
+            out.write("extern \"C\" {");
+            out.new_line();
+            if config.language != Language::Cxx {
+                out.write("#endif // __cplusplus");
+                out.new_line();
+            }
+        }
+        for (i, function) in functions.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write_annotated(
+                out,
+                &function.annotations,
+                false,
+                function.source_location.as_ref(),
+                header_path,
+                |out| self.backend.write_function(config, out, function),
+            );
+        }
+        if extern_c_block {
+            out.new_line();
+            if config.language != Language::Cxx {
+                out.write("#ifdef __cplusplus");
+                out.new_line();
+            }
+            out.write("} // extern \"C\"");
+            if config.language != Language::Cxx {
+                out.new_line();
+                out.write("#endif // __cplusplus");
+            }
+        }
+        condition.write_after(config, out);
+        if config.language == Language::Cxx {
+            out.new_line();
+            out.new_line();
+        }
+    }
+
+    fn helpers(&self) -> Vec<Helper<'_>> {
+        Helper::collect(&self.config, &self.items, &self.functions)
+    }
+
+    /// The operators of structs which forward to an exported function, with
+    /// the name of the operator's trait method.
+    fn forwarding_operators(&self) -> Vec<(&Struct, String, &Function)> {
+        let mut operators = Vec::new();
+        for item in &self.items {
+            let x = match *item {
+                ItemContainer::Struct(ref x) => x,
+                _ => continue,
+            };
+            for op in x.derived_operators(&self.config) {
+                let name = match x.operator_function(&op) {
+                    Some(name) => name,
+                    None => continue,
+                };
+                let (_, is_unary) = cxx_operator(&op).unwrap();
+                match self.functions.iter().find(|f| f.path.name() == name) {
+                    Some(function)
+                        if function.args.len() == if is_unary { 1 } else { 2 }
+                            && function
+                                .args
+                                .iter()
+                                .all(|arg| operand_prefix(x, &arg.ty).is_some()) =>
+                    {
+                        operators.push((x, op, function))
+                    }
+                    Some(_) => warn!(
+                        "Can't forward the {} operator of {} to {}, which doesn't take {} {} \
+                         arguments by value or const pointer.",
+                        op,
+                        x.export_name(),
+                        name,
+                        if is_unary { 1 } else { 2 },
+                        x.export_name()
+                    ),
+                    None => warn!(
+                        "Can't forward the {} operator of {} to {}, which isn't exported.",
+                        op,
+                        x.export_name(),
+                        name
+                    ),
+                }
+            }
+        }
+        operators
+    }
+
+    /// Writes the operators forwarding to the functions named by `{op}-function`
+    /// annotations, as inline functions found through argument-dependent lookup.
+    pub(crate) fn write_forwarding_operators<F: Write>(&self, out: &mut SourceWriter<F>) {
+        for (x, op, function) in self.forwarding_operators() {
+            let (operator, is_unary) = cxx_operator(&op).unwrap();
+            let name = x.export_name();
+            let mut ret = Vec::new();
+            cdecl::write_type(
+                &mut SourceWriter::new(&mut ret as &mut dyn Write, self),
+                &function.ret,
+                &self.config,
+            );
+            let ret = String::from_utf8(ret).unwrap();
+
+            out.new_line();
+            let condition = x.cfg.to_condition(&self.config);
+            condition.write_before(&self.config, out);
+            if is_unary {
+                write!(
+                    out,
+                    "inline {} operator{}(const {}& value)",
+                    ret, operator, name
+                );
+                out.open_brace();
+                write!(
+                    out,
+                    "return {}({}value);",
+                    function.path.name(),
+                    operand_prefix(x, &function.args[0].ty).unwrap()
+                );
+                out.close_brace(false);
+            } else {
+                write!(
+                    out,
+                    "inline {} operator{}(const {}& lhs, const {}& rhs)",
+                    ret, operator, name, name
+                );
+                out.open_brace();
+                write!(
+                    out,
+                    "return {}({}lhs, {}rhs);",
+                    function.path.name(),
+                    operand_prefix(x, &function.args[0].ty).unwrap(),
+                    operand_prefix(x, &function.args[1].ty).unwrap()
+                );
+                out.close_brace(false);
+                if ret == name {
+                    out.new_line();
+                    out.new_line();
+                    write!(
+                        out,
+                        "inline {}& operator{}=({}& lhs, const {}& rhs)",
+                        name, operator, name, name
+                    );
+                    out.open_brace();
+                    write!(out, "lhs = lhs {} rhs;", operator);
+                    out.new_line();
+                    out.write("return lhs;");
+                    out.close_brace(false);
+                }
+            }
+            condition.write_after(&self.config, out);
+            out.new_line();
+        }
+    }
+
+    /// The opaque types annotated with `destructor` in C++, with the function
+    /// destroying them.
+    pub(crate) fn owned_pointers(&self) -> Vec<(&OpaqueItem, &Function)> {
+        if self.config.language != Language::Cxx {
+            return Vec::new();
+        }
+        let mut owned = Vec::new();
+        for item in &self.items {
+            let opaque = match *item {
+                ItemContainer::OpaqueItem(ref x) => x,
+                _ => continue,
+            };
+            let destructor = match opaque.annotations.atom("destructor") {
+                Some(Some(destructor)) => destructor,
+                _ => continue,
+            };
+            if !opaque.generic_params.is_empty() {
+                warn!(
+                    "Can't generate a std::unique_ptr for {}, which is generic.",
+                    opaque.export_name()
+                );
                 continue;
             }
+            match self.functions.iter().find(|f| f.path.name() == destructor) {
+                Some(function) => owned.push((opaque, function)),
+                None => warn!(
+                    "Can't generate a std::unique_ptr for {}, its destructor {} isn't exported.",
+                    opaque.export_name(),
+                    destructor
+                ),
+            }
+        }
+        owned
+    }
+
+    /// Writes a deleter calling the destructor of each opaque type annotated
+    /// with `destructor`, and a `{Type}Ptr` alias of `std::unique_ptr` using it.
+    pub(crate) fn write_owned_pointers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        for (opaque, destructor) in self.owned_pointers() {
+            let name = opaque.export_name();
+            out.new_line();
+            let condition = opaque.cfg.to_condition(&self.config);
+            condition.write_before(&self.config, out);
+            write!(out, "struct {}Deleter", name);
+            out.open_brace();
+            write!(out, "void operator()({} *ptr) const", name);
+            out.open_brace();
+            write!(out, "{}(ptr);", destructor.path.name());
+            out.close_brace(false);
+            out.close_brace(true);
+            out.new_line();
+            out.new_line();
+            write!(
+                out,
+                "using {}Ptr = std::unique_ptr<{}, {}Deleter>;",
+                name, name, name
+            );
+            condition.write_after(&self.config, out);
+            out.new_line();
+        }
+    }
+
+    /// Whether the `{T}Owned` typedef of a box pointing to `item` is declared
+    /// before the items, with the struct or union keyword, so that `item`
+    /// can point to itself. Other typedefs come after the item, as do all of
+    /// them with the `type` style, which leaves the structs without a tag.
+    fn declares_owned_box_early(&self, item: &ItemContainer) -> bool {
+        (self.config.language == Language::Cxx || self.config.style.generate_tag())
+            && matches!(owned_box_keyword(item), Some("struct") | Some("union"))
+    }
+
+    /// Writes the `{T}Owned` typedef of `ptr.owned_box = "typedef"` for a box
+    /// pointing to `name`, if boxes point to it. Types which aren't among
+    /// the items are expected to be declared by the includes.
+    fn write_owned_box_typedef<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        item: Option<&ItemContainer>,
+        name: &str,
+    ) {
+        if !self.owned_boxes.contains(name) {
+            return;
+        }
+        let early = item.map_or(true, |item| self.declares_owned_box_early(item));
+        let keyword = item.and_then(owned_box_keyword);
+        // Before the items, the keyword declares the type; after them, it's
+        // only needed to refer to it in C with the `tag` style.
+        let tag =
+            early || (self.config.language == Language::C && !self.config.style.generate_typedef());
+        let pointee = match keyword {
+            Some(keyword) if tag => format!("{} {}", keyword, name),
+            _ => name.to_owned(),
+        };
+        let condition = item
+            .and_then(|item| item.deref().cfg().cloned())
+            .to_condition(&self.config);
+        if !early {
+            out.new_line();
+            out.new_line();
+        }
+        condition.write_before(&self.config, out);
+        match self.config.language {
+            Language::Cxx => write!(out, "using {}Owned = {}*;", name, pointee),
+            _ => write!(out, "typedef {} *{}Owned;", pointee, name),
+        }
+        condition.write_after(&self.config, out);
+        if early {
+            out.new_line();
+        }
+    }
+
+    /// Whether the header defines helpers using `assert`.
+    pub(crate) fn needs_assert(&self) -> bool {
+        self.config.shim.path.is_none() && self.helpers().iter().any(Helper::needs_assert)
+    }
 
+    /// Writes the source file defining the helper functions which the
+    /// header only declares, see `shim.path`.
+    pub fn write_shim<F: Write>(&self, mut file: F, header_name: &str) {
+        let mut out = SourceWriter::new(&mut file as &mut dyn Write, self);
+        if let Some(ref f) = self.config.header {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
             out.new_line_if_not_start();
-            match *item {
-                ItemContainer::Constant(..) => unreachable!(),
-                ItemContainer::Static(..) => unreachable!(),
-                ItemContainer::Enum(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Struct(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Union(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::OpaqueItem(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Typedef(ref x) => x.write(&self.config, &mut out),
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        let helpers = self.helpers();
+        out.new_line_if_not_start();
+        if helpers.iter().any(Helper::needs_assert) {
+            match self.config.language {
+                Language::Cxx => out.write("#include <cassert>"),
+                _ => out.write("#include <assert.h>"),
             }
             out.new_line();
         }
+        write!(out, "#include \"{}\"", header_name);
+        out.new_line();
+
+        for helper in &helpers {
+            out.new_line();
+            helper.write_definition(&self.config, &mut out, false);
+            out.new_line();
+        }
+    }
 
-        for constant in &self.constants {
-            if !constant.uses_only_primitive_types() {
-                out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
+    /// Writes a `.pyx` module of classes wrapping the opaque types annotated
+    /// with `destructor`, see `cython.wrapper_path`. `module` is the name of the
+    /// `.pxd` file the bindings are written to, without its extension.
+    pub fn write_cython_wrappers<F: Write>(&self, file: F, module: &str) {
+        cython::write_wrappers(self, file, module);
+    }
+
+    pub(crate) fn open_function_block<F: Write>(&self, out: &mut SourceWriter<F>) {
+        // With `extern_c = "item"`, every declaration has its own `extern "C"`.
+        let block = self.config.extern_c == ExternC::Block;
+        if block && self.config.cpp_compatible_c() {
+            out.new_line_if_not_start();
+            out.write("#ifdef __cplusplus");
+        }
+
+        if self.config.language == Language::Cxx {
+            if let Some(ref using_namespaces) = self.config.using_namespaces {
+                for namespace in using_namespaces {
+                    out.new_line();
+                    write!(out, "using namespace {};", namespace);
+                }
                 out.new_line();
             }
         }
 
-        if !self.functions.is_empty() || !self.globals.is_empty() {
-            if self.config.cpp_compatible_c() {
-                out.new_line_if_not_start();
-                out.write("#ifdef __cplusplus");
-            }
+        if block && (self.config.language == Language::Cxx || self.config.cpp_compatible_c()) {
+            out.new_line();
+            out.write("extern \"C\" {");
+            out.new_line();
+        }
 
-            if self.config.language == Language::Cxx {
-                if let Some(ref using_namespaces) = self.config.using_namespaces {
-                    for namespace in using_namespaces {
-                        out.new_line();
-                        write!(out, "using namespace {};", namespace);
+        if block && self.config.cpp_compatible_c() {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    pub(crate) fn close_function_block<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.extern_c != ExternC::Block {
+            return;
+        }
+
+        if self.config.cpp_compatible_c() {
+            out.new_line();
+            out.write("#ifdef __cplusplus");
+        }
+
+        if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
+            out.new_line();
+            out.write("} // extern \"C\"");
+            out.new_line();
+        }
+
+        if self.config.cpp_compatible_c() {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    /// Writes the `#define`s pointing the unversioned names of the functions
+    /// annotated with `version` at their latest version, and the `.symver`
+    /// directives binding each version to its ELF version node.
+    pub(crate) fn write_symbol_versions<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let suffix = &self.config.function.version_suffix;
+        let mut latest: Vec<(String, String, &Function)> = Vec::new();
+        let mut versioned = Vec::new();
+        for function in &self.functions {
+            let version = match function.annotations.atom("version") {
+                Some(Some(version)) => version,
+                _ => continue,
+            };
+            let name = function.path.name();
+            let base = match name.strip_suffix(&suffix.replace("{}", &version)) {
+                Some(base) if !base.is_empty() => base.to_owned(),
+                _ => {
+                    warn!(
+                        "Function {} doesn't end with the suffix of version {}.",
+                        name, version
+                    );
+                    continue;
+                }
+            };
+            versioned.push((base.clone(), version.clone(), function));
+            match latest.iter_mut().find(|(b, _, _)| *b == base) {
+                Some(entry) => {
+                    if version_is_newer(&version, &entry.1) {
+                        *entry = (base, version, function);
                     }
-                    out.new_line();
                 }
+                None => latest.push((base, version, function)),
             }
+        }
 
-            if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
-                out.new_line();
-                out.write("extern \"C\" {");
+        out.new_line_if_not_start();
+        for (i, (base, _, function)) in latest.iter().enumerate() {
+            if i != 0 {
                 out.new_line();
             }
+            let condition = function.cfg.to_condition(&self.config);
+            condition.write_before(&self.config, out);
+            write!(out, "#define {} {}", base, function.path.name());
+            condition.write_after(&self.config, out);
+        }
 
-            if self.config.cpp_compatible_c() {
-                out.write("#endif // __cplusplus");
+        if let Some(ref node) = self.config.function.symver_node {
+            out.new_line();
+            out.new_line();
+            out.write("#if defined(__ELF__)");
+            for (base, version, function) in &versioned {
                 out.new_line();
+                let condition = function.cfg.to_condition(&self.config);
+                condition.write_before(&self.config, out);
+                write!(
+                    out,
+                    "__asm__(\".symver {}, {}@{}\");",
+                    function.path.name(),
+                    base,
+                    node.replace("{}", version)
+                );
+                condition.write_after(&self.config, out);
             }
+            out.new_line();
+            out.write("#endif");
+        }
+        out.new_line();
+    }
 
-            for global in &self.globals {
-                out.new_line_if_not_start();
-                global.write(&self.config, &mut out);
-                out.new_line();
+    /// Writes a `#define` for each alternate name of the functions annotated
+    /// with `alias`, in the condition of the function.
+    pub(crate) fn write_function_aliases<F: Write>(&self, out: &mut SourceWriter<F>) {
+        out.new_line_if_not_start();
+        let mut first = true;
+        for function in &self.functions {
+            let aliases = function.aliases();
+            if aliases.is_empty() {
+                continue;
             }
-
-            for function in &self.functions {
-                out.new_line_if_not_start();
-                function.write(&self.config, &mut out);
+            if !first {
                 out.new_line();
             }
+            first = false;
+            let condition = function.cfg.to_condition(&self.config);
+            condition.write_before(&self.config, out);
+            for (i, alias) in aliases.iter().enumerate() {
+                if i != 0 {
+                    out.new_line();
+                }
+                write!(out, "#define {} {}", alias, function.path.name());
+            }
+            condition.write_after(&self.config, out);
+        }
+        out.new_line();
+    }
 
-            if self.config.cpp_compatible_c() {
-                out.new_line();
-                out.write("#ifdef __cplusplus");
+    /// Writes a `#define` for each of `export.macros`, with the `{Item}`
+    /// placeholders in its body replaced by the exported names.
+    pub(crate) fn write_export_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        // Types and constants are declared in the namespaces in C++, but the
+        // macros may be used outside of them.
+        let mut qualifier = String::new();
+        if self.config.language == Language::Cxx {
+            for namespace in self.all_namespaces() {
+                qualifier.push_str(namespace);
+                qualifier.push_str("::");
             }
+        }
 
-            if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
-                out.new_line();
-                out.write("} // extern \"C\"");
+        let mut names: HashMap<&str, String> = HashMap::new();
+        for item in &self.items {
+            let item = item.deref();
+            names
+                .entry(item.path().name())
+                .or_insert_with(|| format!("{}{}", qualifier, item.export_name()));
+        }
+        for constant in &self.constants {
+            names
+                .entry(constant.path.name())
+                .or_insert_with(|| format!("{}{}", qualifier, constant.export_name()));
+        }
+        for function in &self.functions {
+            names
+                .entry(function.path.name())
+                .or_insert_with(|| function.path.name().to_owned());
+        }
+        for global in &self.globals {
+            names
+                .entry(global.path.name())
+                .or_insert_with(|| global.export_name().to_owned());
+        }
+
+        out.new_line_if_not_start();
+        for (i, (name, body)) in self.config.export.macros.iter().enumerate() {
+            if i != 0 {
                 out.new_line();
             }
+            let body = replace_placeholders(body.trim(), |placeholder| {
+                let exported = names.get(placeholder).cloned();
+                if exported.is_none() {
+                    warn!(
+                        "Macro {} refers to {}, which isn't exported.",
+                        name, placeholder
+                    );
+                }
+                exported
+            });
+            write!(out, "#define {} ", name);
+            // Multi-line bodies are continued with backslashes.
+            for (j, line) in body.lines().enumerate() {
+                if j != 0 {
+                    out.write(" \\");
+                    out.new_line();
+                }
+                write!(out, "{}", line);
+            }
+        }
+        out.new_line();
+    }
 
-            if self.config.cpp_compatible_c() {
-                out.write("#endif // __cplusplus");
-                out.new_line();
+    /// Writes a `\defgroup` for each Doxygen group the declarations are in,
+    /// in order of appearance.
+    pub(crate) fn write_doxygen_groups<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if !self.config.documentation {
+            return;
+        }
+
+        let doxygen = &self.config.doxygen;
+        let mut groups: Vec<String> = Vec::new();
+        let declarations = self
+            .constants
+            .iter()
+            .map(|x| (&x.annotations, x.source_location.as_ref()))
+            .chain(
+                self.items
+                    .iter()
+                    .map(|x| (x.deref().annotations(), x.deref().source_location())),
+            )
+            .chain(
+                self.globals
+                    .iter()
+                    .map(|x| (&x.annotations, x.source_location.as_ref())),
+            )
+            .chain(
+                self.functions
+                    .iter()
+                    .map(|x| (&x.annotations, x.source_location.as_ref())),
+            );
+        for (annotations, location) in declarations {
+            if let Some(group) = doxygen.group(annotations, location) {
+                if !groups.contains(&group) {
+                    groups.push(group);
+                }
             }
         }
+        if groups.is_empty() {
+            return;
+        }
 
-        if self.config.language == Language::Cython
-            && self.globals.is_empty()
+        out.new_line_if_not_start();
+        for group in &groups {
+            write!(
+                out,
+                "/** \\defgroup {} {} */",
+                DoxygenConfig::group_name(group),
+                doxygen.group_title(group)
+            );
+            out.new_line();
+        }
+    }
+
+    /// Whether the bindings declare nothing at all.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.globals.is_empty()
             && self.constants.is_empty()
             && self.items.is_empty()
             && self.functions.is_empty()
-        {
-            out.write("pass");
+    }
+
+    pub(crate) fn write_section_banner<F: Write>(&self, out: &mut SourceWriter<F>, section: &str) {
+        match self.config.function.section_banner {
+            Some(ref banner) => write_lines(out, &banner.replace("{}", section)),
+            None => write!(out, "/* {} */", section),
+        }
+    }
+
+    pub(crate) fn write_module_banner<F: Write>(&self, out: &mut SourceWriter<F>, module: &str) {
+        match self.config.module_banner {
+            Some(ref banner) => write_lines(out, &banner.replace("{}", module)),
+            None => write!(out, "/* ===== module: {} ===== */", module),
+        }
+    }
+
+    /// The include guard, with the `{crate}`, `{version}` and `{file}`
+    /// placeholders replaced by upper-case identifiers made of the crate name,
+    /// its version and the name of the file written, or the crate name when
+    /// it isn't known.
+    ///
+    /// With `defensive_header` and no `include_guard`, it's made of the crate
+    /// name and the name of the file.
+    fn include_guard(&self, file_name: Option<&str>) -> Option<String> {
+        let guard = match self.config.include_guard() {
+            Some(guard) => guard,
+            None if self.config.defensive_header && self.config.language != Language::Cython => {
+                match (self.package_name.is_empty(), file_name) {
+                    (false, Some(_)) => "{crate}_{file}",
+                    (true, None) => "CBINDGEN_BINDINGS_H",
+                    _ => "{file}",
+                }
+            }
+            None => return None,
+        };
+        if !guard.contains('{') {
+            return Some(guard.to_owned());
+        }
+        let identifier = |s: &str| -> String {
+            s.chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_uppercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect()
+        };
+        Some(
+            guard
+                .replace("{crate}", &identifier(&self.package_name))
+                .replace("{version}", &identifier(&self.package_version))
+                .replace(
+                    "{file}",
+                    &identifier(file_name.unwrap_or(&self.package_name)),
+                ),
+        )
+    }
+
+    /// Opens or closes the `extern "C++"` block of `defensive_header`, which
+    /// keeps the C++ declarations from getting C linkage when the header is
+    /// included from an `extern "C"` block. It's opened after the includes,
+    /// which have to be outside of any linkage specification. The functions and
+    /// globals are in an `extern "C"` block of their own.
+    fn write_extern_cxx<F: Write>(&self, out: &mut SourceWriter<F>, open: bool) {
+        let guarded = match self.config.language {
+            Language::C => true,
+            Language::Cxx => false,
+            Language::Cython => return,
+        };
+        out.new_line_if_not_start();
+        if guarded {
+            out.write("#ifdef __cplusplus");
+            out.new_line();
         }
+        if open {
+            out.write("extern \"C++\" {");
+        } else {
+            out.write("} // extern \"C++\"");
+        }
+        out.new_line();
+        if guarded {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    /// The features of C or C++ the header uses which need the most recent
+    /// version of the language, with the name of the version and the value
+    /// of `__STDC_VERSION__` or `__cplusplus` it starts at.
+    fn language_requirements(&self) -> Option<(&'static str, &'static str, Vec<&'static str>)> {
+        let config = &self.config;
+        let asserts = self.layouts.has_static_asserts(config);
+        match config.language {
+            Language::C => {
+                let used = StandardIncludes::new(self);
+                let anonymous = self.items.iter().any(|item| match *item {
+                    ItemContainer::Enum(ref x) if x.tag.is_some() => {
+                        x.repr.style == ReprStyle::C
+                            || x.variants.iter().any(|variant| {
+                                matches!(variant.body, VariantBody::Body { inline: true, .. })
+                            })
+                    }
+                    _ => false,
+                });
+                let c11 = [
+                    (anonymous, "anonymous structs and unions"),
+                    (asserts, "static_assert"),
+                    (used.uchar, "char32_t"),
+                ];
+                let c99 = [
+                    (used.stdbool && config.writes_bool(), "bool"),
+                    (used.stdint, "fixed-width integers"),
+                    (self.wire_format.has_helpers(config), "inline functions"),
+                    (
+                        config.documentation
+                            && matches!(
+                                config.documentation_style,
+                                DocumentationStyle::C99 | DocumentationStyle::Cxx
+                            ),
+                        "`//` comments",
+                    ),
+                ];
+                let features = |features: &[(bool, &'static str)]| -> Vec<_> {
+                    features
+                        .iter()
+                        .filter(|(used, _)| *used)
+                        .map(|(_, feature)| *feature)
+                        .collect()
+                };
+                let (c11, c99) = (features(&c11), features(&c99));
+                if !c11.is_empty() {
+                    Some(("C11", "201112L", c11))
+                } else if !c99.is_empty() {
+                    Some(("C99", "199901L", c99))
+                } else {
+                    None
+                }
+            }
+            Language::Cxx => {
+                let enum_class = self.items.iter().any(|item| match *item {
+                    ItemContainer::Enum(ref x) => {
+                        x.tag.is_some() || config.enumeration.enum_class(&x.annotations)
+                    }
+                    _ => false,
+                });
+                let alias = self
+                    .items
+                    .iter()
+                    .any(|item| matches!(*item, ItemContainer::Typedef(..)));
+                let constexpr = config.constant.allow_constexpr && !self.constants.is_empty();
+                let features: Vec<_> = [
+                    (enum_class, "enum class"),
+                    (alias, "alias declarations"),
+                    (constexpr, "constexpr"),
+                    (asserts, "static_assert"),
+                ]
+                .iter()
+                .filter(|(used, _)| *used)
+                .map(|(_, feature)| *feature)
+                .collect();
+                if features.is_empty() {
+                    None
+                } else {
+                    Some(("C++11", "201103L", features))
+                }
+            }
+            Language::Cython => None,
+        }
+    }
 
-        self.close_namespaces(&mut out);
+    /// Writes the `#error` of `compiler_checks`, stopping compilers which
+    /// don't support the version of C or C++ the header needs.
+    fn write_language_requirements<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let (version, value, features) = match self.language_requirements() {
+            Some(requirements) => requirements,
+            None => return,
+        };
+        out.new_line_if_not_start();
+        match self.config.language {
+            // MSVC only sets `__cplusplus` right with `/Zc:__cplusplus`.
+            Language::Cxx => write!(
+                out,
+                "#if (defined(_MSVC_LANG) ? _MSVC_LANG : __cplusplus) < {}",
+                value
+            ),
+            _ => write!(
+                out,
+                "#if !defined(__cplusplus) && (!defined(__STDC_VERSION__) || __STDC_VERSION__ < {})",
+                value
+            ),
+        }
+        out.new_line();
+        let features = match features.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => features.concat(),
+        };
+        write!(
+            out,
+            "#error \"This header needs {} or later, for {}.\"",
+            version, features
+        );
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
 
-        if let Some(f) = self.config.include_guard() {
+    pub(crate) fn write_footers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.defensive_header {
+            self.write_extern_cxx(out, false);
+        }
+        if let Some(f) = self.include_guard(out.file_name()) {
             out.new_line_if_not_start();
             if self.config.language == Language::C {
                 write!(out, "#endif /* {} */", f);
@@ -489,6 +2726,10 @@ impl Bindings {
             }
             out.new_line();
         }
+        self.write_trailer(out);
+    }
+
+    pub(crate) fn write_trailer<F: Write>(&self, out: &mut SourceWriter<F>) {
         if let Some(ref f) = self.config.trailer {
             out.new_line_if_not_start();
             write!(out, "{}", f);
@@ -498,7 +2739,7 @@ impl Bindings {
         }
     }
 
-    fn all_namespaces(&self) -> Vec<&str> {
+    pub(crate) fn all_namespaces(&self) -> Vec<&str> {
         if self.config.language != Language::Cxx && !self.config.cpp_compatible_c() {
             return vec![];
         }
@@ -515,19 +2756,16 @@ impl Bindings {
     }
 
     fn open_close_namespaces<F: Write>(&self, op: NamespaceOperation, out: &mut SourceWriter<F>) {
-        if self.config.language == Language::Cython {
-            if op == NamespaceOperation::Open {
-                out.new_line();
-                let header = self.config.cython.header.as_deref().unwrap_or("*");
-                write!(out, "cdef extern from {}", header);
-                out.open_brace();
-            } else {
-                out.close_brace(false);
+        let mut namespaces: Vec<(&str, &str)> = self
+            .all_namespaces()
+            .into_iter()
+            .map(|namespace| ("namespace", namespace))
+            .collect();
+        if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
+            if let Some(ref version) = self.config.inline_namespace {
+                namespaces.push(("inline namespace", version));
             }
-            return;
         }
-
-        let mut namespaces = self.all_namespaces();
         if namespaces.is_empty() {
             return;
         }
@@ -541,11 +2779,11 @@ impl Bindings {
             out.write("#ifdef __cplusplus");
         }
 
-        for namespace in namespaces {
+        for (keyword, namespace) in namespaces {
             out.new_line();
             match op {
-                NamespaceOperation::Open => write!(out, "namespace {} {{", namespace),
-                NamespaceOperation::Close => write!(out, "}} // namespace {}", namespace),
+                NamespaceOperation::Open => write!(out, "{} {} {{", keyword, namespace),
+                NamespaceOperation::Close => write!(out, "}} // {} {}", keyword, namespace),
             }
         }
 
@@ -564,3 +2802,235 @@ impl Bindings {
         self.open_close_namespaces(NamespaceOperation::Close, out);
     }
 }
+
+/// How an operand of an operator of `x` is passed to the function the
+/// operator forwards to, when `ty` is a `x` or a const pointer or reference
+/// to one: as is, or by its address.
+fn operand_prefix(x: &Struct, ty: &Type) -> Option<&'static str> {
+    let is_struct = |ty: &Type| match *ty {
+        Type::Path(ref path) => *path.path() == x.path && path.generics().is_empty(),
+        _ => false,
+    };
+    match *ty {
+        Type::Ptr {
+            ref ty,
+            is_const: true,
+            is_ref,
+            ..
+        } if is_struct(ty) => Some(if is_ref { "" } else { "&" }),
+        ref ty if is_struct(ty) => Some(""),
+        _ => None,
+    }
+}
+
+/// Writes a single declaration, along with the raw code and C preprocessor
+/// condition requested by its `prefix`, `postfix`, `body` and `cfg`
+/// annotations, and the Rust source location it was parsed from.
+fn write_annotated<F: Write>(
+    out: &mut SourceWriter<F>,
+    annotations: &AnnotationSet,
+    raw_prefix_postfix: bool,
+    location: Option<&SourceLocation>,
+    header_path: Option<&str>,
+    write: impl FnOnce(&mut SourceWriter<F>),
+) {
+    let condition = match annotations.atom("cfg") {
+        Some(Some(condition)) if out.bindings().config.language != Language::Cython => {
+            Some(condition)
+        }
+        _ => None,
+    };
+    if let Some(ref condition) = condition {
+        write!(out, "#if {}", condition);
+        out.new_line();
+    }
+    // The other prefixes and postfixes of functions are written inline.
+    let is_raw = |code: &str| raw_prefix_postfix || is_raw_lines(code);
+    if let Some(Some(code)) = annotations.atom("prefix") {
+        if is_raw(&code) {
+            write_lines(out, &code);
+            out.new_line();
+        }
+    }
+    let line_directives = match location {
+        Some(location) => write_source_location(out, location, header_path),
+        None => false,
+    };
+    match annotations.atom("body") {
+        Some(Some(code)) => write_lines(out, &code),
+        _ => write(out),
+    }
+    if line_directives {
+        // Point the lines following the declaration back at the header.
+        out.new_line();
+        let next_line = out.line_number() + 1;
+        write_line_directive(out, next_line, header_path.unwrap());
+    }
+    if let Some(Some(code)) = annotations.atom("postfix") {
+        if is_raw(&code) {
+            out.new_line();
+            write_lines(out, &code);
+        }
+    }
+    if condition.is_some() {
+        out.new_line();
+        out.write("#endif");
+    }
+}
+
+/// Writes where a declaration comes from as requested by `source_locations`,
+/// returning whether a `#line` directive was emitted. Without the path of the
+/// header to point back to, `#line` directives fall back to comments.
+fn write_source_location<F: Write>(
+    out: &mut SourceWriter<F>,
+    location: &SourceLocation,
+    header_path: Option<&str>,
+) -> bool {
+    let config = &out.bindings().config;
+    let line_directives = match config.source_locations {
+        SourceLocations::None => return false,
+        SourceLocations::Comment => false,
+        SourceLocations::Line => header_path.is_some() && config.language != Language::Cython,
+    };
+    if line_directives {
+        write_line_directive(out, location.line, &location.file);
+    } else if config.language == Language::Cython {
+        write!(out, "# from {}", location);
+    } else {
+        write!(out, "/* from {} */", location);
+    }
+    out.new_line();
+    line_directives
+}
+
+/// Writes a `#line` directive making the following line `line` of `file`,
+/// whose name is a C string literal.
+fn write_line_directive<F: Write>(out: &mut SourceWriter<F>, line: usize, file: &str) {
+    let file = file.replace('\\', "\\\\").replace('"', "\\\"");
+    write!(out, "#line {} \"{}\"", line, file);
+}
+
+/// Writes verbatim, possibly multi-line, code without a trailing new line.
+fn write_lines<F: Write>(out: &mut SourceWriter<F>, code: &str) {
+    for (i, line) in code.lines().enumerate() {
+        if i != 0 {
+            out.new_line();
+        }
+        write!(out, "{}", line);
+    }
+}
+
+/// Writes `contents` to `path`, creating its directory if needed, unless the
+/// file already has them. Returns whether the file was written.
+fn write_if_changed(path: &path::Path, contents: &[u8]) -> bool {
+    if fs::read(path).map_or(false, |old| old == contents) {
+        return false;
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(path, contents).unwrap();
+    true
+}
+
+/// Compares versions numerically when they're both numbers, so that `10` is
+/// newer than `9`.
+fn version_is_newer(version: &str, than: &str) -> bool {
+    match (version.parse::<u64>(), than.parse::<u64>()) {
+        (Ok(version), Ok(than)) => version > than,
+        _ => version > than,
+    }
+}
+
+/// The header of an `#include "header"` directive.
+fn quoted_include(line: &str) -> Option<&str> {
+    let rest = line
+        .strip_prefix('#')?
+        .trim_start()
+        .strip_prefix("include")?;
+    let rest = rest.trim_start().strip_prefix('"')?;
+    Some(&rest[..rest.find('"')?])
+}
+
+/// Replaces each `{Name}` placeholder in `text`, where `Name` is an
+/// identifier, by what `replace` returns for it, leaving it as is when that's
+/// `None`.
+fn replace_placeholders(text: &str, mut replace: impl FnMut(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let placeholder = rest[1..].find('}').map(|end| &rest[1..end + 1]);
+        match placeholder {
+            Some(name)
+                if !name.is_empty()
+                    && !name.starts_with(|c: char| c.is_ascii_digit())
+                    && name.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+            {
+                match replace(name) {
+                    Some(replacement) => result.push_str(&replacement),
+                    None => result.push_str(&rest[..name.len() + 2]),
+                }
+                rest = &rest[name.len() + 2..];
+            }
+            _ => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// The keyword referring to `item` in C, which is `struct` or `union` for the
+/// types which can be declared before their definition.
+fn owned_box_keyword(item: &ItemContainer) -> Option<&'static str> {
+    match *item {
+        ItemContainer::Struct(ref x) if !x.is_transparent => Some("struct"),
+        ItemContainer::OpaqueItem(..) => Some("struct"),
+        ItemContainer::Union(..) => Some("union"),
+        ItemContainer::Enum(ref x) => match (x.tag.is_some(), x.repr.style == ReprStyle::C) {
+            (true, true) => Some("struct"),
+            (true, false) => Some("union"),
+            (false, true) => Some("enum"),
+            (false, false) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Adds the names of the types the boxes in `ty` point to, which get a
+/// `{T}Owned` typedef, to `names`.
+fn add_owned_boxes(ty: &Type, config: &Config, names: &mut BTreeSet<String>) {
+    match *ty {
+        Type::Ptr {
+            ref ty, owned_box, ..
+        } => {
+            if cdecl::owned_box_typedef(ty, owned_box, config).is_some() {
+                if let Type::Path(ref generic) = **ty {
+                    names.insert(generic.export_name().to_owned());
+                }
+            }
+            add_owned_boxes(ty, config, names);
+        }
+        Type::Array(ref ty, _) => add_owned_boxes(ty, config, names),
+        Type::Path(ref generic) => {
+            for generic in generic.generics() {
+                if let GenericArgument::Type(ref ty) = *generic {
+                    add_owned_boxes(ty, config, names);
+                }
+            }
+        }
+        Type::Primitive(..) => {}
+        Type::FuncPtr {
+            ref ret, ref args, ..
+        } => {
+            add_owned_boxes(ret, config, names);
+            for (_, ty) in args {
+                add_owned_boxes(ty, config, names);
+            }
+        }
+    }
+}
@@ -2,24 +2,42 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::io;
 use std::path;
+use std::sync::Arc;
 
 use crate::bindgen::bindings::Bindings;
 use crate::bindgen::cargo::Cargo;
-use crate::bindgen::config::{Braces, Config, Language, Profile, Style};
+use crate::bindgen::config::{Braces, Config, Language, Profile, SortKey, Style, TypeOrder};
 use crate::bindgen::error::Error;
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::layout::{self, Layouts};
 use crate::bindgen::library::Library;
 use crate::bindgen::parser::{self, Parse};
+use crate::bindgen::pass::IrPass;
+#[cfg(feature = "cc")]
+use crate::bindgen::verify;
+
+/// The name of the crate of the sources added with
+/// `Builder::with_expanded_src_reader`, in errors.
+const EXPANDED_CRATE_NAME: &str = "expanded";
 
 /// A builder for generating a bindings header.
 #[derive(Debug, Clone)]
 pub struct Builder {
     config: Config,
     srcs: Vec<path::PathBuf>,
+    /// The sources given with `with_expanded_src_reader`, or the error
+    /// reading them.
+    expanded_srcs: Vec<Result<String, String>>,
     lib: Option<(path::PathBuf, Option<String>)>,
     lib_cargo: Option<Cargo>,
     std_types: bool,
     lockfile: Option<path::PathBuf>,
+    pub(crate) ir_passes: Vec<Arc<dyn IrPass>>,
+    pub(crate) backend: Option<Arc<dyn LanguageBackend>>,
+    #[cfg(feature = "cc")]
+    verify_compiles: bool,
 }
 
 impl Builder {
@@ -28,10 +46,15 @@ impl Builder {
         Builder {
             config: Config::default(),
             srcs: Vec::new(),
+            expanded_srcs: Vec::new(),
             lib: None,
             lib_cargo: None,
             std_types: true,
             lockfile: None,
+            ir_passes: Vec::new(),
+            backend: None,
+            #[cfg(feature = "cc")]
+            verify_compiles: false,
         }
     }
 
@@ -114,6 +137,12 @@ impl Builder {
         self
     }
 
+    #[allow(unused)]
+    pub fn with_inline_namespace<S: AsRef<str>>(mut self, namespace: S) -> Builder {
+        self.config.inline_namespace = Some(String::from(namespace.as_ref()));
+        self
+    }
+
     #[allow(unused)]
     pub fn with_using_namespaces<S: AsRef<str>>(mut self, namespaces: &[S]) -> Builder {
         self.config.using_namespaces = Some(
@@ -305,6 +334,21 @@ impl Builder {
         self
     }
 
+    /// Adds the source of a crate whose macros have already been expanded,
+    /// like the output of `cargo expand`, read from `reader`. The source is
+    /// parsed as a single file, without cargo: its modules must be inline.
+    #[allow(unused)]
+    pub fn with_expanded_src_reader<R: io::Read>(mut self, mut reader: R) -> Builder {
+        let mut src = String::new();
+        self.expanded_srcs.push(
+            reader
+                .read_to_string(&mut src)
+                .map(|_| src)
+                .map_err(|error| error.to_string()),
+        );
+        self
+    }
+
     #[allow(unused)]
     pub fn with_crate<P: AsRef<path::Path>>(mut self, lib_dir: P) -> Builder {
         debug_assert!(self.lib.is_none());
@@ -344,7 +388,25 @@ impl Builder {
         self
     }
 
-    pub fn generate(self) -> Result<Bindings, Error> {
+    /// Whether to compile a file including the generated bindings with the
+    /// host C or C++ compiler, and fail if that doesn't work.
+    #[cfg(feature = "cc")]
+    #[allow(unused)]
+    pub fn verify_compiles(mut self, verify_compiles: bool) -> Builder {
+        self.verify_compiles = verify_compiles;
+        self
+    }
+
+    pub fn generate(mut self) -> Result<Bindings, Error> {
+        if self.config.deterministic {
+            self.config.sort_by = SortKey::Name;
+            self.config.type_order = TypeOrder::Name;
+        }
+        if self.config.defensive_header {
+            self.config.pragma_once = true;
+            self.config.cpp_compat = true;
+        }
+
         // If macro expansion is enabled, then cbindgen will attempt to build the crate
         // and will run its build script which may run cbindgen again. That second run may start
         // infinite recursion, or overwrite previously written files with bindings.
@@ -360,8 +422,11 @@ impl Builder {
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                Default::default(),
                 true,
                 String::new(),
+                String::new(),
+                None,
             ));
         }
 
@@ -375,6 +440,29 @@ impl Builder {
             result.extend_with(&parser::parse_src(x, &self.config)?);
         }
 
+        for x in &self.expanded_srcs {
+            let src = x.as_ref().map_err(|error| {
+                error!("Couldn't read the expanded source: {}", error);
+                Error::ParseCannotOpenFile {
+                    crate_name: EXPANDED_CRATE_NAME.to_owned(),
+                    src_path: "<reader>".to_owned(),
+                }
+            })?;
+            result.extend_with(&parser::parse_expanded_src(
+                EXPANDED_CRATE_NAME,
+                src,
+                &self.config,
+            )?);
+        }
+
+        let probe = |cargo: &Cargo| -> Result<Option<Layouts>, Error> {
+            if self.config.layout.probe {
+                layout::probe(cargo, &self.config).map(Some)
+            } else {
+                Ok(None)
+            }
+        };
+        let mut layouts = None;
         if let Some((lib_dir, binding_lib_name)) = self.lib.clone() {
             let lockfile = self.lockfile.as_ref().and_then(|p| p.to_str());
 
@@ -388,14 +476,18 @@ impl Builder {
                 /* existing_metadata = */ None,
             )?;
 
+            layouts = probe(&cargo)?;
             result.extend_with(&parser::parse_lib(cargo, &self.config)?);
         } else if let Some(cargo) = self.lib_cargo.clone() {
+            layouts = probe(&cargo)?;
             result.extend_with(&parser::parse_lib(cargo, &self.config)?);
+        } else if self.config.layout.probe {
+            warn!("Can't probe the layouts of types without a crate to build.");
         }
 
         result.source_files.extend_from_slice(self.srcs.as_slice());
 
-        Library::new(
+        let mut library = Library::new(
             self.config,
             result.constants,
             result.globals,
@@ -407,8 +499,30 @@ impl Builder {
             result.functions,
             result.source_files,
             result.package_version,
-        )
-        .generate()
+            result.package_name,
+            result.package_description,
+            result.package_license,
+            result.package_authors,
+            result.skipped,
+        );
+
+        for pass in &self.ir_passes {
+            pass.run(&mut library);
+        }
+
+        let mut bindings = library.generate()?;
+        bindings.set_package_lib_name(result.package_lib_name);
+        if let Some(backend) = self.backend {
+            bindings.set_backend(backend);
+        }
+        if let Some(layouts) = layouts {
+            bindings.set_layouts(layouts);
+        }
+        #[cfg(feature = "cc")]
+        if self.verify_compiles {
+            verify::verify_compiles(&bindings)?;
+        }
+        Ok(bindings)
     }
 }
 
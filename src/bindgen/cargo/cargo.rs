@@ -3,11 +3,12 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use crate::bindgen::cargo::cargo_expand;
 use crate::bindgen::cargo::cargo_lock::{self, Lock};
 pub(crate) use crate::bindgen::cargo::cargo_metadata::PackageRef;
-use crate::bindgen::cargo::cargo_metadata::{self, Metadata};
+use crate::bindgen::cargo::cargo_metadata::{self, Metadata, Package, Target};
 use crate::bindgen::cargo::cargo_toml;
 use crate::bindgen::config::Profile;
 use crate::bindgen::error::Error;
@@ -82,6 +83,11 @@ impl Cargo {
         })
     }
 
+    /// The directory holding the manifest cbindgen was pointed at.
+    pub(crate) fn crate_dir(&self) -> &Path {
+        self.manifest_path.parent().unwrap()
+    }
+
     pub(crate) fn binding_crate_name(&self) -> &str {
         &self.binding_crate_name
     }
@@ -167,7 +173,13 @@ impl Cargo {
                     .metadata
                     .packages
                     .get(package)
-                    .and_then(|meta_package| meta_package.dependencies.get(dep_name))
+                    .and_then(|meta_package| {
+                        meta_package
+                            .dependencies
+                            .iter()
+                            .filter(|dep| dep.name == dep_name)
+                            .min_by_key(|dep| dep.is_dev())
+                    })
                     .and_then(Cfg::load_metadata);
 
                 let package_ref = PackageRef {
@@ -191,8 +203,33 @@ impl Cargo {
         None
     }
 
+    fn binding_crate_package(&self) -> Option<&Package> {
+        self.metadata
+            .packages
+            .iter()
+            .find(|package| package.name_and_version.name == self.binding_crate_name)
+    }
+
+    /// The description of the binding crate in its `Cargo.toml`.
+    pub(crate) fn binding_crate_description(&self) -> Option<String> {
+        self.binding_crate_package()
+            .and_then(|package| package.description.clone())
+    }
+
+    /// The license of the binding crate in its `Cargo.toml`.
+    pub(crate) fn binding_crate_license(&self) -> Option<String> {
+        self.binding_crate_package()
+            .and_then(|package| package.license.clone())
+    }
+
+    /// The authors of the binding crate in its `Cargo.toml`.
+    pub(crate) fn binding_crate_authors(&self) -> Vec<String> {
+        self.binding_crate_package()
+            .map(|package| package.authors.clone())
+            .unwrap_or_default()
+    }
+
     /// Finds the directory for a specified package reference.
-    #[allow(unused)]
     pub(crate) fn find_crate_dir(&self, package: &PackageRef) -> Option<PathBuf> {
         self.metadata
             .packages
@@ -204,8 +241,24 @@ impl Cargo {
             })
     }
 
-    /// Finds `src/lib.rs` for a specified package reference.
-    pub(crate) fn find_crate_src(&self, package: &PackageRef) -> Option<PathBuf> {
+    /// Whether `dep_name` is only a dev-dependency of `package`, used by its
+    /// tests, benches and examples.
+    pub(crate) fn is_dev_dependency(&self, package: &PackageRef, dep_name: &str) -> bool {
+        self.metadata
+            .packages
+            .get(package)
+            .map_or(false, |meta_package| {
+                let mut kinds = meta_package
+                    .dependencies
+                    .iter()
+                    .filter(|dep| dep.name == dep_name)
+                    .peekable();
+                kinds.peek().is_some() && kinds.all(|dep| dep.is_dev())
+            })
+    }
+
+    /// The library target of a specified package reference.
+    fn lib_target(&self, package: &PackageRef) -> Option<&Target> {
         let kind_lib = String::from("lib");
         let kind_staticlib = String::from("staticlib");
         let kind_rlib = String::from("rlib");
@@ -216,20 +269,29 @@ impl Cargo {
             .packages
             .get(package)
             .and_then(|meta_package| {
-                for target in &meta_package.targets {
-                    if target.kind.contains(&kind_lib)
+                meta_package.targets.iter().find(|target| {
+                    target.kind.contains(&kind_lib)
                         || target.kind.contains(&kind_staticlib)
                         || target.kind.contains(&kind_rlib)
                         || target.kind.contains(&kind_cdylib)
                         || target.kind.contains(&kind_dylib)
-                    {
-                        return Some(PathBuf::from(&target.src_path));
-                    }
-                }
-                None
+                })
             })
     }
 
+    /// Finds `src/lib.rs` for a specified package reference.
+    pub(crate) fn find_crate_src(&self, package: &PackageRef) -> Option<PathBuf> {
+        self.lib_target(package)
+            .map(|target| PathBuf::from(&target.src_path))
+    }
+
+    /// The name of the library built from the binding crate, which is the
+    /// name of its `[lib]` target.
+    pub(crate) fn binding_crate_lib_name(&self) -> Option<String> {
+        self.lib_target(&self.binding_crate_ref())
+            .map(|target| target.name.clone())
+    }
+
     pub(crate) fn expand_crate(
         &self,
         package: &PackageRef,
@@ -249,4 +311,27 @@ impl Cargo {
             profile,
         )
     }
+
+    /// A `cargo rustc` command building the library of `package`, see
+    /// `cargo_expand::rustc_command`.
+    pub(crate) fn rustc_command(
+        &self,
+        package: &PackageRef,
+        target_dir: &Path,
+        all_features: bool,
+        default_features: bool,
+        features: &Option<Vec<String>>,
+        profile: Profile,
+    ) -> Command {
+        cargo_expand::rustc_command(
+            &self.manifest_path,
+            &package.name,
+            package.version.as_deref(),
+            Some(target_dir),
+            all_features,
+            default_features,
+            features,
+            profile,
+        )
+    }
 }
@@ -69,21 +69,71 @@ pub fn expand(
     expand_features: &Option<Vec<String>>,
     profile: Profile,
 ) -> Result<String, Error> {
-    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
-    let mut cmd = Command::new(cargo);
-
     let mut _temp_dir = None; // drop guard
-    if use_tempdir {
-        _temp_dir = Some(Builder::new().prefix("cbindgen-expand").tempdir()?);
-        cmd.env("CARGO_TARGET_DIR", _temp_dir.unwrap().path());
-    } else if let Ok(ref path) = env::var("CARGO_EXPAND_TARGET_DIR") {
-        cmd.env("CARGO_TARGET_DIR", path);
-    } else if let Ok(ref path) = env::var("OUT_DIR") {
+    let target_dir = if use_tempdir {
+        let temp_dir = Builder::new().prefix("cbindgen-expand").tempdir()?;
+        let path = temp_dir.path().to_owned();
+        _temp_dir = Some(temp_dir);
+        Some(path)
+    } else if let Ok(path) = env::var("CARGO_EXPAND_TARGET_DIR") {
+        Some(PathBuf::from(path))
+    } else {
         // When cbindgen was started programatically from a build.rs file, Cargo is running and
         // locking the default target directory. In this case we need to use another directory,
         // else we would end up in a deadlock. If Cargo is running `OUT_DIR` will be set, so we
         // can use a directory relative to that.
-        cmd.env("CARGO_TARGET_DIR", PathBuf::from(path).join("expanded"));
+        env::var("OUT_DIR")
+            .ok()
+            .map(|path| PathBuf::from(path).join("expanded"))
+    };
+
+    let mut cmd = rustc_command(
+        manifest_path,
+        crate_name,
+        version,
+        target_dir.as_deref(),
+        expand_all_features,
+        expand_default_features,
+        expand_features,
+        profile,
+    );
+    // When build with the release profile we can't choose the `check` profile.
+    if profile != Profile::Release {
+        cmd.arg("--profile=check");
+    }
+    cmd.arg("--");
+    cmd.arg("-Zunpretty=expanded");
+    info!("Command: {:?}", cmd);
+    let output = cmd.output()?;
+
+    let src = from_utf8(&output.stdout)?.to_owned();
+    let error = from_utf8(&output.stderr)?.to_owned();
+
+    if src.is_empty() {
+        Err(Error::Compile(error))
+    } else {
+        Ok(src)
+    }
+}
+
+/// A `cargo rustc` command building the library of the crate with the given
+/// features, to which arguments for rustc can be added after `--`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rustc_command(
+    manifest_path: &Path,
+    crate_name: &str,
+    version: Option<&str>,
+    target_dir: Option<&Path>,
+    all_features: bool,
+    default_features: bool,
+    features: &Option<Vec<String>>,
+    profile: Profile,
+) -> Command {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let mut cmd = Command::new(cargo);
+
+    if let Some(target_dir) = target_dir {
+        cmd.env("CARGO_TARGET_DIR", target_dir);
     }
 
     // Set this variable so that we don't call it recursively if we expand a crate that is using
@@ -92,13 +142,9 @@ pub fn expand(
 
     cmd.arg("rustc");
     cmd.arg("--lib");
-    // When build with the release profile we can't choose the `check` profile.
-    if profile != Profile::Release {
-        cmd.arg("--profile=check");
-    }
     cmd.arg("--manifest-path");
     cmd.arg(manifest_path);
-    if let Some(features) = expand_features {
+    if let Some(features) = features {
         cmd.arg("--features");
         let mut features_str = String::new();
         for (index, feature) in features.iter().enumerate() {
@@ -109,10 +155,10 @@ pub fn expand(
         }
         cmd.arg(features_str);
     }
-    if expand_all_features {
+    if all_features {
         cmd.arg("--all-features");
     }
-    if !expand_default_features {
+    if !default_features {
         cmd.arg("--no-default-features");
     }
     match profile {
@@ -129,17 +175,5 @@ pub fn expand(
     }
     cmd.arg(&package);
     cmd.arg("--verbose");
-    cmd.arg("--");
-    cmd.arg("-Zunpretty=expanded");
-    info!("Command: {:?}", cmd);
-    let output = cmd.output()?;
-
-    let src = from_utf8(&output.stdout)?.to_owned();
-    let error = from_utf8(&output.stderr)?.to_owned();
-
-    if src.is_empty() {
-        Err(Error::Compile(error))
-    } else {
-        Ok(src)
-    }
+    cmd
 }
@@ -45,11 +45,21 @@ pub struct Package {
     pub name_and_version: PackageRef,
     id: String,
     source: Option<String>,
-    /// List of dependencies of this particular package
-    pub dependencies: HashSet<Dependency>,
+    /// List of dependencies of this particular package, once for each of
+    /// their kinds and targets
+    pub dependencies: Vec<Dependency>,
     /// Targets provided by the crate (lib, bin, example, test, ...)
     pub targets: Vec<Target>,
     features: HashMap<String, Vec<String>>,
+    /// The `description` from the `Cargo.toml`
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The `license` from the `Cargo.toml`, an SPDX expression
+    #[serde(default)]
+    pub license: Option<String>,
+    /// The `authors` from the `Cargo.toml`
+    #[serde(default)]
+    pub authors: Vec<String>,
     /// path containing the `Cargo.toml`
     pub manifest_path: String,
 }
@@ -157,6 +167,14 @@ impl PartialEq for Package {
 
 impl Eq for Package {}
 
+impl Dependency {
+    /// Whether this is a dev-dependency, only used by tests, benches and
+    /// examples.
+    pub fn is_dev(&self) -> bool {
+        self.kind.as_deref() == Some("dev")
+    }
+}
+
 impl Borrow<str> for Dependency {
     fn borrow(&self) -> &str {
         &self.name
@@ -4,12 +4,39 @@
 
 use std::io::Write;
 
-use crate::bindgen::config::Layout;
+use crate::bindgen::config::{Layout, OwnedBox};
 use crate::bindgen::declarationtyperesolver::DeclarationType;
 use crate::bindgen::ir::{ConstExpr, Function, GenericArgument, Type};
 use crate::bindgen::writer::{ListType, SourceWriter};
 use crate::bindgen::{Config, Language};
 
+/// Whether a pointer written as `owned_box` is a box marked as owning what it
+/// points to, rather than written as a plain pointer.
+fn marks_owned_box(owned_box: Option<OwnedBox>, config: &Config) -> bool {
+    config.language != Language::Cython
+        && matches!(owned_box, Some(OwnedBox::Comment) | Some(OwnedBox::Typedef))
+}
+
+/// The name of the typedef a pointer to `ty` written as `owned_box` is
+/// written as, with `OwnedBox::Typedef`, like `FooOwned` for `Foo`. Boxes of
+/// other types than non-generic paths are written as pointers with a comment.
+pub(crate) fn owned_box_typedef(
+    ty: &Type,
+    owned_box: Option<OwnedBox>,
+    config: &Config,
+) -> Option<String> {
+    match *ty {
+        Type::Path(ref generic)
+            if owned_box == Some(OwnedBox::Typedef)
+                && marks_owned_box(owned_box, config)
+                && generic.generics().is_empty() =>
+        {
+            Some(format!("{}Owned", generic.export_name()))
+        }
+        _ => None,
+    }
+}
+
 // This code is for translating Rust types into C declarations.
 // See Section 6.7, Declarations, in the C standard for background.
 // http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
@@ -19,12 +46,16 @@ enum CDeclarator {
         is_const: bool,
         is_nullable: bool,
         is_ref: bool,
+        is_owned: bool,
+        is_restrict: bool,
     },
     Array(String),
     Func {
         args: Vec<(Option<String>, CDecl)>,
         layout: Layout,
         never_return: bool,
+        /// The macro of the calling convention of a function pointer.
+        calling_convention: Option<&'static str>,
     },
 }
 
@@ -41,6 +72,8 @@ struct CDecl {
     declarators: Vec<CDeclarator>,
     type_ctype: Option<DeclarationType>,
     deprecated: Option<String>,
+    /// The SAL annotation of a function argument.
+    sal_annotation: Option<String>,
 }
 
 impl CDecl {
@@ -52,6 +85,7 @@ impl CDecl {
             declarators: Vec::new(),
             type_ctype: None,
             deprecated: None,
+            sal_annotation: None,
         }
     }
 
@@ -86,23 +120,57 @@ impl CDecl {
     }
 
     fn build_func(&mut self, f: &Function, layout: Layout, config: &Config) {
+        let horizontal = layout == Layout::Horizontal;
+        let restrict_args = f.restrict_args();
         let args = f
             .args
             .iter()
-            .map(|arg| {
-                (
-                    arg.name.clone(),
-                    CDecl::from_func_arg(&arg.ty, arg.array_length.as_deref(), config),
-                )
+            .zip(f.sal_arg_annotations(config))
+            .enumerate()
+            .map(|(index, (arg, sal_annotation))| {
+                let mut cdecl = CDecl::from_func_arg(&arg.ty, arg.array_length.as_deref(), config);
+                cdecl.sal_annotation = sal_annotation;
+                if restrict_args.contains(&index) {
+                    if let Some(CDeclarator::Ptr {
+                        ref mut is_restrict,
+                        ..
+                    }) = cdecl.declarators.first_mut()
+                    {
+                        *is_restrict = true;
+                    }
+                }
+                (arg.name.clone(), cdecl)
             })
             .collect();
         self.declarators.push(CDeclarator::Func {
             args,
             layout,
             never_return: f.never_return,
+            calling_convention: None,
         });
         self.deprecated = f.annotations.deprecated.clone();
         self.build_type(&f.ret, false, config);
+        if horizontal {
+            self.set_horizontal();
+        }
+    }
+
+    /// Lays out the arguments of the function pointers within this declaration
+    /// horizontally as well, so that a horizontal declaration stays on one line.
+    fn set_horizontal(&mut self) {
+        for declarator in &mut self.declarators {
+            if let CDeclarator::Func {
+                ref mut args,
+                ref mut layout,
+                ..
+            } = *declarator
+            {
+                *layout = Layout::Horizontal;
+                for (_, arg) in args {
+                    arg.set_horizontal();
+                }
+            }
+        }
     }
 
     fn build_type(&mut self, t: &Type, is_const: bool, config: &Config) {
@@ -153,11 +221,27 @@ impl CDecl {
                 is_nullable,
                 is_const: ptr_is_const,
                 is_ref,
+                owned_box,
             } => {
+                let is_owned = marks_owned_box(*owned_box, config);
+                if let Some(name) = owned_box_typedef(ty, *owned_box, config) {
+                    if is_const {
+                        self.type_qualifers = "const".to_owned();
+                    }
+                    assert!(
+                        self.type_name.is_empty(),
+                        "error generating cdecl for {:?}",
+                        t
+                    );
+                    self.type_name = name;
+                    return;
+                }
                 self.declarators.push(CDeclarator::Ptr {
                     is_const,
                     is_nullable: *is_nullable,
                     is_ref: *is_ref,
+                    is_owned,
+                    is_restrict: false,
                 });
                 self.build_type(ty, *ptr_is_const, config);
             }
@@ -171,20 +255,30 @@ impl CDecl {
                 ref args,
                 is_nullable: _,
                 never_return,
+                calling_convention,
             } => {
                 let args = args
                     .iter()
                     .map(|(ref name, ref ty)| (name.clone(), CDecl::from_type(ty, config)))
                     .collect();
+                // A function pointer is itself the pointer, so it takes the
+                // qualifier of the type pointing to it, if any.
                 self.declarators.push(CDeclarator::Ptr {
-                    is_const: false,
+                    is_const,
                     is_nullable: true,
                     is_ref: false,
+                    is_owned: false,
+                    is_restrict: false,
                 });
                 self.declarators.push(CDeclarator::Func {
                     args,
                     layout: config.function.args.clone(),
                     never_return: *never_return,
+                    calling_convention: if config.language == Language::Cython {
+                        None
+                    } else {
+                        calling_convention.macro_name()
+                    },
                 });
                 self.build_type(ret, false, config);
             }
@@ -192,6 +286,10 @@ impl CDecl {
     }
 
     fn write<F: Write>(&self, out: &mut SourceWriter<F>, ident: Option<&str>, config: &Config) {
+        if let Some(ref sal_annotation) = self.sal_annotation {
+            write!(out, "{} ", sal_annotation);
+        }
+
         // Write the type-specifier and type-qualifier first
         if !self.type_qualifers.is_empty() {
             write!(out, "{} ", self.type_qualifers);
@@ -207,7 +305,7 @@ impl CDecl {
 
         if !self.type_generic_args.is_empty() {
             out.write("<");
-            out.write_horizontal_source_list(&self.type_generic_args, ListType::Join(", "));
+            out.write_horizontal_source_list(config, &self.type_generic_args, ListType::Join(", "));
             out.write(">");
         }
 
@@ -228,13 +326,31 @@ impl CDecl {
                     is_const,
                     is_nullable,
                     is_ref,
+                    is_owned,
+                    is_restrict,
                 } => {
+                    if is_owned {
+                        out.write("/* owned */ ");
+                    }
                     out.write(if is_ref { "&" } else { "*" });
                     if is_const {
                         out.write("const ");
                     }
-                    if !is_nullable && !is_ref && config.language != Language::Cython {
-                        if let Some(attr) = &config.pointer.non_null_attribute {
+                    if is_restrict && config.language != Language::Cython {
+                        // `restrict` is only a keyword in C.
+                        if config.language == Language::C && !config.cpp_compat {
+                            out.write("restrict ");
+                        } else {
+                            out.write("__restrict ");
+                        }
+                    }
+                    if !is_ref && config.language != Language::Cython {
+                        let attr = if is_nullable {
+                            &config.pointer.nullable_attribute
+                        } else {
+                            &config.pointer.non_null_attribute
+                        };
+                        if let Some(attr) = attr {
                             write!(out, "{} ", attr);
                         }
                     }
@@ -244,9 +360,14 @@ impl CDecl {
                         out.write("(");
                     }
                 }
-                CDeclarator::Func { .. } => {
+                CDeclarator::Func {
+                    calling_convention, ..
+                } => {
                     if next_is_pointer {
                         out.write("(");
+                        if let Some(calling_convention) = calling_convention {
+                            write!(out, "{} ", calling_convention);
+                        }
                     }
                 }
             }
@@ -279,6 +400,7 @@ impl CDecl {
                     ref args,
                     ref layout,
                     never_return,
+                    ..
                 } => {
                     if last_was_pointer {
                         out.write(")");
@@ -327,15 +449,56 @@ impl CDecl {
                         }
                     }
 
+                    // Writes each argument on its own line, indented once,
+                    // for when aligning them after the parenthesis doesn't
+                    // fit either.
+                    fn write_indented<F: Write>(
+                        out: &mut SourceWriter<F>,
+                        config: &Config,
+                        args: &[(Option<String>, CDecl)],
+                    ) {
+                        out.push_tab();
+                        for (i, (arg_ident, arg_ty)) in args.iter().enumerate() {
+                            if i != 0 {
+                                out.write(",");
+                            }
+                            out.new_line();
+
+                            // Convert &Option<String> to Option<&str>
+                            let arg_ident = arg_ident.as_ref().map(|x| x.as_ref());
+
+                            arg_ty.write(out, arg_ident, config);
+                        }
+                        out.pop_tab();
+                    }
+
                     match layout {
+                        // Functions are laid out vertically when they don't fit
+                        // on a line with the `Auto` layout.
+                        Layout::Vertical if config.function.args == Layout::Auto => {
+                            if !out.try_write(
+                                |out| write_vertical(out, config, args),
+                                config.line_length,
+                            ) {
+                                write_indented(out, config, args)
+                            }
+                        }
                         Layout::Vertical => write_vertical(out, config, args),
                         Layout::Horizontal => write_horizontal(out, config, args),
                         Layout::Auto => {
-                            if !out.try_write(
+                            if !out.try_write_line(
                                 |out| write_horizontal(out, config, args),
                                 config.line_length,
                             ) {
-                                write_vertical(out, config, args)
+                                // The arguments of function pointers are
+                                // indented relative to where they start, so
+                                // this applies to them too.
+                                if !out.try_write(
+                                    |out| write_vertical(out, config, args),
+                                    config.line_length,
+                                ) {
+                                    write_indented(out, config, args)
+                                }
                             }
                         }
                     }
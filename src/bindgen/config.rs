@@ -10,9 +10,11 @@ use std::{fmt, fs, path::Path as StdPath, path::PathBuf as StdPathBuf};
 use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
 use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 
+use crate::bindgen::error::Error;
 use crate::bindgen::ir::annotation::AnnotationSet;
 use crate::bindgen::ir::path::Path;
 use crate::bindgen::ir::repr::ReprAlign;
+use crate::bindgen::ir::{PrimitiveType, SourceLocation};
 pub use crate::bindgen::rename::RenameRule;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -165,6 +167,15 @@ pub enum DocumentationStyle {
     C,
     C99,
     Doxy,
+    /// Like `Doxy`, but also turns rustdoc conventions into Doxygen commands.
+    DoxygenFull,
+    /// Javadoc comments, turning rustdoc conventions into Javadoc tags.
+    Javadoc,
+    /// QDoc comments, turning rustdoc conventions into QDoc commands.
+    Qdoc,
+    /// GTK-Doc comments, with the GObject-Introspection annotations
+    /// `g-ir-scanner` needs.
+    GtkDoc,
     Cxx,
     Auto,
 }
@@ -179,6 +190,10 @@ impl FromStr for DocumentationStyle {
             "cxx" => Ok(DocumentationStyle::Cxx),
             "c++" => Ok(DocumentationStyle::Cxx),
             "doxy" => Ok(DocumentationStyle::Doxy),
+            "doxygen_full" => Ok(DocumentationStyle::DoxygenFull),
+            "javadoc" => Ok(DocumentationStyle::Javadoc),
+            "qdoc" => Ok(DocumentationStyle::Qdoc),
+            "gtk_doc" => Ok(DocumentationStyle::GtkDoc),
             "auto" => Ok(DocumentationStyle::Auto),
             _ => Err(format!("Unrecognized documentation style: '{}'.", s)),
         }
@@ -208,6 +223,148 @@ impl FromStr for DocumentationLength {
 
 deserialize_enum_str!(DocumentationLength);
 
+/// How to point at the Rust source of each declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceLocations {
+    None,
+    /// A `/* from src/lib.rs:12 */` comment.
+    Comment,
+    /// A `#line` directive, so that compiler diagnostics point at the Rust
+    /// source.
+    Line,
+}
+
+impl FromStr for SourceLocations {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<SourceLocations, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "none" => Ok(SourceLocations::None),
+            "comment" => Ok(SourceLocations::Comment),
+            "line" => Ok(SourceLocations::Line),
+            _ => Err(format!("Unrecognized source locations style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(SourceLocations);
+
+/// What to organize the declarations into Doxygen groups by, besides the
+/// `group` annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoxygenGroups {
+    None,
+    /// The `section` annotation of each declaration.
+    Section,
+    /// The Rust module each declaration comes from.
+    Module,
+}
+
+impl FromStr for DoxygenGroups {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DoxygenGroups, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "none" => Ok(DoxygenGroups::None),
+            "section" => Ok(DoxygenGroups::Section),
+            "module" => Ok(DoxygenGroups::Module),
+            _ => Err(format!("Unrecognized Doxygen groups: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(DoxygenGroups);
+
+/// The CUDA execution space declarations are qualified with, so that the
+/// header can be used from CUDA code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CudaQualifier {
+    #[default]
+    None,
+    /// `__host__`
+    Host,
+    /// `__device__`
+    Device,
+    /// `__host__ __device__`
+    HostDevice,
+}
+
+impl CudaQualifier {
+    /// The macro expanding to the qualifier when compiling CUDA code.
+    pub fn macro_name(self) -> Option<&'static str> {
+        match self {
+            CudaQualifier::None => None,
+            CudaQualifier::Host => Some("CBINDGEN_CUDA_HOST"),
+            CudaQualifier::Device => Some("CBINDGEN_CUDA_DEVICE"),
+            CudaQualifier::HostDevice => Some("CBINDGEN_CUDA_HOST_DEVICE"),
+        }
+    }
+}
+
+impl FromStr for CudaQualifier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<CudaQualifier, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "none" => Ok(CudaQualifier::None),
+            "host" => Ok(CudaQualifier::Host),
+            "device" => Ok(CudaQualifier::Device),
+            "hostdevice" | "host_device" => Ok(CudaQualifier::HostDevice),
+            _ => Err(format!("Unrecognized CUDA qualifier: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(CudaQualifier);
+
+/// What to do when a lint finds a problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    /// Log a warning and generate the bindings anyway.
+    Warn,
+    /// Fail to generate the bindings.
+    Deny,
+}
+
+impl FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<LintLevel, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(format!("Unrecognized lint level: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(LintLevel);
+
+/// The lint level an annotation overrides a lint with, where `false` means
+/// `allow`.
+fn lint_level_annotation(annotations: &AnnotationSet, name: &str) -> Option<LintLevel> {
+    if annotations.bool(name) == Some(false) {
+        return Some(LintLevel::Allow);
+    }
+    match annotations.atom(name)??.parse() {
+        Ok(level) => Some(level),
+        Err(msg) => {
+            warn!("Ignoring the {} annotation: {}", name, msg);
+            None
+        }
+    }
+}
+
+/// Whether the `prefix` or `postfix` annotation of a function is written on
+/// its own lines, like those of the other items, rather than overriding
+/// `fn.prefix` or `fn.postfix`: when it was repeated, spans several lines or
+/// is a preprocessor directive.
+pub(crate) fn is_raw_lines(code: &str) -> bool {
+    code.contains('\n') || code.trim_start().starts_with('#')
+}
+
 /// A style of Style to use when generating structs and enums.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 pub enum Style {
@@ -316,8 +473,240 @@ impl FromStr for SortKey {
 
 deserialize_enum_str!(SortKey);
 
+/// How to order the definitions of types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeOrder {
+    /// In the order they are first used by the exported functions, with
+    /// the types they depend on first.
+    Dependencies,
+    /// In the order they are declared in the Rust source, only moving a
+    /// type after the ones it contains, with forward declarations of the
+    /// structs and unions which are pointed to before their definition.
+    Declaration,
+    /// Like `Declaration`, but by name.
+    Name,
+}
+
+impl FromStr for TypeOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "dependencies" => Ok(TypeOrder::Dependencies),
+            "declaration" => Ok(TypeOrder::Declaration),
+            "name" => Ok(TypeOrder::Name),
+            _ => Err(format!("Unrecognized type order: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(TypeOrder);
+
+/// How to give the functions and globals C linkage in C++.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExternC {
+    /// With a single `extern "C" { ... }` block around all of them.
+    #[default]
+    Block,
+    /// With `extern "C"` in front of each declaration. With `cpp_compat`,
+    /// `CBINDGEN_EXTERN_C` is written instead, which is `extern` in C.
+    Item,
+}
+
+impl FromStr for ExternC {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "block" => Ok(ExternC::Block),
+            "item" => Ok(ExternC::Item),
+            _ => Err(format!("Unrecognized extern \"C\" style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(ExternC);
+
+/// How to write Rust's `char`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CharType {
+    /// As `uint32_t`, which is always the same size as `char`.
+    #[default]
+    Uint32,
+    /// As `char32_t`, in C++ and C11 (from `<uchar.h>`). Cython has no
+    /// `char32_t`, so it still gets `uint32_t`.
+    Char32,
+    /// Fail generation if an exported item uses `char`.
+    Error,
+}
+
+impl FromStr for CharType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "uint32_t" | "uint32" => Ok(CharType::Uint32),
+            "char32_t" | "char32" => Ok(CharType::Char32),
+            "error" => Ok(CharType::Error),
+            _ => Err(format!("Unrecognized char type: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(CharType);
+
+/// How to write Rust's `usize` or `isize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SizeType {
+    /// As `uintptr_t` or `intptr_t`, which always have the size of a pointer.
+    Intptr,
+    /// As `size_t` or `ptrdiff_t`, which have the size of a pointer on the
+    /// usual platforms.
+    SizeT,
+    /// As the given type, which the header has to get from elsewhere.
+    Custom(String),
+}
+
+impl FromStr for SizeType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix("custom:") {
+            return match name.trim() {
+                "" => Err(format!("Missing type name in size type: '{}'.", s)),
+                name => Ok(SizeType::Custom(name.to_owned())),
+            };
+        }
+        match s.to_lowercase().as_ref() {
+            "uintptr_t" | "intptr_t" => Ok(SizeType::Intptr),
+            "size_t" | "ptrdiff_t" => Ok(SizeType::SizeT),
+            _ => Err(format!("Unrecognized size type: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(SizeType);
+
+/// How to write Rust's `bool`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum BoolType {
+    /// As `bool`, from `<stdbool.h>` in C.
+    #[default]
+    Bool,
+    /// As `uint8_t`, which is always the same size as `bool`.
+    Uint8,
+    /// As the given type, like a platform `BOOL`, which the header has to
+    /// get from elsewhere.
+    Custom(String),
+}
+
+impl FromStr for BoolType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix("custom:") {
+            return match name.trim() {
+                "" => Err(format!("Missing type name in bool type: '{}'.", s)),
+                name => Ok(BoolType::Custom(name.to_owned())),
+            };
+        }
+        match s.to_lowercase().as_ref() {
+            "bool" => Ok(BoolType::Bool),
+            "uint8_t" | "uint8" => Ok(BoolType::Uint8),
+            _ => Err(format!("Unrecognized bool type: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(BoolType);
+
+/// The initializer helpers to generate for C structs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StructInitializer {
+    #[default]
+    None,
+    /// A `{Struct}_INIT(...)` macro expanding to a designated initializer.
+    Macro,
+    /// A `static inline` `{Struct}_new(...)` function.
+    Function,
+}
+
+impl FromStr for StructInitializer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "none" => Ok(StructInitializer::None),
+            "macro" => Ok(StructInitializer::Macro),
+            "function" => Ok(StructInitializer::Function),
+            _ => Err(format!("Unrecognized struct initializer: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(StructInitializer);
+
+/// How to give C enums with a `repr` type, like `#[repr(u8)]`, their size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnumFixedType {
+    /// A named enum declaring the enumerators, and a typedef of the integer
+    /// type for the enum itself.
+    #[default]
+    Typedef,
+    /// A C23 enum with a fixed underlying type, `enum Foo : uint8_t`.
+    C23,
+    /// A typedef of the integer type, and a `#define` for each enumerator.
+    Defines,
+    /// A typedef of the integer type, and an anonymous enum declaring the
+    /// enumerators.
+    AnonymousEnum,
+}
+
+impl FromStr for EnumFixedType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "typedef" => Ok(EnumFixedType::Typedef),
+            "c23" => Ok(EnumFixedType::C23),
+            "defines" => Ok(EnumFixedType::Defines),
+            "anonymous-enum" | "anonymous_enum" => Ok(EnumFixedType::AnonymousEnum),
+            _ => Err(format!("Unrecognized enum fixed type: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(EnumFixedType);
+
+/// How visible a Rust item is, from the least to the most visible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Visibility {
+    /// Private, or `pub(self)`.
+    #[default]
+    Private,
+    /// `pub(crate)`, `pub(super)` or `pub(in path)`.
+    Crate,
+    /// `pub`.
+    Public,
+}
+
+impl FromStr for Visibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "private" => Ok(Visibility::Private),
+            "pub(crate)" | "crate" => Ok(Visibility::Crate),
+            "pub" | "public" => Ok(Visibility::Public),
+            _ => Err(format!("Unrecognized visibility: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(Visibility);
+
 /// Settings to apply when exporting items.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -333,6 +722,10 @@ pub struct ExportConfig {
     pub pre_body: HashMap<String, String>,
     /// Table of raw strings to append to the body of items.
     pub body: HashMap<String, String>,
+    /// Table of macros to define after the declarations, by name (which may
+    /// have parameters, like `FOO(x)`). `{Item}` in their bodies is replaced
+    /// by the name `Item` is exported as.
+    pub macros: BTreeMap<String, String>,
     /// A prefix to add before the name of every item
     pub prefix: Option<String>,
     /// Types of items to generate.
@@ -341,6 +734,30 @@ pub struct ExportConfig {
     pub renaming_overrides_prefixing: bool,
     /// Mangling configuration.
     pub mangle: MangleConfig,
+    /// Whether to export items marked `#[doc(hidden)]`, and the items of
+    /// modules marked so.
+    pub doc_hidden: bool,
+    /// The visibility items need to be exported.
+    pub min_visibility: Visibility,
+}
+
+impl Default for ExportConfig {
+    fn default() -> ExportConfig {
+        ExportConfig {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            rename: HashMap::new(),
+            pre_body: HashMap::new(),
+            body: HashMap::new(),
+            macros: BTreeMap::new(),
+            prefix: None,
+            item_types: Vec::new(),
+            renaming_overrides_prefixing: false,
+            mangle: MangleConfig::default(),
+            doc_hidden: true,
+            min_visibility: Visibility::Private,
+        }
+    }
 }
 
 /// Mangling-specific configuration.
@@ -392,6 +809,27 @@ pub struct LayoutConfig {
     /// The way to annotate C types as #[repr(align(...))]. This is assumed to be a functional
     /// macro which takes a single argument (the alignment).
     pub aligned_n: Option<String>,
+    /// The names to use for primitive types, by their Rust name (e.g. `u32`
+    /// or `c_int`).
+    pub type_map: HashMap<String, String>,
+    /// The name to use for `bool`, unless `type_map` has one. Constants use
+    /// `1` and `0` rather than `true` and `false` when it isn't `bool`.
+    #[serde(rename = "bool")]
+    pub bool_type: BoolType,
+    /// Whether to build the crate with `-Zprint-type-sizes` to learn how
+    /// rustc lays out the exported types.
+    pub probe: bool,
+    /// Whether to check the layouts found by `probe` with static asserts.
+    pub static_asserts: bool,
+    /// Whether to give opaque types the size and alignment found by `probe`.
+    pub sized_opaque: bool,
+    /// Whether the structs are used as a wire format, warning about the ones
+    /// with multi-byte integer fields, which are in the byte order of the
+    /// target.
+    pub wire_format: bool,
+    /// Whether to write a function converting each of the structs
+    /// `wire_format` warns about to big-endian byte order, and back.
+    pub wire_format_helpers: bool,
 }
 
 impl LayoutConfig {
@@ -416,6 +854,8 @@ pub struct FunctionConfig {
     pub postfix: Option<String>,
     /// The way to annotation this function as #[must_use]
     pub must_use: Option<String>,
+    /// The way to annotate this function as #[must_use = "message"]
+    pub must_use_with_note: Option<String>,
     /// The way to annotation this function as #[deprecated] without notes
     pub deprecated: Option<String>,
     /// The way to annotation this function as #[deprecated] with notes
@@ -430,6 +870,42 @@ pub struct FunctionConfig {
     pub sort_by: Option<SortKey>,
     /// Optional text to output after functions which return `!`.
     pub no_return: Option<String>,
+    /// Optional attribute listing the 1-based indices of the non-nullable
+    /// pointer arguments of a function, where `{}` is replaced by the list.
+    pub non_null_args_attribute: Option<String>,
+    /// Optional attribute for functions annotated with `returns-ownership`,
+    /// where `{}` is replaced by the function releasing the returned value.
+    pub returns_ownership_attribute: Option<String>,
+    /// Optional attribute written for each argument listed in a `consumes`
+    /// annotation, where `{}` is replaced by its 1-based index.
+    pub consumes_attribute: Option<String>,
+    /// Whether to describe ownership annotations in the documentation.
+    pub ownership_comments: bool,
+    /// Whether to note in the documentation of `extern "C-unwind"` functions
+    /// that they may unwind.
+    pub unwind_comments: bool,
+    /// Optional attribute for functions annotated with `weak`, which are
+    /// otherwise followed by a `#pragma weak`.
+    pub weak_attribute: Option<String>,
+    /// Optional comment introducing the functions of a `section`, where `{}`
+    /// is replaced by the section name.
+    pub section_banner: Option<String>,
+    /// The suffix of the symbol of a function annotated with `version`,
+    /// where `{}` is replaced by the version. The unversioned name, made by
+    /// removing it, is defined to the latest version of the function.
+    pub version_suffix: String,
+    /// Optional ELF version node functions annotated with `version` are
+    /// bound to with `.symver`, where `{}` is replaced by the version.
+    pub symver_node: Option<String>,
+    /// The suffix of the wrappers generated for functions annotated with
+    /// `null-check`.
+    pub null_check_suffix: String,
+    /// Whether to annotate the pointer arguments and return values with SAL,
+    /// for MSVC's code analysis.
+    pub sal_annotations: bool,
+    /// Whether to give functions the attributes of GCC and Clang which can be
+    /// derived from their signature, like `nonnull` and `access`.
+    pub gnu_attributes: bool,
 }
 
 impl Default for FunctionConfig {
@@ -438,6 +914,7 @@ impl Default for FunctionConfig {
             prefix: None,
             postfix: None,
             must_use: None,
+            must_use_with_note: None,
             deprecated: None,
             deprecated_with_note: None,
             args: Layout::Auto,
@@ -445,23 +922,49 @@ impl Default for FunctionConfig {
             swift_name_macro: None,
             sort_by: None,
             no_return: None,
+            non_null_args_attribute: None,
+            returns_ownership_attribute: None,
+            consumes_attribute: None,
+            ownership_comments: false,
+            unwind_comments: false,
+            weak_attribute: None,
+            section_banner: None,
+            version_suffix: "_v{}".to_owned(),
+            symver_node: None,
+            null_check_suffix: "_checked".to_owned(),
+            sal_annotations: false,
+            gnu_attributes: false,
         }
     }
 }
 
 impl FunctionConfig {
     pub(crate) fn prefix(&self, annotations: &AnnotationSet) -> Option<String> {
-        if let Some(x) = annotations.atom("prefix") {
-            return x;
+        match annotations.atom("prefix") {
+            Some(x) if !x.as_deref().map_or(false, is_raw_lines) => x,
+            _ => self.prefix.clone(),
         }
-        self.prefix.clone()
     }
 
     pub(crate) fn postfix(&self, annotations: &AnnotationSet) -> Option<String> {
-        if let Some(x) = annotations.atom("postfix") {
+        match annotations.atom("postfix") {
+            Some(x) if !x.as_deref().map_or(false, is_raw_lines) => x,
+            _ => self.postfix.clone(),
+        }
+    }
+
+    pub(crate) fn sal_annotations(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("sal") {
             return x;
         }
-        self.postfix.clone()
+        self.sal_annotations
+    }
+
+    pub(crate) fn gnu_attributes(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("gnu-attributes") {
+            return x;
+        }
+        self.gnu_attributes
     }
 }
 
@@ -490,11 +993,26 @@ pub struct StructConfig {
     pub derive_gte: bool,
     /// Whether to generate a ostream serializer for the struct
     pub derive_ostream: bool,
+    /// Whether to generate C++ operators for the operator traits, like `Add`
+    /// or `BitOr`, which structs implement
+    pub derive_ops: bool,
+    /// Whether to generate C++ wrapper structs for transparent structs, with
+    /// explicit conversions to and from the type they wrap, rather than
+    /// typedefs
+    pub derive_transparent_conversions: bool,
     /// Whether associated constants should be in the body. Only applicable to
     /// non-transparent structs, and in C++-only.
     pub associated_constants_in_body: bool,
+    /// The initializer helpers, listing every field in order, to generate in
+    /// C.
+    pub initializer: StructInitializer,
+    /// Whether to generate a `DEFAULT` associated constant for structs which
+    /// derive `Default`, or implement it with a constant expression.
+    pub default_constants: bool,
     /// The way to annotate this struct as #[must_use].
     pub must_use: Option<String>,
+    /// The way to annotate this struct as #[must_use = "message"].
+    pub must_use_with_note: Option<String>,
     /// The way to annotation this function as #[deprecated] without notes
     pub deprecated: Option<String>,
     /// The way to annotation this function as #[deprecated] with notes
@@ -550,6 +1068,24 @@ impl StructConfig {
         }
         self.derive_ostream
     }
+    pub(crate) fn derive_ops(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-ops") {
+            return x;
+        }
+        self.derive_ops
+    }
+    pub(crate) fn derive_transparent_conversions(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-transparent-conversions") {
+            return x;
+        }
+        self.derive_transparent_conversions
+    }
+    pub(crate) fn initializer(&self, annotations: &AnnotationSet) -> StructInitializer {
+        if let Some(x) = annotations.parse_atom::<StructInitializer>("initializer") {
+            return x;
+        }
+        self.initializer
+    }
 }
 
 /// Settings to apply to generated enums.
@@ -582,6 +1118,8 @@ pub struct EnumConfig {
     pub cast_assert_name: Option<String>,
     /// The way to annotation this enum as #[must_use].
     pub must_use: Option<String>,
+    /// The way to annotate this enum as #[must_use = "message"].
+    pub must_use_with_note: Option<String>,
     /// The way to annotation this function as #[deprecated] without notes
     pub deprecated: Option<String>,
     /// The way to annotation this function as #[deprecated] with notes
@@ -603,6 +1141,24 @@ pub struct EnumConfig {
     /// Whether to generate empty, private default-constructors for tagged
     /// enums.
     pub private_default_tagged_enum_constructor: bool,
+    /// Whether to generate a `{Enum}_to_string` function returning the name
+    /// of the variant, for enums without data.
+    pub derive_to_string: bool,
+    /// Whether to write the bodies of the variants of enums with fields as
+    /// unnamed structs inside the enum, instead of as separate `{Variant}_Body`
+    /// structs. Doesn't apply to Cython.
+    pub anonymous_variant_bodies: bool,
+    /// How to give C enums with a `repr` type their size. Only relevant when
+    /// targeting C.
+    pub fixed_type: EnumFixedType,
+    /// What to do about variants of an enum with the same value.
+    pub duplicate_values: LintLevel,
+    /// What to do about values missing between the smallest and largest
+    /// values of an enum, for enums used as indices.
+    pub value_gaps: LintLevel,
+    /// What to do about values out of the range of the C type of the
+    /// enumerators.
+    pub value_range: LintLevel,
 }
 
 impl Default for EnumConfig {
@@ -617,6 +1173,7 @@ impl Default for EnumConfig {
             derive_mut_casts: false,
             cast_assert_name: None,
             must_use: None,
+            must_use_with_note: None,
             deprecated: None,
             deprecated_with_note: None,
             derive_tagged_enum_destructor: false,
@@ -625,6 +1182,12 @@ impl Default for EnumConfig {
             derive_ostream: false,
             enum_class: true,
             private_default_tagged_enum_constructor: false,
+            derive_to_string: false,
+            anonymous_variant_bodies: false,
+            fixed_type: EnumFixedType::Typedef,
+            duplicate_values: LintLevel::Warn,
+            value_gaps: LintLevel::Allow,
+            value_range: LintLevel::Warn,
         }
     }
 }
@@ -678,6 +1241,27 @@ impl EnumConfig {
         }
         self.derive_ostream
     }
+    pub(crate) fn derive_to_string(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-to-string") {
+            return x;
+        }
+        self.derive_to_string
+    }
+    pub(crate) fn fixed_type(&self, annotations: &AnnotationSet) -> EnumFixedType {
+        if let Some(x) = annotations.parse_atom::<EnumFixedType>("fixed-type") {
+            return x;
+        }
+        self.fixed_type
+    }
+    pub(crate) fn duplicate_values(&self, annotations: &AnnotationSet) -> LintLevel {
+        lint_level_annotation(annotations, "duplicate-values").unwrap_or(self.duplicate_values)
+    }
+    pub(crate) fn value_gaps(&self, annotations: &AnnotationSet) -> LintLevel {
+        lint_level_annotation(annotations, "value-gaps").unwrap_or(self.value_gaps)
+    }
+    pub(crate) fn value_range(&self, annotations: &AnnotationSet) -> LintLevel {
+        lint_level_annotation(annotations, "value-range").unwrap_or(self.value_range)
+    }
     pub(crate) fn enum_class(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("enum-class") {
             return x;
@@ -693,6 +1277,12 @@ impl EnumConfig {
         }
         self.private_default_tagged_enum_constructor
     }
+    pub(crate) fn anonymous_variant_bodies(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("anonymous-variant-bodies") {
+            return x;
+        }
+        self.anonymous_variant_bodies
+    }
 }
 
 /// Settings to apply to generated constants.
@@ -707,6 +1297,9 @@ pub struct ConstantConfig {
     pub allow_constexpr: bool,
     /// Sort key for constants
     pub sort_by: Option<SortKey>,
+    /// Whether to write a `{name}_LEN` define after statics which are arrays,
+    /// or references to arrays.
+    pub array_length_defines: bool,
 }
 
 impl Default for ConstantConfig {
@@ -715,6 +1308,7 @@ impl Default for ConstantConfig {
             allow_static_const: true,
             allow_constexpr: true,
             sort_by: None,
+            array_length_defines: false,
         }
     }
 }
@@ -848,6 +1442,20 @@ pub struct ParseConfig {
     /// List of crate names which generate consts, statics, and fns. By default
     /// no dependent crates generate them.
     pub extra_bindings: Vec<String>,
+    /// Whether to parse code only used by tests, benches and examples:
+    /// `#[cfg(test)]` items, modules in the `tests`, `benches` and `examples`
+    /// directories of a crate, and dev-dependencies.
+    pub parse_tests: bool,
+    /// The maximum depth of the dependencies to parse, where 1 only parses
+    /// the direct dependencies of the binding crate. Unlimited by default.
+    pub max_depth: Option<usize>,
+    /// Which modules of a dependency to parse, by crate name.
+    pub dependencies: HashMap<String, DependencyParseConfig>,
+    /// An optional whitelist of globs of the module paths to load the items
+    /// of, like `crate::ffi::**`, starting with the name of a crate, or
+    /// `crate` for the binding crate. Crates no glob starts with are parsed
+    /// as usual.
+    pub include_paths: Option<Vec<String>>,
 }
 
 impl ParseConfig {
@@ -863,8 +1471,143 @@ impl ParseConfig {
 
         self.extra_bindings.iter().any(|dep| dep == crate_name)
     }
+
+    /// Whether `include_paths` allows loading the items of the module at
+    /// `module_path` of the crate `crate_name`, or with `prefix`, of a module
+    /// in it.
+    pub(crate) fn includes_path(
+        &self,
+        crate_name: &str,
+        is_binding_crate: bool,
+        module_path: &[String],
+        prefix: bool,
+    ) -> bool {
+        let include_paths = match self.include_paths {
+            Some(ref include_paths) => include_paths,
+            None => return true,
+        };
+        let crate_name = crate_name.replace('-', "_");
+        let mut globs = include_paths
+            .iter()
+            .filter_map(|glob| {
+                let (first, rest) = glob.split_once("::").unwrap_or((glob, ""));
+                let matches = first == crate_name || (is_binding_crate && first == "crate");
+                matches.then_some(rest)
+            })
+            .peekable();
+        if globs.peek().is_none() {
+            return true;
+        }
+        globs.any(|glob| glob_matches(glob, module_path, prefix))
+    }
+}
+
+/// Settings to apply when parsing a dependency.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DependencyParseConfig {
+    /// An optional whitelist of globs of the modules to load the items of,
+    /// like `Win32::Foundation`, relative to the crate root. `*` matches any
+    /// part of a module name, and `**` any number of modules.
+    pub include: Option<Vec<String>>,
+    /// Globs of the modules to not parse, along with their submodules.
+    pub exclude: Vec<String>,
+}
+
+impl DependencyParseConfig {
+    /// Whether to load the items of the module at `module_path`.
+    pub(crate) fn should_load_mod(&self, module_path: &[String]) -> bool {
+        self.should_parse_mod(module_path)
+            && self.include.as_ref().map_or(true, |include| {
+                include
+                    .iter()
+                    .any(|glob| glob_matches(glob, module_path, false))
+            })
+    }
+
+    /// Whether to parse the module at `module_path`, looking for the modules
+    /// to load the items of.
+    pub(crate) fn should_parse_mod(&self, module_path: &[String]) -> bool {
+        !self
+            .exclude
+            .iter()
+            .any(|glob| glob_matches(glob, module_path, false))
+            && self.include.as_ref().map_or(true, |include| {
+                include
+                    .iter()
+                    .any(|glob| glob_matches(glob, module_path, true))
+            })
+    }
+}
+
+/// Whether a glob of module paths matches `module_path`, or with `prefix`, a
+/// module in it.
+fn glob_matches(glob: &str, module_path: &[String], prefix: bool) -> bool {
+    fn matches_segments(glob: &[&str], path: &[String], prefix: bool) -> bool {
+        match (glob.first(), path.first()) {
+            (_, None) => prefix || glob.iter().all(|segment| *segment == "**"),
+            (None, Some(_)) => false,
+            (Some(&"**"), Some(_)) => {
+                matches_segments(&glob[1..], path, prefix)
+                    || matches_segments(glob, &path[1..], prefix)
+            }
+            (Some(segment), Some(name)) => {
+                matches_name(segment.as_bytes(), name.as_bytes())
+                    && matches_segments(&glob[1..], &path[1..], prefix)
+            }
+        }
+    }
+
+    fn matches_name(glob: &[u8], name: &[u8]) -> bool {
+        match (glob.first(), name.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => {
+                matches_name(&glob[1..], name)
+                    || (!name.is_empty() && matches_name(glob, &name[1..]))
+            }
+            (Some(a), Some(b)) if a == b => matches_name(&glob[1..], &name[1..]),
+            _ => false,
+        }
+    }
+
+    let glob: Vec<&str> = glob.split("::").filter(|x| !x.is_empty()).collect();
+    matches_segments(&glob, module_path, prefix)
+}
+
+/// How to write `Box<T>` and `Option<Box<T>>`, which are laid out as a
+/// pointer to `T` (which may be null for the `Option`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
+pub enum OwnedBox {
+    /// As a pointer in C, and as the opaque `Box<T>` template in C++.
+    #[default]
+    Auto,
+    /// As a pointer.
+    Pointer,
+    /// As a pointer, marked with an `/* owned */` comment.
+    Comment,
+    /// As a `{T}Owned` typedef of a pointer to `T`.
+    Typedef,
+}
+
+impl FromStr for OwnedBox {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "auto" => Ok(OwnedBox::Auto),
+            "pointer" => Ok(OwnedBox::Pointer),
+            "comment" => Ok(OwnedBox::Comment),
+            "typedef" => Ok(OwnedBox::Typedef),
+            _ => Err(format!("Unrecognized owned box style: '{}'.", s)),
+        }
+    }
 }
 
+deserialize_enum_str!(OwnedBox);
+
 /// Settings to apply to pointers
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -873,6 +1616,19 @@ impl ParseConfig {
 pub struct PtrConfig {
     /// Optional attribute to apply to pointers that are required to not be null
     pub non_null_attribute: Option<String>,
+    /// Optional attribute to apply to pointers that are allowed to be null
+    pub nullable_attribute: Option<String>,
+    /// How to write `Box<T>` and `Option<Box<T>>`.
+    pub owned_box: OwnedBox,
+}
+
+impl PtrConfig {
+    pub(crate) fn owned_box(&self, annotations: &AnnotationSet) -> OwnedBox {
+        if let Some(x) = annotations.parse_atom::<OwnedBox>("owned") {
+            return x;
+        }
+        self.owned_box
+    }
 }
 
 /// Settings specific to Cython bindings.
@@ -886,6 +1642,285 @@ pub struct CythonConfig {
     /// `from module cimport name1, name2, ...` declarations added in the same place
     /// where you'd get includes in C.
     pub cimports: BTreeMap<String, Vec<String>>,
+    /// Whether to declare functions `nogil`, so that they can be called
+    /// without holding the GIL.
+    pub nogil: bool,
+    /// Where to write a `.pyx` module wrapping the opaque types annotated with
+    /// `destructor` in classes, relative to the bindings.
+    pub wrapper_path: Option<String>,
+    /// Whether to declare enums `cpdef`, so that their values can be used from
+    /// Python.
+    pub cpdef_enums: bool,
+}
+
+impl CythonConfig {
+    pub(crate) fn cpdef_enum(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("cpdef") {
+            return x;
+        }
+        self.cpdef_enums
+    }
+
+    pub(crate) fn nogil(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("nogil") {
+            return x;
+        }
+        self.nogil
+    }
+}
+
+/// Settings for headers used on freestanding targets, which don't have the
+/// standard headers.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct FreestandingConfig {
+    /// Whether to define the standard types instead of including the standard
+    /// headers.
+    pub enabled: bool,
+    /// The types to define, overriding or extending the default ones, from
+    /// standard type to the target's type.
+    pub types: BTreeMap<String, String>,
+}
+
+impl FreestandingConfig {
+    /// The standard types to define, as (name, definition) pairs.
+    pub(crate) fn types(&self) -> Vec<(&str, &str)> {
+        let mut types: Vec<(&str, &str)> = vec![
+            ("int8_t", "signed char"),
+            ("int16_t", "short"),
+            ("int32_t", "int"),
+            ("int64_t", "long long"),
+            ("uint8_t", "unsigned char"),
+            ("uint16_t", "unsigned short"),
+            ("uint32_t", "unsigned int"),
+            ("uint64_t", "unsigned long long"),
+            ("intptr_t", "long"),
+            ("uintptr_t", "unsigned long"),
+            ("size_t", "unsigned long"),
+            ("ptrdiff_t", "long"),
+            ("va_list", "__builtin_va_list"),
+            ("bool", "_Bool"),
+        ];
+        for (name, ty) in &self.types {
+            match types.iter_mut().find(|(default, _)| default == name) {
+                Some(default) => default.1 = ty,
+                None => types.push((name, ty)),
+            }
+        }
+        types
+    }
+}
+
+/// Settings for inlining the project's own headers into the bindings, for a
+/// self-contained single-header distribution.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct AmalgamateConfig {
+    /// Whether to inline the headers of `includes` instead of including them.
+    pub enabled: bool,
+    /// The directories the headers are looked up in, in order.
+    pub include_dirs: Vec<String>,
+}
+
+impl Default for AmalgamateConfig {
+    fn default() -> AmalgamateConfig {
+        AmalgamateConfig {
+            enabled: false,
+            include_dirs: vec![".".to_owned()],
+        }
+    }
+}
+
+/// Settings for organizing the declarations into Doxygen groups.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DoxygenConfig {
+    /// What to group the declarations by, besides the `group` annotation.
+    pub groups: DoxygenGroups,
+    /// The titles of the groups, by section, module path or group annotation.
+    pub group_titles: HashMap<String, String>,
+}
+
+impl Default for DoxygenConfig {
+    fn default() -> DoxygenConfig {
+        DoxygenConfig {
+            groups: DoxygenGroups::None,
+            group_titles: HashMap::new(),
+        }
+    }
+}
+
+impl DoxygenConfig {
+    /// The group of a declaration, as named by its `group` or `section`
+    /// annotation or its module path.
+    pub(crate) fn group(
+        &self,
+        annotations: &AnnotationSet,
+        location: Option<&SourceLocation>,
+    ) -> Option<String> {
+        if let Some(Some(group)) = annotations.atom("group") {
+            return Some(group);
+        }
+        match self.groups {
+            DoxygenGroups::None => None,
+            DoxygenGroups::Section => annotations.atom("section")?,
+            DoxygenGroups::Module => source_module(location),
+        }
+    }
+
+    /// The name of a group in `\defgroup` and `\ingroup`, which Doxygen
+    /// requires to be a single word.
+    pub(crate) fn group_name(group: &str) -> String {
+        group
+            .replace("::", "_")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+
+    pub(crate) fn group_title<'a>(&'a self, group: &'a str) -> &'a str {
+        self.group_titles.get(group).map_or(group, String::as_str)
+    }
+}
+
+/// The module an item declared at `location` comes from, for
+/// `group_by_module`, or `None` for the crate root.
+pub(crate) fn source_module(location: Option<&SourceLocation>) -> Option<String> {
+    location?.module.clone()
+}
+
+/// Settings for the source file defining the helper functions of the
+/// bindings, like the `derive_to_string` functions of enums.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct ShimConfig {
+    /// Where to write the source file, relative to the header. When unset,
+    /// the helpers are defined `static inline` in the header.
+    pub path: Option<String>,
+}
+
+/// Settings for the Rust declarations written by `--emit rust-externs`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct RustExternsConfig {
+    /// The edition of the crate the declarations are included in. From the
+    /// 2024 edition, `extern` blocks have to be `unsafe`, which older
+    /// compilers reject, so they are only written as such from it on.
+    pub edition: Option<String>,
+}
+
+impl RustExternsConfig {
+    pub(crate) fn unsafe_extern_blocks(&self) -> bool {
+        // Editions are years, so they compare like numbers.
+        self.edition
+            .as_deref()
+            .map_or(false, |edition| edition >= "2024")
+    }
+}
+
+/// Settings for the header of the items annotated with `internal`, which are
+/// left out of the public header.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct InternalConfig {
+    /// Where to write the internal header, relative to the header. It
+    /// includes the public header. When unset, the items annotated with
+    /// `internal` are written to the header like any other.
+    pub path: Option<String>,
+}
+
+/// Settings for formatting the bindings with clang-format.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct ClangFormatConfig {
+    /// The `--style` to format the bindings written to a file with, like
+    /// `file` or `LLVM`. When unset, they aren't formatted.
+    pub style: Option<String>,
+    /// The clang-format executable, found in the `PATH` by default.
+    pub path: Option<String>,
+}
+
+/// Shell commands the command-line tool runs around generating the bindings.
+/// `{output}` and `{depfile}` are replaced by the paths given with `--output`
+/// and `--depfile`, quoted for the shell.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run before the crate is parsed.
+    pub pre_generate: Option<String>,
+    /// Run after the bindings are written to a file.
+    pub post_generate: Option<String>,
+}
+
+/// Settings for the SWIG interface file written along with the bindings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SwigConfig {
+    /// The name of the `%module`, defaulting to the header's file stem.
+    pub module: Option<String>,
+    /// How the header is included, defaulting to the header's file name.
+    pub header: Option<String>,
+}
+
+/// Settings for the pkg-config file written next to the bindings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct PackageConfig {
+    /// Whether to write a `.pc` file next to the header.
+    pub emit_pc: bool,
+    /// The package name, defaulting to the crate's.
+    pub name: Option<String>,
+    /// The package description, defaulting to the crate's.
+    pub description: Option<String>,
+    /// The package version, defaulting to the crate's.
+    pub version: Option<String>,
+    /// The installation prefix the other directories are relative to.
+    pub prefix: String,
+    /// The flags to link against the library, defaulting to linking the
+    /// crate's `[lib]` target.
+    pub libs: Option<String>,
+    /// The flags needed to link against the library statically.
+    pub libs_private: Option<String>,
+    /// Additional compiler flags, besides the include directory.
+    pub cflags: Option<String>,
+    /// The pkg-config packages the library depends on.
+    pub requires: Vec<String>,
+}
+
+impl Default for PackageConfig {
+    fn default() -> PackageConfig {
+        PackageConfig {
+            emit_pc: false,
+            name: None,
+            description: None,
+            version: None,
+            prefix: "/usr/local".to_owned(),
+            libs: None,
+            libs_private: None,
+            cflags: None,
+            requires: Vec::new(),
+        }
+    }
 }
 
 /// A collection of settings to customize the generated bindings.
@@ -904,25 +1939,54 @@ pub struct Config {
     pub after_includes: Option<String>,
     /// Optional text to output at the end of the file
     pub trailer: Option<String>,
+    /// Whether to start `header` with the SPDX license identifier and the
+    /// authors of the crate, from its `Cargo.toml`
+    pub license_header: bool,
     /// Optional name to use for an include guard
     pub include_guard: Option<String>,
     /// Add a `#pragma once` guard
     pub pragma_once: bool,
+    /// Whether to make the header safe to include in any way, for SDKs: it
+    /// gets both `#pragma once` and an include guard, C headers are made C++
+    /// compatible, and the declarations after the includes are put in an
+    /// `extern "C++"` block for C++, so that including the header from an
+    /// `extern "C"` block works.
+    pub defensive_header: bool,
+    /// Whether to start the header with an `#error` for compilers older than
+    /// the version of C or C++ which the features the header uses need.
+    pub compiler_checks: bool,
     /// Generates no includes at all. Overrides all other include options
     ///
     /// This option is useful when using cbindgen with tools such as python's cffi which
     /// doesn't understand include directives
     pub no_includes: bool,
+    /// Only include the standard headers declaring the types the bindings
+    /// use, instead of all of them
+    pub prune_includes: bool,
     // Package version: True if the package version should appear as a comment in the .h file
     pub package_version: bool,
     /// Optional text to output at major sections to deter manual editing
     pub autogen_warning: Option<String>,
+    /// Optional file to read `header` from, relative to the config file.
+    pub header_file: Option<StdPathBuf>,
+    /// Optional file to read `after_includes` from, relative to the config file.
+    pub after_includes_file: Option<StdPathBuf>,
+    /// Optional file to read `trailer` from, relative to the config file.
+    pub trailer_file: Option<StdPathBuf>,
+    /// Optional file to read `autogen_warning` from, relative to the config file.
+    pub autogen_warning_file: Option<StdPathBuf>,
     /// Include a comment with the version of cbindgen used to generate the file
     pub include_version: bool,
+    /// The prefix of the `{prefix}_VERSION_MAJOR`, `_MINOR`, `_PATCH` and
+    /// `{prefix}_VERSION` macros defining the version of the package
+    pub version_macros: Option<String>,
     /// An optional name for the root namespace. Only applicable when language="C++"
     pub namespace: Option<String>,
     /// An optional list of namespaces. Only applicable when language="C++"
     pub namespaces: Option<Vec<String>>,
+    /// An optional inline namespace to version the ABI of the declarations,
+    /// inside the other namespaces. Only applicable when language="C++"
+    pub inline_namespace: Option<String>,
     /// An optional list of namespaces to declare as using. Only applicable when language="C++"
     pub using_namespaces: Option<Vec<String>>,
     /// The style to use for braces
@@ -933,17 +1997,41 @@ pub struct Config {
     pub tab_width: usize,
     /// The type of line endings to generate
     pub line_endings: LineEndingStyle,
+    /// Whether to start the generated files with a UTF-8 byte order mark
+    pub utf8_bom: bool,
+    /// Whether to make sure that the generated files end with a line ending
+    pub final_newline: bool,
     /// The language to output bindings for
     pub language: Language,
     /// Include preprocessor defines in C bindings to ensure C++ compatibility
     pub cpp_compat: bool,
+    /// How to give the functions and globals C linkage in C++
+    pub extern_c: ExternC,
     /// The style to declare structs, enums and unions in for C
     pub style: Style,
     /// Default sort key for functions and constants.
     pub sort_by: SortKey,
+    /// How to order the definitions of types.
+    pub type_order: TypeOrder,
+    /// Whether to group the types and functions by the Rust module they're
+    /// declared in, with a banner before the declarations of each module.
+    pub group_by_module: bool,
+    /// Optional banner introducing the declarations of a module with
+    /// `group_by_module`, where `{}` is replaced by the module path.
+    pub module_banner: Option<String>,
+    /// Whether to make the output the same across runs and platforms, by
+    /// sorting the items by name and the includes, and writing the paths of
+    /// source locations relative to their package with `/` separators.
+    pub deterministic: bool,
     /// If this option is true `usize` and `isize` will be converted into `size_t` and `ptrdiff_t`
     /// instead of `uintptr_t` and `intptr_t` respectively.
     pub usize_is_size_t: bool,
+    /// How to write `usize`, overriding `usize_is_size_t`.
+    pub usize_type: Option<SizeType>,
+    /// How to write `isize`, overriding `usize_is_size_t`.
+    pub isize_type: Option<SizeType>,
+    /// How to write Rust's `char`.
+    pub char_type: CharType,
     /// The configuration options for parsing
     pub parse: ParseConfig,
     /// The configuration options for exporting
@@ -970,8 +2058,29 @@ pub struct Config {
     pub documentation: bool,
     /// How documentation comments should be styled.
     pub documentation_style: DocumentationStyle,
+    /// How documentation comments should be styled in C++ output, if not
+    /// like `documentation_style`.
+    pub cpp_documentation_style: Option<DocumentationStyle>,
     /// How much of the documentation should be output for each item.
     pub documentation_length: DocumentationLength,
+    /// Rustdoc sections (by heading) to leave out of the documentation.
+    pub documentation_remove_sections: Vec<String>,
+    /// Rustdoc sections to give another heading, from heading to heading.
+    pub documentation_rename_sections: HashMap<String, String>,
+    /// Whether to strip rustdoc-only syntax, like intra-doc links and hidden
+    /// lines of Rust code blocks, from the documentation.
+    pub documentation_strip_rustdoc: bool,
+    /// Whether to point at the Rust source of each declaration.
+    pub source_locations: SourceLocations,
+    /// The CUDA qualifier of the functions and C++ helper methods which
+    /// don't have a `cuda` annotation.
+    pub cuda: CudaQualifier,
+    /// What to do about exported signatures using types which aren't
+    /// FFI-safe, like `String` or `&str`.
+    pub improper_ctypes: LintLevel,
+    /// What to do about exported types whose fields C can't rely on the
+    /// layout of, like fields holding types which aren't `#[repr(C)]`.
+    pub field_layout: LintLevel,
     /// Configuration options for pointers
     #[serde(rename = "ptr")]
     pub pointer: PtrConfig,
@@ -1012,6 +2121,28 @@ pub struct Config {
     pub only_target_dependencies: bool,
     /// Configuration options specific to Cython.
     pub cython: CythonConfig,
+    /// Configuration options for freestanding targets.
+    pub freestanding: FreestandingConfig,
+    /// Configuration options for the SWIG interface file.
+    pub swig: SwigConfig,
+    /// Configuration options for inlining the project's headers.
+    pub amalgamate: AmalgamateConfig,
+    /// Configuration options for the source file defining helper functions.
+    pub shim: ShimConfig,
+    /// Configuration options for the Rust declarations of `--emit
+    /// rust-externs`.
+    pub rust_externs: RustExternsConfig,
+    /// Configuration options for the header of the items annotated with
+    /// `internal`.
+    pub internal: InternalConfig,
+    /// Configuration options for formatting with clang-format.
+    pub clang_format: ClangFormatConfig,
+    /// Commands run by the command-line tool around generating the bindings
+    pub hooks: HooksConfig,
+    /// Configuration options for Doxygen groups.
+    pub doxygen: DoxygenConfig,
+    /// Configuration options for the pkg-config file.
+    pub package: PackageConfig,
     #[doc(hidden)]
     #[serde(skip)]
     /// Internal field for tracking from which file the config was loaded.
@@ -1031,24 +2162,44 @@ impl Default for Config {
             sys_includes: Vec::new(),
             after_includes: None,
             trailer: None,
+            license_header: false,
             include_guard: None,
             pragma_once: false,
+            defensive_header: false,
+            compiler_checks: false,
             autogen_warning: None,
+            header_file: None,
+            after_includes_file: None,
+            trailer_file: None,
+            autogen_warning_file: None,
             include_version: false,
+            version_macros: None,
             no_includes: false,
+            prune_includes: false,
             package_version: false,
             namespace: None,
             namespaces: None,
+            inline_namespace: None,
             using_namespaces: None,
             braces: Braces::SameLine,
             line_length: 100,
             tab_width: 2,
             line_endings: LineEndingStyle::default(),
+            utf8_bom: false,
+            final_newline: false,
             language: Language::Cxx,
             cpp_compat: false,
+            extern_c: ExternC::default(),
             style: Style::default(),
             usize_is_size_t: false,
+            usize_type: None,
+            isize_type: None,
+            char_type: CharType::default(),
             sort_by: SortKey::None,
+            type_order: TypeOrder::Dependencies,
+            group_by_module: false,
+            module_banner: None,
+            deterministic: false,
             macro_expansion: Default::default(),
             parse: ParseConfig::default(),
             export: ExportConfig::default(),
@@ -1060,10 +2211,28 @@ impl Default for Config {
             defines: HashMap::new(),
             documentation: true,
             documentation_style: DocumentationStyle::Auto,
+            cpp_documentation_style: None,
             documentation_length: DocumentationLength::Full,
+            documentation_remove_sections: Vec::new(),
+            documentation_rename_sections: HashMap::new(),
+            documentation_strip_rustdoc: false,
+            source_locations: SourceLocations::None,
+            cuda: CudaQualifier::None,
+            improper_ctypes: LintLevel::Warn,
+            field_layout: LintLevel::Warn,
             pointer: PtrConfig::default(),
             only_target_dependencies: false,
             cython: CythonConfig::default(),
+            freestanding: FreestandingConfig::default(),
+            swig: SwigConfig::default(),
+            amalgamate: AmalgamateConfig::default(),
+            shim: ShimConfig::default(),
+            rust_externs: RustExternsConfig::default(),
+            internal: InternalConfig::default(),
+            clang_format: ClangFormatConfig::default(),
+            hooks: HooksConfig::default(),
+            doxygen: DoxygenConfig::default(),
+            package: PackageConfig::default(),
             config_path: None,
         }
     }
@@ -1074,6 +2243,118 @@ impl Config {
         self.language == Language::C && self.cpp_compat
     }
 
+    /// What to write in front of the declaration of each function and
+    /// global for `extern_c = "item"`, instead of `extern`.
+    pub(crate) fn extern_c_item(&self) -> Option<&'static str> {
+        if self.extern_c != ExternC::Item {
+            return None;
+        }
+        match self.language {
+            Language::Cxx => Some("extern \"C\""),
+            Language::C if self.cpp_compat => Some("CBINDGEN_EXTERN_C"),
+            _ => None,
+        }
+    }
+
+    /// The name to write `usize`, or `isize` if `signed`, as.
+    pub(crate) fn size_type(&self, signed: bool) -> &str {
+        let ty = if signed {
+            &self.isize_type
+        } else {
+            &self.usize_type
+        };
+        let size_t = match *ty {
+            Some(SizeType::Custom(ref name)) => return name,
+            Some(SizeType::SizeT) => true,
+            Some(SizeType::Intptr) => false,
+            None => self.usize_is_size_t,
+        };
+        match (size_t, signed) {
+            (true, false) => "size_t",
+            (true, true) => "ptrdiff_t",
+            (false, false) => "uintptr_t",
+            (false, true) => "intptr_t",
+        }
+    }
+
+    /// Whether `bool` is written as `bool`, rather than as another type set
+    /// by `layout.bool` or `layout.type_map`, with which `true` and `false`
+    /// are written as `1` and `0`.
+    pub(crate) fn writes_bool(&self) -> bool {
+        PrimitiveType::Bool.to_repr_c(self) == "bool"
+    }
+
+    /// The macro expanding to the CUDA qualifier of a declaration with the
+    /// given annotations, if it has one.
+    pub(crate) fn cuda_qualifier(&self, annotations: &AnnotationSet) -> Option<&'static str> {
+        if self.language == Language::Cython {
+            return None;
+        }
+        annotations
+            .parse_atom::<CudaQualifier>("cuda")
+            .unwrap_or(self.cuda)
+            .macro_name()
+    }
+
+    /// The files the header, trailer and other texts are read from.
+    pub(crate) fn text_files(&self) -> impl Iterator<Item = &StdPathBuf> {
+        IntoIterator::into_iter([
+            &self.header_file,
+            &self.after_includes_file,
+            &self.trailer_file,
+            &self.autogen_warning_file,
+        ])
+        .flatten()
+    }
+
+    /// Replaces the texts which have a `*_file` counterpart with the contents
+    /// of the file, where `{crate}`, `{version}`, `{license}` and `{authors}`
+    /// are replaced with the name, version, license and authors of the crate.
+    pub(crate) fn load_text_files(
+        &mut self,
+        crate_name: &str,
+        version: &str,
+        license: &str,
+        authors: &str,
+    ) -> Result<(), Error> {
+        let texts = [
+            ("header", &self.header_file, &mut self.header),
+            (
+                "after_includes",
+                &self.after_includes_file,
+                &mut self.after_includes,
+            ),
+            ("trailer", &self.trailer_file, &mut self.trailer),
+            (
+                "autogen_warning",
+                &self.autogen_warning_file,
+                &mut self.autogen_warning,
+            ),
+        ];
+        for (name, path, text) in IntoIterator::into_iter(texts) {
+            let path = match path {
+                Some(path) => path,
+                None => continue,
+            };
+            if text.is_some() {
+                warn!("{}_file overrides {}.", name, name);
+            }
+            let contents =
+                fs::read_to_string(path).map_err(|error| Error::TextFile(path.clone(), error))?;
+            // Like the strings of the config, the texts don't end with a new line.
+            let contents = contents.strip_suffix('\n').unwrap_or(&contents);
+            let contents = contents.strip_suffix('\r').unwrap_or(contents);
+            *text = Some(
+                contents
+                    .replace("{crate}", crate_name)
+                    .replace("{version}", version)
+                    .replace("{license}", license)
+                    .replace("{authors}", authors),
+            );
+        }
+        Ok(())
+    }
+
     pub(crate) fn include_guard(&self) -> Option<&str> {
         if self.language == Language::Cython {
             None
@@ -1108,6 +2389,19 @@ impl Config {
 
         let mut config = toml::from_str::<Config>(&config_text)
             .map_err(|e| format!("Couldn't parse config file: {}.", e))?;
+        if let Some(dir) = file_name.as_ref().parent() {
+            let text_files = [
+                &mut config.header_file,
+                &mut config.after_includes_file,
+                &mut config.trailer_file,
+                &mut config.autogen_warning_file,
+            ];
+            for path in IntoIterator::into_iter(text_files).flatten() {
+                if path.is_relative() {
+                    *path = dir.join(&*path);
+                }
+            }
+        }
         config.config_path = Some(StdPathBuf::from(file_name.as_ref()));
         Ok(config)
     }
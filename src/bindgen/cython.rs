@@ -0,0 +1,198 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::Write;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::cdecl;
+use crate::bindgen::ir::{Function, Item, ItemContainer, OpaqueItem, PrimitiveType, Type};
+use crate::bindgen::writer::SourceWriter;
+
+/// An opaque type annotated with `destructor`, wrapped by a `cdef class`
+/// owning a pointer to it.
+struct Wrapper<'a> {
+    opaque: &'a OpaqueItem,
+    constructor: Option<&'a Function>,
+    destructor: &'a Function,
+}
+
+/// The opaque types to wrap, with the functions creating and destroying them.
+fn wrappers(bindings: &Bindings) -> Vec<Wrapper<'_>> {
+    let find = |name: &str| bindings.functions.iter().find(|f| f.path.name() == name);
+    let mut wrappers = Vec::new();
+    for item in &bindings.items {
+        let opaque = match *item {
+            ItemContainer::OpaqueItem(ref x) => x,
+            _ => continue,
+        };
+        let destructor = match opaque.annotations.atom("destructor") {
+            Some(Some(name)) => match find(&name) {
+                Some(destructor) => destructor,
+                None => {
+                    warn!(
+                        "Can't wrap {}, its destructor {} isn't exported.",
+                        opaque.export_name(),
+                        name
+                    );
+                    continue;
+                }
+            },
+            _ => continue,
+        };
+        let constructor = match opaque.annotations.atom("constructor") {
+            Some(Some(name)) => match find(&name) {
+                Some(constructor) if is_constructor(constructor, opaque) => Some(constructor),
+                Some(_) => {
+                    warn!(
+                        "Can't wrap {} with the constructor {}, which must return a pointer \
+                         to it and take numbers.",
+                        opaque.export_name(),
+                        name
+                    );
+                    continue;
+                }
+                None => {
+                    warn!(
+                        "Can't wrap {}, its constructor {} isn't exported.",
+                        opaque.export_name(),
+                        name
+                    );
+                    continue;
+                }
+            },
+            _ => None,
+        };
+        wrappers.push(Wrapper {
+            opaque,
+            constructor,
+            destructor,
+        });
+    }
+    wrappers
+}
+
+/// Whether `function` returns a pointer to `opaque`, and only takes named
+/// arguments which Cython can convert from Python objects.
+fn is_constructor(function: &Function, opaque: &OpaqueItem) -> bool {
+    let returns_opaque = match function.ret {
+        Type::Ptr { ref ty, .. } => {
+            matches!(**ty, Type::Path(ref path) if path.export_name() == opaque.export_name())
+        }
+        _ => false,
+    };
+    returns_opaque
+        && function
+            .args
+            .iter()
+            .all(|arg| arg.name.is_some() && matches!(arg.ty, Type::Primitive(..)))
+}
+
+/// Writes a `.pyx` module with a `cdef class` for each opaque type annotated
+/// with `destructor`, which destroys the pointer it owns when it's
+/// deallocated, and is created with the function of the `constructor`
+/// annotation, or from an existing pointer with `from_ptr`.
+///
+/// The declarations are cimported from `module`, the bindings written as a
+/// `.pxd` file.
+pub(crate) fn write_wrappers<F: Write>(bindings: &Bindings, mut file: F, module: &str) {
+    let config = &bindings.config;
+    let mut out = SourceWriter::new(&mut file as &mut dyn Write, bindings);
+    if let Some(ref f) = config.header {
+        out.new_line_if_not_start();
+        write!(out, "{}", f);
+        out.new_line();
+    }
+    if let Some(ref f) = config.autogen_warning {
+        out.new_line_if_not_start();
+        write!(out, "{}", f);
+        out.new_line();
+    }
+    out.new_line_if_not_start();
+    out.write("from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t");
+    out.new_line();
+    out.write("from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t");
+    out.new_line();
+    write!(out, "cimport {}", module);
+    out.new_line();
+
+    for wrapper in wrappers(bindings) {
+        let name = wrapper.opaque.export_name();
+        out.new_line();
+        write!(out, "cdef class {}", name);
+        out.open_brace();
+        wrapper
+            .opaque
+            .documentation
+            .write_docstring(config, &mut out);
+        write!(out, "cdef {}.{} *ptr", module, name);
+        out.new_line();
+
+        if let Some(constructor) = wrapper.constructor {
+            out.new_line();
+            out.write("def __init__(self");
+            for arg in &constructor.args {
+                let name = arg.name.as_deref().unwrap();
+                out.write(", ");
+                // `bool` is only declared in the `.pxd` file.
+                if arg.ty == Type::Primitive(PrimitiveType::Bool) {
+                    write!(out, "bint {}", name);
+                } else {
+                    cdecl::write_field(&mut out, &arg.ty, name, config);
+                }
+            }
+            out.write(")");
+            out.open_brace();
+            let args: Vec<&str> = constructor
+                .args
+                .iter()
+                .filter_map(|arg| arg.name.as_deref())
+                .collect();
+            write!(
+                out,
+                "self.ptr = {}.{}({})",
+                module,
+                constructor.path.name(),
+                args.join(", ")
+            );
+            out.new_line();
+            out.write("if self.ptr is NULL");
+            out.open_brace();
+            out.write("raise MemoryError()");
+            out.close_brace(false);
+            out.new_line();
+            out.close_brace(false);
+        }
+
+        out.new_line();
+        out.write("def __dealloc__(self)");
+        out.open_brace();
+        out.write("if self.ptr is not NULL");
+        out.open_brace();
+        write!(
+            out,
+            "{}.{}(self.ptr)",
+            module,
+            wrapper.destructor.path.name()
+        );
+        out.close_brace(false);
+        out.new_line();
+        out.close_brace(false);
+
+        out.new_line();
+        out.write("@staticmethod");
+        out.new_line();
+        write!(out, "cdef {} from_ptr({}.{} *ptr)", name, module, name);
+        out.open_brace();
+        write!(out, "\"\"\"Takes ownership of `ptr`.\"\"\"");
+        out.new_line();
+        write!(out, "cdef {} wrapper = {}.__new__({})", name, name, name);
+        out.new_line();
+        out.write("wrapper.ptr = ptr");
+        out.new_line();
+        out.write("return wrapper");
+        out.close_brace(false);
+        out.close_brace(false);
+        out.new_line();
+    }
+}
@@ -17,6 +17,7 @@ impl DeclarationType {
 }
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum DeclarationType {
     Struct,
     Enum,
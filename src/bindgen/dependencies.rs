@@ -3,15 +3,24 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
 
-use crate::bindgen::ir::{ItemContainer, Path};
+use crate::bindgen::config::{source_module, Config, Language, TypeOrder};
+use crate::bindgen::ir::{
+    Cfg, ConditionWrite, GenericArgument, GenericParams, ItemContainer, Path, ToCondition, Type,
+    VariantBody,
+};
+use crate::bindgen::report::SkippedItem;
+use crate::bindgen::writer::SourceWriter;
 
 /// A dependency list is used for gathering what order to output the types.
 #[derive(Default)]
 pub struct Dependencies {
     pub order: Vec<ItemContainer>,
     pub items: HashSet<Path>,
+    /// The types referred to which couldn't be found, for `--emit report`.
+    pub missing: Vec<SkippedItem>,
 }
 
 impl Dependencies {
@@ -19,6 +28,7 @@ impl Dependencies {
         Dependencies {
             order: Vec::new(),
             items: HashSet::new(),
+            missing: Vec::new(),
         }
     }
 
@@ -43,4 +53,247 @@ impl Dependencies {
 
         self.order.sort_by(ordering);
     }
+
+    /// Orders the items by declaration or by name, only moving an item after
+    /// the ones it needs to be complete, and returns the forward declarations
+    /// of the structs and unions which are pointed to before their
+    /// definition.
+    ///
+    /// Opaque items come first, like with `sort`, as they don't depend on
+    /// anything and are already declarations. With `group_by_module`, the
+    /// items are ordered by module first, with the crate root first, and
+    /// opaque items stay in their module.
+    pub fn sort_stable(&mut self, config: &Config) -> Vec<ForwardDeclaration> {
+        let (mut opaque, mut items): (Vec<_>, Vec<_>) = self.order.drain(..).partition(|item| {
+            !config.group_by_module && matches!(*item, ItemContainer::OpaqueItem(..))
+        });
+        opaque.sort_by(|a, b| a.deref().path().cmp(b.deref().path()));
+        let module = |x: &ItemContainer| {
+            if config.group_by_module {
+                source_module(x.deref().source_location())
+            } else {
+                None
+            }
+        };
+        match config.type_order {
+            TypeOrder::Name => items.sort_by(|a, b| {
+                module(a)
+                    .cmp(&module(b))
+                    .then_with(|| a.deref().path().cmp(b.deref().path()))
+            }),
+            _ => items.sort_by(|a, b| {
+                let location = |x: &ItemContainer| {
+                    x.deref()
+                        .source_location()
+                        .map(|location| (location.file.clone(), location.line))
+                };
+                module(a)
+                    .cmp(&module(b))
+                    .then_with(|| location(a).cmp(&location(b)))
+                    .then_with(|| a.deref().path().cmp(b.deref().path()))
+            }),
+        }
+
+        let mut indices: HashMap<&Path, Vec<usize>> = HashMap::new();
+        for (i, item) in items.iter().enumerate() {
+            indices.entry(item.deref().path()).or_default().push(i);
+        }
+        // The items each item needs to be complete, and the ones it only
+        // needs to be declared.
+        let mut complete = vec![Vec::new(); items.len()];
+        let mut declared = vec![Vec::new(); items.len()];
+        for (i, item) in items.iter().enumerate() {
+            let mut references = Vec::new();
+            item_references(item, &mut references);
+            for (path, behind_pointer) in references {
+                for &j in indices.get(path).into_iter().flatten() {
+                    if j == i {
+                        continue;
+                    }
+                    if behind_pointer && can_forward_declare(&items[j], config) {
+                        declared[i].push(j);
+                    } else {
+                        complete[i].push(j);
+                    }
+                }
+            }
+            complete[i].sort_unstable();
+            complete[i].dedup();
+        }
+
+        // Each item is written after the items it needs to be complete, in
+        // their own order, so that an item only moves when it must.
+        let mut order = Vec::with_capacity(items.len());
+        let mut visited = vec![false; items.len()];
+        for i in 0..items.len() {
+            visit(i, &complete, &mut visited, &mut order);
+        }
+
+        let mut written = vec![false; items.len()];
+        let mut forward_declared = vec![false; items.len()];
+        let mut forward_declarations = Vec::new();
+        for &i in &order {
+            written[i] = true;
+            for &j in &declared[i] {
+                if !written[j] && !forward_declared[j] {
+                    forward_declared[j] = true;
+                    forward_declarations.push(ForwardDeclaration::new(&items[j]));
+                }
+            }
+        }
+
+        let mut items: Vec<Option<ItemContainer>> = items.into_iter().map(Some).collect();
+        self.order = opaque;
+        self.order
+            .extend(order.into_iter().map(|i| items[i].take().unwrap()));
+        forward_declarations
+    }
+}
+
+/// Adds `item` to `order` after the items it needs, unless it's already
+/// there or being added, when the types refer to each other by value.
+fn visit(item: usize, complete: &[Vec<usize>], visited: &mut [bool], order: &mut Vec<usize>) {
+    if visited[item] {
+        return;
+    }
+    visited[item] = true;
+    for &dependency in &complete[item] {
+        visit(dependency, complete, visited, order);
+    }
+    order.push(item);
+}
+
+/// Whether `item` can be declared before its definition, for pointers to it.
+fn can_forward_declare(item: &ItemContainer, config: &Config) -> bool {
+    if item
+        .deref()
+        .annotations()
+        .bool("no-export")
+        .unwrap_or(false)
+    {
+        return false;
+    }
+    // Without a tag, C structs and unions can only be named once defined.
+    if config.language != Language::Cxx && !config.style.generate_tag() {
+        return false;
+    }
+    match *item {
+        ItemContainer::Struct(ref x) => !x.is_transparent && x.generic_params.is_empty(),
+        ItemContainer::Union(ref x) => x.generic_params.is_empty(),
+        _ => false,
+    }
+}
+
+/// The paths of the types `item` refers to, and whether they're only
+/// referred to behind a pointer.
+fn item_references<'a>(item: &'a ItemContainer, out: &mut Vec<(&'a Path, bool)>) {
+    match *item {
+        ItemContainer::Struct(ref x) => {
+            for field in &x.fields {
+                type_references(&field.ty, &x.generic_params, false, out);
+            }
+            for constant in &x.associated_constants {
+                type_references(&constant.ty, &x.generic_params, false, out);
+            }
+        }
+        ItemContainer::Union(ref x) => {
+            for field in &x.fields {
+                type_references(&field.ty, &x.generic_params, false, out);
+            }
+        }
+        ItemContainer::Enum(ref x) => {
+            for variant in &x.variants {
+                if let VariantBody::Body { ref body, .. } = variant.body {
+                    for field in &body.fields {
+                        type_references(&field.ty, &x.generic_params, false, out);
+                    }
+                }
+            }
+        }
+        ItemContainer::Typedef(ref x) => {
+            type_references(&x.aliased, &x.generic_params, false, out);
+        }
+        ItemContainer::Constant(..) | ItemContainer::Static(..) | ItemContainer::OpaqueItem(..) => {
+        }
+    }
+}
+
+fn type_references<'a>(
+    ty: &'a Type,
+    generic_params: &GenericParams,
+    behind_pointer: bool,
+    out: &mut Vec<(&'a Path, bool)>,
+) {
+    match *ty {
+        Type::Ptr { ref ty, .. } => type_references(ty, generic_params, true, out),
+        Type::Path(ref generic) => {
+            let path = generic.path();
+            if !generic_params.iter().any(|param| param.name() == path) {
+                out.push((path, behind_pointer));
+            }
+            for generic_value in generic.generics() {
+                if let GenericArgument::Type(ref ty) = *generic_value {
+                    type_references(ty, generic_params, behind_pointer, out);
+                }
+            }
+        }
+        Type::Primitive(_) => {}
+        Type::Array(ref ty, _) => type_references(ty, generic_params, behind_pointer, out),
+        Type::FuncPtr {
+            ref ret, ref args, ..
+        } => {
+            type_references(ret, generic_params, true, out);
+            for (_, ty) in args {
+                type_references(ty, generic_params, true, out);
+            }
+        }
+    }
+}
+
+/// A declaration of a struct or union written before the types pointing to
+/// it, when it's defined after them.
+#[derive(Clone)]
+pub struct ForwardDeclaration {
+    keyword: &'static str,
+    export_name: String,
+    cfg: Option<Cfg>,
+}
+
+impl ForwardDeclaration {
+    fn new(item: &ItemContainer) -> Self {
+        ForwardDeclaration {
+            keyword: match *item {
+                ItemContainer::Union(..) => "union",
+                _ => "struct",
+            },
+            export_name: item.deref().export_name().to_owned(),
+            cfg: item.deref().cfg().cloned(),
+        }
+    }
+
+    pub(crate) fn export_name(&self) -> &str {
+        &self.export_name
+    }
+
+    pub fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        match config.language {
+            Language::C if config.style.generate_typedef() => write!(
+                out,
+                "typedef {} {} {};",
+                self.keyword, self.export_name, self.export_name
+            ),
+            Language::C | Language::Cxx => write!(out, "{} {};", self.keyword, self.export_name),
+            Language::Cython => write!(
+                out,
+                "{}{} {}",
+                config.style.cython_def(),
+                self.keyword,
+                self.export_name
+            ),
+        }
+        condition.write_after(config, out);
+        out.new_line();
+    }
 }
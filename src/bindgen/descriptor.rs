@@ -0,0 +1,311 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::{self, Write};
+
+use crate::bindgen::abi::{AbiSnapshot, FieldAbi, VariantAbi};
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::Language;
+use crate::bindgen::ir::{
+    ConstExpr, Field, IntKind, ItemContainer, PrimitiveType, ReprStyle, Type,
+};
+
+/// The layout of the structs, unions and enums of a set of bindings, for
+/// debuggers, fuzzers and wire-format tools which need to read their values
+/// without parsing C.
+///
+/// The descriptor is written as JSON with `--emit layout`. Sizes, alignments
+/// and offsets are only known when `layout.probe` is set, except for the
+/// sizes of fields of fixed-width types; the C table written otherwise has
+/// the compiler compute them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LayoutDescriptor {
+    /// The byte order of the values, which is always `native`: the types are
+    /// described as they're laid out in the memory of the target.
+    pub byte_order: &'static str,
+    pub types: Vec<TypeDescriptor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TypeDescriptor {
+    pub name: String,
+    pub kind: TypeKind,
+    pub size: Option<usize>,
+    pub align: Option<usize>,
+    /// The fields of a struct or union, in declaration order.
+    pub fields: Vec<FieldDescriptor>,
+    /// The variants of an enum, with the fields of their bodies.
+    pub variants: Vec<VariantAbi>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeKind {
+    Struct,
+    Union,
+    Enum,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldDescriptor {
+    pub name: String,
+    /// The type of the field, as it's spelled in the header.
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub offset: Option<usize>,
+    pub size: Option<usize>,
+}
+
+impl LayoutDescriptor {
+    pub(crate) fn new(bindings: &Bindings) -> LayoutDescriptor {
+        let abi = AbiSnapshot::new(bindings);
+        let mut types = Vec::new();
+        for item in described_items(bindings) {
+            let name = item.deref().export_name();
+            let layout = bindings.layouts.get(item.deref().name());
+            let (kind, fields, variants) = match *item {
+                ItemContainer::Struct(ref x) => {
+                    let offsets = layout
+                        .map(|layout| &layout.field_offsets[..])
+                        .filter(|offsets| has_plain_fields(&x.fields, offsets));
+                    let fields = describe_fields(&x.fields, &abi.structs[name], offsets);
+                    (TypeKind::Struct, fields, Vec::new())
+                }
+                ItemContainer::Union(ref x) => {
+                    let offsets = vec![0; x.fields.len()];
+                    let fields = describe_fields(&x.fields, &abi.unions[name], Some(&offsets));
+                    (TypeKind::Union, fields, Vec::new())
+                }
+                ItemContainer::Enum(..) => {
+                    let variants = abi.enums[name].variants.clone();
+                    (TypeKind::Enum, Vec::new(), variants)
+                }
+                _ => unreachable!(),
+            };
+            types.push(TypeDescriptor {
+                name: name.to_owned(),
+                kind,
+                size: layout.map(|layout| layout.size),
+                align: layout.map(|layout| layout.align),
+                fields,
+                variants,
+            });
+        }
+        LayoutDescriptor {
+            byte_order: "native",
+            types,
+        }
+    }
+}
+
+/// The structs, unions and enums the descriptor describes: the ones which
+/// are written as such, rather than as a typedef or a template.
+fn described_items(bindings: &Bindings) -> impl Iterator<Item = &ItemContainer> {
+    bindings.items.iter().filter(|item| match **item {
+        ItemContainer::Struct(ref x) => !x.is_transparent && x.generic_params.is_empty(),
+        ItemContainer::Union(ref x) => x.generic_params.is_empty(),
+        ItemContainer::Enum(ref x) => x.generic_params.is_empty(),
+        _ => false,
+    })
+}
+
+/// Whether the offsets rustc printed line up with the fields cbindgen knows
+/// about, which isn't the case with `PhantomData`, fields removed by `cfg`s
+/// and bitfields.
+fn has_plain_fields(fields: &[Field], offsets: &[usize]) -> bool {
+    fields.len() == offsets.len() && fields.iter().all(is_plain_field)
+}
+
+fn is_plain_field(field: &Field) -> bool {
+    field.cfg.is_none() && field.annotations.atom("bitfield").is_none()
+}
+
+fn describe_fields(
+    fields: &[Field],
+    spelled: &[FieldAbi],
+    offsets: Option<&[usize]>,
+) -> Vec<FieldDescriptor> {
+    fields
+        .iter()
+        .zip(spelled)
+        .enumerate()
+        .map(|(i, (field, spelled))| FieldDescriptor {
+            name: field.name.clone(),
+            ty: spelled.ty.clone(),
+            offset: offsets.map(|offsets| offsets[i]),
+            size: fixed_size(&field.ty),
+        })
+        .collect()
+}
+
+/// The size of values of `ty`, when it doesn't depend on the target.
+fn fixed_size(ty: &Type) -> Option<usize> {
+    match *ty {
+        Type::Primitive(ref primitive) => match *primitive {
+            PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar => Some(1),
+            PrimitiveType::Char32 | PrimitiveType::Float => Some(4),
+            PrimitiveType::Double => Some(8),
+            PrimitiveType::Integer { kind, .. } => match kind {
+                IntKind::B8 => Some(1),
+                IntKind::B16 => Some(2),
+                IntKind::B32 => Some(4),
+                IntKind::B64 => Some(8),
+                _ => None,
+            },
+            _ => None,
+        },
+        Type::Array(ref ty, ConstExpr::Value(ref len)) => {
+            Some(fixed_size(ty)? * len.parse::<usize>().ok()?)
+        }
+        _ => None,
+    }
+}
+
+/// Writes a C or C++ source file including the header at `header_name`,
+/// which defines a table of the types of the bindings with their size,
+/// alignment, and the name, type, offset and size of their fields, as
+/// computed by the compiler. The table is named after the header, like
+/// `bindings_type_descriptors` for `bindings.h`.
+///
+/// Types and fields behind a `cfg`, and bitfields, are left out.
+pub(crate) fn write_layout_table<F: Write>(
+    bindings: &Bindings,
+    mut out: F,
+    header_name: &str,
+) -> io::Result<()> {
+    let config = &bindings.config;
+    // Constants only have external linkage in C++ when declared `extern`.
+    let (alignof, linkage) = match config.language {
+        Language::C => ("_Alignof", ""),
+        Language::Cxx => ("alignof", "extern "),
+        Language::Cython => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "layout tables can only include C or C++ headers",
+            ))
+        }
+    };
+    let table: String = header_name
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let namespace: String = match config.language {
+        Language::Cxx => bindings
+            .all_namespaces()
+            .iter()
+            .map(|namespace| format!("{}::", namespace))
+            .collect(),
+        _ => String::new(),
+    };
+    let tag = config.language == Language::C && !config.style.generate_typedef();
+    let abi = AbiSnapshot::new(bindings);
+
+    writeln!(out, "#include <stddef.h>")?;
+    writeln!(out, "#include \"{}\"", header_name)?;
+    writeln!(out)?;
+    writeln!(out, "#ifndef CBINDGEN_TYPE_DESCRIPTOR")?;
+    writeln!(out, "#define CBINDGEN_TYPE_DESCRIPTOR")?;
+    writeln!(out, "struct cbindgen_field_descriptor {{")?;
+    writeln!(out, "  const char *name;")?;
+    writeln!(out, "  const char *type;")?;
+    writeln!(out, "  size_t offset;")?;
+    writeln!(out, "  size_t size;")?;
+    writeln!(out, "}};")?;
+    writeln!(out)?;
+    writeln!(out, "struct cbindgen_type_descriptor {{")?;
+    writeln!(out, "  const char *name;")?;
+    writeln!(out, "  size_t size;")?;
+    writeln!(out, "  size_t align;")?;
+    writeln!(out, "  const struct cbindgen_field_descriptor *fields;")?;
+    writeln!(out, "  size_t field_count;")?;
+    writeln!(out, "}};")?;
+    writeln!(out, "#endif // CBINDGEN_TYPE_DESCRIPTOR")?;
+
+    let mut entries = Vec::new();
+    for item in described_items(bindings) {
+        if item.deref().cfg().is_some() {
+            continue;
+        }
+        let export_name = item.deref().export_name();
+        let (keyword, fields) = match *item {
+            ItemContainer::Struct(ref x) => (Some("struct"), Some((&x.fields, &abi.structs))),
+            ItemContainer::Union(ref x) => (Some("union"), Some((&x.fields, &abi.unions))),
+            ItemContainer::Enum(ref x) => {
+                // Enums with a fixed type are a typedef of it in C.
+                let keyword = match (x.tag.is_some(), x.repr.style == ReprStyle::C) {
+                    (true, true) => Some("struct"),
+                    (true, false) => Some("union"),
+                    (false, true) => Some("enum"),
+                    (false, false) => None,
+                };
+                (keyword, None)
+            }
+            _ => unreachable!(),
+        };
+        let name = match keyword {
+            Some(keyword) if tag => format!("{} {}", keyword, export_name),
+            _ => format!("{}{}", namespace, export_name),
+        };
+
+        let mut field_lines = Vec::new();
+        if let Some((fields, spelled)) = fields {
+            for (field, spelled) in fields.iter().zip(&spelled[export_name]) {
+                if is_plain_field(field) {
+                    field_lines.push(format!(
+                        "  {{ \"{}\", \"{}\", offsetof({}, {}), sizeof((({} *)0)->{}) }},",
+                        field.name, spelled.ty, name, field.name, name, field.name
+                    ));
+                }
+            }
+        }
+        if !field_lines.is_empty() {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "static const struct cbindgen_field_descriptor {}_{}_fields[] = {{",
+                table, export_name
+            )?;
+            for line in &field_lines {
+                writeln!(out, "{}", line)?;
+            }
+            writeln!(out, "}};")?;
+        }
+        entries.push((export_name, name, field_lines.len()));
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{}const struct cbindgen_type_descriptor {}_type_descriptors[] = {{",
+        linkage, table
+    )?;
+    for (export_name, name, field_count) in &entries {
+        let fields = if *field_count > 0 {
+            format!("{}_{}_fields", table, export_name)
+        } else {
+            "NULL".to_owned()
+        };
+        writeln!(
+            out,
+            "  {{ \"{}\", sizeof({}), {}({}), {}, {} }},",
+            export_name, name, alignof, name, fields, field_count
+        )?;
+    }
+    writeln!(out, "}};")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "{}const size_t {}_type_descriptor_count = {};",
+        linkage,
+        table,
+        entries.len()
+    )?;
+    Ok(())
+}
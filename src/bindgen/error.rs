@@ -4,6 +4,8 @@
 
 use std::error;
 use std::fmt;
+use std::io;
+use std::path::PathBuf;
 
 pub use crate::bindgen::cargo::cargo_expand::Error as CargoExpandError;
 pub use crate::bindgen::cargo::cargo_metadata::Error as CargoMetadataError;
@@ -16,6 +18,7 @@ pub enum Error {
     CargoMetadata(String, CargoMetadataError),
     CargoToml(String, CargoTomlError),
     CargoExpand(String, CargoExpandError),
+    LayoutProbe(String, CargoExpandError),
     ParseSyntaxError {
         crate_name: String,
         src_path: String,
@@ -25,6 +28,15 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    /// Declarations failing one of the checks of the bindings, like
+    /// `improper_ctypes` set to "deny": what the check found, and a
+    /// diagnostic for each declaration.
+    Diagnostics(&'static str, Vec<String>),
+    TextFile(PathBuf, io::Error),
+    /// The bindings failed `Builder::verify_compiles`, which needs the `cc`
+    /// feature.
+    #[cfg_attr(not(feature = "cc"), allow(dead_code))]
+    CompileCheck(String),
 }
 
 impl fmt::Display for Error {
@@ -43,6 +55,11 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: couldn't run `cargo rustc -Zunpretty=expanded`: {:?}",
                 crate_name, error
             ),
+            Error::LayoutProbe(ref crate_name, ref error) => write!(
+                f,
+                "Probing the layouts of crate `{}`: couldn't run `cargo rustc -Zprint-type-sizes`: {:?}",
+                crate_name, error
+            ),
             Error::ParseSyntaxError {
                 ref crate_name,
                 ref src_path,
@@ -71,6 +88,19 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: cannot open file `{}`.",
                 crate_name, src_path
             ),
+            Error::Diagnostics(summary, ref diagnostics) => {
+                write!(f, "{}:", summary)?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n  {}", diagnostic)?;
+                }
+                Ok(())
+            }
+            Error::TextFile(ref path, ref error) => {
+                write!(f, "Couldn't read {}: {}", path.display(), error)
+            }
+            Error::CompileCheck(ref output) => {
+                write!(f, "The generated bindings don't compile:\n{}", output)
+            }
         }
     }
 }
@@ -81,8 +111,12 @@ impl error::Error for Error {
             Error::CargoMetadata(_, ref error) => Some(error),
             Error::CargoToml(_, ref error) => Some(error),
             Error::CargoExpand(_, ref error) => Some(error),
+            Error::LayoutProbe(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
             Error::ParseCannotOpenFile { .. } => None,
+            Error::Diagnostics(..) => None,
+            Error::TextFile(_, ref error) => Some(error),
+            Error::CompileCheck(_) => None,
         }
     }
 }
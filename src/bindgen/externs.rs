@@ -0,0 +1,331 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The Rust declarations of the functions and globals of the bindings, for
+//! `--emit rust-externs`, checking them against the crate they come from.
+
+use std::io::{self, Write};
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::ir::{
+    CallingConvention, Cfg, Function, GenericArgument, GenericPath, IntKind, PrimitiveType, Static,
+    Type,
+};
+
+/// The part of the checks which doesn't depend on the bindings.
+const ABI_EQ: &str = r#"
+    /// Implemented by the Rust types which are passed to C as `T`.
+    pub unsafe trait AbiEq<T> {}
+    unsafe impl<T> AbiEq<T> for T {}
+    unsafe impl<'a, T, U: AbiEq<T>> AbiEq<*const T> for &'a U {}
+    unsafe impl<'a, T, U: AbiEq<T>> AbiEq<*mut T> for &'a mut U {}
+    unsafe impl<'a, T, U: AbiEq<T>> AbiEq<*const T> for Option<&'a U> {}
+    unsafe impl<'a, T, U: AbiEq<T>> AbiEq<*mut T> for Option<&'a mut U> {}
+    unsafe impl<T, U: AbiEq<T>> AbiEq<*mut T> for core::ptr::NonNull<U> {}
+    unsafe impl<T, U: AbiEq<T>> AbiEq<*mut T> for Option<core::ptr::NonNull<U>> {}
+    unsafe impl<T, U: AbiEq<T>> AbiEq<*mut T> for Box<U> {}
+    unsafe impl<T, U: AbiEq<T>> AbiEq<*mut T> for Option<Box<U>> {}
+    unsafe impl AbiEq<u32> for char {}
+"#;
+
+/// Writes a Rust file declaring the functions and globals of the bindings in
+/// `extern` blocks, `unsafe` ones for crates of the 2024 edition, with a
+/// function for each of them which only compiles if the crate defines it with
+/// the same type.
+///
+/// The file is meant to be included in a test of the crate, with the types of
+/// the crate in scope. The types are spelled from the bindings, so pointers
+/// are raw pointers, which stand for the references, `NonNull`s and boxes they
+/// may be in the crate as well. Functions whose signature is rewritten by a
+/// `result` annotation, or which are declared by the crate rather than
+/// defined, are left out.
+pub(crate) fn write_rust_externs<F: Write>(bindings: &Bindings, mut out: F) -> io::Result<()> {
+    let functions: Vec<_> = bindings
+        .functions
+        .iter()
+        .filter(|f| !f.extern_decl && !f.lowers_result())
+        .collect();
+
+    writeln!(
+        out,
+        "// Rust declarations of the functions and globals of the bindings, generated by"
+    )?;
+    writeln!(out, "// cbindgen.")?;
+    writeln!(out, "//")?;
+    writeln!(
+        out,
+        "// Include this in a test of the crate, with the types of the crate in scope, to"
+    )?;
+    writeln!(
+        out,
+        "// check that the crate defines every function and global with the same type:"
+    )?;
+    writeln!(out, "//")?;
+    writeln!(out, "//     use my_crate::*;")?;
+    writeln!(out, "//     include!(\"externs.rs\");")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "#[allow(dead_code, improper_ctypes, non_snake_case, non_upper_case_globals, clippy::all)]"
+    )?;
+    writeln!(out, "mod cbindgen_externs {{")?;
+    writeln!(out, "    use super::*;")?;
+
+    for unwind in [false, true] {
+        let declared: Vec<_> = functions.iter().filter(|f| f.unwind == unwind).collect();
+        if declared.is_empty() && (unwind || bindings.globals.is_empty()) {
+            continue;
+        }
+        writeln!(out)?;
+        let abi = if unwind { "C-unwind" } else { "C" };
+        let unsafety = if bindings.config.rust_externs.unsafe_extern_blocks() {
+            "unsafe "
+        } else {
+            ""
+        };
+        writeln!(out, "    {}extern \"{}\" {{", unsafety, abi)?;
+        for function in declared {
+            write_cfg(&mut out, function.cfg.as_ref(), "        ")?;
+            writeln!(
+                out,
+                "        pub fn {}({}){};",
+                function.path().name(),
+                args(bindings, function).join(", "),
+                ret(bindings, function, None)
+            )?;
+        }
+        if !unwind {
+            for global in &bindings.globals {
+                write_cfg(&mut out, global.cfg.as_ref(), "        ")?;
+                writeln!(
+                    out,
+                    "        pub static {}{}: {};",
+                    if global.mutable { "mut " } else { "" },
+                    global.path.name(),
+                    rust_type(bindings, &global.ty)
+                )?;
+            }
+        }
+        writeln!(out, "    }}")?;
+    }
+
+    write!(out, "{}", ABI_EQ)?;
+    for function in &functions {
+        write_function_check(&mut out, bindings, function)?;
+    }
+    for global in &bindings.globals {
+        write_global_check(&mut out, bindings, global)?;
+    }
+    writeln!(out, "}}")
+}
+
+fn write_cfg<F: Write>(out: &mut F, cfg: Option<&Cfg>, indent: &str) -> io::Result<()> {
+    match cfg {
+        Some(cfg) => writeln!(out, "{}#[cfg({})]", indent, cfg),
+        None => Ok(()),
+    }
+}
+
+/// Writes a function which only compiles if the crate defines `function`
+/// with the arguments and return type of the bindings.
+fn write_function_check<F: Write>(
+    out: &mut F,
+    bindings: &Bindings,
+    function: &Function,
+) -> io::Result<()> {
+    let mut params: Vec<_> = function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| format!("A{}: AbiEq<{}>", i, rust_type(bindings, &arg.ty)))
+        .collect();
+    let args: Vec<_> = (0..function.args.len())
+        .map(|i| format!("A{}", i))
+        .collect();
+    let returns_value =
+        !function.never_return && function.ret != Type::Primitive(PrimitiveType::Void);
+    if returns_value {
+        params.push(format!("R: AbiEq<{}>", rust_type(bindings, &function.ret)));
+    }
+    let path = match function.self_type_path {
+        Some(ref self_type) => format!("super::{}::{}", self_type.name(), function.path().name()),
+        None => format!("super::{}", function.path().name()),
+    };
+
+    writeln!(out)?;
+    write_cfg(out, function.cfg.as_ref(), "    ")?;
+    writeln!(out, "    fn check_{}() {{", function.path().name())?;
+    let generics = if params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", params.join(", "))
+    };
+    writeln!(
+        out,
+        "        fn check{}(_: unsafe extern \"{}\" fn({}){}) {{}}",
+        generics,
+        if function.unwind { "C-unwind" } else { "C" },
+        args.join(", "),
+        ret(bindings, function, returns_value.then_some("R"))
+    )?;
+    writeln!(out, "        check({});", path)?;
+    writeln!(out, "    }}")
+}
+
+/// Writes a function which only compiles if the crate defines `global` with
+/// the type of the bindings.
+fn write_global_check<F: Write>(
+    out: &mut F,
+    bindings: &Bindings,
+    global: &Static,
+) -> io::Result<()> {
+    writeln!(out)?;
+    write_cfg(out, global.cfg.as_ref(), "    ")?;
+    writeln!(out, "    fn check_{}() {{", global.path.name())?;
+    writeln!(
+        out,
+        "        fn check<T: AbiEq<{}>>(_: *const T) {{}}",
+        rust_type(bindings, &global.ty)
+    )?;
+    writeln!(
+        out,
+        "        check(core::ptr::addr_of!(super::{}));",
+        global.path.name()
+    )?;
+    writeln!(out, "    }}")
+}
+
+fn args(bindings: &Bindings, function: &Function) -> Vec<String> {
+    function
+        .args
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            let ty = rust_type(bindings, &arg.ty);
+            match arg.name {
+                // `self` can't be the name of an argument in a declaration.
+                Some(ref name) if name == "self" => format!("this: {}", ty),
+                Some(ref name) => format!("{}: {}", name, ty),
+                None => format!("_{}: {}", i, ty),
+            }
+        })
+        .collect()
+}
+
+/// The return type of `function`, as written after its arguments, spelled as
+/// `name` if given.
+fn ret(bindings: &Bindings, function: &Function, name: Option<&str>) -> String {
+    if function.never_return {
+        return " -> !".to_owned();
+    }
+    if function.ret == Type::Primitive(PrimitiveType::Void) {
+        return String::new();
+    }
+    match name {
+        Some(name) => format!(" -> {}", name),
+        None => format!(" -> {}", rust_type(bindings, &function.ret)),
+    }
+}
+
+/// The Rust spelling of `ty`, with the generic type monomorphs were
+/// instantiated from rather than their mangled name.
+fn rust_type(bindings: &Bindings, ty: &Type) -> String {
+    match *ty {
+        Type::Ptr {
+            ref ty, is_const, ..
+        } => format!(
+            "*{} {}",
+            if is_const { "const" } else { "mut" },
+            rust_type(bindings, ty)
+        ),
+        Type::Path(ref generic) => match bindings.unmangled_paths.get(generic.path()) {
+            Some(unmangled) => rust_path(bindings, unmangled),
+            None => rust_path(bindings, generic),
+        },
+        Type::Primitive(ref primitive) => rust_primitive(primitive),
+        Type::Array(ref ty, ref len) => {
+            format!("[{}; {}]", rust_type(bindings, ty), len.as_str())
+        }
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            is_nullable,
+            never_return,
+            calling_convention,
+        } => {
+            let args: Vec<_> = args.iter().map(|(_, ty)| rust_type(bindings, ty)).collect();
+            let ret = if never_return {
+                " -> !".to_owned()
+            } else if **ret == Type::Primitive(PrimitiveType::Void) {
+                String::new()
+            } else {
+                format!(" -> {}", rust_type(bindings, ret))
+            };
+            let abi = match calling_convention {
+                CallingConvention::C => "C",
+                CallingConvention::System => "system",
+                CallingConvention::Stdcall => "stdcall",
+            };
+            let ty = format!("extern \"{}\" fn({}){}", abi, args.join(", "), ret);
+            if is_nullable {
+                format!("Option<{}>", ty)
+            } else {
+                ty
+            }
+        }
+    }
+}
+
+fn rust_path(bindings: &Bindings, generic: &GenericPath) -> String {
+    if generic.generics().is_empty() {
+        return generic.path().name().to_owned();
+    }
+    let generics: Vec<_> = generic
+        .generics()
+        .iter()
+        .map(|arg| match *arg {
+            GenericArgument::Type(ref ty) => rust_type(bindings, ty),
+            GenericArgument::Const(ref expr) => format!("{{ {} }}", expr.as_str()),
+        })
+        .collect();
+    format!("{}<{}>", generic.path().name(), generics.join(", "))
+}
+
+fn rust_primitive(primitive: &PrimitiveType) -> String {
+    match *primitive {
+        PrimitiveType::Void => "core::ffi::c_void".to_owned(),
+        PrimitiveType::Char32 => "u32".to_owned(),
+        PrimitiveType::PtrDiffT => "isize".to_owned(),
+        PrimitiveType::VaList => "core::ffi::VaList".to_owned(),
+        PrimitiveType::Integer {
+            zeroable: false,
+            signed,
+            kind,
+        } if !matches!(
+            kind,
+            IntKind::Short | IntKind::Int | IntKind::Long | IntKind::LongLong | IntKind::SizeT
+        ) =>
+        {
+            let name = primitive.to_repr_rust();
+            format!(
+                "core::num::NonZero{}{}",
+                if signed { "I" } else { "U" },
+                &name[1..]
+            )
+        }
+        PrimitiveType::Integer {
+            kind: IntKind::SizeT,
+            signed,
+            ..
+        } => if signed { "isize" } else { "usize" }.to_owned(),
+        _ => {
+            let name = primitive.to_repr_rust();
+            if name.starts_with("c_") {
+                format!("core::ffi::{}", name)
+            } else {
+                name.to_owned()
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::cdecl;
+use crate::bindgen::config::Language;
+use crate::bindgen::ir::{ConditionWrite, Function, IntKind, PrimitiveType, ToCondition, Type};
+use crate::bindgen::writer::SourceWriter;
+
+/// Writes a libFuzzer target to `dir` for each function taking a buffer, that
+/// is a pointer to bytes followed by its length, and returns their paths.
+///
+/// The fuzzer input is passed as the first buffer, and the other arguments
+/// are zero-initialized, to be filled in by hand where that isn't enough.
+pub(crate) fn write_fuzz_stubs(
+    bindings: &Bindings,
+    dir: &Path,
+    header_name: &str,
+) -> io::Result<Vec<PathBuf>> {
+    let config = &bindings.config;
+    let extension = match config.language {
+        Language::C => "c",
+        Language::Cxx => "cpp",
+        Language::Cython => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "fuzz targets can only include C or C++ headers",
+            ))
+        }
+    };
+
+    fs::create_dir_all(dir)?;
+    let mut paths = Vec::new();
+    for function in &bindings.functions {
+        let buffer = match find_buffer(function) {
+            Some(buffer) => buffer,
+            None => continue,
+        };
+        if function.args.iter().any(|arg| arg.name.is_none()) {
+            warn!(
+                "Can't generate a fuzz target for {}, which has unnamed arguments.",
+                function.path.name()
+            );
+            continue;
+        }
+
+        let mut contents = Vec::new();
+        write_fuzz_stub(
+            &mut SourceWriter::new(&mut contents as &mut dyn Write, bindings),
+            bindings,
+            function,
+            buffer,
+            header_name,
+        );
+        let path = dir.join(format!("fuzz_{}.{}", function.path.name(), extension));
+        fs::write(&path, contents)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// The index of the first pointer to bytes which is followed by a length.
+fn find_buffer(function: &Function) -> Option<usize> {
+    function.args.windows(2).position(|args| {
+        let is_bytes = match args[0].ty {
+            Type::Ptr { ref ty, .. } => matches!(
+                **ty,
+                Type::Primitive(
+                    PrimitiveType::Void
+                        | PrimitiveType::Char
+                        | PrimitiveType::SChar
+                        | PrimitiveType::UChar
+                        | PrimitiveType::Integer {
+                            kind: IntKind::B8,
+                            ..
+                        }
+                )
+            ),
+            _ => false,
+        };
+        let is_length = match args[1].ty {
+            Type::Primitive(PrimitiveType::Integer { kind, .. }) => {
+                !matches!(kind, IntKind::B8 | IntKind::B16 | IntKind::Short)
+            }
+            _ => false,
+        };
+        is_bytes && is_length
+    })
+}
+
+fn write_fuzz_stub<F: Write>(
+    out: &mut SourceWriter<F>,
+    bindings: &Bindings,
+    function: &Function,
+    buffer: usize,
+    header_name: &str,
+) {
+    let config = &bindings.config;
+    let is_cxx = config.language == Language::Cxx;
+
+    if let Some(ref f) = config.autogen_warning {
+        write!(out, "{}", f);
+        out.new_line();
+        out.new_line();
+    }
+    write!(
+        out,
+        "/* Fuzz target for `{}`, generated by cbindgen. */",
+        function.path.name()
+    );
+    out.new_line();
+    out.new_line();
+    if is_cxx {
+        out.write("#include <cstddef>");
+        out.new_line();
+        out.write("#include <cstdint>");
+        out.new_line();
+        out.write("#include <cstdlib>");
+        out.new_line();
+        out.write("#include <cstring>");
+    } else {
+        out.write("#include <stddef.h>");
+        out.new_line();
+        out.write("#include <stdint.h>");
+        out.new_line();
+        out.write("#include <stdlib.h>");
+        out.new_line();
+        out.write("#include <string.h>");
+    }
+    out.new_line();
+    write!(out, "#include \"{}\"", header_name);
+    out.new_line();
+    if is_cxx {
+        let namespaces = bindings.all_namespaces();
+        if !namespaces.is_empty() {
+            out.new_line();
+            write!(out, "using namespace {};", namespaces.join("::"));
+            out.new_line();
+        }
+    }
+    out.new_line();
+
+    if is_cxx {
+        out.write("extern \"C\" ");
+    }
+    out.write("int LLVMFuzzerTestOneInput(const uint8_t *data, size_t size)");
+    out.open_brace();
+
+    let condition = function.cfg.to_condition(config);
+    condition.write_before(config, out);
+
+    // The function may write to a mutable buffer, so give it a copy.
+    let is_mutable = matches!(
+        function.args[buffer].ty,
+        Type::Ptr {
+            is_const: false,
+            ..
+        }
+    );
+    if is_mutable {
+        out.write("uint8_t *copy = (uint8_t *)malloc(size);");
+        out.new_line();
+        out.write("if (copy == NULL)");
+        out.open_brace();
+        out.write("return 0;");
+        out.close_brace(false);
+        out.new_line();
+        out.write("memcpy(copy, data, size);");
+        out.new_line();
+    }
+
+    let mut args = Vec::new();
+    for (i, arg) in function.args.iter().enumerate() {
+        let name = arg.name.as_deref().unwrap();
+        if i == buffer {
+            let source = if is_mutable { "copy" } else { "data" };
+            let mut cast = Vec::new();
+            cdecl::write_type(
+                &mut SourceWriter::new(&mut cast as &mut dyn Write, bindings),
+                &arg.ty,
+                config,
+            );
+            args.push(format!("({}){}", String::from_utf8(cast).unwrap(), source));
+            continue;
+        }
+        if i == buffer + 1 {
+            args.push("size".to_owned());
+            continue;
+        }
+        cdecl::write_field(out, &arg.ty, name, config);
+        out.write(";");
+        out.new_line();
+        write!(out, "memset(&{}, 0, sizeof({}));", name, name);
+        out.new_line();
+        args.push(name.to_owned());
+    }
+
+    write!(out, "{}({});", function.path.name(), args.join(", "));
+    if is_mutable {
+        out.new_line();
+        out.write("free(copy);");
+    }
+
+    condition.write_after(config, out);
+    out.new_line();
+    out.write("return 0;");
+    out.close_brace(false);
+    out.new_line();
+}
@@ -0,0 +1,160 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::Config;
+use crate::bindgen::ir::{
+    CallingConvention, GenericArgument, ItemContainer, Literal, PrimitiveType, Type, VariantBody,
+};
+use crate::bindgen::shim::Helper;
+
+/// The standard headers the bindings need, found by going through the types
+/// they use, for `prune_includes`, along with the calling conventions of the
+/// function pointers, whose macros need to be defined.
+#[derive(Debug, Default)]
+pub(crate) struct StandardIncludes {
+    /// `va_list`
+    pub stdarg: bool,
+    /// `bool`
+    pub stdbool: bool,
+    /// `size_t` and `ptrdiff_t`
+    pub stddef: bool,
+    /// `int32_t` and the other fixed-width integers
+    pub stdint: bool,
+    /// `char32_t`, in C
+    pub uchar: bool,
+    /// `std::ostream`, for the `operator<<` of `derive_ostream`
+    pub ostream: bool,
+    /// Placement `new`, for the helpers of enums with fields
+    pub new: bool,
+    /// The calling conventions other than `extern "C"`
+    pub calling_conventions: BTreeSet<CallingConvention>,
+}
+
+impl StandardIncludes {
+    pub fn new(bindings: &Bindings) -> Self {
+        let config = &bindings.config;
+        let mut includes = StandardIncludes::default();
+        for item in &bindings.items {
+            match *item {
+                ItemContainer::Struct(ref x) => {
+                    for field in &x.fields {
+                        includes.add_type(&field.ty, config);
+                    }
+                    for constant in &x.associated_constants {
+                        includes.add_type(&constant.ty, config);
+                        includes.add_literal(&constant.value, config);
+                    }
+                    includes.ostream |= config.structure.derive_ostream(&x.annotations);
+                }
+                ItemContainer::Union(ref x) => {
+                    for field in &x.fields {
+                        includes.add_type(&field.ty, config);
+                    }
+                }
+                ItemContainer::Enum(ref x) => {
+                    if let Some(ty) = x.repr.ty {
+                        includes.add_primitive(&ty.to_primitive(), config);
+                    }
+                    for variant in &x.variants {
+                        if let Some(ref discriminant) = variant.discriminant {
+                            includes.add_literal(discriminant, config);
+                        }
+                        if let VariantBody::Body { ref body, .. } = variant.body {
+                            for field in &body.fields {
+                                includes.add_type(&field.ty, config);
+                            }
+                        }
+                    }
+                    includes.ostream |= config.enumeration.derive_ostream(&x.annotations);
+                    includes.new |= x.tag.is_some();
+                }
+                ItemContainer::Typedef(ref x) => includes.add_type(&x.aliased, config),
+                ItemContainer::OpaqueItem(ref x) => {
+                    if let Some(storage) = bindings.layouts.opaque_storage(x, config) {
+                        includes.add_type(&storage, config);
+                    }
+                }
+                ItemContainer::Constant(..) | ItemContainer::Static(..) => {}
+            }
+        }
+        for function in &bindings.functions {
+            includes.add_type(&function.ret, config);
+            for arg in &function.args {
+                includes.add_type(&arg.ty, config);
+            }
+        }
+        for global in &bindings.globals {
+            includes.add_type(&global.ty, config);
+        }
+        for constant in &bindings.constants {
+            includes.add_type(&constant.ty, config);
+            includes.add_literal(&constant.value, config);
+        }
+        // The helpers defined in the header compare pointers to `NULL`.
+        includes.stddef |=
+            !Helper::collect(config, &bindings.items, &bindings.functions).is_empty();
+        includes
+    }
+
+    fn add_type(&mut self, ty: &Type, config: &Config) {
+        match *ty {
+            Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => self.add_type(ty, config),
+            Type::Path(ref generic) => {
+                for generic in generic.generics() {
+                    if let GenericArgument::Type(ref ty) = *generic {
+                        self.add_type(ty, config);
+                    }
+                }
+            }
+            Type::Primitive(ref primitive) => self.add_primitive(primitive, config),
+            Type::FuncPtr {
+                ref ret,
+                ref args,
+                calling_convention,
+                ..
+            } => {
+                if calling_convention != CallingConvention::C {
+                    self.calling_conventions.insert(calling_convention);
+                }
+                self.add_type(ret, config);
+                for (_, ty) in args {
+                    self.add_type(ty, config);
+                }
+            }
+        }
+    }
+
+    fn add_literal(&mut self, literal: &Literal, config: &Config) {
+        literal.visit(&mut |literal| {
+            match *literal {
+                Literal::Cast { ref ty, .. } => self.add_type(ty, config),
+                Literal::Expr(ref expr) if expr == "true" || expr == "false" => {
+                    self.stdbool |= config.writes_bool()
+                }
+                _ => {}
+            }
+            true
+        });
+    }
+
+    /// Goes by the name the type is written as, as `usize_type` and
+    /// `layout.type_map` change it.
+    fn add_primitive(&mut self, primitive: &PrimitiveType, config: &Config) {
+        match primitive.to_repr_c(config) {
+            "va_list" => self.stdarg = true,
+            "bool" => self.stdbool = true,
+            "size_t" | "ptrdiff_t" => self.stddef = true,
+            "char32_t" => self.uchar = true,
+            name if name.ends_with("_t")
+                && (name.starts_with("int") || name.starts_with("uint")) =>
+            {
+                self.stdint = true
+            }
+            _ => {}
+        }
+    }
+}
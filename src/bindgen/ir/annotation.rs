@@ -7,8 +7,10 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use syn::ext::IdentExt;
+
 use crate::bindgen::config::{Config, Language};
-use crate::bindgen::utilities::SynAttributeHelpers;
+use crate::bindgen::utilities::{annotation_attr_name, SynAttributeHelpers};
 
 // A system for specifying properties on items. Annotations are
 // given through document comments and parsed by this code.
@@ -22,9 +24,28 @@ use crate::bindgen::utilities::SynAttributeHelpers;
 // Examples:
 //  * cbindgen:field-names=[mHandle, mNamespace]
 //  * cbindgen:function-postfix=WR_DESTRUCTOR_SAFE
+//
+// Annotations can also be given as `#[cbindgen::field_names(mHandle, mNamespace)]`
+// attributes, see `attribute_annotation`.
+
+/// Annotations whose value is raw code emitted verbatim. Their value may
+/// contain `=`, and repeating them appends another line.
+const RAW_CODE_ANNOTATIONS: &[&str] = &["prefix", "postfix", "body", "doc"];
+
+/// Annotations whose value is a C expression, which may contain `=`.
+const EXPRESSION_ANNOTATIONS: &[&str] = &["cfg"];
+
+/// Annotations whose value is a name, which may be quoted to contain spaces,
+/// like `section="Audio API"`.
+const NAME_ANNOTATIONS: &[&str] = &["section", "group"];
+
+/// Raw code annotations whose value may be quoted as a whole, like
+/// `body="struct B { int y; };"`.
+const QUOTED_CODE_ANNOTATIONS: &[&str] = &["body"];
 
 /// A value specified by an annotation.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum AnnotationValue {
     List(Vec<String>),
     Atom(Option<String>),
@@ -33,9 +54,12 @@ pub enum AnnotationValue {
 
 /// A set of annotations specified by a document comment.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct AnnotationSet {
     annotations: HashMap<String, AnnotationValue>,
     pub must_use: bool,
+    /// The message of `#[must_use = "..."]`, if any.
+    pub must_use_note: Option<String>,
     pub deprecated: Option<String>,
 }
 
@@ -50,6 +74,7 @@ impl AnnotationSet {
         AnnotationSet {
             annotations: HashMap::new(),
             must_use: false,
+            must_use_note: None,
             deprecated: None,
         }
     }
@@ -62,6 +87,39 @@ impl AnnotationSet {
         self.must_use && config.language != Language::Cython
     }
 
+    /// The attribute marking the item as `#[must_use]`, with its message
+    /// when there's one and `must_use_with_note` is set.
+    pub(crate) fn must_use_attribute<'c>(
+        &self,
+        config: &'c Config,
+        kind: DeprecatedNoteKind,
+    ) -> Option<Cow<'c, str>> {
+        if !self.must_use(config) {
+            return None;
+        }
+
+        let (must_use, must_use_with_note) = match kind {
+            DeprecatedNoteKind::Enum => (
+                &config.enumeration.must_use,
+                &config.enumeration.must_use_with_note,
+            ),
+            DeprecatedNoteKind::Function => (
+                &config.function.must_use,
+                &config.function.must_use_with_note,
+            ),
+            DeprecatedNoteKind::Struct => (
+                &config.structure.must_use,
+                &config.structure.must_use_with_note,
+            ),
+        };
+        match (self.must_use_note.as_deref(), must_use_with_note) {
+            (Some(note), Some(format)) => {
+                Some(Cow::Owned(format.replace("{}", &format!("{:?}", note))))
+            }
+            _ => must_use.as_deref().map(Cow::Borrowed),
+        }
+    }
+
     pub(crate) fn deprecated_note<'c>(
         &self,
         config: &'c Config,
@@ -92,7 +150,7 @@ impl AnnotationSet {
 
     pub fn load(attrs: &[syn::Attribute]) -> Result<AnnotationSet, String> {
         let lines = attrs.get_comment_lines();
-        let lines: Vec<&str> = lines
+        let mut lines: Vec<&str> = lines
             .iter()
             .filter_map(|line| {
                 let line = line.trim_start();
@@ -103,8 +161,14 @@ impl AnnotationSet {
                 Some(line)
             })
             .collect();
+        let attr_lines = attrs
+            .iter()
+            .filter_map(|attr| attribute_annotation(attr).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+        lines.extend(attr_lines.iter().map(String::as_str));
 
-        let must_use = attrs.has_attr_word("must_use");
+        let mut must_use_note = attrs.attr_name_value_lookup("must_use");
+        let mut must_use = must_use_note.is_some() || attrs.has_attr_word("must_use");
         let deprecated = attrs.find_deprecated_note();
         let mut annotations = HashMap::new();
 
@@ -115,16 +179,20 @@ impl AnnotationSet {
             // Remove the "cbindgen:" prefix
             let annotation = &line[9..];
 
-            // Split the annotation in two
-            let parts: Vec<&str> = annotation.split('=').map(|x| x.trim()).collect();
+            // Split the annotation in two. Raw code and expressions may contain
+            // `=` themselves, so only the first one is significant for those.
+            let name = annotation.split('=').next().unwrap().trim();
+            let parts: Vec<&str> =
+                if RAW_CODE_ANNOTATIONS.contains(&name) || EXPRESSION_ANNOTATIONS.contains(&name) {
+                    annotation.splitn(2, '=').map(|x| x.trim()).collect()
+                } else {
+                    annotation.split('=').map(|x| x.trim()).collect()
+                };
 
             if parts.len() > 2 {
                 return Err(format!("Couldn't parse {}.", line));
             }
 
-            // Grab the name that this annotation is modifying
-            let name = parts[0];
-
             // If the annotation only has a name, assume it's setting a bool flag
             if parts.len() == 1 {
                 annotations.insert(name.to_string(), AnnotationValue::Bool(true));
@@ -132,7 +200,32 @@ impl AnnotationSet {
             }
 
             // Parse the value we're setting the name to
-            let value = parts[1];
+            let mut value = parts[1];
+            if NAME_ANNOTATIONS.contains(&name) || QUOTED_CODE_ANNOTATIONS.contains(&name) {
+                value = unquote(value);
+            }
+
+            // Raw code is taken verbatim, and repeating the annotation adds
+            // another line.
+            if RAW_CODE_ANNOTATIONS.contains(&name) {
+                match annotations.get_mut(name) {
+                    Some(AnnotationValue::Atom(Some(ref mut code))) if !value.is_empty() => {
+                        code.push('\n');
+                        code.push_str(value);
+                    }
+                    _ => {
+                        annotations.insert(
+                            name.to_string(),
+                            if value.is_empty() {
+                                AnnotationValue::Atom(None)
+                            } else {
+                                AnnotationValue::Atom(Some(value.to_string()))
+                            },
+                        );
+                    }
+                }
+                continue;
+            }
 
             if let Some(x) = parse_list(value) {
                 annotations.insert(name.to_string(), AnnotationValue::List(x));
@@ -152,9 +245,20 @@ impl AnnotationSet {
             );
         }
 
+        // `must-use` forces the attribute, or removes it when false.
+        match annotations.get("must-use") {
+            Some(AnnotationValue::Bool(x)) => must_use = *x,
+            Some(AnnotationValue::Atom(Some(note))) => {
+                must_use = true;
+                must_use_note = Some(note.clone());
+            }
+            _ => {}
+        }
+
         Ok(AnnotationSet {
             annotations,
             must_use,
+            must_use_note,
             deprecated,
         })
     }
@@ -166,6 +270,16 @@ impl AnnotationSet {
         }
     }
 
+    /// Sets an annotation value, replacing the specified one if any.
+    pub fn set(&mut self, name: &str, value: AnnotationValue) {
+        self.annotations.insert(name.to_owned(), value);
+    }
+
+    /// Removes an annotation, if specified.
+    pub fn remove(&mut self, name: &str) {
+        self.annotations.remove(name);
+    }
+
     pub fn list(&self, name: &str) -> Option<Vec<String>> {
         match self.annotations.get(name) {
             Some(AnnotationValue::List(x)) => Some(x.clone()),
@@ -199,6 +313,66 @@ impl AnnotationSet {
     }
 }
 
+/// Turns a `#[cbindgen::name(...)]` attribute into the equivalent
+/// `cbindgen:name=...` line. A lone string literal or bool is the value
+/// verbatim, so it's parsed just like in a doc comment, while identifiers and
+/// integers make up a list.
+fn attribute_annotation(attr: &syn::Attribute) -> Result<Option<String>, String> {
+    let name = match annotation_attr_name(&attr.path) {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+    let error = || format!("Couldn't parse #[cbindgen::{}] attribute.", name);
+
+    let value = match attr.parse_meta().map_err(|_| error())? {
+        syn::Meta::Path(..) => return Ok(Some(format!("cbindgen:{}", name))),
+        syn::Meta::NameValue(syn::MetaNameValue { lit, .. }) => {
+            literal_value(&lit).ok_or_else(error)?
+        }
+        syn::Meta::List(list) => {
+            let values = list
+                .nested
+                .iter()
+                .map(|nested| match nested {
+                    syn::NestedMeta::Lit(lit) => literal_value(lit),
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                        path.get_ident().map(|ident| ident.unraw().to_string())
+                    }
+                    syn::NestedMeta::Meta(..) => None,
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(error)?;
+            match list.nested.first() {
+                Some(syn::NestedMeta::Lit(syn::Lit::Str(..) | syn::Lit::Bool(..)))
+                    if values.len() == 1 =>
+                {
+                    values.into_iter().next().unwrap()
+                }
+                _ => format!("[{}]", values.join(", ")),
+            }
+        }
+    };
+
+    Ok(Some(format!("cbindgen:{}={}", name, value)))
+}
+
+fn literal_value(lit: &syn::Lit) -> Option<String> {
+    match lit {
+        syn::Lit::Str(lit) => Some(lit.value()),
+        syn::Lit::Int(lit) => Some(lit.base10_digits().to_owned()),
+        syn::Lit::Bool(lit) => Some(lit.value.to_string()),
+        _ => None,
+    }
+}
+
+/// Strips the double quotes around `value`, if any.
+fn unquote(value: &str) -> &str {
+    match value.strip_prefix('"').and_then(|x| x.strip_suffix('"')) {
+        Some(unquoted) => unquoted.trim(),
+        None => value,
+    }
+}
+
 /// Parse lists like "[x, y, z]". This is not implemented efficiently or well.
 fn parse_list(list: &str) -> Option<Vec<String>> {
     if list.len() < 2 {
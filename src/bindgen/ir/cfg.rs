@@ -16,7 +16,7 @@ enum DefineKey<'a> {
 }
 
 impl<'a> DefineKey<'a> {
-    fn load(key: &str) -> DefineKey {
+    fn load(key: &str) -> DefineKey<'_> {
         // TODO: dirty parser
         if !key.contains('=') {
             return DefineKey::Boolean(key);
@@ -43,6 +43,7 @@ impl<'a> DefineKey<'a> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum Cfg {
     Boolean(String),
     Named(String, String),
@@ -103,14 +104,8 @@ impl Cfg {
         let mut configs = Vec::new();
 
         for attr in attrs {
-            if let Ok(syn::Meta::List(syn::MetaList { path, nested, .. })) = attr.parse_meta() {
-                if !path.is_ident("cfg") || nested.len() != 1 {
-                    continue;
-                }
-
-                if let Some(config) = Cfg::load_single(nested.first().unwrap()) {
-                    configs.push(config);
-                }
+            if let Ok(meta) = attr.parse_meta() {
+                Cfg::load_attr(&meta, &[], &mut configs);
             }
         }
 
@@ -121,6 +116,44 @@ impl Cfg {
         }
     }
 
+    /// Loads the cfg of a `#[cfg(..)]` attribute, or the cfgs applied by a
+    /// `#[cfg_attr(..)]` one, which only apply when the predicates of the
+    /// chain of `cfg_attr`s they're in, `preds`, hold. The item is present
+    /// otherwise, so `#[cfg_attr(pred, cfg(c))]` is `any(not(pred), c)`.
+    fn load_attr(meta: &syn::Meta, preds: &[Cfg], configs: &mut Vec<Cfg>) {
+        let (path, nested) = match *meta {
+            syn::Meta::List(syn::MetaList {
+                ref path,
+                ref nested,
+                ..
+            }) => (path, nested),
+            _ => return,
+        };
+        if path.is_ident("cfg") {
+            if nested.len() != 1 {
+                return;
+            }
+            if let Some(config) = Cfg::load_single(nested.first().unwrap()) {
+                configs.push(preds.iter().rev().fold(config, |config, pred| {
+                    Cfg::Any(vec![Cfg::Not(Box::new(pred.clone())), config])
+                }));
+            }
+        } else if path.is_ident("cfg_attr") {
+            let mut nested = nested.iter();
+            let pred = match nested.next() {
+                Some(pred) => pred,
+                None => return,
+            };
+            let mut preds = preds.to_owned();
+            preds.extend(Cfg::load_single(pred));
+            for attr in nested {
+                if let syn::NestedMeta::Meta(ref meta) = *attr {
+                    Cfg::load_attr(meta, &preds, configs);
+                }
+            }
+        }
+    }
+
     pub fn load_metadata(dependency: &Dependency) -> Option<Cfg> {
         let target = dependency.target.as_ref()?;
         match syn::parse_str::<syn::Meta>(target) {
@@ -211,10 +244,13 @@ impl ToCondition for Cfg {
     fn to_condition(&self, config: &Config) -> Option<Condition> {
         match *self {
             Cfg::Boolean(ref cfg_name) => {
+                // The smallest key wins when several are for the same cfg, so
+                // that it doesn't depend on the order of the `HashMap`.
                 let define = config
                     .defines
                     .iter()
-                    .find(|(key, ..)| DefineKey::Boolean(cfg_name) == DefineKey::load(key));
+                    .filter(|(key, ..)| DefineKey::Boolean(cfg_name) == DefineKey::load(key))
+                    .min();
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
                 } else {
@@ -226,9 +262,13 @@ impl ToCondition for Cfg {
                 }
             }
             Cfg::Named(ref cfg_name, ref cfg_value) => {
-                let define = config.defines.iter().find(|(key, ..)| {
-                    DefineKey::Named(cfg_name, cfg_value) == DefineKey::load(key)
-                });
+                let define = config
+                    .defines
+                    .iter()
+                    .filter(|(key, ..)| {
+                        DefineKey::Named(cfg_name, cfg_value) == DefineKey::load(key)
+                    })
+                    .min();
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
                 } else {
@@ -4,17 +4,19 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::io::Write;
 
 use syn::ext::IdentExt;
 use syn::UnOp;
 
+use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Struct, ToCondition, Type,
+    PrimitiveType, SourceLocation, Struct, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::writer::{Source, SourceWriter};
@@ -29,7 +31,7 @@ fn member_to_ident(member: &syn::Member) -> String {
 
 // TODO: Maybe add support to more std associated constants.
 fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
-    use crate::bindgen::ir::{IntKind, PrimitiveType};
+    use crate::bindgen::ir::IntKind;
 
     if name != "MAX" && name != "MIN" {
         return None;
@@ -78,6 +80,7 @@ fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum Literal {
     Expr(String),
     Path {
@@ -158,6 +161,103 @@ impl Literal {
         }
     }
 
+    /// Whether this is a boolean expression, `bools` being the names of the
+    /// boolean parameters of a `constexpr` `const fn`.
+    fn is_bool(&self, bools: &[String]) -> bool {
+        match *self {
+            Literal::Expr(ref value) => {
+                value == "true" || value == "false" || bools.contains(value)
+            }
+            Literal::Path {
+                associated_to: None,
+                ref name,
+            } => bools.contains(name),
+            Literal::PostfixUnaryOp { op, ref value } => {
+                op == "!" || (op == "~" && value.is_bool(bools))
+            }
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => match op {
+                "==" | "!=" | "<" | "<=" | ">" | ">=" | "&&" | "||" => true,
+                "&" | "|" | "^" => left.is_bool(bools) && right.is_bool(bools),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Writes Rust's `!` as a logical not rather than a bitwise one when its
+    /// operand is boolean, `bools` being the names of the boolean parameters
+    /// of a `constexpr` `const fn`.
+    fn lower_bool_nots(&mut self, bools: &[String]) {
+        match *self {
+            Literal::PostfixUnaryOp {
+                ref mut op,
+                ref mut value,
+            } => {
+                value.lower_bool_nots(bools);
+                if *op == "~" && value.is_bool(bools) {
+                    *op = "!";
+                }
+            }
+            Literal::Cast { ref mut value, .. } => value.lower_bool_nots(bools),
+            Literal::BinOp {
+                ref mut left,
+                ref mut right,
+                ..
+            } => {
+                left.lower_bool_nots(bools);
+                right.lower_bool_nots(bools);
+            }
+            Literal::FieldAccess { ref mut base, .. } => base.lower_bool_nots(bools),
+            Literal::Struct { ref mut fields, .. } => {
+                for expr in fields.values_mut() {
+                    expr.lower_bool_nots(bools);
+                }
+            }
+            Literal::Path { .. } | Literal::Expr(..) => {}
+        }
+    }
+
+    /// Replaces the references to the parameters of a `constexpr` `const fn`
+    /// with the expression `f` gives for them, which keeps them from being
+    /// renamed as constants.
+    fn replace_params(&mut self, params: &[String], f: &dyn Fn(&str) -> String) {
+        match *self {
+            Literal::PostfixUnaryOp { ref mut value, .. } | Literal::Cast { ref mut value, .. } => {
+                value.replace_params(params, f);
+            }
+            Literal::BinOp {
+                ref mut left,
+                ref mut right,
+                ..
+            } => {
+                left.replace_params(params, f);
+                right.replace_params(params, f);
+            }
+            Literal::FieldAccess { ref mut base, .. } => {
+                base.replace_params(params, f);
+            }
+            Literal::Struct { ref mut fields, .. } => {
+                for expr in fields.values_mut() {
+                    expr.replace_params(params, f);
+                }
+            }
+            Literal::Path {
+                associated_to: None,
+                ref name,
+            }
+            | Literal::Expr(ref name) => {
+                if params.contains(name) {
+                    *self = Literal::Expr(f(name));
+                }
+            }
+            Literal::Path { .. } => {}
+        }
+    }
+
     fn is_valid(&self, bindings: &Bindings) -> bool {
         match *self {
             Literal::Expr(..) => true,
@@ -187,7 +287,37 @@ impl Literal {
         !self.has_pointer_casts()
     }
 
-    fn visit(&self, visitor: &mut impl FnMut(&Self) -> bool) -> bool {
+    /// The value of an integer literal, or of arithmetic on them, if it can
+    /// be computed without knowing the types involved.
+    pub(crate) fn as_integer(&self) -> Option<i128> {
+        match *self {
+            Literal::Expr(ref expr) => expr.trim_end_matches(['u', 'l']).parse().ok(),
+            Literal::PostfixUnaryOp { op: "-", ref value } => value.as_integer()?.checked_neg(),
+            Literal::BinOp {
+                ref left,
+                op,
+                ref right,
+            } => {
+                let (left, right) = (left.as_integer()?, right.as_integer()?);
+                match op {
+                    "+" => left.checked_add(right),
+                    "-" => left.checked_sub(right),
+                    "*" => left.checked_mul(right),
+                    "/" => left.checked_div(right),
+                    "%" => left.checked_rem(right),
+                    "<<" => left.checked_shl(u32::try_from(right).ok()?),
+                    ">>" => left.checked_shr(u32::try_from(right).ok()?),
+                    "&" => Some(left & right),
+                    "|" => Some(left | right),
+                    "^" => Some(left ^ right),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn visit(&self, visitor: &mut impl FnMut(&Self) -> bool) -> bool {
         if !visitor(self) {
             return false;
         }
@@ -201,7 +331,7 @@ impl Literal {
             } => left.visit(visitor) && right.visit(visitor),
             Literal::FieldAccess { ref base, .. } => base.visit(visitor),
             Literal::Struct { ref fields, .. } => {
-                for (_name, field) in fields.iter() {
+                for field in fields.values() {
                     if !field.visit(visitor) {
                         return false;
                     }
@@ -420,7 +550,7 @@ impl Literal {
                 UnOp::Not(_) => {
                     let val = Self::load(expr)?;
                     Ok(Literal::PostfixUnaryOp {
-                        op: "~",
+                        op: if val.is_bool(&[]) { "!" } else { "~" },
                         value: Box::new(val),
                     })
                 }
@@ -478,6 +608,8 @@ impl Literal {
             Literal::Expr(v) => match (&**v, config.language) {
                 ("true", Language::Cython) => write!(out, "True"),
                 ("false", Language::Cython) => write!(out, "False"),
+                ("true", _) if !config.writes_bool() => write!(out, "1"),
+                ("false", _) if !config.writes_bool() => write!(out, "0"),
                 (v, _) => write!(out, "{}", v),
             },
             Literal::Path {
@@ -576,6 +708,7 @@ impl Literal {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Constant {
     pub path: Path,
     pub export_name: String,
@@ -584,7 +717,12 @@ pub struct Constant {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
     pub associated_to: Option<Path>,
+    /// The parameters of a `const fn` exported with the `constexpr`
+    /// annotation, which is written as a `constexpr` function returning
+    /// `value`, or as a function-like macro in C.
+    pub params: Option<Vec<(String, Type)>>,
 }
 
 impl Constant {
@@ -640,12 +778,82 @@ impl Constant {
             cfg,
             annotations,
             documentation,
+            source_location: None,
             associated_to,
+            params: None,
+        }
+    }
+
+    /// Loads a `const fn` whose body is a single expression, for the
+    /// `constexpr` annotation.
+    pub fn load_const_fn(
+        path: Path,
+        mod_cfg: Option<&Cfg>,
+        item: &syn::ItemFn,
+    ) -> Result<Constant, String> {
+        if !item.sig.generics.params.is_empty() {
+            return Err("Generic const fns can't be constexpr.".to_owned());
         }
+
+        let ty = match item.sig.output {
+            syn::ReturnType::Type(_, ref ty) => Type::load(ty)?,
+            syn::ReturnType::Default => None,
+        };
+        let ty = ty.ok_or_else(|| "A constexpr const fn has to return a value.".to_owned())?;
+
+        let mut params = Vec::new();
+        for input in &item.sig.inputs {
+            let (pat, ty) = match *input {
+                syn::FnArg::Typed(ref arg) => (&*arg.pat, &*arg.ty),
+                syn::FnArg::Receiver(..) => {
+                    return Err("Methods can't be constexpr.".to_owned());
+                }
+            };
+            let name = match *pat {
+                syn::Pat::Ident(ref ident) => ident.ident.unraw().to_string(),
+                _ => return Err(format!("Unsupported parameter pattern. {:?}", pat)),
+            };
+            match Type::load(ty)? {
+                Some(ty) => params.push((name, ty)),
+                None => return Err("Cannot have a zero sized parameter.".to_owned()),
+            }
+        }
+
+        let expr = match item.block.stmts[..] {
+            [syn::Stmt::Expr(ref expr)] => expr,
+            _ => return Err("The body of a constexpr const fn has to be an expression.".to_owned()),
+        };
+        let mut value = Literal::load(expr)?;
+        let names: Vec<String> = params.iter().map(|(name, _)| name.clone()).collect();
+        value.replace_params(&names, &|name| name.to_owned());
+        let bools: Vec<String> = params
+            .iter()
+            .filter(|(_, ty)| matches!(ty, Type::Primitive(PrimitiveType::Bool)))
+            .map(|(name, _)| name.clone())
+            .collect();
+        value.lower_bool_nots(&bools);
+
+        let mut constant = Constant::new(
+            path,
+            ty,
+            value,
+            Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
+            AnnotationSet::load(&item.attrs)?,
+            Documentation::load(&item.attrs),
+            None,
+        );
+        constant.params = Some(params);
+        Ok(constant)
     }
 
     pub fn uses_only_primitive_types(&self) -> bool {
-        self.value.uses_only_primitive_types() && self.ty.is_primitive_or_ptr_primitive()
+        self.value.uses_only_primitive_types()
+            && self.ty.is_primitive_or_ptr_primitive()
+            && self
+                .params
+                .iter()
+                .flatten()
+                .all(|(_, ty)| ty.is_primitive_or_ptr_primitive())
     }
 }
 
@@ -656,6 +864,9 @@ impl Item for Constant {
 
     fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
         self.ty.add_dependencies(library, out);
+        for (_, ty) in self.params.iter().flatten() {
+            ty.add_dependencies(library, out);
+        }
     }
 
     fn export_name(&self) -> &str {
@@ -674,6 +885,10 @@ impl Item for Constant {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::Constant(self.clone())
     }
@@ -684,10 +899,16 @@ impl Item for Constant {
         }
         self.value.rename_for_config(config);
         self.ty.rename_for_config(config, &GenericParams::default()); // FIXME: should probably propagate something here
+        for (_, ty) in self.params.iter_mut().flatten() {
+            ty.rename_for_config(config, &GenericParams::default());
+        }
     }
 
     fn resolve_declaration_types(&mut self, resolver: &DeclarationTypeResolver) {
         self.ty.resolve_declaration_types(resolver);
+        for (_, ty) in self.params.iter_mut().flatten() {
+            ty.resolve_declaration_types(resolver);
+        }
     }
 }
 
@@ -770,7 +991,15 @@ impl Constant {
             _ => &self.value,
         };
 
-        self.documentation.write(config, out);
+        self.documentation
+            .for_symbol(config, &name)
+            .write(config, out);
+
+        if let Some(ref params) = self.params {
+            write_const_fn(config, out, &name, &self.ty, params, value);
+            condition.write_after(config, out);
+            return;
+        }
 
         let allow_constexpr = config.constant.allow_constexpr && self.value.can_be_constexpr();
         match config.language {
@@ -811,3 +1040,90 @@ impl Constant {
         condition.write_after(config, out);
     }
 }
+
+/// Writes a `constexpr` `const fn` as a `static inline constexpr` function in
+/// C++, a function-like macro in C, and a function declaration in Cython.
+fn write_const_fn<F: Write>(
+    config: &Config,
+    out: &mut SourceWriter<F>,
+    name: &str,
+    ret: &Type,
+    params: &[(String, Type)],
+    value: &Literal,
+) {
+    let names: Vec<String> = params.iter().map(|(name, _)| name.clone()).collect();
+    match config.language {
+        Language::Cxx => {
+            out.write("static inline constexpr ");
+            cdecl::write_type(out, ret, config);
+            write!(out, " {}(", name);
+            for (i, (param, ty)) in params.iter().enumerate() {
+                if i != 0 {
+                    out.write(", ");
+                }
+                cdecl::write_field(out, ty, param, config);
+            }
+            out.write(")");
+            out.open_brace();
+            out.write("return ");
+            value.write(config, out);
+            out.write(";");
+            out.close_brace(false);
+        }
+        Language::C => {
+            // The arguments are parenthesized, as they may be expressions, and
+            // cast to the type of their parameter. C promotes the types
+            // narrower than `int` to `int` before operating on them, so the
+            // result is cast to the return type as well, to wrap like in Rust.
+            // Structs can't be cast.
+            let cast_to = |ty: &Type| {
+                if !matches!(ty, Type::Primitive(..) | Type::Ptr { .. }) {
+                    return None;
+                }
+                let mut cast = Vec::new();
+                cdecl::write_type(
+                    &mut SourceWriter::new(&mut cast as &mut dyn Write, out.bindings()),
+                    ty,
+                    config,
+                );
+                Some(String::from_utf8(cast).unwrap())
+            };
+            let casts: HashMap<&str, String> = params
+                .iter()
+                .filter_map(|(name, ty)| Some((name.as_str(), cast_to(ty)?)))
+                .collect();
+            let ret_cast = cast_to(ret);
+            let mut value = value.clone();
+            value.replace_params(&names, &|name| match casts.get(name) {
+                Some(ty) => format!("(({})({}))", ty, name),
+                None => format!("({})", name),
+            });
+            write!(out, "#define {}({}) ", name, names.join(", "));
+            if let Some(ref ty) = ret_cast {
+                write!(out, "(({})", ty);
+            }
+            match value {
+                Literal::Expr(..) | Literal::BinOp { .. } => value.write(config, out),
+                _ => {
+                    out.write("(");
+                    value.write(config, out);
+                    out.write(")");
+                }
+            }
+            if ret_cast.is_some() {
+                out.write(")");
+            }
+        }
+        Language::Cython => {
+            cdecl::write_type(out, ret, config);
+            write!(out, " {}(", name);
+            for (i, (param, ty)) in params.iter().enumerate() {
+                if i != 0 {
+                    out.write(", ");
+                }
+                cdecl::write_field(out, ty, param, config);
+            }
+            out.write(")");
+        }
+    }
+}
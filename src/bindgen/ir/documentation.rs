@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
 use std::io::Write;
 
 use crate::bindgen::config::{Config, DocumentationLength, DocumentationStyle, Language};
@@ -9,6 +10,7 @@ use crate::bindgen::utilities::SynAttributeHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Documentation {
     pub doc_comment: Vec<String>,
 }
@@ -35,34 +37,188 @@ impl Documentation {
             doc_comment: Vec::new(),
         }
     }
+
+    /// The documentation of the item called `symbol`, which GTK-Doc comments
+    /// start with.
+    pub fn for_symbol(&self, config: &Config, symbol: &str) -> Cow<'_, Documentation> {
+        if self.doc_comment.is_empty()
+            || config.language == Language::Cython
+            || documentation_style(config) != DocumentationStyle::GtkDoc
+        {
+            return Cow::Borrowed(self);
+        }
+
+        let mut doc_comment = vec![format!(" {}:", symbol), String::new()];
+        doc_comment.extend(self.doc_comment.iter().cloned());
+        Cow::Owned(Documentation { doc_comment })
+    }
+
+    /// The documentation lines, without the sections and rustdoc syntax the
+    /// `documentation_*` options ask to remove, and with sections renamed.
+    pub fn lines_for_config(&self, config: &Config) -> Cow<'_, [String]> {
+        if config.documentation_remove_sections.is_empty()
+            && config.documentation_rename_sections.is_empty()
+            && !config.documentation_strip_rustdoc
+        {
+            return Cow::Borrowed(&self.doc_comment);
+        }
+
+        let mut out: Vec<String> = Vec::with_capacity(self.doc_comment.len());
+        // The code fence of the code block we're in, and whether it's Rust.
+        let mut code_block: Option<(&str, bool)> = None;
+        // The level of the heading of the section being removed.
+        let mut removed_level = None;
+
+        for line in &self.doc_comment {
+            let trimmed = line.trim();
+
+            if let Some((fence, is_rust)) = code_block {
+                if trimmed.starts_with(fence) {
+                    code_block = None;
+                } else if is_rust && config.documentation_strip_rustdoc {
+                    // Rustdoc hides `# ` lines, and unescapes `##` lines.
+                    let code = line.strip_prefix(' ').unwrap_or(line);
+                    let indent = &code[..code.len() - code.trim_start().len()];
+                    let code = code.trim_start();
+                    if code == "#" || code.starts_with("# ") {
+                        continue;
+                    }
+                    if let Some(rest) = code.strip_prefix("##") {
+                        if removed_level.is_none() {
+                            out.push(format!(" {}#{}", indent, rest));
+                        }
+                        continue;
+                    }
+                }
+                if removed_level.is_none() {
+                    out.push(line.clone());
+                }
+                continue;
+            }
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                let fence = &trimmed[..3];
+                let is_rust = trimmed[3..].split(',').map(str::trim).all(|attr| {
+                    attr.is_empty()
+                        || attr == "rust"
+                        || attr.starts_with("edition")
+                        || matches!(attr, "ignore" | "no_run" | "should_panic" | "compile_fail")
+                });
+                code_block = Some((fence, is_rust));
+                if removed_level.is_none() {
+                    out.push(line.clone());
+                }
+                continue;
+            }
+
+            if let Some(title) = markdown_heading(trimmed) {
+                let level = trimmed.len() - trimmed.trim_start_matches('#').len();
+                if removed_level.map_or(false, |removed| level > removed) {
+                    continue;
+                }
+                removed_level = None;
+                let removed = config
+                    .documentation_remove_sections
+                    .iter()
+                    .any(|section| section.eq_ignore_ascii_case(title));
+                if removed {
+                    removed_level = Some(level);
+                    continue;
+                }
+                if let Some(renamed) = config.documentation_rename_sections.get(title) {
+                    out.push(format!(" {} {}", &trimmed[..level], renamed));
+                    continue;
+                }
+            }
+
+            if removed_level.is_some() {
+                continue;
+            }
+            // Don't leave runs of blank lines behind removed sections.
+            if trimmed.is_empty() && out.last().map_or(true, |line| line.trim().is_empty()) {
+                continue;
+            }
+            if config.documentation_strip_rustdoc {
+                out.push(strip_intra_doc_links(line).into_owned());
+            } else {
+                out.push(line.clone());
+            }
+        }
+
+        while out.last().map_or(false, |line| line.trim().is_empty()) {
+            out.pop();
+        }
+        Cow::Owned(out)
+    }
+}
+
+impl Documentation {
+    /// Writes the documentation as a Python docstring, for the Cython
+    /// declarations which can have one.
+    pub fn write_docstring<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if !config.documentation {
+            return;
+        }
+
+        let doc_comment = self.lines_for_config(config);
+        let end = match config.documentation_length {
+            DocumentationLength::Short => doc_comment.len().min(1),
+            DocumentationLength::Full => doc_comment.len(),
+        };
+        let lines: Vec<String> = doc_comment[..end]
+            .iter()
+            .map(|line| {
+                line.strip_prefix(' ')
+                    .unwrap_or(line)
+                    .replace('\\', "\\\\")
+                    .replace("\"\"\"", "\\\"\"\"")
+            })
+            .collect();
+        match lines[..] {
+            [] => return,
+            [ref line] => write!(out, "\"\"\"{}\"\"\"", line),
+            _ => {
+                out.write("\"\"\"");
+                for line in &lines {
+                    out.new_line();
+                    if !line.is_empty() {
+                        write!(out, "{}", line);
+                    }
+                }
+                out.new_line();
+                out.write("\"\"\"");
+            }
+        }
+        out.new_line();
+    }
 }
 
 impl Source for Documentation {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        if self.doc_comment.is_empty() || !config.documentation {
+        if !config.documentation {
+            return;
+        }
+
+        let doc_comment = self.lines_for_config(config);
+        if doc_comment.is_empty() {
             return;
         }
 
         let end = match config.documentation_length {
             DocumentationLength::Short => 1,
-            DocumentationLength::Full => self.doc_comment.len(),
+            DocumentationLength::Full => doc_comment.len(),
         };
 
         // Cython uses Python-style comments, so `documentation_style` is not relevant.
         if config.language == Language::Cython {
-            for line in &self.doc_comment[..end] {
+            for line in &doc_comment[..end] {
                 write!(out, "#{}", line);
                 out.new_line();
             }
             return;
         }
 
-        let style = match config.documentation_style {
-            DocumentationStyle::Auto if config.language == Language::C => DocumentationStyle::Doxy,
-            DocumentationStyle::Auto if config.language == Language::Cxx => DocumentationStyle::Cxx,
-            DocumentationStyle::Auto => DocumentationStyle::C, // Fallback if `Language` gets extended.
-            other => other,
-        };
+        let style = documentation_style(config);
 
         // Following these documents for style conventions:
         // https://en.wikibooks.org/wiki/C++_Programming/Code/Style_Conventions/Comments
@@ -73,18 +229,36 @@ impl Source for Documentation {
                 out.new_line();
             }
 
-            DocumentationStyle::Doxy => {
+            DocumentationStyle::Doxy
+            | DocumentationStyle::DoxygenFull
+            | DocumentationStyle::Javadoc
+            | DocumentationStyle::GtkDoc => {
                 out.write("/**");
                 out.new_line();
             }
 
+            DocumentationStyle::Qdoc => {
+                out.write("/*!");
+                out.new_line();
+            }
+
             _ => (),
         }
 
-        for line in &self.doc_comment[..end] {
+        let lines = match style {
+            DocumentationStyle::DoxygenFull
+            | DocumentationStyle::Javadoc
+            | DocumentationStyle::Qdoc => Cow::Owned(convert_rustdoc(&doc_comment[..end], style)),
+            _ => Cow::Borrowed(&doc_comment[..end]),
+        };
+
+        for line in lines.iter() {
             match style {
-                DocumentationStyle::C => out.write(""),
-                DocumentationStyle::Doxy => out.write(" *"),
+                DocumentationStyle::C | DocumentationStyle::Qdoc => out.write(""),
+                DocumentationStyle::Doxy
+                | DocumentationStyle::DoxygenFull
+                | DocumentationStyle::Javadoc
+                | DocumentationStyle::GtkDoc => out.write(" *"),
                 DocumentationStyle::C99 => out.write("//"),
                 DocumentationStyle::Cxx => out.write("///"),
                 DocumentationStyle::Auto => unreachable!(), // Auto case should always be covered
@@ -95,12 +269,15 @@ impl Source for Documentation {
         }
 
         match style {
-            DocumentationStyle::C => {
+            DocumentationStyle::C | DocumentationStyle::Qdoc => {
                 out.write(" */");
                 out.new_line();
             }
 
-            DocumentationStyle::Doxy => {
+            DocumentationStyle::Doxy
+            | DocumentationStyle::DoxygenFull
+            | DocumentationStyle::Javadoc
+            | DocumentationStyle::GtkDoc => {
                 out.write(" */");
                 out.new_line();
             }
@@ -109,3 +286,277 @@ impl Source for Documentation {
         }
     }
 }
+
+/// The style documentation is written in for `config`'s language.
+pub fn documentation_style(config: &Config) -> DocumentationStyle {
+    let style = match config.language {
+        Language::Cxx => config
+            .cpp_documentation_style
+            .unwrap_or(config.documentation_style),
+        _ => config.documentation_style,
+    };
+    match style {
+        DocumentationStyle::Auto if config.language == Language::C => DocumentationStyle::Doxy,
+        DocumentationStyle::Auto if config.language == Language::Cxx => DocumentationStyle::Cxx,
+        DocumentationStyle::Auto => DocumentationStyle::C, // Fallback if `Language` gets extended.
+        other => other,
+    }
+}
+
+/// Turns rustdoc conventions into the commands of the documentation tool
+/// `style` is meant for (`DoxygenFull`, `Javadoc` or `Qdoc`):
+///
+///  * `# Safety` and `# Panics` sections become warnings, `# Returns` becomes
+///    `@return` (except for QDoc, which has no such thing), `# Arguments` is
+///    dropped and other sections become titled paragraphs.
+///  * `* arg - description` lines become `@param`s, or `\a`rguments for QDoc.
+///  * Code fences become code blocks.
+///  * Intra-doc links become plain names, which the tools link on their own.
+///  * For QDoc, the first sentence becomes the `\brief`.
+///
+/// Javadoc's block tags are moved after the description, as it requires.
+fn convert_rustdoc(lines: &[String], style: DocumentationStyle) -> Vec<String> {
+    let mut out = Vec::with_capacity(lines.len());
+    let mut block_tags: Vec<String> = Vec::new();
+    let mut in_code = false;
+    // Whether a blank line following a section heading should be dropped, so
+    // that the section's text stays in the command's paragraph.
+    let mut after_heading = false;
+    // Whether we're in the paragraph that makes up a Javadoc `@return`.
+    let mut in_return_tag = false;
+
+    for line in lines {
+        let text = line.strip_prefix(' ').unwrap_or(line);
+        let trimmed = text.trim();
+
+        if trimmed.starts_with("```") {
+            out.push(format!(" {}", code_command(style, !in_code)));
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            out.push(line.clone());
+            continue;
+        }
+
+        if after_heading {
+            after_heading = false;
+            if trimmed.is_empty() {
+                continue;
+            }
+        }
+
+        if in_return_tag {
+            if !trimmed.is_empty() {
+                let tag = block_tags.last_mut().unwrap();
+                tag.push(' ');
+                tag.push_str(&strip_intra_doc_links(trimmed));
+                continue;
+            }
+            in_return_tag = false;
+        }
+
+        if let Some(heading) = markdown_heading(trimmed) {
+            after_heading = true;
+            match (heading, style) {
+                ("Arguments" | "Parameters", _) => {}
+                ("Returns", DocumentationStyle::Javadoc) => {
+                    block_tags.push(" @return".to_owned());
+                    in_return_tag = true;
+                }
+                ("Returns", DocumentationStyle::DoxygenFull) => out.push(" @return".to_owned()),
+                ("Safety" | "Panics", DocumentationStyle::Javadoc) => {
+                    out.push(format!(" <p><b>{}:</b>", heading))
+                }
+                ("Safety" | "Panics", DocumentationStyle::Qdoc) => {
+                    out.push(format!(" \\warning {}:", heading))
+                }
+                ("Safety" | "Panics", _) => out.push(format!(" @warning {}:", heading)),
+                (_, DocumentationStyle::Javadoc) => out.push(format!(" <p><b>{}</b>", heading)),
+                (_, DocumentationStyle::Qdoc) => out.push(format!(" \\b {{{}}}", heading)),
+                _ => out.push(format!(" @par {}", heading)),
+            }
+            continue;
+        }
+
+        if let Some((name, description)) = parameter(trimmed) {
+            let description = strip_intra_doc_links(description);
+            match style {
+                DocumentationStyle::Javadoc => {
+                    block_tags.push(format!(" @param {} {}", name, description))
+                }
+                DocumentationStyle::Qdoc => out.push(format!(" \\a {} {}", name, description)),
+                _ => out.push(format!(" @param {} {}", name, description)),
+            }
+            continue;
+        }
+
+        // Don't leave runs of blank lines behind moved or dropped lines.
+        if trimmed.is_empty() && out.last().map_or(true, |line| line.is_empty()) {
+            continue;
+        }
+        out.push(
+            format!(" {}", strip_intra_doc_links(text))
+                .trim_end()
+                .to_owned(),
+        );
+    }
+
+    if in_code {
+        out.push(format!(" {}", code_command(style, false)));
+    }
+
+    if !block_tags.is_empty() {
+        while out.last().map_or(false, |line| line.is_empty()) {
+            out.pop();
+        }
+        if !out.is_empty() {
+            out.push(String::new());
+        }
+        out.extend(block_tags);
+    }
+
+    if style == DocumentationStyle::Qdoc {
+        extract_brief(&mut out);
+    }
+
+    out
+}
+
+fn code_command(style: DocumentationStyle, open: bool) -> &'static str {
+    match (style, open) {
+        (DocumentationStyle::Javadoc, true) => "<pre>{@code",
+        (DocumentationStyle::Javadoc, false) => "}</pre>",
+        (DocumentationStyle::Qdoc, true) => "\\code",
+        (DocumentationStyle::Qdoc, false) => "\\endcode",
+        (_, true) => "@code",
+        (_, false) => "@endcode",
+    }
+}
+
+/// Turns the first sentence of the leading paragraph into a QDoc `\brief`,
+/// with the rest of the paragraph following it.
+fn extract_brief(lines: &mut Vec<String>) {
+    let paragraph_end = lines
+        .iter()
+        .position(|line| line.is_empty())
+        .unwrap_or(lines.len());
+    if paragraph_end == 0 || lines[0].trim_start().starts_with('\\') {
+        return;
+    }
+
+    let paragraph = lines[..paragraph_end]
+        .iter()
+        .map(|line| line.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let (brief, rest) = match paragraph.find(". ") {
+        Some(end) => paragraph.split_at(end + 1),
+        None => (&paragraph[..], ""),
+    };
+
+    let mut replacement = vec![format!(" \\brief {}", brief)];
+    if !rest.trim().is_empty() {
+        replacement.push(String::new());
+        replacement.push(format!(" {}", rest.trim()));
+    }
+    lines.splice(..paragraph_end, replacement);
+}
+
+/// Returns the title of a `# Title` line.
+pub(crate) fn markdown_heading(line: &str) -> Option<&str> {
+    let title = line.trim_start_matches('#');
+    if title.len() == line.len() || !title.starts_with(' ') {
+        return None;
+    }
+    Some(title.trim())
+}
+
+/// Parses rustdoc's `* arg - description` (or `` * `arg` - description ``)
+/// convention for documenting arguments.
+pub(crate) fn parameter(line: &str) -> Option<(&str, &str)> {
+    let rest = line
+        .strip_prefix("* ")
+        .or_else(|| line.strip_prefix("- "))?
+        .trim_start();
+    let (name, rest) = match rest.strip_prefix('`') {
+        Some(quoted) => {
+            let end = quoted.find('`')?;
+            (&quoted[..end], &quoted[end + 1..])
+        }
+        None => {
+            let end = rest.find(char::is_whitespace)?;
+            (&rest[..end], &rest[end..])
+        }
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    let description = rest.trim_start().strip_prefix('-')?;
+    Some((name, description.trim()))
+}
+
+/// Replaces intra-doc links like ``[`Foo`]``, `[Foo]`, `[text](Foo)` and
+/// `[text][Foo]` by the plain name (or text) they refer to. Links to URLs are
+/// left alone.
+fn strip_intra_doc_links(text: &str) -> Cow<'_, str> {
+    if !text.contains('[') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        out.push_str(&rest[..start]);
+        let link = &rest[start + 1..];
+
+        // Don't mistake indexing, like `values[i]`, for a link.
+        let follows_word = out
+            .chars()
+            .last()
+            .map_or(false, |c| c.is_alphanumeric() || c == '_' || c == ']');
+        let replacement = match link.find(']') {
+            Some(end) if !follows_word => intra_doc_link(&link[..end], &link[end + 1..]),
+            _ => None,
+        };
+        match replacement {
+            Some((name, after)) => {
+                out.push_str(name);
+                rest = after;
+            }
+            None => {
+                out.push('[');
+                rest = link;
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+/// Parses a link given its label and the text following it, returning the
+/// plain name replacing the link and the text after the link.
+fn intra_doc_link<'a>(label: &'a str, after: &'a str) -> Option<(&'a str, &'a str)> {
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        if let Some(target) = after.strip_prefix(open) {
+            let end = target.find(close)?;
+            if target[..end].contains("://") || target.starts_with('#') {
+                return None;
+            }
+            return Some((label.trim_matches('`'), &target[end + 1..]));
+        }
+    }
+
+    // Drop disambiguators like `struct@Foo` and the path, keeping the name.
+    let path = label.trim_matches('`').rsplit('@').next().unwrap();
+    let is_path = path.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && path
+            .trim_end_matches("()")
+            .trim_end_matches('!')
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == ':');
+    if !is_path {
+        return None;
+    }
+    Some((path.rsplit("::").next().unwrap(), after))
+}
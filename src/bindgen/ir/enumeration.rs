@@ -6,13 +6,13 @@ use std::io::Write;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::{Config, EnumFixedType, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, AnnotationValue, Cfg, ConditionWrite, DeprecatedNoteKind, Documentation, Field,
     GenericArgument, GenericParams, GenericPath, Item, ItemContainer, Literal, Path, Repr,
-    ReprStyle, Struct, ToCondition, Type,
+    ReprStyle, SourceLocation, Struct, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -23,6 +23,7 @@ use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum VariantBody {
     Empty(AnnotationSet),
     Body {
@@ -84,6 +85,7 @@ impl VariantBody {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct EnumVariant {
     pub name: String,
     pub export_name: String,
@@ -93,6 +95,16 @@ pub struct EnumVariant {
     pub documentation: Documentation,
 }
 
+/// The value of an enum variant, as computed by `Enum::variant_values`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum VariantValue<'a> {
+    /// A value cbindgen could compute.
+    Integer(i128),
+    /// `base + offset`, following a discriminant cbindgen can't compute, like
+    /// the path of a constant.
+    Offset(&'a Literal, i128),
+}
+
 impl EnumVariant {
     fn load(
         inline_tag_field: bool,
@@ -126,19 +138,14 @@ impl EnumVariant {
             for (i, field) in fields.iter().enumerate() {
                 if let Some(mut ty) = Type::load(&field.ty)? {
                     ty.replace_self_with(self_path);
-                    res.push(Field {
-                        name: inline_name.map_or_else(
-                            || match field.ident {
-                                Some(ref ident) => ident.unraw().to_string(),
-                                None => i.to_string(),
-                            },
-                            |name| name.to_string(),
-                        ),
-                        ty,
-                        cfg: Cfg::load(&field.attrs),
-                        annotations: AnnotationSet::load(&field.attrs)?,
-                        documentation: Documentation::load(&field.attrs),
-                    });
+                    let name = inline_name.map_or_else(
+                        || match field.ident {
+                            Some(ref ident) => ident.unraw().to_string(),
+                            None => i.to_string(),
+                        },
+                        |name| name.to_string(),
+                    );
+                    res.push(Field::load_with_name(name, ty, &field.attrs)?);
                 }
             }
 
@@ -318,6 +325,7 @@ impl Source for EnumVariant {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Enum {
     pub path: Path,
     pub export_name: String,
@@ -328,6 +336,7 @@ pub struct Enum {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
 }
 
 impl Enum {
@@ -342,7 +351,7 @@ impl Enum {
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
-        if self.generic_params.len() > 0 {
+        if !self.generic_params.is_empty() {
             return;
         }
 
@@ -351,6 +360,25 @@ impl Enum {
         }
     }
 
+    /// The variants along with their values. Implicit values count up from
+    /// the last explicit one.
+    pub(crate) fn variant_values(&self) -> impl Iterator<Item = (&EnumVariant, VariantValue<'_>)> {
+        let mut value = VariantValue::Integer(-1);
+        self.variants.iter().map(move |variant| {
+            value = match (&variant.discriminant, value) {
+                (Some(discriminant), _) => match discriminant.as_integer() {
+                    Some(integer) => VariantValue::Integer(integer),
+                    None => VariantValue::Offset(discriminant, 0),
+                },
+                (None, VariantValue::Integer(integer)) => VariantValue::Integer(integer + 1),
+                (None, VariantValue::Offset(base, offset)) => {
+                    VariantValue::Offset(base, offset + 1)
+                }
+            };
+            (variant, value)
+        })
+    }
+
     fn can_derive_eq(&self) -> bool {
         if self.tag.is_none() {
             return false;
@@ -389,6 +417,9 @@ impl Enum {
         let mut has_data = false;
 
         let annotations = AnnotationSet::load(&item.attrs)?;
+        if annotations.bool("opaque").unwrap_or(false) {
+            return Err("Enum is annotated as opaque.".to_owned());
+        }
 
         for variant in item.variants.iter() {
             let variant = EnumVariant::load(
@@ -466,6 +497,7 @@ impl Enum {
             cfg,
             annotations,
             documentation,
+            source_location: None,
         }
     }
 }
@@ -491,6 +523,10 @@ impl Item for Enum {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::Enum(self.clone())
     }
@@ -664,7 +700,12 @@ impl Source for Enum {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
-        self.documentation.write(config, out);
+        // Cpdef enums get their documentation as a docstring instead.
+        if !self.has_docstring(config, size, has_data) {
+            self.documentation
+                .for_symbol(config, self.export_name())
+                .write(config, out);
+        }
         self.generic_params.write(config, out);
 
         // If the enum has data, we need to emit a struct or union for the data
@@ -747,6 +788,22 @@ impl Enum {
         has_data: bool,
         tag_name: &str,
     ) {
+        let fixed_type = match size {
+            Some(_) if config.language == Language::C => {
+                config.enumeration.fixed_type(&self.annotations)
+            }
+            _ => EnumFixedType::Typedef,
+        };
+        // The other ways of fixing the size are valid C++ as they are.
+        let cpp_compatible = config.cpp_compatible_c() && fixed_type == EnumFixedType::Typedef;
+
+        if let (EnumFixedType::Defines, Some(prim)) = (fixed_type, size) {
+            write!(out, "typedef {} {};", prim, tag_name);
+            self.write_variant_defines(config, out);
+            self.write_derived_functions_enum(config, out, has_data, tag_name);
+            return;
+        }
+
         // Open the tag enum.
         match config.language {
             Language::C => {
@@ -760,9 +817,14 @@ impl Enum {
                     {
                         write!(out, " {}", note);
                     }
-                    write!(out, " {}", tag_name);
+                    if fixed_type != EnumFixedType::AnonymousEnum {
+                        write!(out, " {}", tag_name);
+                    }
+                    if fixed_type == EnumFixedType::C23 {
+                        write!(out, " : {}", prim);
+                    }
 
-                    if config.cpp_compatible_c() {
+                    if cpp_compatible {
                         out.new_line();
                         out.write("#ifdef __cplusplus");
                         out.new_line();
@@ -794,10 +856,11 @@ impl Enum {
                     out.write("enum");
                 }
 
-                if self.annotations.must_use(config) {
-                    if let Some(ref anno) = config.enumeration.must_use {
-                        write!(out, " {}", anno)
-                    }
+                if let Some(anno) = self
+                    .annotations
+                    .must_use_attribute(config, DeprecatedNoteKind::Enum)
+                {
+                    write!(out, " {}", anno)
                 }
 
                 if let Some(note) = self
@@ -813,10 +876,17 @@ impl Enum {
                 }
             }
             Language::Cython => {
+                let cpdef = config.cython.cpdef_enum(&self.annotations);
                 if size.is_some() {
                     // If we need to specify size, then we have no choice but to create a typedef,
                     // so `config.style` is not respected.
-                    write!(out, "cdef enum");
+                    write!(out, "{}enum", if cpdef { "cpdef " } else { "cdef " });
+                } else if cpdef {
+                    write!(out, "cpdef enum {}", tag_name);
+                    // Without a tag, the enum is only known by its typedef in C.
+                    if !config.style.generate_tag() {
+                        write!(out, " \"{}\"", tag_name);
+                    }
                 } else {
                     write!(out, "{}enum {}", config.style.cython_def(), tag_name);
                 }
@@ -824,6 +894,10 @@ impl Enum {
         }
         out.open_brace();
 
+        if self.has_docstring(config, size, has_data) {
+            self.documentation.write_docstring(config, out);
+        }
+
         // Emit enumerators for the tag enum.
         for (i, variant) in self.variants.iter().enumerate() {
             if i != 0 {
@@ -844,17 +918,20 @@ impl Enum {
         // In C++ enums can "inherit" from numeric types (`enum E: uint8_t { ... }`),
         // but in C `typedef uint8_t E` is the only way to give a fixed size to `E`.
         if let Some(prim) = size {
-            if config.cpp_compatible_c() {
+            if cpp_compatible {
                 out.new_line_if_not_start();
                 out.write("#ifndef __cplusplus");
             }
 
-            if config.language != Language::Cxx {
+            if fixed_type == EnumFixedType::C23 {
+                out.new_line();
+                write!(out, "typedef enum {} {};", tag_name, tag_name);
+            } else if config.language != Language::Cxx {
                 out.new_line();
                 write!(out, "{} {} {};", config.language.typedef(), prim, tag_name);
             }
 
-            if config.cpp_compatible_c() {
+            if cpp_compatible {
                 out.new_line_if_not_start();
                 out.write("#endif // __cplusplus");
             }
@@ -864,6 +941,39 @@ impl Enum {
         self.write_derived_functions_enum(config, out, has_data, tag_name);
     }
 
+    /// Writes a `#define` for each variant, for `EnumFixedType::Defines`.
+    fn write_variant_defines<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        for (variant, value) in self.variant_values() {
+            out.new_line();
+            let condition = variant.cfg.to_condition(config);
+            condition.write_before(config, out);
+            variant.documentation.write(config, out);
+            write!(out, "#define {} ", variant.export_name);
+            match value {
+                VariantValue::Integer(value) if value < 0 => write!(out, "({})", value),
+                VariantValue::Integer(value) => write!(out, "{}", value),
+                VariantValue::Offset(base, offset) => {
+                    out.write("(");
+                    base.write(config, out);
+                    if offset != 0 {
+                        write!(out, " + {}", offset);
+                    }
+                    out.write(")");
+                }
+            }
+            condition.write_after(config, out);
+        }
+    }
+
+    /// Whether the documentation is written as the docstring of a named
+    /// `cpdef enum`, rather than as comments.
+    fn has_docstring(&self, config: &Config, size: Option<&str>, has_data: bool) -> bool {
+        config.language == Language::Cython
+            && size.is_none()
+            && !has_data
+            && config.cython.cpdef_enum(&self.annotations)
+    }
+
     /// The code here mirrors the beginning of `Struct::write` and `Union::write`.
     fn open_struct_or_union<F: Write>(
         &self,
@@ -879,10 +989,11 @@ impl Enum {
 
         out.write(if inline_tag_field { "union" } else { "struct" });
 
-        if self.annotations.must_use(config) {
-            if let Some(ref anno) = config.structure.must_use {
-                write!(out, " {}", anno);
-            }
+        if let Some(anno) = self
+            .annotations
+            .must_use_attribute(config, DeprecatedNoteKind::Struct)
+        {
+            write!(out, " {}", anno);
         }
 
         if let Some(note) = self
@@ -905,8 +1016,65 @@ impl Enum {
         }
     }
 
+    /// The function named by the `destructor` annotation of an enum with
+    /// fields, dropping it in place on the Rust side, as
+    /// `void destructor(Enum *ptr)`. The annotation is dropped if the function
+    /// isn't exported with that signature.
+    pub(crate) fn destructor_function(&self) -> Option<String> {
+        self.lifecycle_function("destructor")
+    }
+
+    /// The function named by the `clone` annotation of an enum with fields,
+    /// cloning it on the Rust side, as `void clone(const Enum *src, Enum *dst)`,
+    /// or as `Enum clone(const Enum *src)` but in C++. The annotation is
+    /// dropped if the function isn't exported with either signature.
+    pub(crate) fn clone_function(&self) -> Option<String> {
+        self.lifecycle_function("clone")
+    }
+
+    /// Whether an enum with fields is declared as a `struct` or a `union`.
+    pub(crate) fn tagged_keyword(&self) -> &'static str {
+        if self.repr.style == ReprStyle::C {
+            "struct"
+        } else {
+            "union"
+        }
+    }
+
+    fn lifecycle_function(&self, annotation: &str) -> Option<String> {
+        if self.tag.is_none() || !self.generic_params.is_empty() {
+            return None;
+        }
+        match self.annotations.atom(annotation) {
+            Some(Some(function)) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Whether the bodies of the variants are written as unnamed structs in
+    /// the fields of the enum, rather than as separate structs.
+    fn anonymous_variant_bodies(&self, config: &Config) -> bool {
+        // Cython doesn't support unnamed structs.
+        config.language != Language::Cython
+            && config
+                .enumeration
+                .anonymous_variant_bodies(&self.annotations)
+    }
+
+    /// The type of the field `name` holding the body of a variant, for C++.
+    fn variant_body_type(&self, config: &Config, name: &str, body: &Struct) -> String {
+        if self.anonymous_variant_bodies(config) {
+            format!("decltype({})", name)
+        } else {
+            body.export_name().to_owned()
+        }
+    }
+
     /// Emit struct definitions for variants having data.
     fn write_variant_defs<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.anonymous_variant_bodies(config) {
+            return;
+        }
         for variant in &self.variants {
             if let VariantBody::Body {
                 ref body,
@@ -968,6 +1136,7 @@ impl Enum {
         out: &mut SourceWriter<F>,
         inline_tag_field: bool,
     ) {
+        let anonymous = self.anonymous_variant_bodies(config);
         let mut first = true;
         for variant in &self.variants {
             if let VariantBody::Body {
@@ -997,10 +1166,20 @@ impl Enum {
                     }
                     let start_field =
                         usize::from(inline_tag_field && config.language == Language::Cython);
-                    out.write_vertical_source_list(&body.fields[start_field..], ListType::Cap(";"));
+                    out.write_vertical_source_list(
+                        config,
+                        &body.fields[start_field..],
+                        ListType::Cap(";"),
+                    );
                     if config.language != Language::Cython {
                         out.close_brace(true);
                     }
+                } else if anonymous {
+                    out.write("struct");
+                    out.open_brace();
+                    out.write_vertical_source_list(config, &body.fields, ListType::Cap(";"));
+                    out.close_brace(false);
+                    write!(out, " {};", name);
                 } else if config.style.generate_typedef() || config.language == Language::Cython {
                     write!(out, "{} {};", body.export_name(), name);
                 } else {
@@ -1087,7 +1266,7 @@ impl Enum {
                     )
                 })
                 .collect();
-            out.write_vertical_source_list(&vec[..], ListType::Join(""));
+            out.write_vertical_source_list(config, &vec[..], ListType::Join(""));
             out.close_brace(false);
             out.new_line();
 
@@ -1097,6 +1276,7 @@ impl Enum {
             if has_data {
                 // For enums with data, this emits the serializer function for
                 // the top-level union or struct.
+                let anonymous = self.anonymous_variant_bodies(config);
                 out.new_line();
                 out.new_line();
                 write!(
@@ -1126,11 +1306,11 @@ impl Enum {
                     .iter()
                     .map(|x| {
                         let tag_str = format!("\"{}\"", x.export_name);
-                        if let VariantBody::Body {
-                            ref name, ref body, ..
-                        } = x.body
-                        {
-                            format!(
+                        match x.body {
+                            // Unnamed structs have no `operator<<` to print them with.
+                            VariantBody::Body {
+                                ref name, ref body, ..
+                            } if !anonymous => format!(
                                 "case {}::{}: {} << {}{}{}.{}; break;",
                                 tag_name,
                                 x.export_name,
@@ -1139,16 +1319,15 @@ impl Enum {
                                 if body.has_tag_field { "" } else { " << " },
                                 instance,
                                 name,
-                            )
-                        } else {
-                            format!(
+                            ),
+                            _ => format!(
                                 "case {}::{}: {} << {}; break;",
                                 tag_name, x.export_name, stream, tag_str,
-                            )
+                            ),
                         }
                     })
                     .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(""));
+                out.write_vertical_source_list(config, &vec[..], ListType::Join(""));
                 out.close_brace(false);
                 out.new_line();
 
@@ -1185,16 +1364,22 @@ impl Enum {
                         .into_owned()
                 };
 
+                // The annotations of the enum apply to the variants without
+                // their own.
                 macro_rules! write_attrs {
                     ($op:expr) => {{
-                        if let Some(Some(attrs)) =
-                            variant
-                                .body
-                                .annotations()
-                                .atom(concat!("variant-", $op, "-attributes"))
+                        let name = concat!("variant-", $op, "-attributes");
+                        if let Some(Some(attrs)) = variant
+                            .body
+                            .annotations()
+                            .atom(name)
+                            .or_else(|| self.annotations.atom(name))
                         {
                             write!(out, "{} ", attrs);
                         }
+                        if let Some(cuda) = config.cuda_qualifier(&self.annotations) {
+                            write!(out, "{} ", cuda);
+                        }
                     }};
                 }
 
@@ -1215,7 +1400,7 @@ impl Enum {
                             )
                         })
                         .collect();
-                    out.write_vertical_source_list(&vec[..], ListType::Join(","));
+                    out.write_vertical_source_list(config, &vec[..], ListType::Join(","));
                 }
 
                 write!(out, ")");
@@ -1305,13 +1490,17 @@ impl Enum {
                             ty: Box::new(return_type),
                             is_const: const_casts,
                             is_ref: true,
+                            owned_box: None,
                             is_nullable: false,
                         };
                         return_type.write(config, out);
-                    } else if const_casts {
-                        write!(out, "const {}&", body.export_name());
                     } else {
-                        write!(out, "{}&", body.export_name());
+                        let body_type = self.variant_body_type(config, member_name, body);
+                        if const_casts {
+                            write!(out, "const {}&", body_type);
+                        } else {
+                            write!(out, "{}&", body_type);
+                        }
                     }
 
                     write!(out, " As{}()", variant.export_name);
@@ -1351,6 +1540,9 @@ impl Enum {
                 if let Some(Some(attrs)) = self.annotations.atom(concat!($op, "-attributes")) {
                     write!(out, "{} ", attrs);
                 }
+                if let Some(cuda) = config.cuda_qualifier(&self.annotations) {
+                    write!(out, "{} ", cuda);
+                }
             }};
         }
 
@@ -1428,6 +1620,9 @@ impl Enum {
             out.new_line();
             write!(out, "private:");
             out.new_line();
+            if let Some(cuda) = config.cuda_qualifier(&self.annotations) {
+                write!(out, "{} ", cuda);
+            }
             write!(out, "{}()", self.export_name);
             out.open_brace();
             out.close_brace(false);
@@ -1436,7 +1631,15 @@ impl Enum {
             out.new_line();
         }
 
-        if config
+        if let Some(destructor) = self.destructor_function() {
+            out.new_line();
+            out.new_line();
+            write_attrs!("destructor");
+            write!(out, "~{}()", self.export_name);
+            out.open_brace();
+            write!(out, "{}(this);", destructor);
+            out.close_brace(false);
+        } else if config
             .enumeration
             .derive_tagged_enum_destructor(&self.annotations)
         {
@@ -1455,14 +1658,26 @@ impl Enum {
                 {
                     let condition = variant.cfg.to_condition(config);
                     condition.write_before(config, out);
-                    write!(
-                        out,
-                        "case {}::{}: {}.~{}(); break;",
-                        self.tag.as_ref().unwrap(),
-                        variant.export_name,
-                        name,
-                        body.export_name(),
-                    );
+                    if self.anonymous_variant_bodies(config) {
+                        // A pseudo-destructor can't be named with `decltype`.
+                        write!(
+                            out,
+                            "case {}::{}: {{ using Body = decltype({}); {}.~Body(); }} break;",
+                            self.tag.as_ref().unwrap(),
+                            variant.export_name,
+                            name,
+                            name,
+                        );
+                    } else {
+                        write!(
+                            out,
+                            "case {}::{}: {}.~{}(); break;",
+                            self.tag.as_ref().unwrap(),
+                            variant.export_name,
+                            name,
+                            body.export_name(),
+                        );
+                    }
                     condition.write_after(config, out);
                     out.new_line();
                 } else {
@@ -1476,9 +1691,11 @@ impl Enum {
             out.close_brace(false);
         }
 
-        if config
-            .enumeration
-            .derive_tagged_enum_copy_constructor(&self.annotations)
+        let clone = self.clone_function();
+        if clone.is_some()
+            || config
+                .enumeration
+                .derive_tagged_enum_copy_constructor(&self.annotations)
         {
             out.new_line();
             out.new_line();
@@ -1488,40 +1705,46 @@ impl Enum {
                 "{}(const {}& {})",
                 self.export_name, self.export_name, other
             );
-            out.new_line();
-            write!(out, " : tag({}.tag)", other);
-            out.open_brace();
-            write!(out, "switch (tag)");
-            out.open_brace();
-            let mut exhaustive = true;
-            for variant in &self.variants {
-                if let VariantBody::Body {
-                    ref name, ref body, ..
-                } = variant.body
-                {
-                    let condition = variant.cfg.to_condition(config);
-                    condition.write_before(config, out);
-                    write!(
-                        out,
-                        "case {}::{}: ::new (&{}) ({})({}.{}); break;",
-                        self.tag.as_ref().unwrap(),
-                        variant.export_name,
-                        name,
-                        body.export_name(),
-                        other,
-                        name,
-                    );
-                    condition.write_after(config, out);
-                    out.new_line();
-                } else {
-                    exhaustive = false;
+            if let Some(ref clone) = clone {
+                out.open_brace();
+                write!(out, "{}(&{}, this);", clone, other);
+                out.close_brace(false);
+            } else {
+                out.new_line();
+                write!(out, " : tag({}.tag)", other);
+                out.open_brace();
+                write!(out, "switch (tag)");
+                out.open_brace();
+                let mut exhaustive = true;
+                for variant in &self.variants {
+                    if let VariantBody::Body {
+                        ref name, ref body, ..
+                    } = variant.body
+                    {
+                        let condition = variant.cfg.to_condition(config);
+                        condition.write_before(config, out);
+                        write!(
+                            out,
+                            "case {}::{}: ::new (&{}) ({})({}.{}); break;",
+                            self.tag.as_ref().unwrap(),
+                            variant.export_name,
+                            name,
+                            self.variant_body_type(config, name, body),
+                            other,
+                            name,
+                        );
+                        condition.write_after(config, out);
+                        out.new_line();
+                    } else {
+                        exhaustive = false;
+                    }
                 }
+                if !exhaustive {
+                    write!(out, "default: break;");
+                }
+                out.close_brace(false);
+                out.close_brace(false);
             }
-            if !exhaustive {
-                write!(out, "default: break;");
-            }
-            out.close_brace(false);
-            out.close_brace(false);
 
             if config
                 .enumeration
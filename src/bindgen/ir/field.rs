@@ -5,10 +5,13 @@ use syn::ext::IdentExt;
 use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::ir::{AnnotationSet, Cfg, ConditionWrite};
-use crate::bindgen::ir::{Documentation, Path, ToCondition, Type};
+use crate::bindgen::ir::{
+    ConstExpr, Documentation, IntKind, Path, PrimitiveType, ToCondition, Type,
+};
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Field {
     pub name: String,
     pub ty: Type,
@@ -31,22 +34,161 @@ impl Field {
     pub fn load(field: &syn::Field, self_path: &Path) -> Result<Option<Field>, String> {
         Ok(if let Some(mut ty) = Type::load(&field.ty)? {
             ty.replace_self_with(self_path);
-            Some(Field {
-                name: field
-                    .ident
-                    .as_ref()
-                    .ok_or_else(|| "field is missing identifier".to_string())?
-                    .unraw()
-                    .to_string(),
-                ty,
-                cfg: Cfg::load(&field.attrs),
-                annotations: AnnotationSet::load(&field.attrs)?,
-                documentation: Documentation::load(&field.attrs),
-            })
+            let name = field
+                .ident
+                .as_ref()
+                .ok_or_else(|| "field is missing identifier".to_string())?
+                .unraw()
+                .to_string();
+            Some(Field::load_with_name(name, ty, &field.attrs)?)
         } else {
             None
         })
     }
+
+    /// Loads the cfg, annotations and documentation of a field, and applies
+    /// its `hide` and `doc` annotations.
+    pub fn load_with_name(
+        name: String,
+        ty: Type,
+        attrs: &[syn::Attribute],
+    ) -> Result<Field, String> {
+        let annotations = AnnotationSet::load(attrs)?;
+
+        let mut documentation = Documentation::load(attrs);
+        if let Some(Some(doc)) = annotations.atom("doc") {
+            documentation
+                .doc_comment
+                .extend(doc.lines().map(|line| format!(" {}", line)));
+        }
+
+        // Hidden fields are replaced by something of the same size and
+        // alignment, so that the layout of the containing type is kept.
+        let ty = if let Some(Some(size)) = annotations.atom("hide") {
+            hidden_padding(&name, &ty, &size, None)?
+        } else if let Some(list) = annotations.list("hide") {
+            match list.as_slice() {
+                [size, align] => hidden_padding(&name, &ty, size, Some(align))?,
+                _ => {
+                    return Err(format!(
+                        "The hide annotation of field {} should be a size, or a list of a \
+                         size and an alignment.",
+                        name
+                    ))
+                }
+            }
+        } else if annotations.bool("hide").unwrap_or(false) {
+            match ty {
+                Type::Ptr {
+                    is_const,
+                    is_nullable,
+                    ..
+                } => Type::Ptr {
+                    ty: Box::new(Type::Primitive(PrimitiveType::Void)),
+                    is_const,
+                    is_nullable,
+                    is_ref: false,
+                    owned_box: None,
+                },
+                _ => {
+                    warn!(
+                        "Can't hide field {} without knowing its size, use cbindgen:hide=SIZE.",
+                        name
+                    );
+                    ty
+                }
+            }
+        } else {
+            ty
+        };
+
+        Ok(Field {
+            name,
+            ty,
+            cfg: Cfg::load(attrs),
+            annotations,
+            documentation,
+        })
+    }
+
+    /// Overrides the name of the field with its `rename` annotation, if any.
+    pub(crate) fn apply_rename_annotation(&mut self) {
+        if let Some(Some(name)) = self.annotations.atom("rename") {
+            self.name = name;
+        }
+    }
+}
+
+/// The array of integers of `size` bytes standing for a hidden field of type
+/// `ty`, with the alignment of `ty`, or the given one.
+fn hidden_padding(
+    name: &str,
+    ty: &Type,
+    size: &str,
+    align: Option<&String>,
+) -> Result<Type, String> {
+    let align = match align {
+        Some(align) => align.parse::<u64>().ok(),
+        None => fixed_alignment(ty),
+    };
+    let align = align.ok_or_else(|| {
+        format!(
+            "Can't hide field {} without knowing its alignment on every target, \
+             use cbindgen:hide=[SIZE, ALIGN].",
+            name
+        )
+    })?;
+    let element = match align {
+        1 => "u8",
+        2 => "u16",
+        4 => "u32",
+        8 => "u64",
+        _ => {
+            return Err(format!(
+                "Can't hide field {} with an alignment of {}, which isn't 1, 2, 4 or 8.",
+                name, align
+            ))
+        }
+    };
+    let len = match size.parse::<u64>() {
+        Ok(size) if size % align == 0 => size / align,
+        _ => {
+            return Err(format!(
+                "Can't hide field {} with a size of {}, which isn't a multiple of its \
+                 alignment of {}.",
+                name, size, align
+            ))
+        }
+    };
+    let element = Type::Primitive(PrimitiveType::maybe(element).unwrap());
+    Ok(Type::Array(
+        Box::new(element),
+        ConstExpr::Value(len.to_string()),
+    ))
+}
+
+/// The alignment of `ty`, if it's the same on every target.
+fn fixed_alignment(ty: &Type) -> Option<u64> {
+    match *ty {
+        Type::Primitive(ref primitive) => match *primitive {
+            PrimitiveType::Bool
+            | PrimitiveType::Char
+            | PrimitiveType::SChar
+            | PrimitiveType::UChar => Some(1),
+            PrimitiveType::Char32 | PrimitiveType::Float => Some(4),
+            PrimitiveType::Integer { kind, .. } => match kind {
+                IntKind::B8 => Some(1),
+                IntKind::B16 | IntKind::Short => Some(2),
+                IntKind::B32 | IntKind::Int => Some(4),
+                // `u64` and `f64` are only aligned to 4 bytes on some 32-bit
+                // targets, like i686 Linux.
+                _ => None,
+            },
+            _ => None,
+        },
+        Type::Array(ref ty, _) => fixed_alignment(ty),
+        _ => None,
+    }
 }
 
 impl Source for Field {
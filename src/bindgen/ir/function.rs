@@ -2,34 +2,96 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io::Write;
 
 use syn::ext::IdentExt;
 
 use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Language, Layout};
+use crate::bindgen::config::{Config, DocumentationStyle, Language, Layout};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::ir::documentation::{documentation_style, markdown_heading, parameter};
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, DeprecatedNoteKind, Documentation, GenericPath, Path,
-    ToCondition, Type,
+    PrimitiveType, SourceLocation, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::reserved;
-use crate::bindgen::utilities::IterHelpers;
+use crate::bindgen::utilities::{IterHelpers, SynAbiHelpers};
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct FunctionArgument {
     pub name: Option<String>,
     pub ty: Type,
     pub array_length: Option<String>,
 }
 
+/// How a function returning `Result<T, E>` is lowered, as given by its
+/// `result` annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResultLowering {
+    /// `E f(..., T *result)`, with `E` having a variant for success.
+    OutParam,
+    /// `{F}Result f(...)`, with `{F}Result` laid out as
+    /// `#[repr(C, u8)] enum {F}Result { Ok(T), Err(E) }`.
+    Tagged,
+}
+
+impl ResultLowering {
+    fn load(annotations: &AnnotationSet) -> Result<Option<Self>, String> {
+        if let Some(lower) = annotations.bool("result") {
+            return Ok(if lower {
+                Some(ResultLowering::OutParam)
+            } else {
+                None
+            });
+        }
+        match annotations.atom("result") {
+            Some(Some(style)) => match style.as_str() {
+                "out-param" => Ok(Some(ResultLowering::OutParam)),
+                "tagged" => Ok(Some(ResultLowering::Tagged)),
+                _ => Err(format!("Unrecognized result lowering: '{}'.", style)),
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// The `T` and `E` of a `Result<T, E>` return type.
+fn result_types(output: &syn::ReturnType) -> Option<(&syn::Type, &syn::Type)> {
+    let path = match *output {
+        syn::ReturnType::Type(_, ref ty) => match **ty {
+            syn::Type::Path(ref path) => &path.path,
+            _ => return None,
+        },
+        syn::ReturnType::Default => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let args = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref args) => &args.args,
+        _ => return None,
+    };
+    let mut types = args.iter().filter_map(|arg| match *arg {
+        syn::GenericArgument::Type(ref ty) => Some(ty),
+        _ => None,
+    });
+    match (types.next(), types.next(), types.next()) {
+        (Some(ok), Some(err), None) => Some((ok, err)),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Function {
     pub path: Path,
     /// Path to the self-type of the function
@@ -41,7 +103,10 @@ pub struct Function {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
     pub never_return: bool,
+    /// Whether the function is `extern "C-unwind"`, and may unwind.
+    pub unwind: bool,
 }
 
 impl Function {
@@ -64,7 +129,7 @@ impl Function {
             ret.replace_self_with(self_path);
         }
 
-        Ok(Function {
+        let mut function = Function {
             path,
             self_type_path: self_type_path.cloned(),
             ret,
@@ -73,8 +138,574 @@ impl Function {
             cfg: Cfg::append(mod_cfg, Cfg::load(attrs)),
             annotations: AnnotationSet::load(attrs)?,
             documentation: Documentation::load(attrs),
+            source_location: None,
             never_return,
+            unwind: sig.abi.is_c_unwind(),
+        };
+        function.lower_result(sig)?;
+        function.apply_nonnull_annotation();
+        function.check_restrict_annotation();
+        Ok(function)
+    }
+
+    /// Rewrites the signature of a function annotated with `result`, which
+    /// returns a `Result<T, E>`, see `ResultLowering`.
+    fn lower_result(&mut self, sig: &syn::Signature) -> Result<(), String> {
+        let lowering = match ResultLowering::load(&self.annotations)? {
+            Some(lowering) => lowering,
+            None => return Ok(()),
+        };
+        let (ok, err) = match result_types(&sig.output) {
+            Some(types) => types,
+            None => return Err("The result annotation needs a `Result<T, E>` return type.".into()),
+        };
+
+        match lowering {
+            ResultLowering::OutParam => {
+                self.ret = Type::load(err)?.unwrap_or(Type::Primitive(PrimitiveType::Void));
+                if let Some(mut ty) = Type::load(ok)? {
+                    if let Some(ref self_path) = self.self_type_path {
+                        ty.replace_self_with(self_path);
+                    }
+                    self.args.push(FunctionArgument {
+                        name: Some("result".to_owned()),
+                        ty: Type::Ptr {
+                            ty: Box::new(ty),
+                            is_const: false,
+                            is_nullable: false,
+                            is_ref: false,
+                            owned_box: None,
+                        },
+                        array_length: None,
+                    });
+                }
+            }
+            ResultLowering::Tagged => {
+                self.ret = Type::Path(GenericPath::new(Path::new(self.result_enum_name()), vec![]));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the signature is rewritten by a `result` annotation, and so
+    /// differs from the one of the Rust function.
+    pub(crate) fn lowers_result(&self) -> bool {
+        matches!(ResultLowering::load(&self.annotations), Ok(Some(_)))
+    }
+
+    /// The name of the enum returned by a function annotated with
+    /// `result=tagged`.
+    fn result_enum_name(&self) -> String {
+        format!(
+            "{}Result",
+            RenameRule::PascalCase.apply(self.path.name(), IdentifierType::Type)
+        )
+    }
+
+    /// The `#[repr(C, u8)] enum {F}Result { Ok(T), Err(E) }` returned by a
+    /// function annotated with `result=tagged`, to be loaded along with it.
+    pub(crate) fn result_enum(
+        &self,
+        sig: &syn::Signature,
+        config: &Config,
+    ) -> Option<syn::ItemEnum> {
+        if ResultLowering::load(&self.annotations) != Ok(Some(ResultLowering::Tagged)) {
+            return None;
+        }
+        let (ok, err) = result_types(&sig.output)?;
+        let name = syn::Ident::new(&self.result_enum_name(), proc_macro2::Span::call_site());
+        let doc = format!(" The result of `{}`.", self.path.name());
+        // Outside of C++, where the tag enum is nested, the variants of all
+        // the results would clash.
+        let prefix = format!(
+            " cbindgen:prefix-with-name={}",
+            config.language != Language::Cxx
+        );
+        let ok = match *ok {
+            syn::Type::Tuple(ref tuple) if tuple.elems.is_empty() => quote::quote! { Ok },
+            _ => quote::quote! { Ok(#ok) },
+        };
+        syn::parse2(quote::quote! {
+            #[doc = #doc]
+            #[doc = #prefix]
+            #[repr(C, u8)]
+            pub enum #name {
+                #ok,
+                Err(#err),
+            }
         })
+        .ok()
+    }
+
+    /// Resolves the arguments listed in an annotation, either by 1-based
+    /// index or by name, to their 0-based index.
+    fn annotated_args(&self, annotation: &str) -> Vec<usize> {
+        let requested = match self.annotations.list(annotation) {
+            Some(list) => list,
+            None => match self.annotations.atom(annotation) {
+                Some(Some(atom)) => atom.split(',').map(|x| x.trim().to_owned()).collect(),
+                _ => return Vec::new(),
+            },
+        };
+
+        requested
+            .iter()
+            .filter_map(|name| {
+                let index = match name.parse::<usize>() {
+                    Ok(index) => index.checked_sub(1).filter(|i| *i < self.args.len()),
+                    Err(..) => self
+                        .args
+                        .iter()
+                        .position(|arg| arg.name.as_deref() == Some(name.as_str())),
+                };
+                if index.is_none() {
+                    warn!(
+                        "Unknown argument {} in the {} annotation of {}.",
+                        name, annotation, self.path
+                    );
+                }
+                index
+            })
+            .collect()
+    }
+
+    /// Marks the pointer arguments listed in a `cbindgen:nonnull` annotation
+    /// as non-nullable.
+    fn apply_nonnull_annotation(&mut self) {
+        for index in self.annotated_args("nonnull") {
+            match self.args[index].ty {
+                Type::Ptr {
+                    ref mut is_nullable,
+                    ..
+                } => *is_nullable = false,
+                _ => warn!(
+                    "Argument {} of {} in the nonnull annotation is not a pointer.",
+                    index + 1,
+                    self.path
+                ),
+            }
+        }
+    }
+
+    fn check_restrict_annotation(&self) {
+        for index in self.annotated_args("restrict") {
+            if !matches!(self.args[index].ty, Type::Ptr { is_ref: false, .. }) {
+                warn!(
+                    "Argument {} of {} in the restrict annotation is not a pointer.",
+                    index + 1,
+                    self.path
+                );
+            }
+        }
+    }
+
+    /// The 0-based indices of the pointer arguments listed in a
+    /// `cbindgen:restrict` annotation, which are declared `restrict`.
+    pub(crate) fn restrict_args(&self) -> Vec<usize> {
+        self.annotated_args("restrict")
+    }
+
+    /// The 0-based indices of the arguments listed in a `cbindgen:consumes`
+    /// annotation, which the function takes ownership of.
+    pub(crate) fn consumed_args(&self) -> Vec<usize> {
+        self.annotated_args("consumes")
+    }
+
+    /// Whether the function is annotated with `returns-ownership`, along with
+    /// the function releasing the returned value, if specified.
+    pub(crate) fn returned_ownership(&self) -> Option<Option<String>> {
+        match self.annotations.bool("returns-ownership") {
+            Some(true) => Some(None),
+            Some(false) => None,
+            None => self.annotations.atom("returns-ownership"),
+        }
+    }
+
+    /// The documentation, as a GTK-Doc comment for the `gtk_doc` style.
+    fn documentation_for_config(&self, config: &Config) -> Cow<'_, Documentation> {
+        let documentation = self.documentation_with_notes(config);
+        if config.language == Language::Cython
+            || documentation_style(config) != DocumentationStyle::GtkDoc
+        {
+            return documentation;
+        }
+        Cow::Owned(self.gtk_doc(&documentation))
+    }
+
+    /// The documentation, extended with a description of the ownership
+    /// annotations if `fn.ownership_comments` is set, and with a note that
+    /// the function may unwind if `fn.unwind_comments` is.
+    fn documentation_with_notes(&self, config: &Config) -> Cow<'_, Documentation> {
+        let mut lines = Vec::new();
+        if self.unwind && config.function.unwind_comments {
+            lines.push(" This function may unwind (`extern \"C-unwind\"`).".to_owned());
+        }
+        if config.function.ownership_comments {
+            if let Some(deallocator) = self.returned_ownership() {
+                lines.push(
+                    " Ownership of the returned value is transferred to the caller.".to_owned(),
+                );
+                if let Some(deallocator) = deallocator {
+                    lines.push(format!(" It must be released with `{}`.", deallocator));
+                }
+            }
+            for index in self.annotated_args("consumes") {
+                let name = match self.args[index].name {
+                    Some(ref name) => format!("`{}`", name),
+                    None => format!("argument {}", index + 1),
+                };
+                lines.push(format!(" Takes ownership of {}.", name));
+            }
+        }
+        if lines.is_empty() {
+            return Cow::Borrowed(&self.documentation);
+        }
+
+        let mut documentation = self.documentation.clone();
+        if !documentation.doc_comment.is_empty() {
+            documentation.doc_comment.push(String::new());
+        }
+        documentation.doc_comment.extend(lines);
+        Cow::Owned(documentation)
+    }
+
+    /// Turns the documentation into a GTK-Doc comment, listing every argument
+    /// and the return value with the GObject-Introspection annotations that
+    /// can be derived from their types and the ownership annotations.
+    ///
+    /// Argument descriptions are taken from rustdoc's `* arg - description`
+    /// lines and the return value's from the `# Returns` section.
+    fn gtk_doc(&self, documentation: &Documentation) -> Documentation {
+        let mut descriptions = HashMap::new();
+        let mut returns = Vec::new();
+        let mut body: Vec<String> = Vec::new();
+        let mut in_returns = false;
+        for line in &documentation.doc_comment {
+            let trimmed = line.trim();
+            if let Some(heading) = markdown_heading(trimmed) {
+                in_returns = heading == "Returns";
+                if matches!(heading, "Arguments" | "Parameters" | "Returns") {
+                    continue;
+                }
+            } else if in_returns {
+                if !trimmed.is_empty() {
+                    returns.push(trimmed);
+                }
+                continue;
+            }
+            if let Some((name, description)) = parameter(trimmed) {
+                if self
+                    .args
+                    .iter()
+                    .any(|arg| arg.name.as_deref() == Some(name))
+                {
+                    descriptions.insert(name, description);
+                    continue;
+                }
+            }
+            if trimmed.is_empty() && body.last().map_or(true, |line| line.trim().is_empty()) {
+                continue;
+            }
+            body.push(line.clone());
+        }
+        while body.last().map_or(false, |line| line.trim().is_empty()) {
+            body.pop();
+        }
+
+        let mut doc_comment = vec![format!(" {}:", self.path.name())];
+        let consumed = self.annotated_args("consumes");
+        for (i, arg) in self.args.iter().enumerate() {
+            let name = match arg.name {
+                Some(ref name) => name,
+                None => continue,
+            };
+            let mut annotations = Vec::new();
+            if let Type::Ptr { is_nullable, .. } = arg.ty {
+                if is_nullable {
+                    annotations.push("(nullable)".to_owned());
+                }
+                if consumed.contains(&i) {
+                    annotations.push("(transfer full)".to_owned());
+                }
+                if let Some(length) = self.array_length_arg(i) {
+                    annotations.push(format!("(array length={})", length));
+                }
+            }
+            doc_comment.push(gtk_doc_tag(
+                &format!("@{}", name),
+                &annotations,
+                descriptions.get(name.as_str()).copied().unwrap_or(""),
+            ));
+        }
+        if !body.is_empty() {
+            doc_comment.push(String::new());
+            doc_comment.extend(body);
+        }
+
+        if self.ret != Type::Primitive(PrimitiveType::Void) {
+            let mut annotations = Vec::new();
+            if let Type::Ptr { is_nullable, .. } = self.ret {
+                match self.returned_ownership() {
+                    Some(..) => annotations.push("(transfer full)".to_owned()),
+                    None => annotations.push("(transfer none)".to_owned()),
+                }
+                if is_nullable {
+                    annotations.push("(nullable)".to_owned());
+                }
+            }
+            if !annotations.is_empty() || !returns.is_empty() {
+                doc_comment.push(String::new());
+                doc_comment.push(gtk_doc_tag("Returns", &annotations, &returns.join(" ")));
+            }
+        }
+
+        Documentation { doc_comment }
+    }
+
+    /// The name of the argument holding the length of the array the pointer
+    /// argument `index` points to: the one given by `ptrs-as-arrays`, or an
+    /// integer argument right after it called `len`, `length`, `count`,
+    /// `size`, `<name>_len` or `n_<name>`.
+    fn array_length_arg(&self, index: usize) -> Option<&str> {
+        let arg = &self.args[index];
+        if let Some(ref length) = arg.array_length {
+            return self
+                .args
+                .iter()
+                .filter_map(|arg| arg.name.as_deref())
+                .find(|name| name == length);
+        }
+
+        let name = arg.name.as_deref()?;
+        let next = self.args.get(index + 1)?;
+        let next_name = next.name.as_deref()?;
+        let is_integer = matches!(next.ty, Type::Primitive(PrimitiveType::Integer { .. }));
+        let is_length = matches!(next_name, "len" | "length" | "count" | "size")
+            || next_name == format!("{}_len", name)
+            || next_name == format!("n_{}", name);
+        if is_integer && is_length {
+            Some(next_name)
+        } else {
+            None
+        }
+    }
+
+    fn write_ownership_attributes<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.extern_decl || config.language == Language::Cython {
+            return;
+        }
+        if let Some(ref attr) = config.function.returns_ownership_attribute {
+            match self.returned_ownership() {
+                Some(Some(deallocator)) => write!(out, " {}", attr.replace("{}", &deallocator)),
+                Some(None) if !attr.contains("{}") => write!(out, " {}", attr),
+                _ => {}
+            }
+        }
+        if let Some(ref attr) = config.function.consumes_attribute {
+            for index in self.annotated_args("consumes") {
+                write!(out, " {}", attr.replace("{}", &(index + 1).to_string()));
+            }
+        }
+    }
+
+    /// The 1-based indices of the arguments which are non-nullable pointers.
+    fn non_null_arg_indices(&self) -> Vec<String> {
+        self.args
+            .iter()
+            .enumerate()
+            .filter(|(_, arg)| {
+                matches!(
+                    arg.ty,
+                    Type::Ptr {
+                        is_nullable: false,
+                        is_ref: false,
+                        ..
+                    }
+                )
+            })
+            .map(|(i, _)| (i + 1).to_string())
+            .collect()
+    }
+
+    fn write_non_null_args_attribute<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.extern_decl || config.language == Language::Cython {
+            return;
+        }
+        if let Some(ref attr) = config.function.non_null_args_attribute {
+            let indices = self.non_null_arg_indices();
+            if !indices.is_empty() {
+                write!(out, " {}", attr.replace("{}", &indices.join(", ")));
+            }
+        }
+    }
+
+    /// The alternate names of the function, from the `alias` annotation.
+    pub(crate) fn aliases(&self) -> Vec<String> {
+        match self.annotations.list("alias") {
+            Some(list) => list,
+            None => match self.annotations.atom("alias") {
+                Some(Some(atom)) => atom.split(',').map(|x| x.trim().to_owned()).collect(),
+                _ => Vec::new(),
+            },
+        }
+    }
+
+    fn is_weak(&self, config: &Config) -> bool {
+        config.language != Language::Cython && self.annotations.bool("weak").unwrap_or(false)
+    }
+
+    /// Writes `fn.weak_attribute` for functions annotated with `weak`.
+    fn write_weak_attribute<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if let Some(ref attr) = config.function.weak_attribute {
+            if self.is_weak(config) {
+                write!(out, " {}", attr);
+            }
+        }
+    }
+
+    /// Writes a `#pragma weak` after the declaration of functions annotated
+    /// with `weak`, when there is no `fn.weak_attribute`.
+    fn write_weak_pragma<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if config.function.weak_attribute.is_none() && self.is_weak(config) {
+            out.new_line();
+            write!(out, "#pragma weak {}", self.path.name());
+        }
+    }
+
+    pub(crate) fn uses_gnu_attributes(&self, config: &Config) -> bool {
+        !self.extern_decl
+            && config.language != Language::Cython
+            && config.function.gnu_attributes(&self.annotations)
+    }
+
+    /// The GCC `access` attributes of the pointer arguments, as the access
+    /// mode, the 1-based index of the argument and the one of its length.
+    fn gnu_access_args(&self) -> Vec<(&'static str, usize, Option<usize>)> {
+        let out_args = self.annotated_args("sal-out");
+        self.args
+            .iter()
+            .enumerate()
+            .filter_map(|(index, arg)| {
+                let (ty, is_const) = match arg.ty {
+                    Type::Ptr {
+                        ref ty,
+                        is_const,
+                        is_ref: false,
+                        ..
+                    } => (ty, is_const),
+                    _ => return None,
+                };
+                let length = self.array_length_arg(index).and_then(|length| {
+                    self.args
+                        .iter()
+                        .position(|arg| arg.name.as_deref() == Some(length))
+                });
+                // The size of the pointee of `void *` is only known with a length.
+                if length.is_none() && **ty == Type::Primitive(PrimitiveType::Void) {
+                    return None;
+                }
+                let mode = if out_args.contains(&index) {
+                    "write_only"
+                } else if is_const {
+                    "read_only"
+                } else {
+                    "read_write"
+                };
+                Some((mode, index + 1, length.map(|length| length + 1)))
+            })
+            .collect()
+    }
+
+    /// Writes the attributes of GCC and Clang which can be derived from the
+    /// signature and the annotations, with the macros `Bindings` defines.
+    fn write_gnu_attributes<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if !self.uses_gnu_attributes(config) {
+            return;
+        }
+        if self.annotations.must_use(config) && self.ret != Type::Primitive(PrimitiveType::Void) {
+            out.write(" CBINDGEN_WARN_UNUSED_RESULT");
+        }
+        if let Type::Ptr { is_ref: false, .. } = self.ret {
+            if self.returned_ownership().is_some() {
+                out.write(" CBINDGEN_MALLOC");
+            }
+            let alloc_size = self.annotated_args("alloc-size");
+            if !alloc_size.is_empty() {
+                let indices: Vec<String> = alloc_size.iter().map(|i| (i + 1).to_string()).collect();
+                write!(out, " CBINDGEN_ALLOC_SIZE({})", indices.join(", "));
+            }
+        }
+        let non_null = self.non_null_arg_indices();
+        if !non_null.is_empty() {
+            write!(out, " CBINDGEN_NONNULL({})", non_null.join(", "));
+        }
+        for (mode, index, length) in self.gnu_access_args() {
+            match length {
+                Some(length) => write!(out, " CBINDGEN_ACCESS({}, {}, {})", mode, index, length),
+                None => write!(out, " CBINDGEN_ACCESS({}, {})", mode, index),
+            }
+        }
+    }
+
+    fn uses_sal_annotations(&self, config: &Config) -> bool {
+        config.language != Language::Cython && config.function.sal_annotations(&self.annotations)
+    }
+
+    /// The SAL annotations of the arguments, derived from the constness and
+    /// nullability of the pointers, the length of the arrays they point to,
+    /// and the `sal-out` annotation for the ones only written to.
+    pub(crate) fn sal_arg_annotations(&self, config: &Config) -> Vec<Option<String>> {
+        if !self.uses_sal_annotations(config) {
+            return vec![None; self.args.len()];
+        }
+        let out_args = self.annotated_args("sal-out");
+        self.args
+            .iter()
+            .enumerate()
+            .map(|(index, arg)| {
+                let (is_const, is_nullable) = match arg.ty {
+                    Type::Ptr {
+                        is_const,
+                        is_nullable,
+                        is_ref: false,
+                        ..
+                    } => (is_const, is_nullable),
+                    _ => return None,
+                };
+                let (access, buffer_access) = if out_args.contains(&index) {
+                    ("_Out", "_Out_writes")
+                } else if is_const {
+                    ("_In", "_In_reads")
+                } else {
+                    ("_Inout", "_Inout_updates")
+                };
+                let opt = if is_nullable { "_opt" } else { "" };
+                Some(match self.array_length_arg(index) {
+                    Some(length) => format!("{}{}_({})", buffer_access, opt, length),
+                    None => format!("{}{}_", access, opt),
+                })
+            })
+            .collect()
+    }
+
+    /// The SAL annotation of the returned pointer, if any.
+    pub(crate) fn sal_return_annotation(&self, config: &Config) -> Option<&'static str> {
+        if !self.uses_sal_annotations(config) {
+            return None;
+        }
+        match self.ret {
+            Type::Ptr {
+                is_nullable: true,
+                is_ref: false,
+                ..
+            } => Some("_Ret_maybenull_"),
+            Type::Ptr {
+                is_nullable: false,
+                is_ref: false,
+                ..
+            } => Some("_Ret_notnull_"),
+            _ => None,
+        }
     }
 
     pub fn swift_name(&self, config: &Config) -> Option<String> {
@@ -220,6 +851,21 @@ impl Function {
     }
 }
 
+/// Formats a GTK-Doc `@arg:` or `Returns:` line.
+fn gtk_doc_tag(tag: &str, annotations: &[String], description: &str) -> String {
+    let mut line = format!(" {}:", tag);
+    if !annotations.is_empty() {
+        line.push(' ');
+        line.push_str(&annotations.join(" "));
+        line.push(':');
+    }
+    if !description.is_empty() {
+        line.push(' ');
+        line.push_str(description);
+    }
+    line
+}
+
 impl Source for Function {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         fn write_1<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
@@ -229,18 +875,25 @@ impl Source for Function {
             let condition = func.cfg.to_condition(config);
             condition.write_before(config, out);
 
-            func.documentation.write(config, out);
+            func.documentation_for_config(config).write(config, out);
 
-            if func.extern_decl {
+            if let Some(extern_c) = config.extern_c_item() {
+                write!(out, "{} ", extern_c);
+            } else if func.extern_decl {
                 out.write("extern ");
-            } else {
+            }
+            if !func.extern_decl {
+                if let Some(cuda) = config.cuda_qualifier(&func.annotations) {
+                    write!(out, "{} ", cuda);
+                }
                 if let Some(ref prefix) = prefix {
                     write!(out, "{} ", prefix);
                 }
-                if func.annotations.must_use(config) {
-                    if let Some(ref anno) = config.function.must_use {
-                        write!(out, "{} ", anno);
-                    }
+                if let Some(anno) = func
+                    .annotations
+                    .must_use_attribute(config, DeprecatedNoteKind::Function)
+                {
+                    write!(out, "{} ", anno);
                 }
                 if let Some(note) = func
                     .annotations
@@ -249,7 +902,13 @@ impl Source for Function {
                     write!(out, "{} ", note);
                 }
             }
+            if let Some(sal) = func.sal_return_annotation(config) {
+                write!(out, "{} ", sal);
+            }
             cdecl::write_func(out, func, Layout::Horizontal, config);
+            if config.language == Language::Cython && config.cython.nogil(&func.annotations) {
+                out.write(" nogil");
+            }
 
             if !func.extern_decl {
                 if let Some(ref postfix) = postfix {
@@ -257,6 +916,11 @@ impl Source for Function {
                 }
             }
 
+            func.write_non_null_args_attribute(config, out);
+            func.write_ownership_attributes(config, out);
+            func.write_gnu_attributes(config, out);
+            func.write_weak_attribute(config, out);
+
             if let Some(ref swift_name_macro) = config.function.swift_name_macro {
                 if let Some(swift_name) = func.swift_name(config) {
                     write!(out, " {}({})", swift_name_macro, swift_name);
@@ -264,6 +928,7 @@ impl Source for Function {
             }
 
             out.write(";");
+            func.write_weak_pragma(config, out);
 
             condition.write_after(config, out);
         }
@@ -276,20 +941,28 @@ impl Source for Function {
 
             condition.write_before(config, out);
 
-            func.documentation.write(config, out);
+            func.documentation_for_config(config).write(config, out);
 
-            if func.extern_decl {
+            if let Some(extern_c) = config.extern_c_item() {
+                write!(out, "{} ", extern_c);
+            } else if func.extern_decl {
                 out.write("extern ");
-            } else {
+            }
+            if !func.extern_decl {
+                if let Some(cuda) = config.cuda_qualifier(&func.annotations) {
+                    write!(out, "{}", cuda);
+                    out.new_line();
+                }
                 if let Some(ref prefix) = prefix {
                     write!(out, "{}", prefix);
                     out.new_line();
                 }
-                if func.annotations.must_use(config) {
-                    if let Some(ref anno) = config.function.must_use {
-                        write!(out, "{}", anno);
-                        out.new_line();
-                    }
+                if let Some(anno) = func
+                    .annotations
+                    .must_use_attribute(config, DeprecatedNoteKind::Function)
+                {
+                    write!(out, "{}", anno);
+                    out.new_line();
                 }
                 if let Some(note) = func
                     .annotations
@@ -299,7 +972,14 @@ impl Source for Function {
                     out.new_line();
                 }
             }
+            if let Some(sal) = func.sal_return_annotation(config) {
+                write!(out, "{}", sal);
+                out.new_line();
+            }
             cdecl::write_func(out, func, Layout::Vertical, config);
+            if config.language == Language::Cython && config.cython.nogil(&func.annotations) {
+                out.write(" nogil");
+            }
             if !func.extern_decl {
                 if let Some(ref postfix) = postfix {
                     out.new_line();
@@ -307,6 +987,11 @@ impl Source for Function {
                 }
             }
 
+            func.write_non_null_args_attribute(config, out);
+            func.write_ownership_attributes(config, out);
+            func.write_gnu_attributes(config, out);
+            func.write_weak_attribute(config, out);
+
             if let Some(ref swift_name_macro) = config.function.swift_name_macro {
                 if let Some(swift_name) = func.swift_name(config) {
                     write!(out, " {}({})", swift_name_macro, swift_name);
@@ -314,6 +999,7 @@ impl Source for Function {
             }
 
             out.write(";");
+            func.write_weak_pragma(config, out);
 
             condition.write_after(config, out);
         }
@@ -346,6 +1032,7 @@ fn gen_self_type(receiver: &syn::Receiver) -> Type {
         is_const,
         is_nullable: false,
         is_ref: false,
+        owned_box: None,
     }
 }
 
@@ -11,12 +11,14 @@ use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum GenericParamType {
     Type,
     Const(Type),
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct GenericParam {
     name: Path,
     ty: GenericParamType,
@@ -60,6 +62,7 @@ impl GenericParam {
 }
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct GenericParams(pub Vec<GenericParam>);
 
 impl GenericParams {
@@ -80,7 +83,7 @@ impl GenericParams {
         item_name: &str,
         arguments: &'out [GenericArgument],
     ) -> Vec<(&'out Path, &'out GenericArgument)> {
-        assert!(self.len() > 0, "{} is not generic", item_name);
+        assert!(!self.is_empty(), "{} is not generic", item_name);
         assert!(
             self.len() == arguments.len(),
             "{} has {} params but is being instantiated with {} values",
@@ -151,6 +154,7 @@ impl Source for GenericParams {
 /// `GenericArgument::Type`s, even if `N` is actually the name of a const. This
 /// is a consequence of `syn::GenericArgument` doing the same thing.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum GenericArgument {
     Type(Type),
     Const(ConstExpr),
@@ -195,6 +199,7 @@ impl Source for GenericArgument {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct GenericPath {
     path: Path,
     export_name: String,
@@ -5,14 +5,17 @@
 use std::io::Write;
 
 use crate::bindgen::cdecl;
-use crate::bindgen::config::Config;
+use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
-use crate::bindgen::ir::{AnnotationSet, Cfg, Documentation, Item, ItemContainer, Path, Type};
+use crate::bindgen::ir::{
+    AnnotationSet, Cfg, Documentation, Item, ItemContainer, Path, SourceLocation, Type,
+};
 use crate::bindgen::library::Library;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Static {
     pub path: Path,
     pub export_name: String,
@@ -20,7 +23,9 @@ pub struct Static {
     pub mutable: bool,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
+    #[allow(dead_code)]
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
 }
 
 impl Static {
@@ -62,12 +67,37 @@ impl Static {
             cfg,
             annotations,
             documentation,
+            source_location: None,
         }
     }
 
     pub fn simplify_standard_types(&mut self, config: &Config) {
         self.ty.simplify_standard_types(config);
     }
+
+    /// The length written as `{name}_LEN` after the declaration: the one given
+    /// by the `len` annotation, or with `const.array_length_defines` the
+    /// length of the array the static is, or points to.
+    fn length(&self, config: &Config) -> Option<String> {
+        if let Some(Some(len)) = self.annotations.atom("len") {
+            return Some(len);
+        }
+        let enabled = self
+            .annotations
+            .bool("len")
+            .unwrap_or(config.constant.array_length_defines);
+        if !enabled {
+            return None;
+        }
+        match self.ty {
+            Type::Array(_, ref len) => Some(len.as_str().to_owned()),
+            Type::Ptr { ref ty, .. } => match **ty {
+                Type::Array(_, ref len) => Some(len.as_str().to_owned()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 impl Item for Static {
@@ -91,6 +121,10 @@ impl Item for Static {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::Static(self.clone())
     }
@@ -110,12 +144,21 @@ impl Item for Static {
 
 impl Source for Static {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        out.write("extern ");
+        write!(out, "{} ", config.extern_c_item().unwrap_or("extern"));
         if let Type::Ptr { is_const: true, .. } = self.ty {
         } else if !self.mutable {
             out.write("const ");
         }
         cdecl::write_field(out, &self.ty, &self.export_name, config);
         out.write(";");
+
+        if let Some(len) = self.length(config) {
+            out.new_line();
+            if config.language == Language::Cython {
+                write!(out, "const size_t {}_LEN # = {}", self.export_name, len);
+            } else {
+                write!(out, "#define {}_LEN {}", self.export_name, len);
+            }
+        }
     }
 }
@@ -9,8 +9,8 @@ use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Enum, GenericArgument, OpaqueItem, Path, Static, Struct, Typedef,
-    Union,
+    AnnotationSet, Cfg, Constant, Enum, GenericArgument, OpaqueItem, Path, SourceLocation, Static,
+    Struct, Typedef, Union,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
@@ -27,6 +27,7 @@ pub trait Item {
     fn cfg(&self) -> Option<&Cfg>;
     fn annotations(&self) -> &AnnotationSet;
     fn annotations_mut(&mut self) -> &mut AnnotationSet;
+    fn source_location(&self) -> Option<&SourceLocation>;
 
     fn container(&self) -> ItemContainer;
 
@@ -49,6 +50,7 @@ pub trait Item {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum ItemContainer {
     Constant(Constant),
     Static(Static),
@@ -60,6 +62,7 @@ pub enum ItemContainer {
 }
 
 impl ItemContainer {
+    #[allow(clippy::should_implement_trait)]
     pub fn deref(&self) -> &dyn Item {
         match *self {
             ItemContainer::Constant(ref x) => x,
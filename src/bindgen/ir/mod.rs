@@ -15,6 +15,7 @@ pub mod item;
 pub mod opaque;
 pub mod path;
 pub mod repr;
+pub mod source_location;
 pub mod structure;
 pub mod ty;
 pub mod typedef;
@@ -33,6 +34,7 @@ pub use self::item::*;
 pub use self::opaque::*;
 pub use self::path::*;
 pub use self::repr::*;
+pub use self::source_location::SourceLocation;
 pub use self::structure::*;
 pub use self::ty::*;
 pub use self::typedef::*;
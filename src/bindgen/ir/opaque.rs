@@ -4,12 +4,13 @@
 
 use std::io::Write;
 
+use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, GenericArgument, GenericParams, Item,
-    ItemContainer, Path, ToCondition,
+    ItemContainer, Path, SourceLocation, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -17,6 +18,7 @@ use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct OpaqueItem {
     pub path: Path,
     pub export_name: String,
@@ -24,6 +26,7 @@ pub struct OpaqueItem {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
 }
 
 impl OpaqueItem {
@@ -57,6 +60,7 @@ impl OpaqueItem {
             cfg,
             annotations,
             documentation,
+            source_location: None,
         }
     }
 }
@@ -82,6 +86,10 @@ impl Item for OpaqueItem {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::OpaqueItem(self.clone())
     }
@@ -137,15 +145,57 @@ impl Item for OpaqueItem {
     }
 }
 
+impl OpaqueItem {
+    /// Writes the opaque type as a struct with the size and alignment of the
+    /// Rust type, for `layout.sized_opaque`.
+    fn write_sized<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>, storage: &Type) {
+        match config.language {
+            Language::C if config.style.generate_typedef() => out.write("typedef struct"),
+            Language::C | Language::Cxx => out.write("struct"),
+            Language::Cython => write!(out, "{}struct", config.style.cython_def()),
+        }
+        let align = out.bindings().layouts.get(self.path.name()).unwrap().align;
+        if align > 8 && config.language != Language::Cython {
+            if let Some(ref anno) = config.layout.aligned_n {
+                write!(out, " {}({})", anno, align);
+            }
+        }
+        write!(out, " {}", self.export_name());
+        out.open_brace();
+        cdecl::write_field(out, storage, "_opaque", config);
+        match config.language {
+            Language::C if config.style.generate_typedef() => {
+                out.write(";");
+                out.close_brace(false);
+                write!(out, " {};", self.export_name());
+            }
+            Language::C | Language::Cxx => {
+                out.write(";");
+                out.close_brace(true);
+            }
+            Language::Cython => out.close_brace(false),
+        }
+    }
+}
+
 impl Source for OpaqueItem {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
-        self.documentation.write(config, out);
+        self.documentation
+            .for_symbol(config, self.export_name())
+            .write(config, out);
 
         self.generic_params.write_with_default(config, out);
 
+        let storage = out.bindings().layouts.opaque_storage(self, config);
+        if let Some(storage) = storage {
+            self.write_sized(config, out, &storage);
+            condition.write_after(config, out);
+            return;
+        }
+
         match config.language {
             Language::C if config.style.generate_typedef() => {
                 write!(
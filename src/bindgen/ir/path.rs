@@ -6,6 +6,7 @@ use std::cmp::Ordering;
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize), serde(transparent))]
 pub struct Path {
     name: String,
 }
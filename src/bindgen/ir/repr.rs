@@ -7,6 +7,7 @@ use syn::ext::IdentExt;
 use crate::bindgen::ir::ty::{IntKind, PrimitiveType};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum ReprStyle {
     #[default]
     Rust,
@@ -15,6 +16,7 @@ pub enum ReprStyle {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct ReprType {
     kind: IntKind,
     signed: bool,
@@ -28,15 +30,32 @@ impl ReprType {
             zeroable: true,
         }
     }
+
+    /// The smallest and largest values of the type.
+    pub(crate) fn range(self) -> (i128, i128) {
+        let bits = match self.kind {
+            IntKind::B8 => 8,
+            IntKind::Short | IntKind::B16 => 16,
+            IntKind::Int | IntKind::B32 => 32,
+            IntKind::Long | IntKind::LongLong | IntKind::SizeT | IntKind::Size | IntKind::B64 => 64,
+        };
+        if self.signed {
+            (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+        } else {
+            (0, (1 << bits) - 1)
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum ReprAlign {
     Packed,
     Align(u64),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Repr {
     pub style: ReprStyle,
     pub ty: Option<ReprType>,
@@ -0,0 +1,25 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::fmt;
+
+/// Where an item was declared in the Rust sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
+pub struct SourceLocation {
+    /// The path of the source file, relative to the crate being parsed when
+    /// the file is part of it.
+    pub file: String,
+    /// The 1-based line of the item's name.
+    pub line: usize,
+    /// The path of the module the item was declared in, relative to the root
+    /// of its crate, or `None` for the crate root.
+    pub module: Option<String>,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
@@ -6,13 +6,15 @@ use std::io::Write;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::config::{Config, Language, LayoutConfig};
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{Config, Language, LayoutConfig, StructInitializer};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Constant, DeprecatedNoteKind, Documentation, Field,
-    GenericArgument, GenericParams, Item, ItemContainer, Path, Repr, ReprAlign, ReprStyle,
-    ToCondition, Type, Typedef,
+    AnnotationSet, CallingConvention, Cfg, ConditionWrite, Constant, DeprecatedNoteKind,
+    Documentation, Field, Function, GenericArgument, GenericParams, GenericPath, IntKind, Item,
+    ItemContainer, Path, PrimitiveType, Repr, ReprAlign, ReprStyle, SourceLocation, ToCondition,
+    Type, Typedef,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -23,6 +25,7 @@ use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Struct {
     pub path: Path,
     pub export_name: String,
@@ -38,10 +41,133 @@ pub struct Struct {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
     pub associated_constants: Vec<Constant>,
+    /// Set on the vtable of a `cbindgen:com` interface.
+    pub com_interface: Option<ComInterface>,
+}
+
+/// The COM interface a vtable struct belongs to.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
+pub struct ComInterface {
+    /// The name of the interface, prefixing its call macros.
+    pub name: String,
+    pub iid: Option<Guid>,
+}
+
+/// A GUID in the `{ Data1, Data2, Data3, Data4 }` layout used by COM.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
+pub struct Guid {
+    pub data1: u32,
+    pub data2: u16,
+    pub data3: u16,
+    pub data4: [u8; 8],
+}
+
+impl Guid {
+    /// Parses the `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` form, braces optional.
+    pub fn parse(s: &str) -> Result<Guid, String> {
+        let groups: Vec<&str> = s
+            .trim()
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .split('-')
+            .collect();
+        let lengths: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+        let hex = groups.concat();
+        if lengths != [8, 4, 4, 4, 12] || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("{:?} is not a valid GUID.", s));
+        }
+        let bytes: Vec<u8> = (0..16)
+            .map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap())
+            .collect();
+        let mut data4 = [0; 8];
+        data4.copy_from_slice(&bytes[8..]);
+        Ok(Guid {
+            data1: u32::from_str_radix(&hex[0..8], 16).unwrap(),
+            data2: u16::from_str_radix(&hex[8..12], 16).unwrap(),
+            data3: u16::from_str_radix(&hex[12..16], 16).unwrap(),
+            data4,
+        })
+    }
+}
+
+/// The C++ operator of the Rust operator trait whose method is `name`, and
+/// whether it's unary.
+pub fn cxx_operator(name: &str) -> Option<(&'static str, bool)> {
+    Some(match name {
+        "add" => ("+", false),
+        "sub" => ("-", false),
+        "mul" => ("*", false),
+        "div" => ("/", false),
+        "rem" => ("%", false),
+        "bitand" => ("&", false),
+        "bitor" => ("|", false),
+        "bitxor" => ("^", false),
+        "neg" => ("-", true),
+        "not" => ("~", true),
+        _ => return None,
+    })
 }
 
 impl Struct {
+    /// The C++ operators to generate for this struct, by the name of the
+    /// method of their trait: those listed by `derive-ops`, or those of the
+    /// operator traits it implements with `derive_ops`.
+    pub fn derived_operators(&self, config: &Config) -> Vec<String> {
+        if config.language != Language::Cxx
+            || self.is_transparent
+            || self.is_enum_variant_body
+            || self
+                .annotations
+                .bool("internal-derive-bitflags")
+                .unwrap_or(false)
+        {
+            return Vec::new();
+        }
+        let ops = match self.annotations.list("derive-ops") {
+            Some(ops) => ops,
+            None if config.structure.derive_ops(&self.annotations) => self
+                .annotations
+                .list("internal-op-traits")
+                .unwrap_or_default(),
+            None => return Vec::new(),
+        };
+        ops.into_iter()
+            .filter(|op| {
+                if cxx_operator(op).is_none() {
+                    warn!("Unknown operator {} for {}.", op, self.export_name());
+                    return false;
+                }
+                true
+            })
+            .collect()
+    }
+
+    /// The function implementing the operator `op`, from the `{op}-function`
+    /// annotation, which the operator forwards to instead of being
+    /// implemented memberwise.
+    pub fn operator_function(&self, op: &str) -> Option<String> {
+        match self.annotations.atom(&format!("{}-function", op)) {
+            Some(Some(function)) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Whether the operator `op` can be applied to each field.
+    fn can_derive_memberwise(&self, op: &str) -> bool {
+        // C++ only has these for integers, `%` included.
+        let is_integer_only = matches!(op, "rem" | "bitand" | "bitor" | "bitxor" | "not");
+        !self.fields.is_empty()
+            && self.fields.iter().all(|field| match field.ty {
+                Type::Primitive(PrimitiveType::Integer { .. }) => true,
+                Type::Primitive(PrimitiveType::Float | PrimitiveType::Double) => !is_integer_only,
+                _ => false,
+            })
+    }
+
     /// Whether this struct can derive operator== / operator!=.
     pub fn can_derive_eq(&self) -> bool {
         !self.fields.is_empty() && self.fields.iter().all(|x| x.ty.can_cmp_eq())
@@ -56,6 +182,11 @@ impl Struct {
         item: &syn::ItemStruct,
         mod_cfg: Option<&Cfg>,
     ) -> Result<Self, String> {
+        let annotations = AnnotationSet::load(&item.attrs)?;
+        if annotations.bool("opaque").unwrap_or(false) {
+            return Err("Struct is annotated as opaque.".to_owned());
+        }
+
         let repr = Repr::load(&item.attrs)?;
         let is_transparent = match repr.style {
             ReprStyle::C => false,
@@ -84,13 +215,11 @@ impl Struct {
                 for field in fields.unnamed.iter() {
                     if let Some(mut ty) = Type::load(&field.ty)? {
                         ty.replace_self_with(&path);
-                        out.push(Field {
-                            name: format!("{}", current),
+                        out.push(Field::load_with_name(
+                            format!("{}", current),
                             ty,
-                            cfg: Cfg::load(&field.attrs),
-                            annotations: AnnotationSet::load(&field.attrs)?,
-                            documentation: Documentation::load(&field.attrs),
-                        });
+                            &field.attrs,
+                        )?);
                         current += 1;
                     }
                 }
@@ -110,11 +239,367 @@ impl Struct {
             repr.align,
             is_transparent,
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
-            AnnotationSet::load(&item.attrs)?,
+            annotations,
             Documentation::load(&item.attrs),
         ))
     }
 
+    /// Loads a trait annotated with `cbindgen:vtable` as a pair of structs:
+    /// `{Trait}VTable`, holding a function pointer per method, and
+    /// `Dyn{Trait}`, the `{ data, vtable }` fat pointer to a trait object.
+    ///
+    /// A trait annotated with `cbindgen:com` instead becomes a COM interface:
+    /// `{Trait}Vtbl`, starting with the `IUnknown` methods, and `{Trait}`,
+    /// holding just the `lpVtbl` pointer.
+    pub fn load_vtable(
+        item: &syn::ItemTrait,
+        mod_cfg: Option<&Cfg>,
+    ) -> Result<(Struct, Struct), String> {
+        if !item.generics.params.is_empty() {
+            return Err("Generic traits are not supported as vtables.".to_owned());
+        }
+
+        let annotations = AnnotationSet::load(&item.attrs)?;
+        let cfg = Cfg::append(mod_cfg, Cfg::load(&item.attrs));
+        let trait_name = item.ident.unraw().to_string();
+        let is_com = annotations.bool("com").unwrap_or(false);
+        let (vtable_path, object_path, self_name) = if is_com {
+            (
+                Path::new(format!("{}Vtbl", trait_name)),
+                Path::new(trait_name.clone()),
+                "This",
+            )
+        } else {
+            (
+                Path::new(format!("{}VTable", trait_name)),
+                Path::new(format!("Dyn{}", trait_name)),
+                "self",
+            )
+        };
+
+        let void_ptr = |is_const| Type::Ptr {
+            ty: Box::new(Type::Primitive(PrimitiveType::Void)),
+            is_const,
+            is_nullable: false,
+            is_ref: false,
+            owned_box: None,
+        };
+        let method_ptr = |ret, args: Vec<(&str, Type)>| Type::FuncPtr {
+            ret: Box::new(ret),
+            args: args
+                .into_iter()
+                .map(|(name, ty)| (Some(name.to_owned()), ty))
+                .collect(),
+            is_nullable: false,
+            never_return: false,
+            calling_convention: CallingConvention::C,
+        };
+
+        let mut methods = Vec::new();
+        if is_com {
+            let hresult = Type::Primitive(PrimitiveType::Integer {
+                zeroable: true,
+                signed: true,
+                kind: IntKind::B32,
+            });
+            let ulong = Type::Primitive(PrimitiveType::Integer {
+                zeroable: true,
+                signed: false,
+                kind: IntKind::B32,
+            });
+            let object_out = Type::Ptr {
+                ty: Box::new(void_ptr(false)),
+                is_const: false,
+                is_nullable: false,
+                is_ref: false,
+                owned_box: None,
+            };
+            methods.push(Field::from_name_and_type(
+                "QueryInterface".to_owned(),
+                method_ptr(
+                    hresult,
+                    vec![
+                        (self_name, void_ptr(false)),
+                        ("riid", void_ptr(true)),
+                        ("object", object_out),
+                    ],
+                ),
+            ));
+            for name in &["AddRef", "Release"] {
+                methods.push(Field::from_name_and_type(
+                    name.to_string(),
+                    method_ptr(ulong.clone(), vec![(self_name, void_ptr(false))]),
+                ));
+            }
+        }
+
+        for trait_item in &item.items {
+            let method = match trait_item {
+                syn::TraitItem::Method(ref method) => method,
+                _ => continue,
+            };
+            let name = method.sig.ident.unraw().to_string();
+            if !method.sig.generics.params.is_empty() {
+                return Err(format!("Method {} is generic.", name));
+            }
+            let is_const = match method.sig.receiver() {
+                Some(syn::FnArg::Receiver(ref receiver)) if receiver.reference.is_some() => {
+                    // COM methods always take a mutable `This`.
+                    !is_com && receiver.mutability.is_none()
+                }
+                _ => {
+                    return Err(format!(
+                        "Method {} doesn't take `&self` or `&mut self`.",
+                        name
+                    ))
+                }
+            };
+
+            let function = Function::load(
+                Path::new(name.clone()),
+                None,
+                &method.sig,
+                false,
+                &method.attrs,
+                None,
+            )?;
+            let args = function
+                .args
+                .into_iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    if i == 0 {
+                        (Some(self_name.to_owned()), void_ptr(is_const))
+                    } else {
+                        (arg.name, arg.ty)
+                    }
+                })
+                .collect();
+            let ty = Type::FuncPtr {
+                ret: Box::new(function.ret),
+                args,
+                is_nullable: false,
+                never_return: function.never_return,
+                calling_convention: CallingConvention::load(method.sig.abi.as_ref()),
+            };
+            methods.push(Field {
+                name,
+                ty,
+                cfg: Cfg::load(&method.attrs),
+                annotations: AnnotationSet::new(),
+                documentation: Documentation::load(&method.attrs),
+            });
+        }
+
+        let mut vtable = Struct::new(
+            vtable_path.clone(),
+            GenericParams::default(),
+            methods,
+            false,
+            false,
+            None,
+            false,
+            cfg.clone(),
+            annotations.clone(),
+            Documentation::none(),
+        );
+
+        let vtable_ptr = Type::Ptr {
+            ty: Box::new(Type::Path(GenericPath::new(vtable_path, vec![]))),
+            is_const: true,
+            is_nullable: false,
+            is_ref: false,
+            owned_box: None,
+        };
+        let object_fields = if is_com {
+            let iid = match annotations.atom("iid") {
+                Some(Some(iid)) => Some(Guid::parse(&iid)?),
+                _ => None,
+            };
+            vtable.com_interface = Some(ComInterface {
+                name: trait_name,
+                iid,
+            });
+            vec![Field::from_name_and_type("lpVtbl".to_owned(), vtable_ptr)]
+        } else {
+            vec![
+                Field::from_name_and_type("data".to_owned(), void_ptr(false)),
+                Field::from_name_and_type("vtable".to_owned(), vtable_ptr),
+            ]
+        };
+        let object = Struct::new(
+            object_path,
+            GenericParams::default(),
+            object_fields,
+            false,
+            false,
+            None,
+            false,
+            cfg,
+            annotations,
+            Documentation::load(&item.attrs),
+        );
+
+        Ok((vtable, object))
+    }
+
+    /// Writes the `struct.initializer` helper, which takes every field in
+    /// order, so that adding a field breaks the code using it instead of
+    /// leaving the field zeroed.
+    fn write_initializer<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.fields.is_empty() {
+            return;
+        }
+        let type_name = if config.style.generate_typedef() {
+            self.export_name().to_owned()
+        } else {
+            format!("struct {}", self.export_name())
+        };
+        match config.structure.initializer(&self.annotations) {
+            StructInitializer::None => {}
+            StructInitializer::Macro => {
+                // The parameters can't be named like the fields, whose
+                // designators they would replace.
+                let mut params: Vec<String> =
+                    self.fields.iter().map(|f| format!("{}_", f.name)).collect();
+                if params
+                    .iter()
+                    .any(|p| self.fields.iter().any(|f| f.name == *p))
+                {
+                    params = (0..self.fields.len()).map(|i| format!("_{}", i)).collect();
+                }
+                // The commas of a brace initializer would split it into several
+                // arguments, so an array can only be given as the variadic
+                // arguments of the macro, when it's the last field. Struct
+                // fields can be given with their own `_INIT` macro.
+                let last = self.fields.len() - 1;
+                let is_array = |field: &Field| matches!(field.ty, Type::Array(..));
+                if let Some(field) = self.fields[..last].iter().find(|f| is_array(f)) {
+                    warn!(
+                        "Can't generate an initializer macro for {}, as its field {} is an array.",
+                        self.export_name(),
+                        field.name
+                    );
+                    return;
+                }
+                let mut designators: Vec<String> = self
+                    .fields
+                    .iter()
+                    .zip(&params)
+                    .map(|(field, param)| format!(".{} = {}", field.name, param))
+                    .collect();
+                if is_array(&self.fields[last]) {
+                    params[last] = "...".to_owned();
+                    designators[last] = format!(".{} = __VA_ARGS__", self.fields[last].name);
+                }
+                out.new_line();
+                out.new_line();
+                write!(
+                    out,
+                    "#define {}_INIT({}) {{ {} }}",
+                    self.export_name(),
+                    params.join(", "),
+                    designators.join(", ")
+                );
+            }
+            StructInitializer::Function => {
+                if let Some(field) = self.fields.iter().find(|f| matches!(f.ty, Type::Array(..))) {
+                    warn!(
+                        "Can't generate an initializer function for {}, as its field {} is an array.",
+                        self.export_name(),
+                        field.name
+                    );
+                    return;
+                }
+                out.new_line();
+                out.new_line();
+                write!(
+                    out,
+                    "static inline {} {}_new(",
+                    type_name,
+                    self.export_name()
+                );
+                for (i, field) in self.fields.iter().enumerate() {
+                    if i != 0 {
+                        out.write(", ");
+                    }
+                    cdecl::write_field(out, &field.ty, &field.name, config);
+                }
+                out.write(")");
+                out.open_brace();
+                let designators: Vec<String> = self
+                    .fields
+                    .iter()
+                    .map(|field| format!(".{0} = {0}", field.name))
+                    .collect();
+                write!(
+                    out,
+                    "{} result = {{ {} }};",
+                    type_name,
+                    designators.join(", ")
+                );
+                out.new_line();
+                out.write("return result;");
+                out.close_brace(false);
+            }
+        }
+    }
+
+    /// Writes the call macros and the IID of a COM interface after its vtable.
+    fn write_com_interface<F: Write>(
+        &self,
+        com: &ComInterface,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        out.new_line();
+        for field in &self.fields {
+            let args = match field.ty {
+                Type::FuncPtr { ref args, .. } => args,
+                _ => continue,
+            };
+            let names: Vec<String> = args
+                .iter()
+                .enumerate()
+                .map(|(i, (name, _))| match name {
+                    Some(name) if name != "_" => name.clone(),
+                    _ => format!("arg{}", i),
+                })
+                .collect();
+            let params = names.join(", ");
+            out.new_line();
+            field.cfg.to_condition(config).write_before(config, out);
+            write!(
+                out,
+                "#define {}_{}({}) ((This)->lpVtbl->{}({}))",
+                com.name, field.name, params, field.name, params
+            );
+            field.cfg.to_condition(config).write_after(config, out);
+        }
+
+        if let Some(ref iid) = com.iid {
+            out.new_line();
+            out.new_line();
+            out.write("#if defined(_WIN32)");
+            out.new_line();
+            write!(
+                out,
+                "static const IID IID_{} = {{ 0x{:08x}, 0x{:04x}, 0x{:04x}, {{ {} }} }};",
+                com.name,
+                iid.data1,
+                iid.data2,
+                iid.data3,
+                iid.data4
+                    .iter()
+                    .map(|b| format!("0x{:02x}", b))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            out.new_line();
+            out.write("#endif");
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         path: Path,
@@ -141,7 +626,9 @@ impl Struct {
             cfg,
             annotations,
             documentation,
+            source_location: None,
             associated_constants: vec![],
+            com_interface: None,
         }
     }
 
@@ -152,7 +639,7 @@ impl Struct {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -203,8 +690,78 @@ impl Struct {
         )
     }
 
+    fn emit_memberwise_op<F: Write>(
+        &self,
+        config: &Config,
+        cuda_prefix: &str,
+        constexpr_prefix: &str,
+        op: &str,
+        other: &str,
+        out: &mut SourceWriter<F>,
+    ) {
+        let (operator, is_unary) = cxx_operator(op).unwrap();
+        out.new_line();
+        if is_unary {
+            write!(
+                out,
+                "{}{}{} operator{}() const",
+                cuda_prefix,
+                constexpr_prefix,
+                self.export_name(),
+                operator
+            );
+        } else {
+            write!(
+                out,
+                "{}{}{} operator{}(const {}& {}) const",
+                cuda_prefix,
+                constexpr_prefix,
+                self.export_name(),
+                operator,
+                self.export_name(),
+                other
+            );
+        }
+        out.open_brace();
+        write!(out, "return {} {{ ", self.export_name());
+        let vec: Vec<_> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let value = if is_unary {
+                    format!("{}{}", operator, field.name)
+                } else {
+                    format!("{} {} {}.{}", field.name, operator, other, field.name)
+                };
+                format!("static_cast<decltype({})>({})", field.name, value)
+            })
+            .collect();
+        out.write_vertical_source_list(config, &vec[..], ListType::Join(","));
+        out.write(" };");
+        out.close_brace(false);
+
+        if !is_unary {
+            out.new_line();
+            write!(
+                out,
+                "{}{}& operator{}=(const {}& {})",
+                cuda_prefix,
+                self.export_name(),
+                operator,
+                self.export_name(),
+                other
+            );
+            out.open_brace();
+            write!(out, "*this = (*this {} {});", operator, other);
+            out.new_line();
+            write!(out, "return *this;");
+            out.close_brace(false);
+        }
+    }
+
     fn emit_bitflags_binop<F: Write>(
         &self,
+        cuda_prefix: &str,
         constexpr_prefix: &str,
         operator: char,
         other: &str,
@@ -214,7 +771,8 @@ impl Struct {
         out.new_line();
         write!(
             out,
-            "{}{} operator{}(const {}& {}) const",
+            "{}{}{} operator{}(const {}& {}) const",
+            cuda_prefix,
             constexpr_prefix,
             self.export_name(),
             operator,
@@ -232,7 +790,8 @@ impl Struct {
         out.new_line();
         write!(
             out,
-            "{}& operator{}=(const {}& {})",
+            "{}{}& operator{}=(const {}& {})",
+            cuda_prefix,
             self.export_name(),
             operator,
             self.export_name(),
@@ -267,6 +826,10 @@ impl Item for Struct {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::Struct(self.clone())
     }
@@ -300,6 +863,7 @@ impl Item for Struct {
         }
 
         // Apply renaming rules to fields in the following order
+        //   0. `cbindgen::rename` annotation of the field
         //   1. `cbindgen::field-names` annotation
         //   2. `cbindgen::rename-all` annotation
         //   3. config struct rename rule
@@ -337,6 +901,7 @@ impl Item for Struct {
         }
 
         for field in &mut self.fields {
+            field.apply_rename_annotation();
             reserved::escape(&mut field.name);
         }
 
@@ -387,6 +952,7 @@ impl Source for Struct {
                 cfg: self.cfg.clone(),
                 annotations: self.annotations.clone(),
                 documentation: self.documentation.clone(),
+                source_location: self.source_location.clone(),
             };
             typedef.write(config, out);
             for constant in &self.associated_constants {
@@ -399,7 +965,9 @@ impl Source for Struct {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
-        self.documentation.write(config, out);
+        self.documentation
+            .for_symbol(config, self.export_name())
+            .write(config, out);
 
         if !self.is_enum_variant_body {
             self.generic_params.write(config, out);
@@ -449,10 +1017,11 @@ impl Source for Struct {
             }
         }
 
-        if self.annotations.must_use(config) {
-            if let Some(ref anno) = config.structure.must_use {
-                write!(out, " {}", anno);
-            }
+        if let Some(anno) = self
+            .annotations
+            .must_use_attribute(config, DeprecatedNoteKind::Struct)
+        {
+            write!(out, " {}", anno);
         }
         if let Some(note) = self
             .annotations
@@ -473,13 +1042,17 @@ impl Source for Struct {
             out.new_line();
         }
 
-        out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
+        out.write_vertical_source_list(config, &self.fields, ListType::Cap(";"));
         if config.language == Language::Cython && self.fields.is_empty() {
             out.write("pass");
         }
 
         if config.language == Language::Cxx {
             let mut wrote_start_newline = false;
+            let cuda_prefix = config
+                .cuda_qualifier(&self.annotations)
+                .map(|cuda| format!("{} ", cuda))
+                .unwrap_or_default();
 
             if config.structure.derive_constructor(&self.annotations) && !self.fields.is_empty() {
                 if !wrote_start_newline {
@@ -496,7 +1069,7 @@ impl Source for Struct {
                         .apply(name, IdentifierType::FunctionArg)
                         .into_owned()
                 };
-                write!(out, "{}(", self.export_name());
+                write!(out, "{}{}(", cuda_prefix, self.export_name());
                 let vec: Vec<_> = self
                     .fields
                     .iter()
@@ -508,7 +1081,7 @@ impl Source for Struct {
                         )
                     })
                     .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(","));
+                out.write_vertical_source_list(config, &vec[..], ListType::Join(","));
                 write!(out, ")");
                 out.new_line();
                 write!(out, "  : ");
@@ -517,12 +1090,57 @@ impl Source for Struct {
                     .iter()
                     .map(|field| format!("{}({})", field.name, arg_renamer(&field.name)))
                     .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(","));
+                out.write_vertical_source_list(config, &vec[..], ListType::Join(","));
                 out.new_line();
                 write!(out, "{{}}");
                 out.new_line();
             }
 
+            // Transparent structs deriving conversions wrap their only field
+            // instead of being typedefs, and convert explicitly to and from it.
+            if self
+                .annotations
+                .bool("internal-derive-transparent-conversions")
+                .unwrap_or(false)
+            {
+                let field = &self.fields[0];
+                if !wrote_start_newline {
+                    wrote_start_newline = true;
+                    out.new_line();
+                }
+                let constexpr_prefix = if config.constant.allow_constexpr {
+                    "constexpr "
+                } else {
+                    ""
+                };
+
+                out.new_line();
+                write!(out, "{}{}() = default;", cuda_prefix, self.export_name());
+                if !config.structure.derive_constructor(&self.annotations) {
+                    let value = config
+                        .function
+                        .rename_args
+                        .apply("value", IdentifierType::FunctionArg);
+                    out.new_line();
+                    write!(
+                        out,
+                        "{}{}explicit {}(",
+                        cuda_prefix,
+                        constexpr_prefix,
+                        self.export_name()
+                    );
+                    cdecl::write_field(out, &field.ty, &format!("const& {}", value), config);
+                    write!(out, ") : {}({}) {{}}", field.name, value);
+                }
+                out.new_line();
+                write!(out, "{}{}explicit operator ", cuda_prefix, constexpr_prefix);
+                cdecl::write_type(out, &field.ty, config);
+                out.write("() const");
+                out.open_brace();
+                write!(out, "return {};", field.name);
+                out.close_brace(false);
+            }
+
             let other = config
                 .function
                 .rename_args
@@ -546,7 +1164,11 @@ impl Source for Struct {
                 };
 
                 out.new_line();
-                write!(out, "{}explicit operator bool() const", constexpr_prefix);
+                write!(
+                    out,
+                    "{}{}explicit operator bool() const",
+                    cuda_prefix, constexpr_prefix
+                );
                 out.open_brace();
                 write!(out, "return !!{bits};");
                 out.close_brace(false);
@@ -554,7 +1176,8 @@ impl Source for Struct {
                 out.new_line();
                 write!(
                     out,
-                    "{}{} operator~() const",
+                    "{}{}{} operator~() const",
+                    cuda_prefix,
                     constexpr_prefix,
                     self.export_name()
                 );
@@ -565,9 +1188,36 @@ impl Source for Struct {
                     self.export_name()
                 );
                 out.close_brace(false);
-                self.emit_bitflags_binop(constexpr_prefix, '|', &other, out);
-                self.emit_bitflags_binop(constexpr_prefix, '&', &other, out);
-                self.emit_bitflags_binop(constexpr_prefix, '^', &other, out);
+                self.emit_bitflags_binop(&cuda_prefix, constexpr_prefix, '|', &other, out);
+                self.emit_bitflags_binop(&cuda_prefix, constexpr_prefix, '&', &other, out);
+                self.emit_bitflags_binop(&cuda_prefix, constexpr_prefix, '^', &other, out);
+            }
+
+            let ops: Vec<_> = self
+                .derived_operators(config)
+                .into_iter()
+                .filter(|op| self.operator_function(op).is_none())
+                .collect();
+            for op in &ops {
+                if !self.can_derive_memberwise(op) {
+                    warn!(
+                        "Can't derive the {} operator of {} memberwise, use `{}-function`.",
+                        op,
+                        self.export_name(),
+                        op
+                    );
+                    continue;
+                }
+                if !wrote_start_newline {
+                    wrote_start_newline = true;
+                    out.new_line();
+                }
+                let constexpr_prefix = if config.constant.allow_constexpr {
+                    "constexpr "
+                } else {
+                    ""
+                };
+                self.emit_memberwise_op(config, &cuda_prefix, constexpr_prefix, op, &other, out);
             }
 
             // Generate a serializer function that allows dumping this struct
@@ -601,9 +1251,30 @@ impl Source for Struct {
                 let vec: Vec<_> = self
                     .fields
                     .iter()
-                    .map(|x| format!(" << \"{}=\" << {}.{}", x.name, instance, x.name))
+                    .map(|x| {
+                        // Streams print 8-bit integers as characters, so
+                        // promote them to print their value.
+                        let promote = matches!(
+                            x.ty,
+                            Type::Primitive(
+                                PrimitiveType::SChar
+                                    | PrimitiveType::UChar
+                                    | PrimitiveType::Integer {
+                                        kind: IntKind::B8,
+                                        ..
+                                    }
+                            )
+                        );
+                        format!(
+                            " << \"{}=\" << {}{}.{}",
+                            x.name,
+                            if promote { "+" } else { "" },
+                            instance,
+                            x.name
+                        )
+                    })
                     .collect();
-                out.write_vertical_source_list(&vec[..], ListType::Join(" << \", \""));
+                out.write_vertical_source_list(config, &vec[..], ListType::Join(" << \", \""));
                 out.write(" << \" }\";");
                 out.close_brace(false);
             }
@@ -627,6 +1298,7 @@ impl Source for Struct {
                     {
                         write!(out, "{} ", attrs);
                     }
+                    write!(out, "{}", cuda_prefix);
 
                     write!(
                         out,
@@ -644,6 +1316,7 @@ impl Source for Struct {
                         .map(|field| format!("{} {} {}.{}", field.name, $op, other, field.name))
                         .collect();
                     out.write_vertical_source_list(
+                        config,
                         &vec[..],
                         ListType::Join(&format!(" {}", $conjuc)),
                     );
@@ -712,6 +1385,16 @@ impl Source for Struct {
             constant.write(config, out, Some(self));
         }
 
+        if let Some(ref com) = self.com_interface {
+            if config.language != Language::Cython {
+                self.write_com_interface(com, config, out);
+            }
+        }
+
+        if config.language == Language::C {
+            self.write_initializer(config, out);
+        }
+
         condition.write_after(config, out);
     }
 }
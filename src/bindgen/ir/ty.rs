@@ -8,16 +8,18 @@ use std::io::Write;
 use syn::ext::IdentExt;
 
 use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::{BoolType, CharType, Config, Language, OwnedBox};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{GenericArgument, GenericParams, GenericPath, Path};
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
+use crate::bindgen::report::{SkippedItem, SkippedKind};
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum PrimitiveType {
     Void,
     Bool,
@@ -37,6 +39,7 @@ pub enum PrimitiveType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum IntKind {
     Short,
     Int,
@@ -194,14 +197,22 @@ impl PrimitiveType {
         }
     }
 
-    pub fn to_repr_c(&self, config: &Config) -> &'static str {
+    pub fn to_repr_c<'a>(&self, config: &'a Config) -> &'a str {
+        if let Some(ty) = config.layout.type_map.get(self.to_repr_rust()) {
+            return ty;
+        }
+
         match *self {
             PrimitiveType::Void => "void",
-            PrimitiveType::Bool => "bool",
+            PrimitiveType::Bool => match config.layout.bool_type {
+                BoolType::Bool => "bool",
+                BoolType::Uint8 => "uint8_t",
+                BoolType::Custom(ref name) => name,
+            },
             PrimitiveType::Char => "char",
             PrimitiveType::SChar => "signed char",
             PrimitiveType::UChar => "unsigned char",
-            // NOTE: It'd be nice to use a char32_t, but:
+            // NOTE: char32_t is opt-in, because:
             //
             //  * uchar.h is not present on mac (see #423).
             //
@@ -209,7 +220,10 @@ impl PrimitiveType {
             //    the C++ spec only requires it to be the same size as
             //    uint_least32_t, which is _not_ guaranteed to be 4-bytes.
             //
-            PrimitiveType::Char32 => "uint32_t",
+            PrimitiveType::Char32 => match config.char_type {
+                CharType::Char32 if config.language != Language::Cython => "char32_t",
+                _ => "uint32_t",
+            },
             PrimitiveType::Integer {
                 kind,
                 signed,
@@ -250,19 +264,7 @@ impl PrimitiveType {
                         "size_t"
                     }
                 }
-                IntKind::Size => {
-                    if config.usize_is_size_t {
-                        if signed {
-                            "ptrdiff_t"
-                        } else {
-                            "size_t"
-                        }
-                    } else if signed {
-                        "intptr_t"
-                    } else {
-                        "uintptr_t"
-                    }
-                }
+                IntKind::Size => config.size_type(signed),
                 IntKind::B8 => {
                     if signed {
                         "int8_t"
@@ -313,6 +315,7 @@ impl PrimitiveType {
 /// Used for the `U` part of `[T; U]` and const generics. We support a very
 /// limited vocabulary here: only identifiers and literals.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum ConstExpr {
     Name(String),
     Value(String),
@@ -386,8 +389,47 @@ impl Source for ConstExpr {
     }
 }
 
+/// The calling convention of a function pointer, from its ABI. ABIs which
+/// are the same as `extern "C"` everywhere cbindgen's output is used with,
+/// including the Rust one, are `C`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
+pub enum CallingConvention {
+    C,
+    /// `extern "system"`, which is `stdcall` on 32-bit Windows.
+    System,
+    /// `extern "stdcall"`.
+    Stdcall,
+}
+
+impl CallingConvention {
+    pub fn load(abi: Option<&syn::Abi>) -> Self {
+        let name = match abi.and_then(|abi| abi.name.as_ref()) {
+            Some(name) => name.value(),
+            None => return CallingConvention::C,
+        };
+        match name.as_str() {
+            "system" | "system-unwind" => CallingConvention::System,
+            "stdcall" | "stdcall-unwind" => CallingConvention::Stdcall,
+            _ => CallingConvention::C,
+        }
+    }
+
+    /// The macro the calling convention is written as, which is defined in
+    /// the header for the compilers and targets that need a qualifier.
+    pub fn macro_name(self) -> Option<&'static str> {
+        match self {
+            CallingConvention::C => None,
+            CallingConvention::System => Some("CBINDGEN_SYSTEM"),
+            CallingConvention::Stdcall => Some("CBINDGEN_STDCALL"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub enum Type {
+    #[non_exhaustive]
     Ptr {
         ty: Box<Type>,
         is_const: bool,
@@ -396,6 +438,9 @@ pub enum Type {
         // `T&` / `const T&`, but we should probably pass that down as an option
         // to code generation or something.
         is_ref: bool,
+        /// For a `Box`, owning what it points to, how it's written, see
+        /// `ptr.owned_box` and the `owned` annotation. Never `OwnedBox::Auto`.
+        owned_box: Option<OwnedBox>,
     },
     Path(GenericPath),
     Primitive(PrimitiveType),
@@ -405,6 +450,7 @@ pub enum Type {
         args: Vec<(Option<String>, Type)>,
         is_nullable: bool,
         never_return: bool,
+        calling_convention: CallingConvention,
     },
 }
 
@@ -415,6 +461,7 @@ impl Type {
             is_const: true,
             is_nullable: false,
             is_ref: true,
+            owned_box: None,
         }
     }
 
@@ -451,6 +498,7 @@ impl Type {
                     is_const,
                     is_nullable: false,
                     is_ref: false,
+                    owned_box: None,
                 }
             }
             syn::Type::Ptr(ref pointer) => {
@@ -467,6 +515,7 @@ impl Type {
                     is_const,
                     is_nullable: true,
                     is_ref: false,
+                    owned_box: None,
                 }
             }
             syn::Type::Path(ref path) => {
@@ -527,6 +576,7 @@ impl Type {
                     args,
                     is_nullable: false,
                     never_return,
+                    calling_convention: CallingConvention::load(function.abi.as_ref()),
                 }
             }
             syn::Type::Tuple(ref tuple) => {
@@ -579,11 +629,13 @@ impl Type {
                 ref ty,
                 is_const,
                 is_ref,
+                owned_box,
                 is_nullable: false,
             } => Some(Type::Ptr {
                 ty: ty.clone(),
                 is_const,
                 is_ref,
+                owned_box,
                 is_nullable: true,
             }),
             Type::FuncPtr {
@@ -591,11 +643,13 @@ impl Type {
                 ref args,
                 is_nullable: false,
                 never_return,
+                calling_convention,
             } => Some(Type::FuncPtr {
                 ret: ret.clone(),
                 args: args.clone(),
                 is_nullable: true,
                 never_return,
+                calling_convention,
             }),
             _ => None,
         }
@@ -675,13 +729,24 @@ impl Type {
                 is_const: false,
                 is_nullable: false,
                 is_ref: false,
+                owned_box: None,
             }),
-            "Box" if config.language != Language::Cxx => Some(Type::Ptr {
-                ty: Box::new(generic.into_owned()),
-                is_const: false,
-                is_nullable: false,
-                is_ref: false,
-            }),
+            "Box"
+                if config.language != Language::Cxx
+                    || config.pointer.owned_box != OwnedBox::Auto =>
+            {
+                let owned_box = match config.pointer.owned_box {
+                    OwnedBox::Auto => OwnedBox::Pointer,
+                    owned_box => owned_box,
+                };
+                Some(Type::Ptr {
+                    ty: Box::new(generic.into_owned()),
+                    is_const: false,
+                    is_nullable: false,
+                    is_ref: false,
+                    owned_box: Some(owned_box),
+                })
+            }
             "Cell" => Some(generic.into_owned()),
             "ManuallyDrop" | "MaybeUninit" | "Pin" if config.language != Language::Cxx => {
                 Some(generic.into_owned())
@@ -729,6 +794,31 @@ impl Type {
         }
     }
 
+    /// Calls `visitor` with the path of every type this type refers to,
+    /// through pointers, arrays, generic arguments and function pointers.
+    pub(crate) fn visit_paths(&self, visitor: &mut impl FnMut(&Path)) {
+        match *self {
+            Type::Array(ref ty, ..) | Type::Ptr { ref ty, .. } => ty.visit_paths(visitor),
+            Type::Path(ref generic) => {
+                visitor(generic.path());
+                for generic in generic.generics() {
+                    if let GenericArgument::Type(ref ty) = *generic {
+                        ty.visit_paths(visitor);
+                    }
+                }
+            }
+            Type::Primitive(..) => {}
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => {
+                ret.visit_paths(visitor);
+                for (_, ty) in args {
+                    ty.visit_paths(visitor);
+                }
+            }
+        }
+    }
+
     pub fn get_root_path(&self) -> Option<Path> {
         let mut current = self;
         loop {
@@ -757,11 +847,13 @@ impl Type {
                 is_const,
                 is_nullable,
                 is_ref,
+                owned_box,
             } => Type::Ptr {
                 ty: Box::new(ty.specialize(mappings)),
                 is_const,
                 is_nullable,
                 is_ref,
+                owned_box,
             },
             Type::Path(ref generic_path) => {
                 for &(param, value) in mappings {
@@ -792,6 +884,7 @@ impl Type {
                 ref args,
                 is_nullable,
                 never_return,
+                calling_convention,
             } => Type::FuncPtr {
                 ret: Box::new(ret.specialize(mappings)),
                 args: args
@@ -801,6 +894,7 @@ impl Type {
                     .collect(),
                 is_nullable,
                 never_return,
+                calling_convention,
             },
         }
     }
@@ -840,6 +934,19 @@ impl Type {
                              not found.",
                             path
                         );
+                        if !out.missing.iter().any(|x| x.name == path.name()) {
+                            let reason = if generic.generics().is_empty() {
+                                "not found, or not supported"
+                            } else {
+                                "generic type which couldn't be instantiated"
+                            };
+                            out.missing.push(SkippedItem::new(
+                                path.name().to_owned(),
+                                SkippedKind::Type,
+                                reason,
+                                None,
+                            ));
+                        }
                     }
                 }
             }
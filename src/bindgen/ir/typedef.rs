@@ -12,7 +12,7 @@ use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, Field, GenericArgument, GenericParams, Item,
-    ItemContainer, Path, ToCondition, Type,
+    ItemContainer, Path, SourceLocation, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -21,6 +21,7 @@ use crate::bindgen::writer::{Source, SourceWriter};
 
 /// A type alias that is represented as a C typedef
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Typedef {
     pub path: Path,
     pub export_name: String,
@@ -29,6 +30,7 @@ pub struct Typedef {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
 }
 
 impl Typedef {
@@ -65,6 +67,7 @@ impl Typedef {
             cfg,
             annotations,
             documentation,
+            source_location: None,
         }
     }
 
@@ -92,7 +95,7 @@ impl Typedef {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -131,6 +134,10 @@ impl Item for Typedef {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::Typedef(self.clone())
     }
@@ -185,7 +192,9 @@ impl Source for Typedef {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
-        self.documentation.write(config, out);
+        self.documentation
+            .for_symbol(config, self.export_name())
+            .write(config, out);
 
         self.generic_params.write(config, out);
 
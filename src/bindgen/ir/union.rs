@@ -11,7 +11,7 @@ use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, Field, GenericArgument, GenericParams, Item,
-    ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition,
+    ItemContainer, Path, Repr, ReprAlign, ReprStyle, SourceLocation, ToCondition,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -21,6 +21,7 @@ use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
 pub struct Union {
     pub path: Path,
     pub export_name: String,
@@ -31,6 +32,7 @@ pub struct Union {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    pub source_location: Option<SourceLocation>,
 }
 
 impl Union {
@@ -39,6 +41,11 @@ impl Union {
         item: &syn::ItemUnion,
         mod_cfg: Option<&Cfg>,
     ) -> Result<Union, String> {
+        let annotations = AnnotationSet::load(&item.attrs)?;
+        if annotations.bool("opaque").unwrap_or(false) {
+            return Err("Union is annotated as opaque.".to_owned());
+        }
+
         let repr = Repr::load(&item.attrs)?;
         if repr.style != ReprStyle::C {
             return Err("Union is not marked #[repr(C)].".to_owned());
@@ -67,7 +74,7 @@ impl Union {
             repr.align,
             tuple_union,
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
-            AnnotationSet::load(&item.attrs)?,
+            annotations,
             Documentation::load(&item.attrs),
         ))
     }
@@ -94,6 +101,7 @@ impl Union {
             cfg,
             annotations,
             documentation,
+            source_location: None,
         }
     }
 
@@ -104,7 +112,7 @@ impl Union {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -147,6 +155,10 @@ impl Item for Union {
         &mut self.annotations
     }
 
+    fn source_location(&self) -> Option<&SourceLocation> {
+        self.source_location.as_ref()
+    }
+
     fn container(&self) -> ItemContainer {
         ItemContainer::Union(self.clone())
     }
@@ -211,6 +223,10 @@ impl Item for Union {
                 field.name.insert(0, '_');
             }
         }
+
+        for field in &mut self.fields {
+            field.apply_rename_annotation();
+        }
     }
 
     fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
@@ -264,7 +280,9 @@ impl Source for Union {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
-        self.documentation.write(config, out);
+        self.documentation
+            .for_symbol(config, self.export_name())
+            .write(config, out);
 
         self.generic_params.write(config, out);
 
@@ -313,7 +331,7 @@ impl Source for Union {
             out.new_line();
         }
 
-        out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
+        out.write_vertical_source_list(config, &self.fields, ListType::Cap(";"));
         if config.language == Language::Cython && self.fields.is_empty() {
             out.write("pass");
         }
@@ -0,0 +1,94 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::Write;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::Language;
+use crate::bindgen::language_backend::LanguageBackend;
+use crate::bindgen::writer::SourceWriter;
+
+/// The built-in backend writing C and C++ headers, which every method of
+/// `LanguageBackend` defaults to.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CLikeBackend;
+
+impl LanguageBackend for CLikeBackend {}
+
+/// Writes everything preceding the declarations of a C or C++ header.
+pub(crate) fn write_headers<F: Write>(bindings: &Bindings, out: &mut SourceWriter<F>) {
+    let config = &bindings.config;
+    bindings.write_preamble(
+        out,
+        |out, text| write!(out, "/* {} */", text),
+        config.pragma_once,
+    );
+    bindings.write_macros(out);
+
+    if !bindings.has_includes() {
+        return;
+    }
+
+    out.new_line_if_not_start();
+
+    if !config.no_includes {
+        if config.freestanding.enabled {
+            bindings.write_freestanding_types(out);
+        } else {
+            match config.language {
+                Language::Cxx => write_cxx_includes(bindings, out),
+                _ => write_c_includes(bindings, out),
+            }
+        }
+    }
+
+    bindings.write_includes(out, config.amalgamate.enabled);
+    bindings.write_after_includes(out);
+}
+
+fn write_c_includes<F: Write>(bindings: &Bindings, out: &mut SourceWriter<F>) {
+    let used = bindings.used_includes();
+    let stdlib = !bindings.config.prune_includes;
+    // `offsetof`, `static_assert` and `alignof`.
+    let asserts = bindings.layouts.has_static_asserts(&bindings.config);
+    // `size_t`.
+    let wire = bindings.wire_format.has_helpers(&bindings.config);
+    include(out, used.stdarg, "stdarg.h");
+    include(out, used.stdbool, "stdbool.h");
+    include(out, used.stddef || asserts || wire, "stddef.h");
+    include(out, used.stdint, "stdint.h");
+    include(out, used.uchar, "uchar.h");
+    include(out, stdlib, "stdlib.h");
+    include(out, bindings.needs_assert() || asserts, "assert.h");
+    include(out, asserts, "stdalign.h");
+}
+
+fn write_cxx_includes<F: Write>(bindings: &Bindings, out: &mut SourceWriter<F>) {
+    let config = &bindings.config;
+    let used = bindings.used_includes();
+    let stdlib = !config.prune_includes;
+    let asserts = bindings.layouts.has_static_asserts(config);
+    let wire = bindings.wire_format.has_helpers(config);
+    include(out, used.stdarg, "cstdarg");
+    include(out, used.stddef || asserts || wire, "cstddef");
+    include(out, used.stdint, "cstdint");
+    include(out, stdlib, "cstdlib");
+    include(out, used.ostream, "ostream");
+    include(out, used.new, "new");
+    include(out, !bindings.owned_pointers().is_empty(), "memory");
+    include(
+        out,
+        (config.enumeration.cast_assert_name.is_none()
+            && (config.enumeration.derive_mut_casts || config.enumeration.derive_const_casts))
+            || bindings.needs_assert(),
+        "cassert",
+    );
+}
+
+fn include<F: Write>(out: &mut SourceWriter<F>, used: bool, header: &str) {
+    if used {
+        write!(out, "#include <{}>", header);
+        out.new_line();
+    }
+}
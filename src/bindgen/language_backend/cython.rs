@@ -0,0 +1,114 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::Write;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::language_backend::{BackendWriter, LanguageBackend};
+use crate::bindgen::writer::SourceWriter;
+
+/// The built-in backend writing Cython `.pxd` files. The declarations are
+/// written in a `cdef extern from` block standing for the C header, and
+/// nothing outside of it but the cimports.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CythonBackend;
+
+impl LanguageBackend for CythonBackend {
+    fn write_headers(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        write_headers(bindings, out);
+    }
+
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        out.new_line();
+        let header = bindings.config.cython.header.as_deref().unwrap_or("*");
+        write!(out, "cdef extern from {}", header);
+        out.open_brace();
+    }
+
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        if bindings.is_empty() {
+            out.write("pass");
+        }
+        out.close_brace(false);
+    }
+
+    fn open_function_block(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn close_function_block(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn write_footers(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_trailer(out);
+    }
+
+    fn write_symbol_versions(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn write_function_aliases(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn write_export_macros(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn write_owned_pointers(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn write_forwarding_operators(&self, _: &Bindings, _: &mut BackendWriter) {}
+
+    fn write_section_banner(&self, bindings: &Bindings, out: &mut BackendWriter, section: &str) {
+        match bindings.config.function.section_banner {
+            Some(_) => bindings.write_section_banner(out, section),
+            None => write!(out, "# {}", section),
+        }
+    }
+
+    fn write_module_banner(&self, bindings: &Bindings, out: &mut BackendWriter, module: &str) {
+        match bindings.config.module_banner {
+            Some(_) => bindings.write_module_banner(out, module),
+            None => write!(out, "# ===== module: {} =====", module),
+        }
+    }
+
+    fn write_doxygen_groups(&self, _: &Bindings, _: &mut BackendWriter) {}
+}
+
+/// Writes everything preceding the declarations of a Cython `.pxd` file.
+/// None of the macros of the C-like headers apply to it.
+pub(crate) fn write_headers<F: Write>(bindings: &Bindings, out: &mut SourceWriter<F>) {
+    let config = &bindings.config;
+    bindings.write_preamble(out, |out, text| write!(out, "''' {} '''", text), false);
+
+    if !bindings.has_includes() && config.cython.cimports.is_empty() {
+        return;
+    }
+
+    out.new_line_if_not_start();
+
+    if !config.no_includes {
+        let used = bindings.used_includes();
+        if used.stdint {
+            out.write("from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t");
+            out.new_line();
+            out.write("from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t");
+            out.new_line();
+        }
+        if used.stdbool || used.stdarg {
+            out.write("cdef extern from *");
+            out.open_brace();
+            if used.stdbool {
+                out.write("ctypedef bint bool");
+                out.new_line();
+            }
+            if used.stdarg {
+                out.write("ctypedef struct va_list");
+                out.new_line();
+            }
+            out.close_brace(false);
+        }
+    }
+
+    bindings.write_includes(out, false);
+
+    for (module, names) in &config.cython.cimports {
+        write!(out, "from {} cimport {}", module, names.join(", "));
+        out.new_line();
+    }
+
+    bindings.write_after_includes(out);
+}
@@ -0,0 +1,169 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+pub(crate) mod clike;
+pub(crate) mod cython;
+
+pub use self::clike::CLikeBackend;
+pub use self::cython::CythonBackend;
+
+use std::fmt;
+use std::io::Write;
+use std::sync::Arc;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::{Config, Language};
+use crate::bindgen::ir::{Constant, Enum, Function, OpaqueItem, Static, Struct, Typedef, Union};
+use crate::bindgen::writer::{Source, SourceWriter};
+
+/// The writer handed to a `LanguageBackend`.
+pub type BackendWriter<'a, 'b> = SourceWriter<'a, &'b mut dyn Write>;
+
+/// Emits the declarations that make up a set of bindings.
+///
+/// `Bindings::write` drives the backend: it decides which items get written
+/// and in which order, and calls into the backend for every piece of output.
+/// Backends are registered with `Builder::with_backend`, and the built-in
+/// backend of `Config::language` is used otherwise: `CLikeBackend` for C and
+/// C++, and `CythonBackend` for Cython.
+///
+/// Every method defaults to the output of `CLikeBackend`, so a backend only
+/// needs to override what it wants to change. A backend changing the Cython
+/// output instead delegates the methods it doesn't override to
+/// `CythonBackend`.
+///
+/// The backend is handed whole declarations. What they contain, like the
+/// fields and documentation of a struct, its associated constants or the
+/// structs of the variants of an enum with data, is written along with them
+/// by the built-in writers, without going through the backend again: to
+/// change it, override the method writing the declaration containing it. The
+/// forward declarations and the helper functions, like `derive_to_string`,
+/// are always written by the built-in writers.
+///
+/// The declarations are written by item writers shared by the built-in
+/// backends, which choose the C, C++ or Cython form of the declaration by
+/// `Config::language`, so a backend for another language writes every
+/// declaration it emits itself.
+pub trait LanguageBackend: Send + Sync {
+    /// Writes everything preceding the declarations: the header, include
+    /// guard, autogen warning and includes.
+    fn write_headers(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        clike::write_headers(bindings, out);
+    }
+
+    fn open_namespaces(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.open_namespaces(out);
+    }
+
+    fn close_namespaces(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.close_namespaces(out);
+    }
+
+    /// Opens the block wrapping the globals and functions (e.g. `extern "C"`).
+    fn open_function_block(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.open_function_block(out);
+    }
+
+    fn close_function_block(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.close_function_block(out);
+    }
+
+    /// Writes everything following the declarations: the end of the include
+    /// guard and the trailer.
+    fn write_footers(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_footers(out);
+    }
+
+    fn write_constant(&self, config: &Config, out: &mut BackendWriter, constant: &Constant) {
+        constant.write(config, out, None);
+    }
+
+    fn write_enum(&self, config: &Config, out: &mut BackendWriter, item: &Enum) {
+        item.write(config, out);
+    }
+
+    fn write_struct(&self, config: &Config, out: &mut BackendWriter, item: &Struct) {
+        item.write(config, out);
+    }
+
+    fn write_union(&self, config: &Config, out: &mut BackendWriter, item: &Union) {
+        item.write(config, out);
+    }
+
+    fn write_opaque_item(&self, config: &Config, out: &mut BackendWriter, item: &OpaqueItem) {
+        item.write(config, out);
+    }
+
+    fn write_typedef(&self, config: &Config, out: &mut BackendWriter, item: &Typedef) {
+        item.write(config, out);
+    }
+
+    fn write_static(&self, config: &Config, out: &mut BackendWriter, item: &Static) {
+        item.write(config, out);
+    }
+
+    fn write_function(&self, config: &Config, out: &mut BackendWriter, item: &Function) {
+        item.write(config, out);
+    }
+
+    /// Writes the indirections of the functions annotated with `version`,
+    /// after the function block.
+    fn write_symbol_versions(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_symbol_versions(out);
+    }
+
+    /// Writes the `#define`s of the alternate names of the functions
+    /// annotated with `alias`, after the function block.
+    fn write_function_aliases(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_function_aliases(out);
+    }
+
+    /// Writes the macros of `export.macros`, after the declarations.
+    fn write_export_macros(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_export_macros(out);
+    }
+
+    /// Writes the `std::unique_ptr` aliases of the opaque types annotated
+    /// with `destructor`, after the function block.
+    fn write_owned_pointers(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_owned_pointers(out);
+    }
+
+    /// Writes the operators of structs forwarding to the functions named by
+    /// `{op}-function` annotations, after the function block.
+    fn write_forwarding_operators(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_forwarding_operators(out);
+    }
+
+    /// Writes the banner introducing the functions of a `cbindgen:section`.
+    fn write_section_banner(&self, bindings: &Bindings, out: &mut BackendWriter, section: &str) {
+        bindings.write_section_banner(out, section);
+    }
+
+    /// Writes the banner introducing the declarations of a module, with
+    /// `group_by_module`.
+    fn write_module_banner(&self, bindings: &Bindings, out: &mut BackendWriter, module: &str) {
+        bindings.write_module_banner(out, module);
+    }
+
+    /// Writes the `\defgroup`s of the Doxygen groups, before the declarations.
+    fn write_doxygen_groups(&self, bindings: &Bindings, out: &mut BackendWriter) {
+        bindings.write_doxygen_groups(out);
+    }
+}
+
+/// The built-in backend writing bindings in `language`, used when no backend
+/// has been registered.
+pub(crate) fn builtin_backend(language: Language) -> Arc<dyn LanguageBackend> {
+    match language {
+        Language::C | Language::Cxx => Arc::new(CLikeBackend),
+        Language::Cython => Arc::new(CythonBackend),
+    }
+}
+
+impl fmt::Debug for dyn LanguageBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LanguageBackend")
+    }
+}
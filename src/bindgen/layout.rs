@@ -0,0 +1,352 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The layout of the exported types according to rustc, which `layout.probe`
+//! learns by building the crate with `-Zprint-type-sizes`, rather than
+//! relying on the C compiler to agree with cbindgen's understanding of
+//! `#[repr]`.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::Stdio;
+use std::thread;
+
+use crate::bindgen::cargo::Cargo;
+use crate::bindgen::config::{Config, Language};
+use crate::bindgen::error::{CargoExpandError, Error};
+use crate::bindgen::ir::{
+    ConditionWrite, ConstExpr, ItemContainer, OpaqueItem, PrimitiveType, ReprStyle, ToCondition,
+    Type,
+};
+use crate::bindgen::writer::SourceWriter;
+
+/// The size, alignment and field offsets of a type, in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize))]
+pub struct TypeLayout {
+    pub size: usize,
+    pub align: usize,
+    /// The offsets of the fields of a struct, in the order rustc printed
+    /// them, which is the declaration order for `#[repr(C)]` structs. Empty
+    /// for enums and unions.
+    pub field_offsets: Vec<usize>,
+}
+
+/// The layouts of the types rustc printed, by name.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serialize-ir", derive(Serialize), serde(transparent))]
+pub struct Layouts {
+    /// `None` for names shared by types laid out differently, in different
+    /// modules or crates, which can't be told apart.
+    types: HashMap<String, Option<TypeLayout>>,
+}
+
+impl Layouts {
+    /// The layout of the type named `name`, without its module path.
+    pub fn get(&self, name: &str) -> Option<&TypeLayout> {
+        self.types.get(name)?.as_ref()
+    }
+
+    /// Reads the layouts printed by `-Zprint-type-sizes`, as they're
+    /// printed. Generic types are skipped, since their monomorphs are named
+    /// differently in the bindings.
+    pub(crate) fn read<R: BufRead>(reader: R) -> io::Result<Layouts> {
+        let mut layouts = Layouts::default();
+        let mut current: Option<(String, TypeLayout)> = None;
+        let mut offset = 0;
+        for line in reader.lines() {
+            let line = line?;
+            let line = match line.strip_prefix("print-type-size ") {
+                Some(line) => line,
+                None => continue,
+            };
+            if let Some(ty) = line.strip_prefix("type: `") {
+                layouts.insert(current.take());
+                current = parse_type(ty);
+                offset = 0;
+                continue;
+            }
+            let layout = match current {
+                Some((_, ref mut layout)) => layout,
+                None => continue,
+            };
+            // The fields of variants are indented further.
+            let entry = match line.strip_prefix("    ") {
+                Some(entry) if !entry.starts_with(' ') => entry,
+                _ => continue,
+            };
+            if let Some(field) = entry.strip_prefix("field `") {
+                let mut parts = match field.split_once("`: ") {
+                    Some((_, parts)) => parts.split(", "),
+                    None => continue,
+                };
+                let size = parts.next().and_then(parse_bytes).unwrap_or(0);
+                let field_offset = parts
+                    .find_map(|part| part.strip_prefix("offset: "))
+                    .and_then(parse_bytes)
+                    .unwrap_or(offset);
+                layout.field_offsets.push(field_offset);
+                offset = field_offset + size;
+            } else if let Some(padding) = entry.strip_prefix("padding: ") {
+                offset += parse_bytes(padding).unwrap_or(0);
+            } else if entry.starts_with("variant ") || entry.starts_with("discriminant") {
+                layout.field_offsets.clear();
+            }
+        }
+        layouts.insert(current);
+        Ok(layouts)
+    }
+
+    fn insert(&mut self, layout: Option<(String, TypeLayout)>) {
+        let (name, layout) = match layout {
+            Some(layout) => layout,
+            None => return,
+        };
+        let known = self
+            .types
+            .entry(name)
+            .or_insert_with(|| Some(layout.clone()));
+        if known.as_ref() != Some(&layout) {
+            *known = None;
+        }
+    }
+
+    /// The array opaque types are declared with under `layout.sized_opaque`,
+    /// made of the unsigned integers of their alignment, if they have a
+    /// layout which can be written that way.
+    pub(crate) fn opaque_storage(&self, item: &OpaqueItem, config: &Config) -> Option<Type> {
+        if !config.layout.sized_opaque || !item.generic_params.is_empty() {
+            return None;
+        }
+        let layout = self.get(item.path.name())?;
+        let integer = match layout.align {
+            1 => "u8",
+            2 => "u16",
+            4 => "u32",
+            8 => "u64",
+            // Larger alignments need `layout.aligned_n`.
+            align if align > 8 && config.layout.aligned_n.is_some() => "u64",
+            _ => return None,
+        };
+        if layout.size == 0 {
+            return None;
+        }
+        let integer = PrimitiveType::maybe(integer).unwrap();
+        let len = layout.size / layout.align.min(8);
+        Some(Type::Array(
+            Box::new(Type::Primitive(integer)),
+            ConstExpr::Value(len.to_string()),
+        ))
+    }
+
+    /// Whether `write_static_asserts` writes anything for these bindings.
+    pub(crate) fn has_static_asserts(&self, config: &Config) -> bool {
+        config.layout.static_asserts
+            && config.language != Language::Cython
+            && !self.types.is_empty()
+    }
+
+    /// Writes static asserts checking that the C or C++ compiler lays `item`
+    /// out like rustc does, after its definition, under the same condition.
+    pub(crate) fn write_static_asserts<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        item: &ItemContainer,
+    ) {
+        let config = &out.bindings().config;
+        if !self.has_static_asserts(config) {
+            return;
+        }
+        let tag = config.language == Language::C && !config.style.generate_typedef();
+        let (keyword, fields) = match *item {
+            ItemContainer::Struct(ref x) if x.generic_params.is_empty() => {
+                let keyword = if x.is_transparent {
+                    None
+                } else {
+                    Some("struct")
+                };
+                // Fields cbindgen doesn't know about, like `PhantomData` and
+                // fields removed by `cfg`s, make the offsets ambiguous.
+                let fields = x
+                    .fields
+                    .iter()
+                    .all(|f| f.cfg.is_none() && f.annotations.atom("bitfield").is_none())
+                    .then_some(&x.fields);
+                (keyword, fields.filter(|_| !x.is_transparent))
+            }
+            ItemContainer::Union(ref x) if x.generic_params.is_empty() => (Some("union"), None),
+            ItemContainer::Enum(ref x) if x.generic_params.is_empty() => {
+                let keyword = match (x.tag.is_some(), x.repr.style == ReprStyle::C) {
+                    (true, true) => Some("struct"),
+                    (true, false) => Some("union"),
+                    (false, true) => Some("enum"),
+                    (false, false) => None,
+                };
+                (keyword, None)
+            }
+            ItemContainer::OpaqueItem(ref x) if self.opaque_storage(x, config).is_some() => {
+                (Some("struct"), None)
+            }
+            _ => return,
+        };
+        let layout = match self.get(item.deref().name()) {
+            Some(layout) => layout,
+            None => return,
+        };
+        let export_name = item.deref().export_name();
+        let name = match keyword {
+            Some(keyword) if tag => format!("{} {}", keyword, export_name),
+            _ => export_name.to_owned(),
+        };
+
+        let condition = item.deref().cfg().cloned().to_condition(config);
+        out.new_line();
+        condition.write_before(config, out);
+        write!(
+            out,
+            "static_assert(sizeof({}) == {}, \"size of {}\");",
+            name, layout.size, export_name
+        );
+        out.new_line();
+        write!(
+            out,
+            "static_assert(alignof({}) == {}, \"alignment of {}\");",
+            name, layout.align, export_name
+        );
+        if let Some(fields) = fields.filter(|fields| fields.len() == layout.field_offsets.len()) {
+            for (field, offset) in fields.iter().zip(&layout.field_offsets) {
+                out.new_line();
+                write!(
+                    out,
+                    "static_assert(offsetof({}, {}) == {}, \"offset of {}::{}\");",
+                    name, field.name, offset, export_name, field.name
+                );
+            }
+        }
+        condition.write_after(config, out);
+    }
+}
+
+/// Parses the name, size and alignment of a type, after ``type: ` ``.
+fn parse_type(ty: &str) -> Option<(String, TypeLayout)> {
+    let (path, layout) = ty.rsplit_once("`: ")?;
+    let name = path.rsplit("::").next()?;
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    let (size, align) = layout.split_once(", alignment: ")?;
+    Some((
+        name.to_owned(),
+        TypeLayout {
+            size: parse_bytes(size)?,
+            align: parse_bytes(align)?,
+            field_offsets: Vec::new(),
+        },
+    ))
+}
+
+fn parse_bytes(bytes: &str) -> Option<usize> {
+    bytes.strip_suffix(" bytes")?.parse().ok()
+}
+
+/// Builds the crate the bindings are generated for with
+/// `-Zprint-type-sizes`, in a temporary directory since rustc only prints
+/// the layouts when it compiles the crate, and reads the layouts as rustc
+/// prints them.
+///
+/// Like `parse.expand`, this needs a nightly toolchain, and uses the
+/// features and profile of `parse.expand`. The crate is built for `TARGET`
+/// when it's set, as it is in build scripts.
+pub(crate) fn probe(cargo: &Cargo, config: &Config) -> Result<Layouts, Error> {
+    let package = cargo.binding_crate_ref();
+    let failed = |err: CargoExpandError| Error::LayoutProbe(package.name.clone(), err);
+
+    let target_dir = tempfile::Builder::new()
+        .prefix("cbindgen-layout")
+        .tempdir()
+        .map_err(|err| failed(err.into()))?;
+    let expand = &config.parse.expand;
+    let mut cmd = cargo.rustc_command(
+        &package,
+        target_dir.path(),
+        expand.all_features,
+        expand.default_features,
+        &expand.features,
+        expand.profile,
+    );
+    if let Ok(target) = std::env::var("TARGET") {
+        cmd.arg("--target").arg(target);
+    }
+    cmd.arg("--");
+    cmd.arg("-Zprint-type-sizes");
+    info!("Command: {:?}", cmd);
+
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| failed(err.into()))?;
+    let mut stderr = child.stderr.take().unwrap();
+    let errors = thread::spawn(move || {
+        let mut errors = String::new();
+        let _ = stderr.read_to_string(&mut errors);
+        errors
+    });
+    let layouts = Layouts::read(BufReader::new(child.stdout.take().unwrap()))
+        .map_err(|err| failed(err.into()))?;
+    let status = child.wait().map_err(|err| failed(err.into()))?;
+    let errors = errors.join().unwrap_or_default();
+    if !status.success() {
+        return Err(failed(CargoExpandError::Compile(errors)));
+    }
+    Ok(layouts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_print_type_sizes() {
+        let output = "\
+print-type-size type: `ffi::Mixed`: 24 bytes, alignment: 8 bytes
+print-type-size     field `.a`: 1 bytes
+print-type-size     padding: 7 bytes
+print-type-size     field `.b`: 8 bytes, alignment: 8 bytes
+print-type-size     field `.c`: 2 bytes
+print-type-size     end padding: 6 bytes
+print-type-size type: `std::option::Option<&ffi::Mixed>`: 8 bytes, alignment: 8 bytes
+print-type-size     variant `Some`: 8 bytes
+print-type-size         field `.0`: 8 bytes
+print-type-size     variant `None`: 0 bytes
+print-type-size type: `Either`: 4 bytes, alignment: 4 bytes
+print-type-size     variant `Either`: 4 bytes
+print-type-size         field `.a`: 1 bytes
+print-type-size         field `.b`: 4 bytes, offset: 0 bytes, alignment: 4 bytes
+print-type-size type: `a::Handle`: 8 bytes, alignment: 8 bytes
+print-type-size     field `.ptr`: 8 bytes
+print-type-size type: `b::Handle`: 4 bytes, alignment: 4 bytes
+print-type-size     field `.id`: 4 bytes
+";
+        let layouts = Layouts::read(output.as_bytes()).unwrap();
+        assert_eq!(
+            layouts.get("Mixed"),
+            Some(&TypeLayout {
+                size: 24,
+                align: 8,
+                field_offsets: vec![0, 8, 16],
+            })
+        );
+        assert_eq!(
+            layouts.get("Either"),
+            Some(&TypeLayout {
+                size: 4,
+                align: 4,
+                field_offsets: vec![],
+            })
+        );
+        assert_eq!(layouts.get("Option"), None);
+        assert_eq!(layouts.get("Handle"), None);
+    }
+}
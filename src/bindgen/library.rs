@@ -2,17 +2,26 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use crate::bindgen::bindings::Bindings;
-use crate::bindgen::config::{Config, Language, SortKey};
+use crate::bindgen::config::{
+    source_module, CharType, Config, DoxygenConfig, DoxygenGroups, EnumFixedType, Language,
+    LintLevel, SortKey, TypeOrder,
+};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
-use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::dependencies::{Dependencies, ForwardDeclaration};
 use crate::bindgen::error::Error;
-use crate::bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
-use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use crate::bindgen::ir::{AnnotationSet, AnnotationValue, Documentation};
+use crate::bindgen::ir::{Constant, Enum, Function, GenericArgument, GenericPath, Item};
+use crate::bindgen::ir::{ItemContainer, ItemMap};
+use crate::bindgen::ir::{OpaqueItem, Path, SourceLocation, Static, Struct, Type, Typedef, Union};
+use crate::bindgen::ir::{PrimitiveType, VariantBody, VariantValue};
 use crate::bindgen::monomorph::Monomorphs;
+use crate::bindgen::report::{SkippedItem, SkippedKind};
+use crate::bindgen::wire::WireFormat;
 use crate::bindgen::ItemType;
 
 #[derive(Debug, Clone)]
@@ -28,6 +37,13 @@ pub struct Library {
     functions: Vec<Function>,
     source_files: Vec<PathBuf>,
     package_version: String,
+    package_name: String,
+    package_description: Option<String>,
+    package_license: Option<String>,
+    package_authors: Vec<String>,
+    skipped: Vec<SkippedItem>,
+    /// The generic types of the monomorphs, for `--emit rust-externs`.
+    unmangled_paths: HashMap<Path, GenericPath>,
 }
 
 impl Library {
@@ -44,6 +60,11 @@ impl Library {
         functions: Vec<Function>,
         source_files: Vec<PathBuf>,
         package_version: String,
+        package_name: String,
+        package_description: Option<String>,
+        package_license: Option<String>,
+        package_authors: Vec<String>,
+        skipped: Vec<SkippedItem>,
     ) -> Library {
         Library {
             config,
@@ -57,27 +78,56 @@ impl Library {
             functions,
             source_files,
             package_version,
+            package_name,
+            package_description,
+            package_license,
+            package_authors,
+            skipped,
+            unmangled_paths: HashMap::new(),
         }
     }
 
     pub fn generate(mut self) -> Result<Bindings, Error> {
+        let authors = self.package_authors.join(", ");
+        self.config.load_text_files(
+            &self.package_name,
+            &self.package_version,
+            self.package_license.as_deref().unwrap_or(""),
+            &authors,
+        )?;
+        if self.config.license_header {
+            self.add_license_header(&authors);
+        }
         self.transfer_annotations();
         self.simplify_standard_types();
+        self.check_improper_ctypes()?;
 
         match self.config.function.sort_by.unwrap_or(self.config.sort_by) {
             SortKey::Name => self.functions.sort_by(|x, y| x.path.cmp(&y.path)),
             SortKey::None => { /* keep input order */ }
         }
+        self.warn_ignored_sections();
+        self.group_functions_by_section();
+        if self.config.group_by_module {
+            self.functions
+                .sort_by_key(|function| source_module(function.source_location.as_ref()));
+        }
+        if self.config.language == Language::Cxx {
+            self.derive_transparent_conversions();
+        }
 
         if self.config.language != Language::Cxx {
             self.instantiate_monomorphs();
         }
         self.remove_excluded();
+        self.check_enum_lifecycle_functions();
         if self.config.language == Language::C {
             self.resolve_declaration_types();
         }
 
+        self.add_annotated_renames();
         self.rename_items();
+        self.propagate_cfg_annotations();
 
         let mut dependencies = Dependencies::new();
 
@@ -103,11 +153,61 @@ impl Library {
                 }
             }
         }
+        // Traits annotated with `cbindgen:vtable` or `cbindgen:com` aren't
+        // referenced by any function, but exporting them is the whole point
+        // of the annotation.
+        let mut vtables = Vec::new();
+        self.structs.for_all_items(|st| {
+            if st.annotations.bool("vtable").unwrap_or(false)
+                || st.annotations.bool("com").unwrap_or(false)
+            {
+                vtables.push(st.path.clone());
+            }
+        });
+        for path in vtables {
+            if let Some(items) = self.get_items(&path) {
+                if dependencies.items.insert(path) {
+                    for item in &items {
+                        item.deref().add_dependencies(&self, &mut dependencies);
+                    }
+                    for item in items {
+                        dependencies.order.push(item);
+                    }
+                }
+            }
+        }
 
-        dependencies.sort();
+        let forward_declarations =
+            if self.config.type_order == TypeOrder::Dependencies && !self.config.group_by_module {
+                dependencies.sort();
+                Vec::new()
+            } else {
+                dependencies.sort_stable(&self.config)
+            };
 
-        let items = dependencies.order;
-        let constants = if self.config.export.should_generate(ItemType::Constants) {
+        let mut items = dependencies.order;
+        // Only the opaque items the bindings use are worth reporting.
+        let mut skipped: Vec<_> = self
+            .skipped
+            .drain(..)
+            .filter(|skipped| {
+                skipped.kind != SkippedKind::Opaque
+                    || items.iter().any(|item| match *item {
+                        ItemContainer::OpaqueItem(ref x) => skipped.is_opaque(&x.path),
+                        _ => false,
+                    })
+            })
+            .collect();
+        skipped.extend(dependencies.missing);
+        self.check_field_layout(&items)?;
+        let wire_format = if self.config.layout.wire_format {
+            let wire_format = WireFormat::new(&items);
+            wire_format.warn(&items);
+            wire_format
+        } else {
+            WireFormat::default()
+        };
+        let mut constants = if self.config.export.should_generate(ItemType::Constants) {
             let mut constants = self.constants.to_vec();
             match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
                 SortKey::Name => constants.sort_by(|x, y| x.path.cmp(&y.path)),
@@ -118,7 +218,7 @@ impl Library {
             vec![]
         };
 
-        let globals = if self.config.export.should_generate(ItemType::Globals) {
+        let mut globals = if self.config.export.should_generate(ItemType::Globals) {
             let mut globals = self.globals.to_vec();
             match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
                 SortKey::Name => globals.sort_by(|x, y| x.path.cmp(&y.path)),
@@ -128,24 +228,103 @@ impl Library {
         } else {
             vec![]
         };
-        let functions = if self.config.export.should_generate(ItemType::Functions) {
+        let mut functions = if self.config.export.should_generate(ItemType::Functions) {
             self.functions
         } else {
             vec![]
         };
 
-        Ok(Bindings::new(
+        if self.config.char_type == CharType::Error {
+            check_char_type(&items, &constants, &globals, &functions)?;
+        }
+        check_enum_values(&self.config, &items)?;
+        lint_enum_values(&self.config, &items)?;
+
+        if self.config.documentation && self.config.language != Language::Cython {
+            add_doxygen_groups(
+                &self.config,
+                &mut items,
+                &mut constants,
+                &mut globals,
+                &mut functions,
+            );
+        }
+
+        // The internal declarations are only split off when the bindings are
+        // written to a file, the bindings still declare everything otherwise.
+        let split =
+            if self.config.internal.path.is_some() && self.config.language != Language::Cython {
+                let mut public = (
+                    items.clone(),
+                    constants.clone(),
+                    globals.clone(),
+                    functions.clone(),
+                    forward_declarations.clone(),
+                );
+                split_internal(
+                    &mut public.0,
+                    &mut public.1,
+                    &mut public.2,
+                    &mut public.3,
+                    &mut public.4,
+                )
+                .map(|internal| (public, internal))
+            } else {
+                None
+            };
+        let config = &self.config;
+        let (structs, typedefs) = (&self.structs, &self.typedefs);
+        let (package_version, package_name) = (&self.package_version, &self.package_name);
+        let package_description = &self.package_description;
+        let split = split.map(|(public, internal)| {
+            let split_bindings = |config, declarations: InternalDeclarations| {
+                let (items, constants, globals, functions, forward_declarations) = declarations;
+                let mut bindings = Bindings::new(
+                    config,
+                    structs.clone(),
+                    typedefs.clone(),
+                    constants,
+                    globals,
+                    items,
+                    forward_declarations,
+                    functions,
+                    Vec::new(),
+                    false,
+                    package_version.clone(),
+                    package_name.clone(),
+                    package_description.clone(),
+                );
+                bindings.set_wire_format(wire_format.clone());
+                bindings
+            };
+            (
+                split_bindings(config.clone(), public),
+                split_bindings(internal_config(config), internal),
+            )
+        });
+
+        let mut bindings = Bindings::new(
             self.config,
             self.structs,
             self.typedefs,
             constants,
             globals,
             items,
+            forward_declarations,
             functions,
             self.source_files,
             false,
             self.package_version,
-        ))
+            self.package_name,
+            self.package_description,
+        );
+        if let Some((public, internal)) = split {
+            bindings.set_internal(public, internal);
+        }
+        bindings.set_skipped(skipped);
+        bindings.set_wire_format(wire_format);
+        bindings.set_unmangled_paths(self.unmangled_paths);
+        Ok(bindings)
     }
 
     pub fn get_items(&self, p: &Path) -> Option<Vec<ItemContainer>> {
@@ -172,6 +351,181 @@ impl Library {
         &self.config
     }
 
+    #[allow(unused)]
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    /// The functions in output order. Note that `fn.sort_by` is applied after
+    /// any `IrPass` has run, so reordering only sticks with `sort_by = "None"`.
+    #[allow(unused)]
+    pub fn functions_mut(&mut self) -> &mut Vec<Function> {
+        &mut self.functions
+    }
+
+    #[allow(unused)]
+    pub fn for_all_items<F>(&self, mut callback: F)
+    where
+        F: FnMut(&dyn Item),
+    {
+        self.constants.for_all_items(|x| callback(x));
+        self.globals.for_all_items(|x| callback(x));
+        self.enums.for_all_items(|x| callback(x));
+        self.structs.for_all_items(|x| callback(x));
+        self.unions.for_all_items(|x| callback(x));
+        self.opaque_items.for_all_items(|x| callback(x));
+        self.typedefs.for_all_items(|x| callback(x));
+    }
+
+    #[allow(unused)]
+    pub fn for_all_items_mut<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(&mut dyn Item),
+    {
+        self.constants.for_all_items_mut(|x| callback(x));
+        self.globals.for_all_items_mut(|x| callback(x));
+        self.enums.for_all_items_mut(|x| callback(x));
+        self.structs.for_all_items_mut(|x| callback(x));
+        self.unions.for_all_items_mut(|x| callback(x));
+        self.opaque_items.for_all_items_mut(|x| callback(x));
+        self.typedefs.for_all_items_mut(|x| callback(x));
+    }
+
+    /// Removes every item for which `callback` returns false.
+    #[allow(unused)]
+    pub fn retain_items<F>(&mut self, callback: F)
+    where
+        F: Fn(&dyn Item) -> bool,
+    {
+        self.constants.filter(|x| !callback(x));
+        self.globals.filter(|x| !callback(x));
+        self.enums.filter(|x| !callback(x));
+        self.structs.filter(|x| !callback(x));
+        self.unions.filter(|x| !callback(x));
+        self.opaque_items.filter(|x| !callback(x));
+        self.typedefs.filter(|x| !callback(x));
+    }
+
+    /// Gates the declarations depending on items with a `cfg` annotation
+    /// behind the conditions of those items as well, so that the header
+    /// still compiles when they're left out.
+    fn propagate_cfg_annotations(&mut self) {
+        let mut conditions = HashMap::new();
+        add_cfg_annotations(&self.constants, &mut conditions);
+        add_cfg_annotations(&self.globals, &mut conditions);
+        add_cfg_annotations(&self.enums, &mut conditions);
+        add_cfg_annotations(&self.structs, &mut conditions);
+        add_cfg_annotations(&self.unions, &mut conditions);
+        add_cfg_annotations(&self.opaque_items, &mut conditions);
+        add_cfg_annotations(&self.typedefs, &mut conditions);
+        if conditions.is_empty() {
+            return;
+        }
+
+        let constants = dependent_cfg_annotations(self, &self.constants, &conditions);
+        let globals = dependent_cfg_annotations(self, &self.globals, &conditions);
+        let enums = dependent_cfg_annotations(self, &self.enums, &conditions);
+        let structs = dependent_cfg_annotations(self, &self.structs, &conditions);
+        let unions = dependent_cfg_annotations(self, &self.unions, &conditions);
+        let opaque_items = dependent_cfg_annotations(self, &self.opaque_items, &conditions);
+        let typedefs = dependent_cfg_annotations(self, &self.typedefs, &conditions);
+        let functions: Vec<_> = self
+            .functions
+            .iter()
+            .map(|function| {
+                let mut dependencies = Dependencies::new();
+                function.add_dependencies(self, &mut dependencies);
+                combined_cfg_annotation(&function.annotations, &dependencies, &conditions)
+            })
+            .collect();
+
+        set_cfg_annotations(&mut self.constants, constants);
+        set_cfg_annotations(&mut self.globals, globals);
+        set_cfg_annotations(&mut self.enums, enums);
+        set_cfg_annotations(&mut self.structs, structs);
+        set_cfg_annotations(&mut self.unions, unions);
+        set_cfg_annotations(&mut self.opaque_items, opaque_items);
+        set_cfg_annotations(&mut self.typedefs, typedefs);
+        for (function, condition) in self.functions.iter_mut().zip(functions) {
+            if let Some(condition) = condition {
+                function
+                    .annotations
+                    .set("cfg", AnnotationValue::Atom(Some(condition)));
+            }
+        }
+    }
+
+    /// Warns about the `section` annotations of declarations other than
+    /// functions, which only Doxygen groups by section make use of.
+    fn warn_ignored_sections(&self) {
+        if self.config.doxygen.groups == DoxygenGroups::Section {
+            return;
+        }
+        let warn = |annotations: &AnnotationSet, path: &Path| {
+            if annotations.atom("section").is_some() {
+                warn!(
+                    "Ignoring the section annotation of {}: only functions are grouped into \
+                     sections.",
+                    path
+                );
+            }
+        };
+        self.constants
+            .for_all_items(|x| warn(&x.annotations, &x.path));
+        self.globals
+            .for_all_items(|x| warn(&x.annotations, &x.path));
+        self.enums.for_all_items(|x| warn(&x.annotations, &x.path));
+        self.structs
+            .for_all_items(|x| warn(&x.annotations, &x.path));
+        self.unions.for_all_items(|x| warn(&x.annotations, &x.path));
+        self.opaque_items
+            .for_all_items(|x| warn(&x.annotations, &x.path));
+        self.typedefs
+            .for_all_items(|x| warn(&x.annotations, &x.path));
+    }
+
+    /// Moves functions annotated with a `section` together, after the ones
+    /// without one, with sections in order of first appearance.
+    fn group_functions_by_section(&mut self) {
+        let mut sections: Vec<String> = Vec::new();
+        for function in &self.functions {
+            if let Some(Some(section)) = function.annotations.atom("section") {
+                if !sections.contains(&section) {
+                    sections.push(section);
+                }
+            }
+        }
+        if sections.is_empty() {
+            return;
+        }
+
+        self.functions
+            .sort_by_key(|function| match function.annotations.atom("section") {
+                Some(Some(section)) => sections.iter().position(|x| *x == section).unwrap() + 1,
+                _ => 0,
+            });
+    }
+
+    /// Turns the transparent structs which derive conversions from typedefs
+    /// into wrapper structs, so that they can have them.
+    fn derive_transparent_conversions(&mut self) {
+        let config = &self.config;
+        self.structs.for_all_items_mut(|x| {
+            if x.is_transparent
+                && x.fields.len() == 1
+                && config
+                    .structure
+                    .derive_transparent_conversions(&x.annotations)
+            {
+                x.is_transparent = false;
+                x.annotations.add_default(
+                    "internal-derive-transparent-conversions",
+                    AnnotationValue::Bool(true),
+                );
+            }
+        });
+    }
+
     fn remove_excluded(&mut self) {
         let config = &self.config;
         // FIXME: interpret `config.export.exclude` as `Path`s.
@@ -193,6 +547,332 @@ impl Library {
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
     }
 
+    /// Drops the `destructor` and `clone` annotations of enums with fields
+    /// whose function isn't exported with a signature the enum can use,
+    /// so that neither a declaration nor a C++ destructor or copy constructor
+    /// is generated for it.
+    fn check_enum_lifecycle_functions(&mut self) {
+        let functions = if self.config.export.should_generate(ItemType::Functions) {
+            &self.functions[..]
+        } else {
+            &[]
+        };
+        let language = self.config.language;
+        self.enums.for_all_items_mut(|x| {
+            let path = x.path.clone();
+            let is_enum = |ty: &Type| match *ty {
+                Type::Path(ref p) => *p.path() == path && p.generics().is_empty(),
+                _ => false,
+            };
+            let points_to = |ty: &Type, is_const: bool| match *ty {
+                Type::Ptr {
+                    ref ty,
+                    is_const: ptr_const,
+                    ..
+                } => ptr_const == is_const && is_enum(ty),
+                _ => false,
+            };
+            let void = Type::Primitive(PrimitiveType::Void);
+
+            if let Some(destructor) = x.destructor_function() {
+                let error = match functions.iter().find(|f| f.path.name() == destructor) {
+                    None => Some("isn't exported"),
+                    Some(f)
+                        if f.args.len() != 1
+                            || !points_to(&f.args[0].ty, false)
+                            || f.ret != void =>
+                    {
+                        Some("doesn't take a mutable pointer to it and return nothing")
+                    }
+                    Some(_) => None,
+                };
+                if let Some(error) = error {
+                    warn!(
+                        "Ignoring the destructor annotation of {}: {} {}.",
+                        x.path, destructor, error
+                    );
+                    x.annotations.remove("destructor");
+                }
+            }
+
+            if let Some(clone) = x.clone_function() {
+                let error = match functions.iter().find(|f| f.path.name() == clone) {
+                    None => Some("isn't exported"),
+                    Some(f)
+                        if f.args.len() == 2
+                            && points_to(&f.args[0].ty, true)
+                            && points_to(&f.args[1].ty, false)
+                            && f.ret == void =>
+                    {
+                        None
+                    }
+                    // C++ returns types with a copy constructor differently
+                    // than C does, so the copy constructor can't use it.
+                    Some(f)
+                        if f.args.len() == 1
+                            && points_to(&f.args[0].ty, true)
+                            && is_enum(&f.ret) =>
+                    {
+                        if language == Language::Cxx {
+                            Some("returns it, which C++ can't call from a copy constructor")
+                        } else {
+                            None
+                        }
+                    }
+                    Some(_) => Some(
+                        "doesn't take a const pointer to it and either return it or write it \
+                         to a mutable pointer",
+                    ),
+                };
+                if let Some(error) = error {
+                    warn!(
+                        "Ignoring the clone annotation of {}: {} {}.",
+                        x.path, clone, error
+                    );
+                    x.annotations.remove("clone");
+                }
+            }
+        });
+    }
+
+    /// Looks for exported signatures using types which can't cross the FFI
+    /// boundary, and warns about them or fails according to
+    /// `improper_ctypes`.
+    fn check_improper_ctypes(&self) -> Result<(), Error> {
+        if self.config.improper_ctypes == LintLevel::Allow {
+            return Ok(());
+        }
+
+        let excluded = |path: &Path| self.config.export.exclude.iter().any(|x| x == path.name());
+        let mut diagnostics = Vec::new();
+        let mut report = |location: &Option<SourceLocation>, what: String, reason: String| {
+            let diagnostic = match location {
+                Some(location) => format!("{}: {}: {}", location, what, reason),
+                None => format!("{}: {}", what, reason),
+            };
+            diagnostics.push(diagnostic);
+        };
+
+        for function in &self.functions {
+            if excluded(&function.path) {
+                continue;
+            }
+            for (i, arg) in function.args.iter().enumerate() {
+                if let Some(reason) = self.improper_ctype(&arg.ty) {
+                    let arg_name = match arg.name {
+                        Some(ref name) => format!("argument `{}`", name),
+                        None => format!("argument {}", i + 1),
+                    };
+                    report(
+                        &function.source_location,
+                        format!("{} of `{}`", arg_name, function.path.name()),
+                        reason,
+                    );
+                }
+            }
+            if let Some(reason) = self.improper_ctype(&function.ret) {
+                report(
+                    &function.source_location,
+                    format!("return type of `{}`", function.path.name()),
+                    reason,
+                );
+            }
+        }
+        self.globals.for_all_items(|global| {
+            if excluded(&global.path) {
+                return;
+            }
+            if let Some(reason) = self.improper_ctype(&global.ty) {
+                report(
+                    &global.source_location,
+                    format!("type of `{}`", global.path.name()),
+                    reason,
+                );
+            }
+        });
+
+        if diagnostics.is_empty() {
+            return Ok(());
+        }
+        if self.config.improper_ctypes == LintLevel::Deny {
+            return Err(Error::Diagnostics(
+                "Exported signatures use types which aren't FFI-safe",
+                diagnostics,
+            ));
+        }
+        for diagnostic in diagnostics {
+            warn!("{} (improper_ctypes)", diagnostic);
+        }
+        Ok(())
+    }
+
+    /// Starts the header with the license and the authors of the crate, for
+    /// `license_header`.
+    fn add_license_header(&mut self, authors: &str) {
+        let mut lines = Vec::new();
+        match self.package_license {
+            Some(ref license) => lines.push(format!("SPDX-License-Identifier: {}", license)),
+            None => warn!(
+                "Can't write the SPDX license identifier, {} has no `license` in its Cargo.toml.",
+                if self.package_name.is_empty() {
+                    "the crate"
+                } else {
+                    &self.package_name
+                }
+            ),
+        }
+        if !authors.is_empty() {
+            lines.push(format!("Authors: {}", authors));
+        }
+        if lines.is_empty() {
+            return;
+        }
+
+        let mut banner = match self.config.language {
+            Language::Cython => lines
+                .iter()
+                .map(|line| format!("# {}", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Language::C | Language::Cxx => format!("/* {} */", lines.join("\n * ")),
+        };
+        if let Some(ref header) = self.config.header {
+            banner.push('\n');
+            banner.push_str(header);
+        }
+        self.config.header = Some(banner);
+    }
+
+    /// and warns about them or fails according to `field_layout`: fields
+    /// holding types which aren't FFI-safe by value, which rustc may lay out
+    /// as it wants, and `field-names` annotations which don't name every
+    /// field, as they're applied by position.
+    fn check_field_layout(&self, items: &[ItemContainer]) -> Result<(), Error> {
+        if self.config.field_layout == LintLevel::Allow {
+            return Ok(());
+        }
+
+        let mut diagnostics = Vec::new();
+        for item in items {
+            let (fields, field_names) = match *item {
+                ItemContainer::Struct(ref x) => (
+                    x.fields.iter().skip(x.has_tag_field as usize).collect(),
+                    x.annotations.list("field-names"),
+                ),
+                ItemContainer::Union(ref x) => {
+                    (x.fields.iter().collect(), x.annotations.list("field-names"))
+                }
+                ItemContainer::Enum(ref x) => (
+                    x.variants
+                        .iter()
+                        .filter_map(|variant| match variant.body {
+                            VariantBody::Body { ref body, .. } => Some(&body.fields),
+                            VariantBody::Empty(..) => None,
+                        })
+                        .flatten()
+                        .collect::<Vec<_>>(),
+                    None,
+                ),
+                _ => continue,
+            };
+            let item = item.deref();
+            let mut report = |message: String| {
+                diagnostics.push(match item.source_location() {
+                    Some(location) => format!("{}: {}", location, message),
+                    None => message,
+                });
+            };
+
+            for field in &fields {
+                if let Some(reason) = self.improper_ctype(&field.ty) {
+                    report(format!(
+                        "field `{}::{}`: {}",
+                        item.export_name(),
+                        field.name,
+                        reason
+                    ));
+                }
+            }
+            if let Some(names) = field_names {
+                if names.len() != fields.len() {
+                    report(format!(
+                        "`{}` has {} fields, but its `field-names` annotation has {} names, \
+                         which are given to the fields by position",
+                        item.export_name(),
+                        fields.len(),
+                        names.len()
+                    ));
+                }
+            }
+        }
+
+        if diagnostics.is_empty() {
+            return Ok(());
+        }
+        if self.config.field_layout == LintLevel::Deny {
+            return Err(Error::Diagnostics(
+                "Exported types have fields whose layout C can't rely on",
+                diagnostics,
+            ));
+        }
+        for diagnostic in diagnostics {
+            warn!("{} (field_layout)", diagnostic);
+        }
+        Ok(())
+    }
+
+    /// Why a type used in an exported signature isn't FFI-safe, if it isn't.
+    fn improper_ctype(&self, ty: &Type) -> Option<String> {
+        match *ty {
+            Type::Ptr { ref ty, .. } => match **ty {
+                Type::Path(ref generic) if generic.name() == "str" => Some(
+                    "`&str` is a reference to an unsized type; use `*const c_char` instead"
+                        .to_owned(),
+                ),
+                Type::FuncPtr { .. } => self.improper_ctype(ty),
+                _ => None,
+            },
+            Type::Path(ref generic) => {
+                // Outside of C, these are kept as is, and are FFI-safe as long
+                // as what they wrap is.
+                match generic.name() {
+                    "Box" => return None,
+                    "ManuallyDrop" | "MaybeUninit" | "Pin" => {
+                        return generic.generics().iter().find_map(|arg| match arg {
+                            GenericArgument::Type(ty) => self.improper_ctype(ty),
+                            _ => None,
+                        });
+                    }
+                    _ => {}
+                }
+                for item in self.get_items(generic.path()).unwrap_or_default() {
+                    match item {
+                        ItemContainer::OpaqueItem(_) => {
+                            return Some(format!(
+                                "`{}` has no C layout and can only be passed behind a pointer",
+                                generic.name()
+                            ));
+                        }
+                        ItemContainer::Typedef(ref typedef) => {
+                            if let Some(reason) = self.improper_ctype(&typedef.aliased) {
+                                return Some(reason);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                None
+            }
+            Type::Array(ref ty, _) => self.improper_ctype(ty),
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => std::iter::once(&**ret)
+                .chain(args.iter().map(|(_, ty)| ty))
+                .find_map(|ty| self.improper_ctype(ty)),
+            Type::Primitive(_) => None,
+        }
+    }
+
     fn transfer_annotations(&mut self) {
         let mut annotations = HashMap::new();
 
@@ -282,6 +962,18 @@ impl Library {
         }
     }
 
+    /// Turns `rename` annotations into `export.rename` entries, so that uses of
+    /// an item are renamed along with it.
+    fn add_annotated_renames(&mut self) {
+        let mut renames = Vec::new();
+        self.for_all_items(|item| {
+            if let Some(Some(name)) = item.annotations().atom("rename") {
+                renames.push((item.path().name().to_owned(), name));
+            }
+        });
+        self.config.export.rename.extend(renames);
+    }
+
     fn rename_items(&mut self) {
         let config = &self.config;
 
@@ -369,24 +1061,35 @@ impl Library {
 
     fn simplify_standard_types(&mut self) {
         let config = &self.config;
+        // The `owned` annotation of an item overrides `ptr.owned_box` for the
+        // boxes in it.
+        let item_config = |annotations: &AnnotationSet| {
+            let owned_box = config.pointer.owned_box(annotations);
+            if owned_box == config.pointer.owned_box {
+                return Cow::Borrowed(config);
+            }
+            let mut config = config.clone();
+            config.pointer.owned_box = owned_box;
+            Cow::Owned(config)
+        };
 
         self.structs.for_all_items_mut(|x| {
-            x.simplify_standard_types(config);
+            x.simplify_standard_types(&item_config(&x.annotations));
         });
         self.enums.for_all_items_mut(|x| {
-            x.simplify_standard_types(config);
+            x.simplify_standard_types(&item_config(&x.annotations));
         });
         self.unions.for_all_items_mut(|x| {
-            x.simplify_standard_types(config);
+            x.simplify_standard_types(&item_config(&x.annotations));
         });
         self.globals.for_all_items_mut(|x| {
-            x.simplify_standard_types(config);
+            x.simplify_standard_types(&item_config(&x.annotations));
         });
         self.typedefs.for_all_items_mut(|x| {
-            x.simplify_standard_types(config);
+            x.simplify_standard_types(&item_config(&x.annotations));
         });
         for x in &mut self.functions {
-            x.simplify_standard_types(config);
+            x.simplify_standard_types(&item_config(&x.annotations));
         }
     }
 
@@ -428,11 +1131,11 @@ impl Library {
         }
 
         // Remove structs and opaque items that are generic
-        self.opaque_items.filter(|x| x.generic_params.len() > 0);
-        self.structs.filter(|x| x.generic_params.len() > 0);
-        self.unions.filter(|x| x.generic_params.len() > 0);
-        self.enums.filter(|x| x.generic_params.len() > 0);
-        self.typedefs.filter(|x| x.generic_params.len() > 0);
+        self.opaque_items.filter(|x| !x.generic_params.is_empty());
+        self.structs.filter(|x| !x.generic_params.is_empty());
+        self.unions.filter(|x| !x.generic_params.is_empty());
+        self.enums.filter(|x| !x.generic_params.is_empty());
+        self.typedefs.filter(|x| !x.generic_params.is_empty());
 
         // Mangle the paths that remain
         self.unions
@@ -446,5 +1149,556 @@ impl Library {
         for x in &mut self.functions {
             x.mangle_paths(&monomorphs);
         }
+        self.unmangled_paths = monomorphs.unmangled_paths();
+    }
+}
+
+/// Fails if any of the declarations of the bindings use `char`, for
+/// `char_type = "error"`.
+fn check_char_type(
+    items: &[ItemContainer],
+    constants: &[Constant],
+    globals: &[Static],
+    functions: &[Function],
+) -> Result<(), Error> {
+    fn uses_char(ty: &Type) -> bool {
+        match *ty {
+            Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => uses_char(ty),
+            Type::Path(ref generic) => generic.generics().iter().any(|arg| match arg {
+                GenericArgument::Type(ty) => uses_char(ty),
+                _ => false,
+            }),
+            Type::Primitive(ref primitive) => *primitive == PrimitiveType::Char32,
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => uses_char(ret) || args.iter().any(|(_, ty)| uses_char(ty)),
+        }
+    }
+
+    let mut diagnostics = Vec::new();
+    let mut report = |location: Option<&SourceLocation>, name: &str| {
+        diagnostics.push(match location {
+            Some(location) => format!("{}: `{}`", location, name),
+            None => format!("`{}`", name),
+        });
+    };
+
+    for item in items {
+        let item_uses_char = match *item {
+            ItemContainer::Struct(ref x) => {
+                x.fields.iter().any(|field| uses_char(&field.ty))
+                    || x.associated_constants.iter().any(|c| uses_char(&c.ty))
+            }
+            ItemContainer::Union(ref x) => x.fields.iter().any(|field| uses_char(&field.ty)),
+            ItemContainer::Enum(ref x) => x.variants.iter().any(|variant| match variant.body {
+                VariantBody::Body { ref body, .. } => {
+                    body.fields.iter().any(|field| uses_char(&field.ty))
+                }
+                VariantBody::Empty(..) => false,
+            }),
+            ItemContainer::Typedef(ref x) => uses_char(&x.aliased),
+            ItemContainer::Constant(ref x) => uses_char(&x.ty),
+            ItemContainer::Static(ref x) => uses_char(&x.ty),
+            ItemContainer::OpaqueItem(..) => false,
+        };
+        if item_uses_char {
+            let item = item.deref();
+            report(item.source_location(), item.export_name());
+        }
+    }
+    for constant in constants.iter().filter(|x| uses_char(&x.ty)) {
+        report(constant.source_location.as_ref(), constant.export_name());
+    }
+    for global in globals.iter().filter(|x| uses_char(&x.ty)) {
+        report(global.source_location.as_ref(), global.export_name());
+    }
+    for function in functions {
+        if uses_char(&function.ret) || function.args.iter().any(|arg| uses_char(&arg.ty)) {
+            report(function.source_location.as_ref(), function.path.name());
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Diagnostics(
+            "Exported declarations use `char`, and `char_type` is \"error\"",
+            diagnostics,
+        ))
+    }
+}
+
+type InternalDeclarations = (
+    Vec<ItemContainer>,
+    Vec<Constant>,
+    Vec<Static>,
+    Vec<Function>,
+    Vec<ForwardDeclaration>,
+);
+
+/// Takes the declarations annotated with `internal` out of the public ones,
+/// for `internal.path`, or returns `None` when there are none. Types used by
+/// public declarations stay public, as the public header has to stand on its
+/// own.
+fn split_internal(
+    items: &mut Vec<ItemContainer>,
+    constants: &mut Vec<Constant>,
+    globals: &mut Vec<Static>,
+    functions: &mut Vec<Function>,
+    forward_declarations: &mut Vec<ForwardDeclaration>,
+) -> Option<InternalDeclarations> {
+    let is_internal = |annotations: &AnnotationSet| annotations.bool("internal") == Some(true);
+
+    let mut internal_types: HashSet<Path> = items
+        .iter()
+        .filter(|item| is_internal(item.deref().annotations()))
+        .map(|item| item.deref().path().clone())
+        .collect();
+
+    // Keeping a type public can make the types it uses public in turn.
+    loop {
+        let mut used = Vec::new();
+        {
+            let mut visit = |ty: &Type, user: &str| {
+                ty.visit_paths(&mut |path| {
+                    if internal_types.contains(path) {
+                        used.push((path.clone(), user.to_owned()));
+                    }
+                });
+            };
+            for item in items.iter() {
+                if internal_types.contains(item.deref().path()) {
+                    continue;
+                }
+                let name = item.deref().export_name();
+                match *item {
+                    ItemContainer::Struct(ref x) => {
+                        x.fields.iter().for_each(|f| visit(&f.ty, name))
+                    }
+                    ItemContainer::Union(ref x) => x.fields.iter().for_each(|f| visit(&f.ty, name)),
+                    ItemContainer::Enum(ref x) => {
+                        for variant in &x.variants {
+                            if let VariantBody::Body { ref body, .. } = variant.body {
+                                body.fields.iter().for_each(|f| visit(&f.ty, name));
+                            }
+                        }
+                    }
+                    ItemContainer::Typedef(ref x) => visit(&x.aliased, name),
+                    _ => {}
+                }
+            }
+            for x in constants.iter().filter(|x| !is_internal(&x.annotations)) {
+                visit(&x.ty, x.export_name());
+            }
+            for x in globals.iter().filter(|x| !is_internal(&x.annotations)) {
+                visit(&x.ty, x.export_name());
+            }
+            for x in functions.iter().filter(|x| !is_internal(&x.annotations)) {
+                visit(&x.ret, x.path.name());
+                for arg in &x.args {
+                    visit(&arg.ty, x.path.name());
+                }
+            }
+        }
+        if used.is_empty() {
+            break;
+        }
+        for (path, user) in used {
+            if internal_types.remove(&path) {
+                warn!(
+                    "Keeping {} in the public header, as it's used by {}, which isn't internal.",
+                    path, user
+                );
+            }
+        }
+    }
+
+    let internal_items: Vec<ItemContainer> = items
+        .iter()
+        .filter(|item| internal_types.contains(item.deref().path()))
+        .cloned()
+        .collect();
+    items.retain(|item| !internal_types.contains(item.deref().path()));
+    let internal_names: HashSet<&str> = internal_items
+        .iter()
+        .map(|item| item.deref().export_name())
+        .collect();
+    let (internal_forward_declarations, public_forward_declarations) =
+        std::mem::take(forward_declarations)
+            .into_iter()
+            .partition(|x: &ForwardDeclaration| internal_names.contains(x.export_name()));
+    *forward_declarations = public_forward_declarations;
+
+    let (internal_constants, public_constants) = std::mem::take(constants)
+        .into_iter()
+        .partition(|x: &Constant| is_internal(&x.annotations));
+    *constants = public_constants;
+    let (internal_globals, public_globals) = std::mem::take(globals)
+        .into_iter()
+        .partition(|x: &Static| is_internal(&x.annotations));
+    *globals = public_globals;
+    let (internal_functions, public_functions) = std::mem::take(functions)
+        .into_iter()
+        .partition(|x: &Function| is_internal(&x.annotations));
+    *functions = public_functions;
+
+    if internal_items.is_empty()
+        && internal_constants.is_empty()
+        && internal_globals.is_empty()
+        && internal_functions.is_empty()
+    {
+        return None;
+    }
+    Some((
+        internal_items,
+        internal_constants,
+        internal_globals,
+        internal_functions,
+        internal_forward_declarations,
+    ))
+}
+
+/// The configuration of the internal header, which gets the standard
+/// headers and the configured includes through the public one.
+fn internal_config(config: &Config) -> Config {
+    let mut config = config.clone();
+    config.internal.path = None;
+    config.no_includes = true;
+    config.includes.clear();
+    config.sys_includes.clear();
+    config.after_includes = None;
+    config.version_macros = None;
+    if let Some(ref mut guard) = config.include_guard {
+        if !guard.contains("{file}") {
+            guard.push_str("_INTERNAL");
+        }
+    }
+    config
+}
+
+/// Looks for variants of an enum with the same value, and values missing
+/// between the smallest and largest value of an enum, and warns about them
+/// or fails according to `enum.duplicate_values` and `enum.value_gaps`.
+/// Variants whose value isn't known, like ones given by a constant, are
+/// left out.
+fn lint_enum_values(config: &Config, items: &[ItemContainer]) -> Result<(), Error> {
+    let mut errors = Vec::new();
+    for item in items {
+        let x = match *item {
+            ItemContainer::Enum(ref x) => x,
+            _ => continue,
+        };
+        let duplicate_values = config.enumeration.duplicate_values(&x.annotations);
+        let value_gaps = config.enumeration.value_gaps(&x.annotations);
+        if duplicate_values == LintLevel::Allow && value_gaps == LintLevel::Allow {
+            continue;
+        }
+
+        let values: Vec<_> = x
+            .variant_values()
+            .filter_map(|(variant, value)| match value {
+                VariantValue::Integer(value) => Some((variant, value)),
+                VariantValue::Offset(..) => None,
+            })
+            .collect();
+
+        // Variants under different conditions may not exist together, but a
+        // variant without one exists along with any other.
+        for (i, &(variant, value)) in values.iter().enumerate() {
+            let cfg = variant.cfg.as_ref().map(ToString::to_string);
+            let duplicate = values[..i].iter().find(|(other, other_value)| {
+                let other_cfg = other.cfg.as_ref().map(ToString::to_string);
+                *other_value == value && (cfg.is_none() || other_cfg.is_none() || other_cfg == cfg)
+            });
+            if let Some((other, _)) = duplicate {
+                report_enum_lint(
+                    x,
+                    duplicate_values,
+                    "duplicate_values",
+                    format!(
+                        "`{}::{}` has the same value as `{}::{}`, {}",
+                        x.export_name(),
+                        variant.export_name,
+                        x.export_name(),
+                        other.export_name,
+                        value
+                    ),
+                    &mut errors,
+                );
+            }
+        }
+
+        let mut sorted: Vec<i128> = values.iter().map(|&(_, value)| value).collect();
+        sorted.sort_unstable();
+        sorted.dedup();
+        let missing: Vec<String> = sorted
+            .windows(2)
+            .filter(|pair| pair[1] - pair[0] > 1)
+            .map(|pair| {
+                if pair[1] - pair[0] == 2 {
+                    (pair[0] + 1).to_string()
+                } else {
+                    format!("{} to {}", pair[0] + 1, pair[1] - 1)
+                }
+            })
+            .collect();
+        if !missing.is_empty() {
+            report_enum_lint(
+                x,
+                value_gaps,
+                "value_gaps",
+                format!(
+                    "`{}` has no variant for {}",
+                    x.export_name(),
+                    missing.join(", ")
+                ),
+                &mut errors,
+            );
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Diagnostics(
+            "Enums have duplicate or missing values",
+            errors,
+        ))
+    }
+}
+
+/// Looks for variants of an enum whose value doesn't fit in the type of its
+/// enumerator, as writing it out of range is undefined behavior, and warns
+/// about them or fails according to `enum.value_range`.
+fn check_enum_values(config: &Config, items: &[ItemContainer]) -> Result<(), Error> {
+    let int_range = (i128::from(i32::MIN), i128::from(i32::MAX));
+
+    let mut diagnostics = Vec::new();
+    for item in items {
+        let x = match *item {
+            ItemContainer::Enum(ref x) => x,
+            _ => continue,
+        };
+        let level = config.enumeration.value_range(&x.annotations);
+        if level == LintLevel::Allow {
+            continue;
+        }
+        // C enumerators are `int`, whatever the type of the enum is, unless
+        // the enum has a fixed underlying type or they're written as defines.
+        let fixed = match config.language {
+            Language::Cxx => true,
+            Language::C => matches!(
+                config.enumeration.fixed_type(&x.annotations),
+                EnumFixedType::C23 | EnumFixedType::Defines
+            ),
+            Language::Cython => false,
+        };
+        let (range, ty) = match x.repr.ty {
+            Some(ty) if fixed => (ty.range(), ty.to_primitive().to_repr_c(config)),
+            _ => (int_range, "int"),
+        };
+
+        for (variant, value) in x.variant_values() {
+            let value = match value {
+                VariantValue::Integer(value) if value < range.0 || value > range.1 => value,
+                _ => continue,
+            };
+            report_enum_lint(
+                x,
+                level,
+                "value_range",
+                format!(
+                    "`{}::{}` is {}, out of the range of `{}`",
+                    x.export_name(),
+                    variant.export_name,
+                    value,
+                    ty
+                ),
+                &mut diagnostics,
+            );
+        }
+    }
+
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Diagnostics(
+            "Enum variants have values out of the range of their C type",
+            diagnostics,
+        ))
+    }
+}
+
+/// Reports `message` about the enum `x` according to `level`: as a warning
+/// naming the `enum.<lint>` option, or as one of the `errors` to fail with.
+fn report_enum_lint(
+    x: &Enum,
+    level: LintLevel,
+    lint: &str,
+    message: String,
+    errors: &mut Vec<String>,
+) {
+    let message = match x.source_location {
+        Some(ref location) => format!("{}: {}", location, message),
+        None => message,
+    };
+    match level {
+        LintLevel::Allow => {}
+        LintLevel::Warn => warn!("{} (enum.{})", message, lint),
+        LintLevel::Deny => errors.push(message),
+    }
+}
+
+/// Records the conditions of the items with a `cfg` annotation.
+fn add_cfg_annotations<T: Item + Clone>(
+    items: &ItemMap<T>,
+    conditions: &mut HashMap<Path, String>,
+) {
+    items.for_all_items(|item| {
+        if let Some(Some(condition)) = item.annotations().atom("cfg") {
+            conditions.insert(item.path().clone(), condition);
+        }
+    });
+}
+
+/// The `cfg` annotations the items need for the items they depend on, see
+/// `combined_cfg_annotation`.
+fn dependent_cfg_annotations<T: Item + Clone>(
+    library: &Library,
+    items: &ItemMap<T>,
+    conditions: &HashMap<Path, String>,
+) -> Vec<(Path, String)> {
+    let mut annotations = Vec::new();
+    items.for_all_items(|item| {
+        let mut dependencies = Dependencies::new();
+        item.add_dependencies(library, &mut dependencies);
+        if let Some(condition) =
+            combined_cfg_annotation(item.annotations(), &dependencies, conditions)
+        {
+            annotations.push((item.path().clone(), condition));
+        }
+    });
+    annotations
+}
+
+fn set_cfg_annotations<T: Item + Clone>(items: &mut ItemMap<T>, annotations: Vec<(Path, String)>) {
+    for (path, condition) in annotations {
+        items.for_items_mut(&path, |item| {
+            item.annotations_mut()
+                .set("cfg", AnnotationValue::Atom(Some(condition.clone())));
+        });
+    }
+}
+
+/// The condition of a declaration and those of the items it depends on,
+/// joined with `&&`, if it differs from its own `cfg` annotation.
+fn combined_cfg_annotation(
+    annotations: &AnnotationSet,
+    dependencies: &Dependencies,
+    conditions: &HashMap<Path, String>,
+) -> Option<String> {
+    let own = annotations.atom("cfg").flatten();
+    let mut all: Vec<&String> = own.iter().collect();
+    for item in &dependencies.order {
+        if let Some(condition) = conditions.get(item.deref().path()) {
+            if !all.contains(&condition) {
+                all.push(condition);
+            }
+        }
+    }
+    let combined = match all.len() {
+        0 => return None,
+        1 => all[0].clone(),
+        _ => {
+            let all: Vec<_> = all.iter().map(|x| format!("({})", x)).collect();
+            all.join(" && ")
+        }
+    };
+    Some(combined).filter(|combined| Some(combined) != own.as_ref())
+}
+
+/// Tags the documentation of the declarations with `\ingroup` for the
+/// Doxygen group they belong to, see `DoxygenConfig`.
+fn add_doxygen_groups(
+    config: &Config,
+    items: &mut [ItemContainer],
+    constants: &mut [Constant],
+    globals: &mut [Static],
+    functions: &mut [Function],
+) {
+    let tag = |documentation: &mut Documentation,
+               annotations: &AnnotationSet,
+               location: Option<&SourceLocation>| {
+        if let Some(group) = config.doxygen.group(annotations, location) {
+            let lines = &mut documentation.doc_comment;
+            while lines.last().map_or(false, |line| line.trim().is_empty()) {
+                lines.pop();
+            }
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!(" \\ingroup {}", DoxygenConfig::group_name(&group)));
+        }
+    };
+
+    for item in items {
+        match *item {
+            ItemContainer::Constant(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+            ItemContainer::Static(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+            ItemContainer::OpaqueItem(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+            ItemContainer::Struct(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+            ItemContainer::Union(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+            ItemContainer::Enum(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+            ItemContainer::Typedef(ref mut x) => tag(
+                &mut x.documentation,
+                &x.annotations,
+                x.source_location.as_ref(),
+            ),
+        }
+    }
+    for x in constants {
+        tag(
+            &mut x.documentation,
+            &x.annotations,
+            x.source_location.as_ref(),
+        );
+    }
+    for x in globals {
+        tag(
+            &mut x.documentation,
+            &x.annotations,
+            x.source_location.as_ref(),
+        );
+    }
+    for x in functions {
+        tag(
+            &mut x.documentation,
+            &x.annotations,
+            x.source_location.as_ref(),
+        );
     }
 }
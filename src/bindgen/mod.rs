@@ -36,30 +36,72 @@ macro_rules! deserialize_enum_str {
     };
 }
 
+mod abi;
 mod bindings;
 mod bitflags;
 mod builder;
 mod cargo;
 mod cdecl;
 mod config;
+mod cython;
 mod declarationtyperesolver;
 mod dependencies;
+mod descriptor;
 mod error;
-mod ir;
+mod externs;
+mod fuzz;
+mod includes;
+pub(crate) mod ir;
+mod language_backend;
+mod layout;
 mod library;
 mod mangle;
 mod monomorph;
 mod parser;
+mod pass;
+mod printers;
 mod rename;
+mod report;
 mod reserved;
+mod shim;
+mod symbols;
+#[allow(unused)]
+pub mod testing;
 mod utilities;
+#[cfg(feature = "cc")]
+mod verify;
+mod wire;
 mod writer;
 
 #[allow(unused)]
 pub(crate) use self::cargo::*;
 
+#[allow(unused)]
+pub use self::abi::{AbiSnapshot, EnumAbi, FieldAbi, FunctionAbi, VariantAbi};
 pub use self::bindings::Bindings;
+#[cfg(feature = "serialize-ir")]
+#[allow(unused)]
+pub use self::bindings::BindingsIr;
 pub use self::builder::Builder;
 pub use self::config::Profile; // disambiguate with cargo::Profile
 pub use self::config::*;
+#[allow(unused)]
+pub use self::descriptor::{FieldDescriptor, LayoutDescriptor, TypeDescriptor, TypeKind};
 pub use self::error::Error;
+#[allow(unused)]
+pub use self::ir::{AnnotationSet, AnnotationValue, Function, FunctionArgument, Item, Path};
+#[allow(unused)]
+pub use self::language_backend::{BackendWriter, CLikeBackend, CythonBackend, LanguageBackend};
+#[cfg(feature = "serialize-ir")]
+#[allow(unused)]
+pub use self::layout::{Layouts, TypeLayout};
+#[allow(unused)]
+pub use self::library::Library;
+#[allow(unused)]
+pub use self::pass::IrPass;
+#[allow(unused)]
+pub use self::report::{SkippedItem, SkippedKind};
+#[allow(unused)]
+pub use self::symbols::{LibraryCheck, Symbol, SymbolKind};
+#[allow(unused)]
+pub use self::writer::{ListType, SourceWriter};
@@ -29,12 +29,13 @@ impl Monomorphs {
         &mut self,
         library: &Library,
         generic: &Struct,
-        monomorph: Struct,
+        mut monomorph: Struct,
         arguments: Vec<GenericArgument>,
     ) {
+        monomorph.source_location = generic.source_location.clone();
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -49,12 +50,13 @@ impl Monomorphs {
         &mut self,
         library: &Library,
         generic: &Enum,
-        monomorph: Enum,
+        mut monomorph: Enum,
         arguments: Vec<GenericArgument>,
     ) {
+        monomorph.source_location = generic.source_location.clone();
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -69,12 +71,13 @@ impl Monomorphs {
         &mut self,
         library: &Library,
         generic: &Union,
-        monomorph: Union,
+        mut monomorph: Union,
         arguments: Vec<GenericArgument>,
     ) {
+        monomorph.source_location = generic.source_location.clone();
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -88,12 +91,13 @@ impl Monomorphs {
     pub fn insert_opaque(
         &mut self,
         generic: &OpaqueItem,
-        monomorph: OpaqueItem,
+        mut monomorph: OpaqueItem,
         arguments: Vec<GenericArgument>,
     ) {
+        monomorph.source_location = generic.source_location.clone();
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -105,12 +109,13 @@ impl Monomorphs {
         &mut self,
         library: &Library,
         generic: &Typedef,
-        monomorph: Typedef,
+        mut monomorph: Typedef,
         arguments: Vec<GenericArgument>,
     ) {
+        monomorph.source_location = generic.source_location.clone();
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -125,6 +130,15 @@ impl Monomorphs {
         self.replacements.get(path)
     }
 
+    /// The generic types the monomorphs were instantiated from, by the
+    /// mangled path of the monomorph.
+    pub fn unmangled_paths(&self) -> HashMap<Path, GenericPath> {
+        self.replacements
+            .iter()
+            .map(|(generic, mangled)| (mangled.clone(), generic.clone()))
+            .collect()
+    }
+
     pub fn drain_opaques(&mut self) -> Vec<OpaqueItem> {
         mem::take(&mut self.opaques)
     }
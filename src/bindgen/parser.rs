@@ -4,9 +4,10 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs::File;
 use std::io::Read;
-use std::path::{Path as FilePath, PathBuf as FilePathBuf};
+use std::path::{Component, Path as FilePath, PathBuf as FilePathBuf};
 
 use syn::ext::IdentExt;
 
@@ -15,10 +16,14 @@ use crate::bindgen::cargo::{Cargo, PackageRef};
 use crate::bindgen::config::{Config, ParseConfig};
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
-    AnnotationSet, AnnotationValue, Cfg, Constant, Documentation, Enum, Function, GenericParam,
-    GenericParams, ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
+    cxx_operator, AnnotationSet, AnnotationValue, Cfg, Constant, Documentation, Enum, Function,
+    GenericParam, GenericParams, ItemMap, OpaqueItem, Path, PrimitiveType, SourceLocation, Static,
+    Struct, Type, Typedef, Union,
+};
+use crate::bindgen::report::{SkippedItem, SkippedKind};
+use crate::bindgen::utilities::{
+    item_visibility, visibility, SynAbiHelpers, SynAttributeHelpers, SynItemHelpers,
 };
-use crate::bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemHelpers};
 
 const STD_CRATES: &[&str] = &[
     "std",
@@ -37,6 +42,7 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
     let mut config = config.clone();
     config.parse = ParseConfig {
         parse_deps: true,
+        include_paths: config.parse.include_paths.clone(),
         ..ParseConfig::default()
     };
 
@@ -48,6 +54,8 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        module_path: Vec::new(),
+        source_root: src_file.parent().map(|dir| dir.to_owned()),
         out: Parse::new(),
     };
 
@@ -58,6 +66,47 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
 
     context.parse_mod(&pkg_ref, src_file, 0)?;
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
+    context.out.add_op_impls();
+    Ok(context.out)
+}
+
+/// Parses the source of a crate whose macros have already been expanded, like
+/// the output of `cargo expand`, as a single file. `crate_name` is only used
+/// in errors, as there are no files to look for the modules in.
+pub fn parse_expanded_src(crate_name: &str, src: &str, config: &Config) -> ParseResult {
+    let mut context = Parser {
+        binding_crate_name: crate_name.to_owned(),
+        config,
+        lib: None,
+        parsed_crates: HashSet::new(),
+        cache_src: HashMap::new(),
+        cache_expanded_crate: HashMap::new(),
+        cfg_stack: Vec::new(),
+        module_path: Vec::new(),
+        source_root: None,
+        out: Parse::new(),
+    };
+
+    let pkg_ref = PackageRef {
+        name: crate_name.to_owned(),
+        version: None,
+    };
+
+    let file = syn::parse_file(src).map_err(|x| Error::ParseSyntaxError {
+        crate_name: crate_name.to_owned(),
+        src_path: "".to_owned(),
+        error: x,
+    })?;
+    context.process_mod(
+        &pkg_ref,
+        None,
+        None,
+        &file.items,
+        0,
+        /* is_mod_rs = */ true,
+        /* is_inline = */ false,
+    )?;
+    context.out.add_op_impls();
     Ok(context.out)
 }
 
@@ -70,17 +119,20 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
     let mut context = Parser {
         binding_crate_name: lib.binding_crate_name().to_owned(),
         config,
+        source_root: Some(lib.crate_dir().to_owned()),
         lib: Some(lib),
         parsed_crates: HashSet::new(),
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        module_path: Vec::new(),
         out: Parse::new(),
     };
 
     let binding_crate = context.lib.as_ref().unwrap().binding_crate_ref();
-    context.parse_crate(&binding_crate)?;
+    context.parse_crate(&binding_crate, 0)?;
     context.out.source_files = context.cache_src.keys().map(|k| k.to_owned()).collect();
+    context.out.add_op_impls();
     context.out.package_version = context
         .lib
         .as_ref()
@@ -88,6 +140,11 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
         .binding_crate_ref()
         .version
         .unwrap();
+    context.out.package_name = context.binding_crate_name.clone();
+    context.out.package_description = context.lib.as_ref().unwrap().binding_crate_description();
+    context.out.package_license = context.lib.as_ref().unwrap().binding_crate_license();
+    context.out.package_authors = context.lib.as_ref().unwrap().binding_crate_authors();
+    context.out.package_lib_name = context.lib.as_ref().unwrap().binding_crate_lib_name();
     Ok(context.out)
 }
 
@@ -102,12 +159,18 @@ struct Parser<'a> {
     cache_expanded_crate: HashMap<String, Vec<syn::Item>>,
 
     cfg_stack: Vec<Cfg>,
+    /// The names of the modules containing the one being parsed, from the
+    /// crate root.
+    module_path: Vec<String>,
+
+    /// The directory source locations are relative to.
+    source_root: Option<FilePathBuf>,
 
     out: Parse,
 }
 
 impl<'a> Parser<'a> {
-    fn should_parse_dependency(&self, pkg_name: &str) -> bool {
+    fn should_parse_dependency(&self, pkg_name: &str, depth: usize) -> bool {
         if self.parsed_crates.contains(pkg_name) {
             return false;
         }
@@ -116,6 +179,11 @@ impl<'a> Parser<'a> {
             return false;
         }
 
+        if self.config.parse.max_depth.map_or(false, |max| depth > max) {
+            debug!("Not parsing crate {}, deeper than max_depth", pkg_name);
+            return false;
+        }
+
         // Skip any whitelist or blacklist for expand
         if self
             .config
@@ -146,7 +214,9 @@ impl<'a> Parser<'a> {
                 .any(|name| name == pkg_name)
     }
 
-    fn parse_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
+    /// Parses `pkg`, then its dependencies, where `depth` is how far it is
+    /// from the binding crate.
+    fn parse_crate(&mut self, pkg: &PackageRef, depth: usize) -> Result<(), Error> {
         assert!(self.lib.is_some());
         debug!("Parsing crate {}", pkg.name);
         self.parsed_crates.insert(pkg.name.clone());
@@ -175,7 +245,18 @@ impl<'a> Parser<'a> {
         }
 
         for (dep_pkg, cfg) in self.lib.as_ref().unwrap().dependencies(pkg) {
-            if !self.should_parse_dependency(&dep_pkg.name) {
+            if !self.should_parse_dependency(&dep_pkg.name, depth + 1) {
+                continue;
+            }
+
+            if !self.config.parse.parse_tests
+                && self
+                    .lib
+                    .as_ref()
+                    .unwrap()
+                    .is_dev_dependency(pkg, &dep_pkg.name)
+            {
+                debug!("Not parsing dev-dependency {}", dep_pkg.name);
                 continue;
             }
 
@@ -183,7 +264,7 @@ impl<'a> Parser<'a> {
                 self.cfg_stack.push(cfg.clone());
             }
 
-            self.parse_crate(&dep_pkg)?;
+            self.parse_crate(&dep_pkg, depth + 1)?;
 
             if cfg.is_some() {
                 self.cfg_stack.pop();
@@ -193,6 +274,34 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// Whether `mod_path` is in the `tests`, `benches` or `examples`
+    /// directory of `pkg`, which are only meant for the targets of the same
+    /// name.
+    fn is_test_file(&self, pkg: &PackageRef, mod_path: &FilePath) -> bool {
+        let crate_dir = match self.lib.as_ref().and_then(|lib| lib.find_crate_dir(pkg)) {
+            Some(crate_dir) => crate_dir,
+            None => return false,
+        };
+        let path = match mod_path.strip_prefix(&crate_dir) {
+            Ok(path) => path,
+            Err(..) => return false,
+        };
+        // `#[path]` attributes can go through `..`.
+        let mut dirs = Vec::new();
+        for component in path.components() {
+            match component {
+                Component::ParentDir => {
+                    dirs.pop();
+                }
+                Component::Normal(dir) => dirs.push(dir),
+                _ => {}
+            }
+        }
+        dirs.first().map_or(false, |dir| {
+            ["tests", "benches", "examples"].iter().any(|x| *dir == *x)
+        })
+    }
+
     fn parse_expand_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
         assert!(self.lib.is_some());
 
@@ -233,6 +342,11 @@ impl<'a> Parser<'a> {
         mod_path: &FilePath,
         depth: usize,
     ) -> Result<(), Error> {
+        if !self.config.parse.parse_tests && self.is_test_file(pkg, mod_path) {
+            debug!("Not parsing {}, only used by tests", mod_path.display());
+            return Ok(());
+        }
+
         let mod_items = match self.cache_src.entry(mod_path.to_path_buf()) {
             Entry::Vacant(vacant_entry) => {
                 let mut s = String::new();
@@ -274,7 +388,10 @@ impl<'a> Parser<'a> {
             &submod_dir_2018
         };
 
-        self.process_mod(
+        let source_file = self.source_file(pkg, mod_path);
+        let parent_source_file = self.out.source_file.replace(source_file);
+
+        let result = self.process_mod(
             pkg,
             Some(mod_dir),
             Some(submod_dir),
@@ -282,7 +399,39 @@ impl<'a> Parser<'a> {
             depth,
             /* is_inline = */ false,
             is_mod_rs,
-        )
+        );
+
+        self.out.source_file = parent_source_file;
+        result
+    }
+
+    /// The path of a source file for the source locations of its items.
+    fn source_file(&self, pkg: &PackageRef, mod_path: &FilePath) -> String {
+        let relative = self
+            .source_root
+            .as_ref()
+            .and_then(|root| mod_path.strip_prefix(root).ok());
+        if !self.config.deterministic {
+            return relative.unwrap_or(mod_path).to_string_lossy().into_owned();
+        }
+
+        // Absolute paths depend on where the package was checked out or
+        // downloaded, so the files of other packages are written relative
+        // to their package instead.
+        let path = match relative {
+            Some(relative) => relative.to_owned(),
+            None => match self.lib.as_ref().and_then(|lib| lib.find_crate_dir(pkg)) {
+                Some(crate_dir) => match mod_path.strip_prefix(crate_dir) {
+                    Ok(path) => FilePath::new(&pkg.name).join(path),
+                    Err(..) => mod_path.to_owned(),
+                },
+                None => mod_path.to_owned(),
+            },
+        };
+        path.components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
     }
 
     /// `mod_dir` is the path to the current directory of the module. It may be
@@ -302,17 +451,55 @@ impl<'a> Parser<'a> {
         is_in_mod_rs: bool,
     ) -> Result<(), Error> {
         debug_assert_eq!(mod_dir.is_some(), submod_dir.is_some());
+        let dependency = self.config.parse.dependencies.get(&pkg.name);
         // We process the items first then the nested modules.
-        let nested_modules = self.out.load_syn_crate_mod(
-            self.config,
-            &self.binding_crate_name,
-            &pkg.name,
-            Cfg::join(&self.cfg_stack).as_ref(),
-            items,
-        );
+        let is_binding_crate = pkg.name == self.binding_crate_name;
+        let nested_modules = if dependency.map_or(true, |x| x.should_load_mod(&self.module_path))
+            && self.config.parse.includes_path(
+                &pkg.name,
+                is_binding_crate,
+                &self.module_path,
+                false,
+            ) {
+            self.out.module = (!self.module_path.is_empty()).then(|| self.module_path.join("::"));
+            self.out.load_syn_crate_mod(
+                self.config,
+                &self.binding_crate_name,
+                &pkg.name,
+                Cfg::join(&self.cfg_stack).as_ref(),
+                items,
+            )
+        } else {
+            items
+                .iter()
+                .filter(|item| !item.should_skip_parsing(!self.config.parse.parse_tests))
+                .filter(|item| should_export(self.config, *item, None))
+                .filter_map(|item| match *item {
+                    syn::Item::Mod(ref item) => Some(item),
+                    _ => None,
+                })
+                .collect()
+        };
 
         for item in nested_modules {
             let next_mod_name = item.ident.unraw().to_string();
+            self.module_path.push(next_mod_name.clone());
+            if !dependency.map_or(true, |x| x.should_parse_mod(&self.module_path))
+                || !self.config.parse.includes_path(
+                    &pkg.name,
+                    is_binding_crate,
+                    &self.module_path,
+                    true,
+                )
+            {
+                debug!(
+                    "Not parsing mod {} of crate {}",
+                    self.module_path.join("::"),
+                    pkg.name
+                );
+                self.module_path.pop();
+                continue;
+            }
             let cfg = Cfg::load(&item.attrs);
             if let Some(ref cfg) = cfg {
                 self.cfg_stack.push(cfg.clone());
@@ -399,6 +586,7 @@ impl<'a> Parser<'a> {
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
+            self.module_path.pop();
         }
 
         Ok(())
@@ -417,6 +605,38 @@ pub struct Parse {
     pub functions: Vec<Function>,
     pub source_files: Vec<FilePathBuf>,
     pub package_version: String,
+    pub package_name: String,
+    pub package_description: Option<String>,
+    pub package_license: Option<String>,
+    pub package_authors: Vec<String>,
+    /// The name of the library built from the binding crate, if known.
+    pub package_lib_name: Option<String>,
+    /// The items left out of the bindings, or made opaque, for `--emit
+    /// report`.
+    pub skipped: Vec<SkippedItem>,
+    /// The file the items being loaded come from, if known.
+    source_file: Option<String>,
+    /// The module the items being loaded are declared in, or `None` for the
+    /// crate root.
+    module: Option<String>,
+    /// The operator traits implemented by each type, by the name of their
+    /// method, added to the structs once everything is loaded.
+    op_impls: Vec<(Path, String)>,
+}
+
+/// Whether an item with the given visibility is exported according to
+/// `export.doc_hidden` and `export.min_visibility`. Modules are hidden along
+/// with their items, but their visibility doesn't matter since the items can
+/// be re-exported.
+fn should_export<T: SynAttributeHelpers + ?Sized>(
+    config: &Config,
+    item: &T,
+    vis: Option<&syn::Visibility>,
+) -> bool {
+    if !config.export.doc_hidden && item.is_doc_hidden() {
+        return false;
+    }
+    vis.map_or(true, |vis| visibility(vis) >= config.export.min_visibility)
 }
 
 impl Parse {
@@ -432,9 +652,42 @@ impl Parse {
             functions: Vec::new(),
             source_files: Vec::new(),
             package_version: String::new(),
+            package_name: String::new(),
+            package_description: None,
+            package_license: None,
+            package_authors: Vec::new(),
+            package_lib_name: None,
+            skipped: Vec::new(),
+            source_file: None,
+            module: None,
+            op_impls: Vec::new(),
         }
     }
 
+    /// Records that the item named `ident` was left out of the bindings, or
+    /// made opaque, for `--emit report`.
+    fn skip(
+        &mut self,
+        kind: SkippedKind,
+        name: String,
+        reason: impl fmt::Display,
+        ident: &syn::Ident,
+    ) {
+        let location = self.source_location(ident);
+        self.skipped
+            .push(SkippedItem::new(name, kind, reason, location));
+    }
+
+    /// Where the item named `ident` was declared, if we know it.
+    fn source_location(&self, ident: &syn::Ident) -> Option<SourceLocation> {
+        Some(SourceLocation {
+            file: self.source_file.clone()?,
+            line: ident.span().start().line,
+            module: self.module.clone(),
+        })
+        .filter(|location| location.line != 0)
+    }
+
     pub fn add_std_types(&mut self) {
         let mut add_opaque = |path: &str, generic_params: Vec<&str>| {
             let path = Path::new(path);
@@ -470,6 +723,23 @@ impl Parse {
         add_opaque("MaybeUninit", vec!["T"]);
     }
 
+    /// Records the operator traits structs implement, see `derive_ops`.
+    fn add_op_impls(&mut self) {
+        let mut ops_by_path: Vec<(Path, Vec<String>)> = Vec::new();
+        for (path, op) in self.op_impls.drain(..) {
+            match ops_by_path.iter_mut().find(|(x, _)| *x == path) {
+                Some((_, ops)) => ops.push(op),
+                None => ops_by_path.push((path, vec![op])),
+            }
+        }
+        for (path, ops) in ops_by_path {
+            self.structs.for_items_mut(&path, |x| {
+                x.annotations
+                    .add_default("internal-op-traits", AnnotationValue::List(ops.clone()));
+            });
+        }
+    }
+
     pub fn extend_with(&mut self, other: &Parse) {
         self.constants.extend_with(&other.constants);
         self.globals.extend_with(&other.globals);
@@ -481,6 +751,12 @@ impl Parse {
         self.functions.extend_from_slice(&other.functions);
         self.source_files.extend_from_slice(&other.source_files);
         self.package_version = other.package_version.clone();
+        self.package_name = other.package_name.clone();
+        self.package_description = other.package_description.clone();
+        self.package_license = other.package_license.clone();
+        self.package_authors = other.package_authors.clone();
+        self.package_lib_name = other.package_lib_name.clone();
+        self.skipped.extend_from_slice(&other.skipped);
     }
 
     fn load_syn_crate_mod<'a>(
@@ -492,10 +768,13 @@ impl Parse {
         items: &'a [syn::Item],
     ) -> Vec<&'a syn::ItemMod> {
         let mut impls_with_assoc_consts = Vec::new();
+        let mut default_constants = Vec::new();
         let mut nested_modules = Vec::new();
 
         for item in items {
-            if item.should_skip_parsing() {
+            if item.should_skip_parsing(!config.parse.parse_tests)
+                || !should_export(config, item, item_visibility(item))
+            {
                 continue;
             }
             match item {
@@ -529,6 +808,9 @@ impl Parse {
                 syn::Item::Type(ref item) => {
                     self.load_syn_ty(crate_name, mod_cfg, item);
                 }
+                syn::Item::Trait(ref item) => {
+                    self.load_syn_trait(crate_name, mod_cfg, item);
+                }
                 syn::Item::Impl(ref item_impl) => {
                     let has_assoc_const = item_impl
                         .items
@@ -537,9 +819,18 @@ impl Parse {
                     if has_assoc_const {
                         impls_with_assoc_consts.push(item_impl);
                     }
+                    if config.structure.default_constants {
+                        if let Some(expr) = default_impl_value(item_impl) {
+                            default_constants.push((item_impl, default_constant(expr)));
+                        }
+                    }
 
                     if let syn::Type::Path(ref path) = *item_impl.self_ty {
                         if let Some(type_name) = path.path.get_ident() {
+                            if let Some(op) = operator_trait(item_impl, type_name) {
+                                self.op_impls
+                                    .push((Path::new(type_name.unraw().to_string()), op));
+                            }
                             for method in item_impl.items.iter().filter_map(|item| match item {
                                 syn::ImplItem::Method(method) => Some(method),
                                 _ => None,
@@ -570,6 +861,15 @@ impl Parse {
             self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, item_impl)
         }
 
+        for (item_impl, constant) in default_constants {
+            self.load_syn_assoc_consts(
+                crate_name,
+                mod_cfg,
+                &item_impl.self_ty,
+                std::iter::once(&constant),
+            );
+        }
+
         nested_modules
     }
 
@@ -607,6 +907,9 @@ impl Parse {
 
         for foreign_item in &item.items {
             if let syn::ForeignItem::Fn(ref function) = *foreign_item {
+                if !should_export(config, &function.attrs[..], Some(&function.vis)) {
+                    continue;
+                }
                 if !config
                     .parse
                     .should_generate_top_level_item(crate_name, binding_crate_name)
@@ -619,8 +922,10 @@ impl Parse {
                 }
                 let path = Path::new(function.sig.ident.unraw().to_string());
                 match Function::load(path, None, &function.sig, true, &function.attrs, mod_cfg) {
-                    Ok(func) => {
+                    Ok(mut func) => {
                         info!("Take {}::{}.", crate_name, &function.sig.ident);
+                        func.source_location = self.source_location(&function.sig.ident);
+                        func.unwind = item.abi.is_c_unwind();
 
                         self.functions.push(func);
                     }
@@ -629,6 +934,12 @@ impl Parse {
                             "Cannot use fn {}::{} ({}).",
                             crate_name, &function.sig.ident, msg
                         );
+                        self.skip(
+                            SkippedKind::Function,
+                            format!("{}::{}", crate_name, function.sig.ident),
+                            msg,
+                            &function.sig.ident,
+                        );
                     }
                 }
             }
@@ -645,6 +956,9 @@ impl Parse {
         self_type: &Path,
         item: &syn::ImplItemMethod,
     ) {
+        if !should_export(config, item, Some(&item.vis)) {
+            return;
+        }
         self.load_fn_declaration(
             config,
             binding_crate_name,
@@ -666,6 +980,15 @@ impl Parse {
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemFn,
     ) {
+        let constexpr = item.sig.constness.is_some()
+            && AnnotationSet::load(&item.attrs).map_or(false, |annotations| {
+                annotations.bool("constexpr") == Some(true)
+            });
+        if constexpr {
+            self.load_syn_const_fn(config, binding_crate_name, crate_name, mod_cfg, item);
+            return;
+        }
+
         self.load_fn_declaration(
             config,
             binding_crate_name,
@@ -718,12 +1041,32 @@ impl Parse {
             (true, Some(exported_name)) => {
                 let path = Path::new(exported_name);
                 match Function::load(path, self_type, sig, false, attrs, mod_cfg) {
-                    Ok(func) => {
+                    Ok(mut func) => {
                         info!("Take {}.", loggable_item_name());
+                        func.source_location = self.source_location(&sig.ident);
+                        if let Some(item) = func.result_enum(sig, config) {
+                            match Enum::load(&item, func.cfg.as_ref(), config) {
+                                Ok(mut en) => {
+                                    en.source_location = func.source_location.clone();
+                                    self.enums.try_insert(en);
+                                }
+                                Err(msg) => {
+                                    error!("Cannot use fn {} ({}).", loggable_item_name(), msg);
+                                    self.skip(
+                                        SkippedKind::Function,
+                                        loggable_item_name(),
+                                        msg,
+                                        &sig.ident,
+                                    );
+                                    return;
+                                }
+                            }
+                        }
                         self.functions.push(func);
                     }
                     Err(msg) => {
                         error!("Cannot use fn {} ({}).", loggable_item_name(), msg);
+                        self.skip(SkippedKind::Function, loggable_item_name(), msg, &sig.ident);
                     }
                 }
             }
@@ -732,9 +1075,21 @@ impl Parse {
                     "Skipping {} - (not `no_mangle`, and has no `export_name` attribute)",
                     loggable_item_name()
                 );
+                self.skip(
+                    SkippedKind::Function,
+                    loggable_item_name(),
+                    "not `no_mangle`, and has no `export_name` attribute",
+                    &sig.ident,
+                );
             }
             (false, Some(_exported_name)) => {
                 warn!("Skipping {} - (not `extern \"C\"`", loggable_item_name());
+                self.skip(
+                    SkippedKind::Function,
+                    loggable_item_name(),
+                    "not `extern \"C\"`",
+                    &sig.ident,
+                );
             }
             (false, None) => {}
         }
@@ -790,8 +1145,9 @@ impl Parse {
                 &item.attrs,
                 Some(impl_path.clone()),
             ) {
-                Ok(constant) => {
+                Ok(mut constant) => {
                     info!("Take {}::{}::{}.", crate_name, impl_path, &item.ident);
+                    constant.source_location = self.source_location(&item.ident);
                     let mut any = false;
                     self.structs.for_items_mut(&impl_path, |item| {
                         any = true;
@@ -808,6 +1164,12 @@ impl Parse {
                 }
                 Err(msg) => {
                     warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    self.skip(
+                        SkippedKind::Constant,
+                        format!("{}::{}::{}", crate_name, impl_path, item.ident),
+                        msg,
+                        &item.ident,
+                    );
                 }
             }
         }
@@ -841,8 +1203,9 @@ impl Parse {
 
         let path = Path::new(item.ident.unraw().to_string());
         match Constant::load(path, mod_cfg, &item.ty, &item.expr, &item.attrs, None) {
-            Ok(constant) => {
+            Ok(mut constant) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
+                constant.source_location = self.source_location(&item.ident);
 
                 let full_name = constant.path.clone();
                 if !self.constants.try_insert(constant) {
@@ -851,6 +1214,61 @@ impl Parse {
             }
             Err(msg) => {
                 warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                self.skip(
+                    SkippedKind::Constant,
+                    format!("{}::{}", crate_name, item.ident),
+                    msg,
+                    &item.ident,
+                );
+            }
+        }
+    }
+
+    /// Loads a `const fn` with the `constexpr` annotation
+    fn load_syn_const_fn(
+        &mut self,
+        config: &Config,
+        binding_crate_name: &str,
+        crate_name: &str,
+        mod_cfg: Option<&Cfg>,
+        item: &syn::ItemFn,
+    ) {
+        if !config
+            .parse
+            .should_generate_top_level_item(crate_name, binding_crate_name)
+        {
+            info!(
+                "Skip {}::{} - (const fn's outside of the binding crate are not used).",
+                crate_name, &item.sig.ident
+            );
+            return;
+        }
+
+        if let syn::Visibility::Public(_) = item.vis {
+        } else {
+            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.sig.ident);
+            return;
+        }
+
+        let path = Path::new(item.sig.ident.unraw().to_string());
+        match Constant::load_const_fn(path, mod_cfg, item) {
+            Ok(mut constant) => {
+                info!("Take {}::{}.", crate_name, &item.sig.ident);
+                constant.source_location = self.source_location(&item.sig.ident);
+
+                let full_name = constant.path.clone();
+                if !self.constants.try_insert(constant) {
+                    error!("Conflicting name for constant {}", full_name);
+                }
+            }
+            Err(msg) => {
+                warn!("Skip {}::{} - ({})", crate_name, &item.sig.ident, msg);
+                self.skip(
+                    SkippedKind::Constant,
+                    format!("{}::{}", crate_name, item.sig.ident),
+                    msg,
+                    &item.sig.ident,
+                );
             }
         }
     }
@@ -878,16 +1296,29 @@ impl Parse {
         if let Some(exported_name) = item.exported_name() {
             let path = Path::new(exported_name);
             match Static::load(path, item, mod_cfg) {
-                Ok(constant) => {
+                Ok(mut constant) => {
                     info!("Take {}::{}.", crate_name, &item.ident);
+                    constant.source_location = self.source_location(&item.ident);
                     self.globals.try_insert(constant);
                 }
                 Err(msg) => {
                     warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    self.skip(
+                        SkippedKind::Static,
+                        format!("{}::{}", crate_name, item.ident),
+                        msg,
+                        &item.ident,
+                    );
                 }
             }
         } else {
             warn!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident);
+            self.skip(
+                SkippedKind::Static,
+                format!("{}::{}", crate_name, item.ident),
+                "not `no_mangle`",
+                &item.ident,
+            );
         }
     }
 
@@ -900,16 +1331,60 @@ impl Parse {
         item: &syn::ItemStruct,
     ) {
         match Struct::load(&config.layout, item, mod_cfg) {
-            Ok(st) => {
+            Ok(mut st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
+                st.source_location = self.source_location(&item.ident);
+                let default = match st.annotations.atom("default") {
+                    Some(Some(value)) => match syn::parse_str::<syn::Expr>(&value) {
+                        Ok(expr) => Some(expr),
+                        Err(msg) => {
+                            warn!(
+                                "Skip default value of {}::{} - ({})",
+                                crate_name, &item.ident, msg
+                            );
+                            None
+                        }
+                    },
+                    _ if config.structure.default_constants => derived_default_value(item),
+                    _ => None,
+                };
+                if let Some(expr) = default {
+                    let constant = default_constant(expr);
+                    match Constant::load(
+                        Path::new(constant.ident.to_string()),
+                        mod_cfg,
+                        &constant.ty,
+                        &constant.expr,
+                        &constant.attrs,
+                        Some(st.path.clone()),
+                    ) {
+                        Ok(mut constant) => {
+                            constant.source_location = st.source_location.clone();
+                            st.add_associated_constant(constant);
+                        }
+                        Err(msg) => {
+                            warn!(
+                                "Skip default value of {}::{} - ({})",
+                                crate_name, &item.ident, msg
+                            );
+                        }
+                    }
+                }
                 self.structs.try_insert(st);
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
-                let path = Path::new(item.ident.unraw().to_string());
-                self.opaque_items.try_insert(
-                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
+                self.skip(
+                    SkippedKind::Opaque,
+                    format!("{}::{}", crate_name, item.ident),
+                    msg,
+                    &item.ident,
                 );
+                let path = Path::new(item.ident.unraw().to_string());
+                let mut opaque =
+                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap();
+                opaque.source_location = self.source_location(&item.ident);
+                self.opaque_items.try_insert(opaque);
             }
         }
     }
@@ -923,17 +1398,25 @@ impl Parse {
         item: &syn::ItemUnion,
     ) {
         match Union::load(&config.layout, item, mod_cfg) {
-            Ok(st) => {
+            Ok(mut st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
+                st.source_location = self.source_location(&item.ident);
 
                 self.unions.try_insert(st);
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
-                let path = Path::new(item.ident.unraw().to_string());
-                self.opaque_items.try_insert(
-                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
+                self.skip(
+                    SkippedKind::Opaque,
+                    format!("{}::{}", crate_name, item.ident),
+                    msg,
+                    &item.ident,
                 );
+                let path = Path::new(item.ident.unraw().to_string());
+                let mut opaque =
+                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap();
+                opaque.source_location = self.source_location(&item.ident);
+                self.opaque_items.try_insert(opaque);
             }
         }
     }
@@ -947,15 +1430,52 @@ impl Parse {
         item: &syn::ItemEnum,
     ) {
         match Enum::load(item, mod_cfg, config) {
-            Ok(en) => {
+            Ok(mut en) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
+                en.source_location = self.source_location(&item.ident);
                 self.enums.try_insert(en);
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                self.skip(
+                    SkippedKind::Opaque,
+                    format!("{}::{}", crate_name, item.ident),
+                    msg,
+                    &item.ident,
+                );
                 let path = Path::new(item.ident.unraw().to_string());
-                self.opaque_items.try_insert(
-                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
+                let mut opaque =
+                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap();
+                opaque.source_location = self.source_location(&item.ident);
+                self.opaque_items.try_insert(opaque);
+            }
+        }
+    }
+
+    /// Loads a `trait` declaration annotated with `cbindgen:vtable` or `cbindgen:com`
+    fn load_syn_trait(&mut self, crate_name: &str, mod_cfg: Option<&Cfg>, item: &syn::ItemTrait) {
+        let is_vtable = AnnotationSet::load(&item.attrs)
+            .map(|annotations| {
+                annotations.bool("vtable").unwrap_or(false)
+                    || annotations.bool("com").unwrap_or(false)
+            })
+            .unwrap_or(false);
+        if !is_vtable {
+            return;
+        }
+
+        match Struct::load_vtable(item, mod_cfg) {
+            Ok((mut vtable, mut object)) => {
+                info!("Take {}::{}.", crate_name, &item.ident);
+                vtable.source_location = self.source_location(&item.ident);
+                object.source_location = self.source_location(&item.ident);
+                self.structs.try_insert(vtable);
+                self.structs.try_insert(object);
+            }
+            Err(msg) => {
+                error!(
+                    "Cannot use trait {}::{} as a vtable ({}).",
+                    crate_name, &item.ident, msg
                 );
             }
         }
@@ -964,17 +1484,25 @@ impl Parse {
     /// Loads a `type` declaration
     fn load_syn_ty(&mut self, crate_name: &str, mod_cfg: Option<&Cfg>, item: &syn::ItemType) {
         match Typedef::load(item, mod_cfg) {
-            Ok(st) => {
+            Ok(mut st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
+                st.source_location = self.source_location(&item.ident);
 
                 self.typedefs.try_insert(st);
             }
             Err(msg) => {
                 info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
-                let path = Path::new(item.ident.unraw().to_string());
-                self.opaque_items.try_insert(
-                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
+                self.skip(
+                    SkippedKind::Opaque,
+                    format!("{}::{}", crate_name, item.ident),
+                    msg,
+                    &item.ident,
                 );
+                let path = Path::new(item.ident.unraw().to_string());
+                let mut opaque =
+                    OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap();
+                opaque.source_location = self.source_location(&item.ident);
+                self.opaque_items.try_insert(opaque);
             }
         }
     }
@@ -1019,3 +1547,118 @@ impl Parse {
         self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, &impl_)
     }
 }
+
+/// The `DEFAULT` associated constant of `struct.default_constants` and the
+/// `default` annotation, with the given value.
+fn default_constant(value: syn::Expr) -> syn::ImplItemConst {
+    syn::parse_quote! {
+        /// The default value.
+        pub const DEFAULT: Self = #value;
+    }
+}
+
+/// The value returned by the `default` method of an `impl Default`, if it's
+/// a single constant struct expression.
+fn default_impl_value(item_impl: &syn::ItemImpl) -> Option<syn::Expr> {
+    let (_, ref trait_path, _) = *item_impl.trait_.as_ref()?;
+    if !trait_path.segments.last()?.ident.eq("Default") {
+        return None;
+    }
+    let method = item_impl.items.iter().find_map(|item| match item {
+        syn::ImplItem::Method(method) if method.sig.ident == "default" => Some(method),
+        _ => None,
+    })?;
+    let expr = match method.block.stmts[..] {
+        [syn::Stmt::Expr(ref expr)] => expr,
+        _ => return None,
+    };
+    match *expr {
+        syn::Expr::Struct(ref value) if is_constant_struct(value) => Some(expr.clone()),
+        _ => None,
+    }
+}
+
+/// Whether a struct expression only consists of literals, constants and
+/// operations on them, without calls, and has all of its fields.
+fn is_constant_struct(value: &syn::ExprStruct) -> bool {
+    fn is_constant(expr: &syn::Expr) -> bool {
+        match *expr {
+            syn::Expr::Lit(..) | syn::Expr::Path(..) => true,
+            syn::Expr::Binary(ref expr) => is_constant(&expr.left) && is_constant(&expr.right),
+            syn::Expr::Unary(ref expr) => is_constant(&expr.expr),
+            syn::Expr::Paren(ref expr) => is_constant(&expr.expr),
+            syn::Expr::Cast(ref expr) => is_constant(&expr.expr),
+            syn::Expr::Struct(ref value) => is_constant_struct(value),
+            _ => false,
+        }
+    }
+    value.rest.is_none() && value.fields.iter().all(|field| is_constant(&field.expr))
+}
+
+/// The value of `#[derive(Default)]` for a struct with named fields, if they
+/// are all of primitive types, whose default is zero or `false`.
+fn derived_default_value(item: &syn::ItemStruct) -> Option<syn::Expr> {
+    let derives_default = item.attrs.iter().any(|attr| {
+        attr.path.is_ident("derive")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .map_or(false, |paths| {
+                    paths
+                        .iter()
+                        .any(|path| path.segments.last().map_or(false, |s| s.ident == "Default"))
+                })
+    });
+    if !derives_default {
+        return None;
+    }
+    let fields = match item.fields {
+        syn::Fields::Named(ref fields) => &fields.named,
+        _ => return None,
+    };
+    let mut values = Vec::new();
+    for field in fields {
+        let value: syn::Expr = match Type::load(&field.ty) {
+            Ok(Some(Type::Primitive(PrimitiveType::Bool))) => syn::parse_quote!(false),
+            Ok(Some(Type::Primitive(PrimitiveType::Float | PrimitiveType::Double))) => {
+                syn::parse_quote!(0.0)
+            }
+            Ok(Some(Type::Primitive(PrimitiveType::Integer { zeroable: true, .. }))) => {
+                syn::parse_quote!(0)
+            }
+            _ => return None,
+        };
+        let name = field.ident.as_ref()?;
+        values.push(quote::quote!(#name: #value));
+    }
+    Some(syn::parse_quote!(Self { #(#values),* }))
+}
+
+/// The name of the method of the operator trait implemented by `item_impl`,
+/// if it's one of the traits of `derive_ops` taking `Self` as the right-hand
+/// side, as in `impl BitOr for Flags`.
+fn operator_trait(item_impl: &syn::ItemImpl, type_name: &syn::Ident) -> Option<String> {
+    let (_, ref trait_path, _) = *item_impl.trait_.as_ref()?;
+    let segment = trait_path.segments.last()?;
+    let op = segment.ident.unraw().to_string().to_lowercase();
+    cxx_operator(&op)?;
+
+    let is_self = |ty: &syn::Type| match *ty {
+        syn::Type::Path(ref path) => path
+            .path
+            .get_ident()
+            .map_or(false, |ident| ident == "Self" || ident == type_name),
+        _ => false,
+    };
+    match segment.arguments {
+        syn::PathArguments::None => Some(op),
+        syn::PathArguments::AngleBracketed(ref args) => match args.args.iter().next() {
+            Some(syn::GenericArgument::Type(ref ty)) if args.args.len() == 1 && is_self(ty) => {
+                Some(op)
+            }
+            _ => None,
+        },
+        syn::PathArguments::Parenthesized(..) => None,
+    }
+}
@@ -0,0 +1,32 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::fmt;
+
+use crate::bindgen::library::Library;
+
+/// A user supplied transformation of the parsed items.
+///
+/// Passes are registered with `Builder::with_ir_pass` and run in registration
+/// order once all sources have been parsed, before cbindgen does its own
+/// processing (annotation transfer, monomorphization, exclusion, renaming and
+/// sorting) and before anything is written.
+pub trait IrPass: Send + Sync {
+    fn run(&self, library: &mut Library);
+}
+
+impl<F> IrPass for F
+where
+    F: Fn(&mut Library) + Send + Sync,
+{
+    fn run(&self, library: &mut Library) {
+        self(library)
+    }
+}
+
+impl fmt::Debug for dyn IrPass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("IrPass")
+    }
+}
@@ -0,0 +1,211 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::{self, Write};
+
+use crate::bindgen::abi::AbiSnapshot;
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::Language;
+use crate::bindgen::ir::{Enum, Item, ItemContainer, VariantBody};
+
+/// The part of the printers which doesn't depend on the bindings, reading
+/// `TAGGED_ENUMS` and `OPTION_LIKE`.
+const PRINTERS: &str = r#"
+
+def _member(val, path, get):
+    for name in path:
+        val = get(val, name)
+    return val
+
+
+def _type_name(name):
+    for keyword in ("struct ", "union "):
+        if name.startswith(keyword):
+            return name[len(keyword):]
+    return name
+
+
+try:
+    import gdb
+    import gdb.printing
+    import gdb.types
+except ImportError:
+    gdb = None
+
+if gdb is not None:
+    class TaggedEnumPrinter(object):
+        def __init__(self, val, name):
+            self.val = val
+            self.tag = int(val["tag"])
+            self.variant = TAGGED_ENUMS[name].get(self.tag)
+            self.option_like = name in OPTION_LIKE
+
+        def to_string(self):
+            if self.variant is None:
+                return "<unknown tag %d>" % self.tag
+            name, fields = self.variant
+            if self.option_like and fields:
+                return _member(self.val, fields[0][1], lambda val, name: val[name])
+            return name
+
+        def children(self):
+            if self.variant is None or self.option_like:
+                return
+            for label, path in self.variant[1]:
+                yield label, _member(self.val, path, lambda val, name: val[name])
+
+    def lookup_tagged_enum(val):
+        name = gdb.types.get_basic_type(val.type).tag
+        if name is None or name not in TAGGED_ENUMS:
+            return None
+        return TaggedEnumPrinter(val, name)
+
+    gdb.printing.register_pretty_printer(gdb.current_objfile(), lookup_tagged_enum)
+
+
+def tagged_enum_summary(valobj, internal_dict):
+    name = _type_name(valobj.GetType().GetCanonicalType().GetName())
+    tag = valobj.GetChildMemberWithName("tag").GetValueAsSigned()
+    variant = TAGGED_ENUMS[name].get(tag)
+    if variant is None:
+        return "<unknown tag %d>" % tag
+
+    def show(path):
+        val = _member(valobj, path, lambda val, name: val.GetChildMemberWithName(name))
+        return val.GetSummary() or val.GetValue() or ""
+
+    variant_name, fields = variant
+    if name in OPTION_LIKE and fields:
+        return show(fields[0][1])
+    if not fields:
+        return variant_name
+    return "%s { %s }" % (variant_name, ", ".join("%s: %s" % (label, show(path)) for label, path in fields))
+
+
+def __lldb_init_module(debugger, internal_dict):
+    for name in TAGGED_ENUMS:
+        debugger.HandleCommand(
+            'type summary add -F %s.tagged_enum_summary "%s"' % (__name__, name))
+"#;
+
+/// Writes a Python file with GDB pretty-printers and LLDB summaries for the
+/// enums with data of the bindings, which show the active variant and its
+/// fields rather than the raw tag and union.
+///
+/// The variants are told apart by the value of the tag, so variants with a
+/// discriminant which isn't an integer literal are printed as unknown.
+/// Enums with an empty variant and a variant with a single field, like
+/// `Option`, are printed as the name of the empty variant or as the field.
+pub(crate) fn write_pretty_printers<F: Write>(bindings: &Bindings, mut out: F) -> io::Result<()> {
+    let config = &bindings.config;
+    let namespace: String = match config.language {
+        Language::Cxx => bindings
+            .all_namespaces()
+            .iter()
+            .map(|namespace| format!("{}::", namespace))
+            .collect(),
+        _ => String::new(),
+    };
+    let abi = AbiSnapshot::new(bindings);
+
+    writeln!(
+        out,
+        "# Pretty-printers for the enums with data of the bindings, generated by cbindgen."
+    )?;
+    writeln!(out, "#")?;
+    writeln!(
+        out,
+        "# Load with `source` in GDB, or `command script import` in LLDB."
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "# The enums by name, with their variants by tag, and the fields of these."
+    )?;
+    writeln!(out, "TAGGED_ENUMS = {{")?;
+    let mut option_like = Vec::new();
+    for item in &bindings.items {
+        let x = match *item {
+            ItemContainer::Enum(ref x) if x.tag.is_some() && x.generic_params.is_empty() => x,
+            _ => continue,
+        };
+        let name = format!("{}{}", namespace, x.export_name());
+        writeln!(out, "    \"{}\": {{", name)?;
+        for (variant, spelled) in x.variants.iter().zip(&abi.enums[x.export_name()].variants) {
+            let tag = match spelled.value.parse::<i64>() {
+                Ok(tag) => tag,
+                Err(_) => continue,
+            };
+            let fields: Vec<_> = variant_fields(&variant.body)
+                .into_iter()
+                .map(|(label, path)| {
+                    let path: Vec<_> = path.iter().map(|name| format!("\"{}\"", name)).collect();
+                    // A tuple of one element needs a trailing comma.
+                    let comma = if path.len() == 1 { "," } else { "" };
+                    format!("(\"{}\", ({}{}))", label, path.join(", "), comma)
+                })
+                .collect();
+            writeln!(
+                out,
+                "        {}: (\"{}\", [{}]),",
+                tag,
+                variant.export_name,
+                fields.join(", ")
+            )?;
+        }
+        writeln!(out, "    }},")?;
+        if is_option_like(x) {
+            option_like.push(name);
+        }
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "# The enums shown as their only field, or as the name of their empty variant."
+    )?;
+    let option_like: Vec<_> = option_like
+        .iter()
+        .map(|name| format!("\"{}\"", name))
+        .collect();
+    writeln!(out, "OPTION_LIKE = set([{}])", option_like.join(", "))?;
+    write!(out, "{}", PRINTERS)
+}
+
+/// The fields of a variant, with the path of members leading to them from
+/// the enum. Single field tuple variants which are inlined are a member of
+/// the enum themselves, and the tag of variants with their own is left out.
+fn variant_fields(body: &VariantBody) -> Vec<(String, Vec<String>)> {
+    match *body {
+        VariantBody::Empty(..) => Vec::new(),
+        VariantBody::Body {
+            ref name,
+            ref body,
+            inline,
+            ..
+        } => {
+            let fields = &body.fields[body.has_tag_field as usize..];
+            if inline {
+                return vec![("_0".to_owned(), vec![name.clone()])];
+            }
+            fields
+                .iter()
+                .map(|field| (field.name.clone(), vec![name.clone(), field.name.clone()]))
+                .collect()
+        }
+    }
+}
+
+fn is_option_like(x: &Enum) -> bool {
+    let mut empty = 0;
+    let mut single = 0;
+    for variant in &x.variants {
+        match variant_fields(&variant.body).len() {
+            0 => empty += 1,
+            1 => single += 1,
+            _ => return false,
+        }
+    }
+    x.variants.len() == 2 && empty == 1 && single == 1
+}
@@ -0,0 +1,94 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::bindgen::ir::{Path, SourceLocation};
+
+/// An item left out of the bindings, or written as an opaque type, along
+/// with why.
+///
+/// The report is written with `--emit report`, to find what's missing from
+/// the bindings without going through the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedItem {
+    /// The path of the item, like `my_crate::Foo`, or the type as it's
+    /// referred to for `SkippedKind::Type`.
+    pub name: String,
+    pub kind: SkippedKind,
+    pub reason: String,
+    /// Where the item was declared, as `file:line`, if it's known.
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkippedKind {
+    Function,
+    Constant,
+    Static,
+    /// A struct, enum, union or type alias which is only written as an
+    /// opaque type, as it can't be represented in C.
+    Opaque,
+    /// A type referred to by the bindings which couldn't be found, or a
+    /// generic type which couldn't be instantiated.
+    Type,
+}
+
+impl fmt::Display for SkippedKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SkippedKind::Function => write!(f, "function"),
+            SkippedKind::Constant => write!(f, "constant"),
+            SkippedKind::Static => write!(f, "static"),
+            SkippedKind::Opaque => write!(f, "opaque"),
+            SkippedKind::Type => write!(f, "type"),
+        }
+    }
+}
+
+impl SkippedItem {
+    pub(crate) fn new(
+        name: String,
+        kind: SkippedKind,
+        reason: impl fmt::Display,
+        location: Option<SourceLocation>,
+    ) -> SkippedItem {
+        SkippedItem {
+            name,
+            kind,
+            reason: reason.to_string(),
+            location: location.map(|location| location.to_string()),
+        }
+    }
+
+    /// The name of the item in the bindings, without the crate, to match it
+    /// with the items written.
+    fn item_name(&self) -> &str {
+        self.name.rsplit("::").next().unwrap_or(&self.name)
+    }
+
+    /// Whether this is the opaque item `path`.
+    pub(crate) fn is_opaque(&self, path: &Path) -> bool {
+        self.kind == SkippedKind::Opaque && self.item_name() == path.name()
+    }
+}
+
+/// Writes the skipped items as a plain list, one
+/// `location<TAB>kind<TAB>name<TAB>reason` line per item, with `-` for
+/// unknown locations.
+pub(crate) fn write_skipped_list<F: Write>(mut out: F, skipped: &[SkippedItem]) -> io::Result<()> {
+    for item in skipped {
+        writeln!(
+            out,
+            "{}\t{}\t{}\t{}",
+            item.location.as_deref().unwrap_or("-"),
+            item.kind,
+            item.name,
+            item.reason
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,215 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::io::Write;
+
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{Config, Language, Layout};
+use crate::bindgen::ir::{
+    Cfg, ConditionWrite, Enum, Function, Item, ItemContainer, Path, PrimitiveType, ToCondition,
+    Type,
+};
+use crate::bindgen::writer::SourceWriter;
+
+/// A function which the bindings define, rather than only declare.
+///
+/// Helpers are defined `static inline` in the header, unless `shim.path` is
+/// set, in which case the header only declares them, and they're defined in
+/// the shim source file written next to it.
+pub(crate) enum Helper<'a> {
+    /// `const char *{Enum}_to_string({Enum} value)`, for `derive-to-string`.
+    EnumToString(&'a Enum),
+    /// A wrapper of a function annotated with `null-check`, checking its
+    /// pointer arguments before calling it.
+    NullCheck(&'a Function),
+}
+
+impl<'a> Helper<'a> {
+    /// The helpers of the given items and functions, in order.
+    pub fn collect(
+        config: &Config,
+        items: &'a [ItemContainer],
+        functions: &'a [Function],
+    ) -> Vec<Helper<'a>> {
+        if config.language == Language::Cython {
+            return Vec::new();
+        }
+        let enums = items.iter().filter_map(|item| match *item {
+            ItemContainer::Enum(ref x)
+                if x.tag.is_none() && config.enumeration.derive_to_string(&x.annotations) =>
+            {
+                Some(Helper::EnumToString(x))
+            }
+            _ => None,
+        });
+        let null_checks = functions
+            .iter()
+            .filter(|f| null_check(f).is_some())
+            .filter(|f| {
+                if f.args.iter().any(|arg| arg.name.is_none()) {
+                    warn!(
+                        "Can't generate a null-checking wrapper for {}, which has unnamed arguments.",
+                        f.path.name()
+                    );
+                    return false;
+                }
+                true
+            })
+            .map(Helper::NullCheck);
+        enums.chain(null_checks).collect()
+    }
+
+    /// Whether the definition of the helper uses `assert`.
+    pub fn needs_assert(&self) -> bool {
+        match *self {
+            Helper::EnumToString(..) => false,
+            Helper::NullCheck(f) => null_check(f) == Some(None),
+        }
+    }
+
+    fn cfg(&self) -> &Option<Cfg> {
+        match *self {
+            Helper::EnumToString(x) => &x.cfg,
+            Helper::NullCheck(f) => &f.cfg,
+        }
+    }
+
+    fn write_signature<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        match *self {
+            Helper::EnumToString(x) => {
+                let name = x.export_name();
+                // Without a typedef, C needs the `enum` keyword.
+                let needs_keyword = config.language == Language::C
+                    && x.repr.ty.is_none()
+                    && !config.style.generate_typedef();
+                let keyword = if needs_keyword { "enum " } else { "" };
+                write!(
+                    out,
+                    "const char *{}_to_string({}{} value)",
+                    name, keyword, name
+                );
+            }
+            Helper::NullCheck(f) => {
+                let mut wrapper = f.clone();
+                wrapper.path = Path::new(format!(
+                    "{}{}",
+                    f.path.name(),
+                    config.function.null_check_suffix
+                ));
+                cdecl::write_func(out, &wrapper, Layout::Horizontal, config);
+            }
+        }
+    }
+
+    fn write_body<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        match *self {
+            Helper::EnumToString(x) => {
+                let qualifier = if config.language == Language::Cxx
+                    && config.enumeration.enum_class(&x.annotations)
+                {
+                    format!("{}::", x.export_name())
+                } else {
+                    String::new()
+                };
+                out.write("switch (value)");
+                out.open_brace();
+                for variant in &x.variants {
+                    let condition = variant.cfg.to_condition(config);
+                    condition.write_before(config, out);
+                    write!(
+                        out,
+                        "case {}{}: return \"{}\";",
+                        qualifier, variant.export_name, variant.export_name
+                    );
+                    condition.write_after(config, out);
+                    out.new_line();
+                }
+                out.write("default: return NULL;");
+                out.close_brace(false);
+            }
+            Helper::NullCheck(f) => {
+                let returns_void = f.ret == Type::Primitive(PrimitiveType::Void);
+                // C++ references can't be null.
+                let pointers: Vec<&str> = f
+                    .args
+                    .iter()
+                    .filter(|arg| matches!(arg.ty, Type::Ptr { is_ref: false, .. }))
+                    .filter_map(|arg| arg.name.as_deref())
+                    .collect();
+                match null_check(f).unwrap() {
+                    None => {
+                        for name in &pointers {
+                            write!(out, "assert({} != NULL);", name);
+                            out.new_line();
+                        }
+                    }
+                    Some(error) if !pointers.is_empty() => {
+                        let conditions: Vec<String> =
+                            pointers.iter().map(|x| format!("{} == NULL", x)).collect();
+                        write!(out, "if ({})", conditions.join(" || "));
+                        out.open_brace();
+                        if returns_void {
+                            out.write("return;");
+                        } else {
+                            write!(out, "return {};", error);
+                        }
+                        out.close_brace(false);
+                        out.new_line();
+                    }
+                    Some(_) => {}
+                }
+                let args: Vec<&str> = f.args.iter().filter_map(|x| x.name.as_deref()).collect();
+                if !returns_void {
+                    out.write("return ");
+                }
+                write!(out, "{}({});", f.path.name(), args.join(", "));
+            }
+        }
+    }
+
+    /// Writes the declaration of the helper, for when it's defined in the
+    /// shim source file.
+    pub fn write_declaration<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = self.cfg().to_condition(config);
+        condition.write_before(config, out);
+        if let Some(extern_c) = config.extern_c_item() {
+            write!(out, "{} ", extern_c);
+        }
+        self.write_signature(config, out);
+        out.write(";");
+        condition.write_after(config, out);
+    }
+
+    /// Writes the definition of the helper, `static inline` when it's
+    /// defined in the header.
+    pub fn write_definition<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        in_header: bool,
+    ) {
+        let condition = self.cfg().to_condition(config);
+        condition.write_before(config, out);
+        if in_header {
+            out.write("static inline ");
+        }
+        self.write_signature(config, out);
+        out.open_brace();
+        self.write_body(config, out);
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+}
+
+/// How the wrapper of a function annotated with `null-check` handles a null
+/// pointer: `Some(None)` asserts, and `Some(Some(error))` returns the error.
+fn null_check(f: &Function) -> Option<Option<String>> {
+    if f.annotations.bool("null-check") == Some(true) {
+        return Some(None);
+    }
+    match f.annotations.atom("null-check") {
+        Some(Some(error)) => Some(Some(error)),
+        _ => None,
+    }
+}
@@ -0,0 +1,358 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+
+/// A symbol the library built from the crate is expected to export, as
+/// declared by the bindings.
+///
+/// The manifest is written with `--emit symbols`, to check the symbols of
+/// the built library (with `nm` for example) against the header.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The declaration of the symbol as it's written in the header, on one
+    /// line and without the trailing `;`.
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolKind {
+    Function,
+    Static,
+}
+
+impl fmt::Display for SymbolKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SymbolKind::Function => write!(f, "function"),
+            SymbolKind::Static => write!(f, "static"),
+        }
+    }
+}
+
+impl Symbol {
+    /// Collapses a declaration written by a backend to a single line, leaving
+    /// out the preprocessor lines around it, like `#pragma weak`.
+    pub(crate) fn new(name: &str, kind: SymbolKind, declaration: &str) -> Symbol {
+        let lines: Vec<_> = declaration
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        Symbol {
+            name: name.to_owned(),
+            kind,
+            signature: lines.join(" ").trim_end_matches(';').to_owned(),
+        }
+    }
+}
+
+/// Writes the symbols as a plain list, one `kind<TAB>name<TAB>signature`
+/// line per symbol.
+pub(crate) fn write_symbol_list<F: Write>(mut out: F, symbols: &[Symbol]) -> io::Result<()> {
+    for symbol in symbols {
+        writeln!(
+            out,
+            "{}\t{}\t{}",
+            symbol.kind, symbol.name, symbol.signature
+        )?;
+    }
+    Ok(())
+}
+
+/// The differences between the symbols declared by the bindings and those
+/// a built library exports, found by `Bindings::check_library`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LibraryCheck {
+    /// The symbols declared by the bindings which the library doesn't export.
+    pub missing: Vec<Symbol>,
+    /// The symbols exported by the library which the bindings don't declare.
+    pub undeclared: Vec<String>,
+}
+
+impl LibraryCheck {
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.undeclared.is_empty()
+    }
+}
+
+pub(crate) fn check_library(symbols: Vec<Symbol>, path: &Path) -> io::Result<LibraryCheck> {
+    let mut exported = library_symbols(path)?;
+    let mut check = LibraryCheck::default();
+    for symbol in symbols {
+        let found = match symbol.kind {
+            SymbolKind::Function => exported.functions.remove(&symbol.name),
+            SymbolKind::Static => exported.statics.remove(&symbol.name),
+        };
+        if !found {
+            check.missing.push(symbol);
+        }
+    }
+    check.undeclared = exported
+        .functions
+        .into_iter()
+        .chain(exported.statics)
+        .collect();
+    check.undeclared.sort();
+    Ok(check)
+}
+
+/// The global symbols defined by a library, by the kind of symbol the
+/// bindings could declare for them.
+#[derive(Debug, Default)]
+struct ExportedSymbols {
+    functions: BTreeSet<String>,
+    statics: BTreeSet<String>,
+}
+
+/// The crates of the Rust standard library, which are linked into static
+/// libraries along with the crate. Their members are named after the crate,
+/// like `std-0123456789abcdef.std.0123456789abcdef-cgu.0.rcgu.o`.
+const STD_CRATES: &[&str] = &[
+    "addr2line",
+    "adler",
+    "adler2",
+    "alloc",
+    "cfg_if",
+    "compiler_builtins",
+    "core",
+    "gimli",
+    "hashbrown",
+    "libc",
+    "memchr",
+    "miniz_oxide",
+    "object",
+    "panic_abort",
+    "panic_unwind",
+    "profiler_builtins",
+    "rustc_demangle",
+    "rustc_std_workspace_alloc",
+    "rustc_std_workspace_core",
+    "rustc_std_workspace_std",
+    "std",
+    "std_detect",
+    "unwind",
+];
+
+/// Whether an archive member comes from the standard library rather than the
+/// crate: either one of its crates, or one of the C objects compiler-builtins
+/// bundles, which are named `0123456789abcdef-muldc3.o`.
+fn is_std_member(member: &str) -> bool {
+    let (prefix, _) = match member.split_once('-') {
+        Some(split) => split,
+        None => return false,
+    };
+    STD_CRATES.contains(&prefix)
+        || (prefix.len() == 16 && prefix.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Lists the global functions and data defined by a library with `nm`, or
+/// the program in the `NM` environment variable.
+///
+/// The symbols starting with an underscore are left out: they're reserved,
+/// added by the toolchain like `_init`, or mangled Rust symbols, so the
+/// bindings can't declare them. So are weak and absolute symbols, like the
+/// version nodes of a version script, and in static libraries the symbols of
+/// the standard library. Versioned names like `foo@@VERS_1` are reduced to
+/// the name of the symbol.
+fn library_symbols(path: &Path) -> io::Result<ExportedSymbols> {
+    let nm = env::var("NM").unwrap_or_else(|_| "nm".to_owned());
+    let output = Command::new(&nm)
+        .args(["-g", "--defined-only", "-P"])
+        .arg(path)
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "{} failed: {}",
+                nm,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+
+    // Mach-O prefixes C symbols with an underscore, in dynamic and static
+    // libraries alike. `nm` reports libraries it can't read.
+    let prefix = if fs::read(path).map_or(false, |library| is_mach_o(&library)) {
+        "_"
+    } else {
+        ""
+    };
+    Ok(read_nm_output(
+        &String::from_utf8_lossy(&output.stdout),
+        prefix,
+    ))
+}
+
+/// Whether `library` is a Mach-O library, a universal one, or an archive of
+/// Mach-O objects, whatever the platform cbindgen runs on.
+fn is_mach_o(library: &[u8]) -> bool {
+    const MAGICS: [[u8; 4]; 6] = [
+        [0xfe, 0xed, 0xfa, 0xce],
+        [0xfe, 0xed, 0xfa, 0xcf],
+        [0xce, 0xfa, 0xed, 0xfe],
+        [0xcf, 0xfa, 0xed, 0xfe],
+        [0xca, 0xfe, 0xba, 0xbe],
+        [0xbe, 0xba, 0xfe, 0xca],
+    ];
+    let is_object = |data: &[u8]| MAGICS.iter().any(|magic| data.starts_with(magic));
+
+    let mut rest = match library.strip_prefix(b"!<arch>\n") {
+        Some(members) => members,
+        None => return is_object(library),
+    };
+    // The members of an archive start with a 60 byte header, holding their
+    // name and size. BSD archives put long names, like `#1/20`, before the
+    // data. The first object decides.
+    while rest.len() >= 60 {
+        let (header, data) = rest.split_at(60);
+        let field = |range: std::ops::Range<usize>| {
+            std::str::from_utf8(&header[range]).unwrap_or("").trim()
+        };
+        let (name, size) = match field(48..58).parse::<usize>() {
+            Ok(size) if size <= data.len() => (field(0..16), size),
+            _ => return false,
+        };
+        let mut member = &data[..size];
+        let mut member_name = name;
+        if let Some(len) = name.strip_prefix("#1/") {
+            let len = len.parse::<usize>().unwrap_or(0).min(member.len());
+            member_name = std::str::from_utf8(&member[..len])
+                .unwrap_or("")
+                .trim_end_matches('\0');
+            member = &member[len..];
+        }
+        // Skip the symbol tables, and the table of long names of GNU archives.
+        if !member_name.starts_with("__.SYMDEF") && !matches!(member_name, "/" | "//" | "/SYM64/") {
+            return is_object(member);
+        }
+        rest = data.get(size + size % 2..).unwrap_or_default();
+    }
+    false
+}
+
+/// Reads the symbols listed by `nm -g --defined-only -P`, which start with
+/// `prefix` in the object format of the library.
+fn read_nm_output(stdout: &str, prefix: &str) -> ExportedSymbols {
+    let mut exported = ExportedSymbols::default();
+    let mut in_std_member = false;
+    for line in stdout.lines() {
+        let mut fields = line.split_whitespace();
+        let (name, kind) = match (fields.next(), fields.next()) {
+            (Some(name), Some(kind)) => (name, kind),
+            // Archives list their members as `libfoo.a[foo.o]:`, without a
+            // type.
+            (Some(member), None) => {
+                in_std_member = member
+                    .trim_end_matches(':')
+                    .trim_end_matches(']')
+                    .rsplit('[')
+                    .next()
+                    .map_or(false, is_std_member);
+                continue;
+            }
+            _ => continue,
+        };
+        if in_std_member {
+            continue;
+        }
+        let name = match name.strip_prefix(prefix) {
+            Some(name) => name.split('@').next().unwrap_or(name),
+            None => continue,
+        };
+        if name.is_empty() || name.starts_with('_') {
+            continue;
+        }
+        let symbols = match kind {
+            "T" => &mut exported.functions,
+            "D" | "B" | "R" | "G" | "S" => &mut exported.statics,
+            _ => continue,
+        };
+        symbols.insert(name.to_owned());
+    }
+    exported
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(symbols: &BTreeSet<String>) -> Vec<&str> {
+        symbols.iter().map(String::as_str).collect()
+    }
+
+    /// An archive holding a symbol table and an object starting with `magic`,
+    /// with a BSD long name if `long_name`.
+    fn archive(magic: &[u8], long_name: bool) -> Vec<u8> {
+        let mut archive = b"!<arch>\n".to_vec();
+        let mut member = |name: &str, data: &[u8]| {
+            archive.extend(format!("{:<48}{:<10}`\n", name, data.len()).bytes());
+            archive.extend(data);
+            if data.len() % 2 == 1 {
+                archive.push(b'\n');
+            }
+        };
+        member("__.SYMDEF SORTED", b"table");
+        if long_name {
+            member("#1/12", &[b"mylib.o\0\0\0\0\0", magic].concat());
+        } else {
+            member("mylib.o/", magic);
+        }
+        archive
+    }
+
+    #[test]
+    fn detect_mach_o() {
+        let mach_o = [0xcf, 0xfa, 0xed, 0xfe, 0x07, 0x00, 0x00, 0x01];
+        let elf = [0x7f, b'E', b'L', b'F', 0x02, 0x01, 0x01, 0x00];
+        assert!(is_mach_o(&mach_o));
+        assert!(is_mach_o(&[0xca, 0xfe, 0xba, 0xbe, 0x00]));
+        assert!(!is_mach_o(&elf));
+        assert!(is_mach_o(&archive(&mach_o, true)));
+        assert!(is_mach_o(&archive(&mach_o, false)));
+        assert!(!is_mach_o(&archive(&elf, false)));
+        assert!(!is_mach_o(b"!<arch>\n"));
+    }
+
+    #[test]
+    fn read_elf_nm_output() {
+        let output = "\
+libmylib.a[mylib-0123456789abcdef.mylib.0123456789abcdef-cgu.0.rcgu.o]:
+_ZN5mylib4util17h0123456789abcdefE T 0 0
+mylib_reset@@MYLIB_1 T 10 8
+mylib_version D 20 4
+MYLIB_1 A 0 0
+libmylib.a[std-0123456789abcdef.std.0123456789abcdef-cgu.0.rcgu.o]:
+rust_eh_personality T 0 0
+";
+        let exported = read_nm_output(output, "");
+        assert_eq!(names(&exported.functions), ["mylib_reset"]);
+        assert_eq!(names(&exported.statics), ["mylib_version"]);
+    }
+
+    #[test]
+    fn read_mach_o_nm_output() {
+        let output = "\
+libmylib.a[mylib-0123456789abcdef.mylib.0123456789abcdef-cgu.0.rcgu.o]:
+__ZN5mylib4util17h0123456789abcdefE T 0 0
+_mylib_reset T 10 8
+_mylib_version D 20 4
+libmylib.a[std-0123456789abcdef.std.0123456789abcdef-cgu.0.rcgu.o]:
+_rust_eh_personality T 0 0
+";
+        let exported = read_nm_output(output, "_");
+        assert_eq!(names(&exported.functions), ["mylib_reset"]);
+        assert_eq!(names(&exported.statics), ["mylib_version"]);
+    }
+}
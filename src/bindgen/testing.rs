@@ -0,0 +1,101 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Helpers for checking generated bindings against expectations committed
+//! along with a crate, the way cbindgen's own tests do.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::bindgen::builder::Builder;
+
+/// The environment variable which, when set, makes `assert_bindings_match`
+/// write the expectations instead of checking them.
+pub const BLESS_ENV_VAR: &str = "CBINDGEN_BLESS";
+
+/// Generates the bindings of `builder` and checks that they match the
+/// contents of `expectation`, panicking with a diff when they don't.
+///
+/// With `CBINDGEN_BLESS` set in the environment, the expectation is written
+/// instead, so that it can be updated after an intended change and reviewed
+/// with the rest of it.
+#[track_caller]
+pub fn assert_bindings_match<P: AsRef<Path>>(builder: Builder, expectation: P) {
+    let expectation = expectation.as_ref();
+    let bindings = match builder.generate() {
+        Ok(bindings) => bindings,
+        Err(err) => panic!("Couldn't generate bindings: {}", err),
+    };
+    let mut generated = Vec::new();
+    bindings.write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    if env::var_os(BLESS_ENV_VAR).is_some() {
+        if let Some(parent) = expectation.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        if let Err(err) = fs::write(expectation, generated) {
+            panic!("Couldn't write {}: {}", expectation.display(), err);
+        }
+        return;
+    }
+
+    let expected = match fs::read_to_string(expectation) {
+        Ok(expected) => expected,
+        Err(err) => panic!(
+            "Couldn't read {}: {}\nRun with {}=1 to create it.",
+            expectation.display(),
+            err,
+            BLESS_ENV_VAR
+        ),
+    };
+    if expected != generated {
+        panic!(
+            "The bindings don't match {}:\n{}\nRun with {}=1 to update it.",
+            expectation.display(),
+            diff(&expected, &generated),
+            BLESS_ENV_VAR
+        );
+    }
+}
+
+/// A rough diff of the lines which changed between the common beginning and
+/// end of `old` and `new`.
+fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "@@ line {} @@", prefix + 1);
+    for line in &old[prefix..old.len() - suffix] {
+        let _ = writeln!(out, "-{}", line);
+    }
+    for line in &new[prefix..new.len() - suffix] {
+        let _ = writeln!(out, "+{}", line);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_changed_lines() {
+        assert_eq!(
+            diff("a\nb\nc\nd\n", "a\nx\ny\nd\n"),
+            "@@ line 2 @@\n-b\n-c\n+x\n+y\n"
+        );
+        assert_eq!(diff("a\nb\n", "a\nb\nc\n"), "@@ line 3 @@\n+c\n");
+    }
+}
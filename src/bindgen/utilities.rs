@@ -6,6 +6,8 @@
 
 use syn::ext::IdentExt;
 
+use crate::bindgen::config::Visibility;
+
 pub trait IterHelpers: Iterator {
     fn try_skip_map<F, T, E>(&mut self, f: F) -> Result<Vec<T>, E>
     where
@@ -77,21 +79,20 @@ impl SynItemHelpers for syn::ItemStatic {
 }
 
 /// Returns whether this attribute causes us to skip at item. This basically
-/// checks for `#[cfg(test)]`, `#[test]`, `/// cbindgen::ignore` and
-/// variations thereof.
-fn is_skip_item_attr(attr: &syn::Meta) -> bool {
+/// checks for `/// cbindgen::ignore` and, with `skip_tests`, `#[cfg(test)]`,
+/// `#[cfg(all(test, ...))]` and `#[test]`, and variations thereof.
+fn is_skip_item_attr(attr: &syn::Meta, skip_tests: bool) -> bool {
     match *attr {
         syn::Meta::Path(ref path) => {
-            // TODO(emilio): It'd be great if rustc allowed us to use a syntax
-            // like `#[cbindgen::ignore]` or such.
-            path.is_ident("test")
+            (skip_tests && path.is_ident("test"))
+                || annotation_attr_name(path).map_or(false, |x| x == "ignore")
         }
         syn::Meta::List(ref list) => {
-            if !list.path.is_ident("cfg") {
+            if !list.path.is_ident("cfg") && !list.path.is_ident("all") {
                 return false;
             }
             list.nested.iter().any(|nested| match *nested {
-                syn::NestedMeta::Meta(ref meta) => is_skip_item_attr(meta),
+                syn::NestedMeta::Meta(ref meta) => is_skip_item_attr(meta, skip_tests),
                 syn::NestedMeta::Lit(..) => false,
             })
         }
@@ -110,6 +111,20 @@ fn is_skip_item_attr(attr: &syn::Meta) -> bool {
     }
 }
 
+/// Returns the annotation name of a `#[cbindgen::name]` attribute (or
+/// `#[cbindgen_macros::name]`, for people that don't rename the crate), with
+/// underscores turned into dashes.
+pub fn annotation_attr_name(path: &syn::Path) -> Option<String> {
+    if path.leading_colon.is_some() || path.segments.len() != 2 {
+        return None;
+    }
+    let krate = &path.segments[0].ident;
+    if krate != "cbindgen" && krate != "cbindgen_macros" {
+        return None;
+    }
+    Some(path.segments[1].ident.to_string().replace('_', "-"))
+}
+
 pub trait SynAttributeHelpers {
     /// Returns the list of attributes for an item.
     fn attrs(&self) -> &[syn::Attribute];
@@ -173,14 +188,27 @@ pub trait SynAttributeHelpers {
         self.has_attr_word("no_mangle")
     }
 
-    /// Sees whether we should skip parsing a given item.
-    fn should_skip_parsing(&self) -> bool {
+    /// Whether the item is marked `#[doc(hidden)]`.
+    fn is_doc_hidden(&self) -> bool {
+        self.attrs().iter().any(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(ref list)) if list.path.is_ident("doc") => {
+                list.nested.iter().any(|nested| {
+                    matches!(*nested, syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("hidden"))
+                })
+            }
+            _ => false,
+        })
+    }
+
+    /// Sees whether we should skip parsing a given item, where `skip_tests`
+    /// skips test-only items.
+    fn should_skip_parsing(&self, skip_tests: bool) -> bool {
         for attr in self.attrs() {
             let meta = match attr.parse_meta() {
                 Ok(attr) => attr,
                 Err(..) => return false,
             };
-            if is_skip_item_attr(&meta) {
+            if is_skip_item_attr(&meta, skip_tests) {
                 return true;
             }
         }
@@ -255,6 +283,33 @@ macro_rules! syn_item_match_helper {
     };
 }
 
+/// The visibility of an item, for the kinds of items which have one.
+pub fn item_visibility(item: &syn::Item) -> Option<&syn::Visibility> {
+    match *item {
+        syn::Item::Const(ref item) => Some(&item.vis),
+        syn::Item::Enum(ref item) => Some(&item.vis),
+        syn::Item::Fn(ref item) => Some(&item.vis),
+        syn::Item::Static(ref item) => Some(&item.vis),
+        syn::Item::Struct(ref item) => Some(&item.vis),
+        syn::Item::Type(ref item) => Some(&item.vis),
+        syn::Item::Union(ref item) => Some(&item.vis),
+        _ => None,
+    }
+}
+
+/// How visible `vis` makes an item.
+pub fn visibility(vis: &syn::Visibility) -> Visibility {
+    match *vis {
+        syn::Visibility::Public(..) => Visibility::Public,
+        syn::Visibility::Crate(..) => Visibility::Crate,
+        syn::Visibility::Restricted(ref restricted) if restricted.path.is_ident("self") => {
+            Visibility::Private
+        }
+        syn::Visibility::Restricted(..) => Visibility::Crate,
+        syn::Visibility::Inherited => Visibility::Private,
+    }
+}
+
 impl SynAttributeHelpers for syn::Item {
     fn attrs(&self) -> &[syn::Attribute] {
         syn_item_match_helper!(self =>
@@ -295,6 +350,7 @@ impl_syn_item_helper!(syn::ItemTraitAlias);
 /// Helper function for accessing Abi information
 pub trait SynAbiHelpers {
     fn is_c(&self) -> bool;
+    fn is_c_unwind(&self) -> bool;
     fn is_omitted(&self) -> bool;
 }
 
@@ -307,6 +363,12 @@ impl SynAbiHelpers for Option<syn::Abi> {
         }
         false
     }
+    fn is_c_unwind(&self) -> bool {
+        match *self {
+            Some(ref abi) => abi.is_c_unwind(),
+            None => false,
+        }
+    }
     fn is_omitted(&self) -> bool {
         if let Some(ref abi) = *self {
             abi.name.is_none()
@@ -324,6 +386,9 @@ impl SynAbiHelpers for syn::Abi {
             false
         }
     }
+    fn is_c_unwind(&self) -> bool {
+        matches!(self.name, Some(ref lit_string) if lit_string.value() == "C-unwind")
+    }
     fn is_omitted(&self) -> bool {
         self.name.is_none()
     }
@@ -0,0 +1,85 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use crate::bindgen::bindings::Bindings;
+use crate::bindgen::config::Language;
+use crate::bindgen::error::Error;
+
+/// Compiles a translation unit including the bindings with the C or C++
+/// compiler `cc` finds, to catch headers which don't compile before their
+/// consumers do.
+///
+/// Outside of build scripts, where Cargo doesn't set `TARGET`, the bindings
+/// are compiled for the host `rustc` reports.
+pub(crate) fn verify_compiles(bindings: &Bindings) -> Result<(), Error> {
+    let (header, source) = match bindings.config.language {
+        Language::C => ("bindings.h", "check.c"),
+        Language::Cxx => ("bindings.hpp", "check.cpp"),
+        Language::Cython => {
+            warn!("Can't check that Cython bindings compile.");
+            return Ok(());
+        }
+    };
+    let check_failed = |message: String| Error::CompileCheck(message);
+
+    let dir = tempfile::tempdir().map_err(|err| check_failed(err.to_string()))?;
+    let mut header_contents = Vec::new();
+    bindings.write(&mut header_contents);
+    fs::write(dir.path().join(header), header_contents)
+        .and_then(|_| {
+            fs::write(
+                dir.path().join(source),
+                format!("#include \"{}\"\n", header),
+            )
+        })
+        .map_err(|err| check_failed(err.to_string()))?;
+
+    let mut build = cc::Build::new();
+    build
+        .cpp(bindings.config.language == Language::Cxx)
+        .cargo_metadata(false)
+        .opt_level(0)
+        .debug(false);
+    if env::var_os("TARGET").is_none() {
+        let host = host_triple().ok_or_else(|| {
+            check_failed("couldn't find the host target with `rustc -vV`".to_owned())
+        })?;
+        build.target(&host).host(&host);
+    }
+    let compiler = build
+        .try_get_compiler()
+        .map_err(|err| check_failed(err.to_string()))?;
+
+    let mut command = compiler.to_command();
+    if compiler.is_like_msvc() {
+        command.arg("/Zs");
+    } else {
+        command.arg("-fsyntax-only");
+    }
+    let output = command
+        .arg(source)
+        .current_dir(dir.path())
+        .output()
+        .map_err(|err| check_failed(format!("couldn't run {:?}: {}", compiler.path(), err)))?;
+    if !output.status.success() {
+        return Err(check_failed(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+    Ok(())
+}
+
+fn host_triple() -> Option<String> {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let output = Command::new(rustc).arg("-vV").output().ok()?;
+    String::from_utf8(output.stdout)
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(str::to_owned)
+}
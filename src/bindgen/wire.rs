@@ -0,0 +1,284 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The structs whose layout depends on the byte order of the target, which
+//! `layout.wire_format` warns about for crates sending `repr(C)` structs over
+//! the wire as they are, and the helpers converting them to big-endian
+//! (network) byte order.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use crate::bindgen::config::{Config, Language};
+use crate::bindgen::ir::{
+    ConditionWrite, IntKind, ItemContainer, PrimitiveType, ToCondition, Type,
+};
+use crate::bindgen::writer::SourceWriter;
+
+/// How a value is converted to the wire byte order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Swap {
+    /// A multi-byte integer, whose bytes are reversed.
+    Integer,
+    /// A struct holding multi-byte integers, by its helper.
+    Struct(String),
+}
+
+/// A field of a struct which needs to be converted, inside `depth` arrays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WireField {
+    name: String,
+    swap: Swap,
+    depth: usize,
+}
+
+/// The types whose byte order depends on the target, by export name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WireFormat {
+    types: HashMap<String, Swap>,
+    structs: HashMap<String, Vec<WireField>>,
+}
+
+impl WireFormat {
+    pub(crate) fn new(items: &[ItemContainer]) -> WireFormat {
+        let mut wire = WireFormat::default();
+        // Types can refer to types coming after them, as with `type_order`,
+        // so look at them again until nothing new is found.
+        loop {
+            let found = wire.types.len();
+            let fields: usize = wire.structs.values().map(Vec::len).sum();
+            for item in items {
+                wire.add(item);
+            }
+            if wire.types.len() == found
+                && wire.structs.values().map(Vec::len).sum::<usize>() == fields
+            {
+                return wire;
+            }
+        }
+    }
+
+    fn add(&mut self, item: &ItemContainer) {
+        let name = item.deref().export_name().to_owned();
+        match *item {
+            ItemContainer::Typedef(ref x) if x.generic_params.is_empty() => {
+                if let Some((Swap::Integer, 0)) = self.swap(&x.aliased) {
+                    self.types.insert(name, Swap::Integer);
+                }
+            }
+            ItemContainer::Enum(ref x) if x.tag.is_none() && x.generic_params.is_empty() => {
+                // Enums without a type are an `int`.
+                if x.repr.ty.map_or(true, |ty| {
+                    !matches!(
+                        ty.to_primitive(),
+                        PrimitiveType::Integer {
+                            kind: IntKind::B8,
+                            ..
+                        }
+                    )
+                }) {
+                    self.types.insert(name, Swap::Integer);
+                }
+            }
+            ItemContainer::Struct(ref x) if x.is_transparent && x.generic_params.is_empty() => {
+                if let Some((Swap::Integer, 0)) = x.fields.first().and_then(|f| self.swap(&f.ty)) {
+                    self.types.insert(name, Swap::Integer);
+                }
+            }
+            ItemContainer::Struct(ref x) if !x.has_tag_field && x.generic_params.is_empty() => {
+                let fields: Vec<_> = x
+                    .fields
+                    .iter()
+                    .filter(|field| {
+                        field.cfg.is_none() && field.annotations.atom("bitfield").is_none()
+                    })
+                    .filter_map(|field| {
+                        let (swap, depth) = self.swap(&field.ty)?;
+                        Some(WireField {
+                            name: field.name.clone(),
+                            swap,
+                            depth,
+                        })
+                    })
+                    .collect();
+                if !fields.is_empty() {
+                    self.types.insert(name.clone(), Swap::Struct(name.clone()));
+                    self.structs.insert(name, fields);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// How values of `ty` are converted, and inside how many arrays.
+    fn swap(&self, ty: &Type) -> Option<(Swap, usize)> {
+        match *ty {
+            Type::Primitive(PrimitiveType::Integer { kind, .. }) if kind != IntKind::B8 => {
+                Some((Swap::Integer, 0))
+            }
+            Type::Primitive(PrimitiveType::Char32 | PrimitiveType::PtrDiffT) => {
+                Some((Swap::Integer, 0))
+            }
+            Type::Array(ref ty, _) => {
+                let (swap, depth) = self.swap(ty)?;
+                Some((swap, depth + 1))
+            }
+            Type::Path(ref generic) => Some((self.types.get(generic.export_name())?.clone(), 0)),
+            _ => None,
+        }
+    }
+
+    /// Warns about the structs whose layout depends on the byte order of the
+    /// target, with the fields responsible for it.
+    pub(crate) fn warn(&self, items: &[ItemContainer]) {
+        for item in items {
+            let item = item.deref();
+            let fields = match self.structs.get(item.export_name()) {
+                Some(fields) => fields,
+                None => continue,
+            };
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|field| format!("`{}`", field.name))
+                .collect();
+            let message = format!(
+                "`{}` is laid out in the byte order of the target, because of its fields {} \
+                 holding multi-byte integers",
+                item.export_name(),
+                fields.join(", ")
+            );
+            match item.source_location() {
+                Some(location) => warn!("{}: {} (wire_format)", location, message),
+                None => warn!("{} (wire_format)", message),
+            }
+        }
+    }
+
+    /// Whether `write_helper` writes anything for these bindings, which
+    /// need `write_swap_bytes` before them.
+    pub(crate) fn has_helpers(&self, config: &Config) -> bool {
+        config.layout.wire_format_helpers
+            && config.language != Language::Cython
+            && !self.structs.is_empty()
+    }
+
+    /// Writes the function reversing the bytes of integers on little-endian
+    /// targets, which the helpers of the structs use.
+    pub(crate) fn write_swap_bytes<F: Write>(&self, out: &mut SourceWriter<F>) {
+        out.write("#ifndef CBINDGEN_SWAP_WIRE_BYTES");
+        out.new_line();
+        out.write("#define CBINDGEN_SWAP_WIRE_BYTES");
+        out.new_line();
+        out.write("/* Converts the `count` integers of `size` bytes at `values` between the byte");
+        out.new_line();
+        out.write(" * order of the target and big-endian (network) byte order, in place. */");
+        out.new_line();
+        out.write(
+            "static inline void cbindgen_swap_wire_bytes(void *values, size_t size, size_t count)",
+        );
+        out.open_brace();
+        out.write("const unsigned int one = 1;");
+        out.new_line();
+        out.write("unsigned char *bytes = (unsigned char *)values;");
+        out.new_line();
+        out.write("size_t i, j;");
+        out.new_line();
+        out.write("if (*(const unsigned char *)&one == 0)");
+        out.open_brace();
+        out.write("return;");
+        out.close_brace(false);
+        out.new_line();
+        out.write("for (i = 0; i < count; i++, bytes += size)");
+        out.open_brace();
+        out.write("for (j = 0; j < size / 2; j++)");
+        out.open_brace();
+        out.write("unsigned char byte = bytes[j];");
+        out.new_line();
+        out.write("bytes[j] = bytes[size - 1 - j];");
+        out.new_line();
+        out.write("bytes[size - 1 - j] = byte;");
+        out.close_brace(false);
+        out.close_brace(false);
+        out.close_brace(false);
+        out.new_line();
+        out.write("#endif");
+    }
+
+    /// Writes the function converting the struct `item` between the byte
+    /// order of the target and big-endian byte order, after its definition,
+    /// under the same condition.
+    pub(crate) fn write_helper<F: Write>(&self, out: &mut SourceWriter<F>, item: &ItemContainer) {
+        let config = &out.bindings().config;
+        if !self.has_helpers(config) {
+            return;
+        }
+        let export_name = item.deref().export_name();
+        let fields = match (item, self.structs.get(export_name)) {
+            (ItemContainer::Struct(..), Some(fields)) => fields,
+            _ => return,
+        };
+        let tag = config.language == Language::C && !config.style.generate_typedef();
+        let name = if tag {
+            format!("struct {}", export_name)
+        } else {
+            export_name.to_owned()
+        };
+
+        let condition = item.deref().cfg().cloned().to_condition(config);
+        out.new_line();
+        out.new_line();
+        condition.write_before(config, out);
+        write!(
+            out,
+            "static inline void {}_swap_wire_bytes({} *value)",
+            export_name, name
+        );
+        out.open_brace();
+        if fields
+            .iter()
+            .any(|field| field.depth > 0 && field.swap != Swap::Integer)
+        {
+            out.write("size_t i;");
+            out.new_line();
+        }
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                out.new_line();
+            }
+            let value = format!("value->{}", field.name);
+            let element = format!("{}{}", value, "[0]".repeat(field.depth));
+            match field.swap {
+                Swap::Integer if field.depth == 0 => {
+                    write!(
+                        out,
+                        "cbindgen_swap_wire_bytes(&{}, sizeof({}), 1);",
+                        value, value
+                    );
+                }
+                Swap::Integer => {
+                    write!(
+                        out,
+                        "cbindgen_swap_wire_bytes(&{}, sizeof({}), sizeof({}) / sizeof({}));",
+                        value, element, value, element
+                    );
+                }
+                Swap::Struct(ref ty) if field.depth == 0 => {
+                    write!(out, "{}_swap_wire_bytes(&{});", ty, value);
+                }
+                Swap::Struct(ref ty) => {
+                    write!(
+                        out,
+                        "for (i = 0; i < sizeof({}) / sizeof({}); i++)",
+                        value, element
+                    );
+                    out.open_brace();
+                    write!(out, "{}_swap_wire_bytes(&(&{})[i]);", ty, element);
+                    out.close_brace(false);
+                }
+            }
+        }
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+}
@@ -32,9 +32,35 @@ impl<'a, 'b, F: Write> Write for InnerWriter<'a, 'b, F> {
             writer.line_length += writer.spaces();
         }
 
-        let written = writer.out.write(buf)?;
-        writer.line_length += written;
-        writer.max_line_length = cmp::max(writer.max_line_length, writer.line_length);
+        // Verbatim text, like the header, can contain line breaks, which need
+        // to use the configured line endings too.
+        let line_ending = writer.bindings.config.line_endings.as_str();
+        let written = if line_ending != "\n" && buf.contains(&b'\n') {
+            let mut lines = buf.split(|&b| b == b'\n').peekable();
+            while let Some(line) = lines.next() {
+                writer
+                    .out
+                    .write_all(line.strip_suffix(b"\r").unwrap_or(line))?;
+                if lines.peek().is_some() {
+                    writer.out.write_all(line_ending.as_bytes())?;
+                }
+            }
+            buf.len()
+        } else {
+            writer.out.write(buf)?
+        };
+        if let Some(&last) = buf[..written].last() {
+            writer.last_byte = Some(last);
+        }
+        // Measured source written at once can span several lines.
+        for (i, line) in buf[..written].split(|&b| b == b'\n').enumerate() {
+            if i != 0 {
+                writer.line_length = 0;
+                writer.line_number += 1;
+            }
+            writer.line_length += line.len();
+            writer.max_line_length = cmp::max(writer.max_line_length, writer.line_length);
+        }
         Ok(written)
     }
 
@@ -47,11 +73,59 @@ impl<'a, 'b, F: Write> Write for InnerWriter<'a, 'b, F> {
 pub struct SourceWriter<'a, F: Write> {
     out: F,
     bindings: &'a Bindings,
+    file_name: Option<&'a str>,
     spaces: Vec<usize>,
     line_started: bool,
     line_length: usize,
     line_number: usize,
     max_line_length: usize,
+    last_byte: Option<u8>,
+}
+
+/// The state of a `SourceWriter` to write source from, see
+/// `SourceWriter::fork`.
+pub struct Fork<'a> {
+    bindings: &'a Bindings,
+    file_name: Option<&'a str>,
+    spaces: Vec<usize>,
+    line_started: bool,
+    line_length: usize,
+    line_number: usize,
+    last_byte: Option<u8>,
+}
+
+impl<'a> Fork<'a> {
+    /// Writes source with `func` to a buffer, as if it was written by the
+    /// writer this was forked from.
+    pub fn write<T>(&self, func: T) -> Forked
+    where
+        T: FnOnce(&mut SourceWriter<'a, &mut dyn Write>),
+    {
+        let mut source = Vec::new();
+        let mut out = SourceWriter {
+            out: &mut source as &mut dyn Write,
+            bindings: self.bindings,
+            file_name: self.file_name,
+            spaces: self.spaces.clone(),
+            line_started: self.line_started,
+            line_length: self.line_length,
+            line_number: self.line_number,
+            max_line_length: self.line_length,
+            last_byte: self.last_byte,
+        };
+        func(&mut out);
+        let line_started = out.line_started;
+        Forked {
+            source,
+            line_started,
+        }
+    }
+}
+
+/// Source written from a `Fork`, to `append` to a writer.
+pub struct Forked {
+    source: Vec<u8>,
+    line_started: bool,
 }
 
 pub type MeasureWriter<'a> = SourceWriter<'a, &'a mut Vec<u8>>;
@@ -61,21 +135,54 @@ impl<'a, F: Write> SourceWriter<'a, F> {
         SourceWriter {
             out,
             bindings,
+            file_name: None,
             spaces: vec![0],
             line_started: false,
             line_length: 0,
             line_number: 1,
             max_line_length: 0,
+            last_byte: None,
         }
     }
 
-    pub fn bindings(&self) -> &Bindings {
+    pub fn bindings(&self) -> &'a Bindings {
         self.bindings
     }
 
+    /// Sets the name of the file being written, when it's known.
+    pub fn with_file_name(mut self, file_name: Option<&'a str>) -> Self {
+        self.file_name = file_name;
+        self
+    }
+
+    /// The name of the file being written, if it's known.
+    pub fn file_name(&self) -> Option<&'a str> {
+        self.file_name
+    }
+
+    /// The line the writer is currently on, starting at 1.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
     /// Takes a function that writes source and returns the maximum line length
     /// written.
     pub fn try_write<T>(&mut self, func: T, max_line_length: usize) -> bool
+    where
+        T: Fn(&mut MeasureWriter),
+    {
+        self.try_write_lines(func, max_line_length, false)
+    }
+
+    /// Like `try_write`, but also fails when the source spans several lines.
+    pub fn try_write_line<T>(&mut self, func: T, max_line_length: usize) -> bool
+    where
+        T: Fn(&mut MeasureWriter),
+    {
+        self.try_write_lines(func, max_line_length, true)
+    }
+
+    fn try_write_lines<T>(&mut self, func: T, max_line_length: usize, single_line: bool) -> bool
     where
         T: Fn(&mut MeasureWriter),
     {
@@ -88,15 +195,20 @@ impl<'a, F: Write> SourceWriter<'a, F> {
             let mut measurer = SourceWriter {
                 out: &mut buffer,
                 bindings: self.bindings,
+                file_name: self.file_name,
                 spaces: self.spaces.clone(),
                 line_started: self.line_started,
                 line_length: self.line_length,
                 line_number: self.line_number,
                 max_line_length: self.line_length,
+                last_byte: self.last_byte,
             };
 
             func(&mut measurer);
 
+            if single_line && measurer.line_number != self.line_number {
+                return false;
+            }
             measurer.max_line_length
         };
 
@@ -110,6 +222,32 @@ impl<'a, F: Write> SourceWriter<'a, F> {
         true
     }
 
+    /// Where this writer is at, to write source from there separately,
+    /// possibly on another thread, and `append` it later on.
+    pub fn fork(&self) -> Fork<'a> {
+        Fork {
+            bindings: self.bindings,
+            file_name: self.file_name,
+            spaces: self.spaces.clone(),
+            line_started: self.line_started,
+            line_length: self.line_length,
+            line_number: self.line_number,
+            last_byte: self.last_byte,
+        }
+    }
+
+    /// Writes source written from a `Fork` of a writer in the same state as
+    /// this one.
+    pub fn append(&mut self, forked: Forked) {
+        if forked.source.is_empty() {
+            return;
+        }
+        // The forked writer already wrote the indentation.
+        self.line_started = true;
+        InnerWriter(self).write_all(&forked.source).unwrap();
+        self.line_started = forked.line_started;
+    }
+
     fn spaces(&self) -> usize {
         *self.spaces.last().unwrap()
     }
@@ -148,6 +286,15 @@ impl<'a, F: Write> SourceWriter<'a, F> {
         self.line_started = false;
         self.line_length = 0;
         self.line_number += 1;
+        self.last_byte = Some(b'\n');
+    }
+
+    /// Ends the current line, unless nothing was written after the last line
+    /// ending.
+    pub fn end_line(&mut self) {
+        if !matches!(self.last_byte, None | Some(b'\n') | Some(b'\r')) {
+            self.new_line();
+        }
     }
 
     pub fn new_line_if_not_start(&mut self) {
@@ -209,11 +356,12 @@ impl<'a, F: Write> SourceWriter<'a, F> {
 
     pub fn write_horizontal_source_list<S: Source>(
         &mut self,
+        config: &Config,
         items: &[S],
         list_type: ListType<'_>,
     ) {
         for (i, item) in items.iter().enumerate() {
-            item.write(&self.bindings.config, self);
+            item.write(config, self);
 
             match list_type {
                 ListType::Join(text) => {
@@ -228,11 +376,16 @@ impl<'a, F: Write> SourceWriter<'a, F> {
         }
     }
 
-    pub fn write_vertical_source_list<S: Source>(&mut self, items: &[S], list_type: ListType<'_>) {
+    pub fn write_vertical_source_list<S: Source>(
+        &mut self,
+        config: &Config,
+        items: &[S],
+        list_type: ListType<'_>,
+    ) {
         let align_length = self.line_length_for_align();
         self.push_set_spaces(align_length);
         for (i, item) in items.iter().enumerate() {
-            item.write(&self.bindings.config, self);
+            item.write(config, self);
 
             match list_type {
                 ListType::Join(text) => {
@@ -0,0 +1,45 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The `Builder` methods customizing the bindings from code, which only make
+//! sense for library users and so aren't compiled into the command line tool.
+
+use std::sync::Arc;
+
+use crate::bindgen::LanguageBackend;
+use crate::bindgen::{Builder, Function, IrPass, Item, Library};
+
+impl Builder {
+    /// Registers a pass that can inspect and rewrite the parsed items before
+    /// the bindings are generated. Passes run in the order they were added.
+    pub fn with_ir_pass<P: IrPass + 'static>(mut self, pass: P) -> Builder {
+        self.ir_passes.push(Arc::new(pass));
+        self
+    }
+
+    /// Runs `callback` on every parsed function.
+    pub fn map_functions<F>(self, callback: F) -> Builder
+    where
+        F: Fn(&mut Function) + Send + Sync + 'static,
+    {
+        self.with_ir_pass(move |library: &mut Library| {
+            library.functions_mut().iter_mut().for_each(&callback)
+        })
+    }
+
+    /// Runs `callback` on every parsed item that isn't a function.
+    pub fn map_items<F>(self, callback: F) -> Builder
+    where
+        F: Fn(&mut dyn Item) + Send + Sync + 'static,
+    {
+        self.with_ir_pass(move |library: &mut Library| library.for_all_items_mut(&callback))
+    }
+
+    /// Uses `backend` instead of the built-in backend of `Config::language`
+    /// to write the bindings.
+    pub fn with_backend(mut self, backend: Box<dyn LanguageBackend>) -> Builder {
+        self.backend = Some(Arc::from(backend));
+        self
+    }
+}
@@ -15,14 +15,27 @@ extern crate syn;
 extern crate toml;
 
 mod bindgen;
+mod hooks;
 
 pub use crate::bindgen::*;
 
-use std::path::Path;
+/// The types making up the intermediate representation, as handed to a
+/// `LanguageBackend` or an `IrPass`, or exposed by `Bindings::ir`.
+pub mod ir {
+    pub use crate::bindgen::ir::{
+        AnnotationSet, AnnotationValue, Cfg, ConstExpr, Constant, Documentation, Enum, EnumVariant,
+        Field, Function, FunctionArgument, GenericArgument, GenericParam, GenericParamType,
+        GenericParams, GenericPath, IntKind, ItemContainer, Literal, OpaqueItem, Path,
+        PrimitiveType, Repr, ReprAlign, ReprStyle, ReprType, Static, Struct, Type, Typedef, Union,
+        VariantBody,
+    };
+}
+
+use std::path;
 
 /// A utility function for build scripts to generate bindings for a crate, using
 /// a `cbindgen.toml` if it exists.
-pub fn generate<P: AsRef<Path>>(crate_dir: P) -> Result<Bindings, Error> {
+pub fn generate<P: AsRef<path::Path>>(crate_dir: P) -> Result<Bindings, Error> {
     let config = Config::from_root_or_default(crate_dir.as_ref());
 
     generate_with_config(crate_dir, config)
@@ -30,7 +43,7 @@ pub fn generate<P: AsRef<Path>>(crate_dir: P) -> Result<Bindings, Error> {
 
 /// A utility function for build scripts to generate bindings for a crate with a
 /// custom config.
-pub fn generate_with_config<P: AsRef<Path>>(
+pub fn generate_with_config<P: AsRef<path::Path>>(
     crate_dir: P,
     config: Config,
 ) -> Result<Bindings, Error> {
@@ -3,8 +3,10 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::env;
+use std::fs::{self, File};
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process;
 use std::str::FromStr;
 
 extern crate clap;
@@ -25,7 +27,7 @@ use clap::{Arg, ArgMatches, Command};
 mod bindgen;
 mod logging;
 
-use crate::bindgen::{Bindings, Builder, Cargo, Config, Error, Profile, Style};
+use crate::bindgen::{AbiSnapshot, Bindings, Builder, Cargo, Config, Error, Profile, Style};
 
 fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     // We allow specifying a language to override the config default. This is
@@ -82,6 +84,76 @@ fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     }
 }
 
+/// Quotes `arg` as a single argument for the shell `run_hook` uses.
+fn shell_quote(arg: &str) -> String {
+    if cfg!(windows) {
+        // `"` can't be part of a Windows path.
+        format!("\"{}\"", arg)
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// Runs a command of `[hooks]` with the shell, exiting if it fails.
+fn run_hook(name: &str, command: &Option<String>, matches: &ArgMatches) {
+    let command = match *command {
+        Some(ref command) => command
+            .replace(
+                "{output}",
+                &shell_quote(matches.value_of("out").unwrap_or("")),
+            )
+            .replace(
+                "{depfile}",
+                &shell_quote(matches.value_of("depfile").unwrap_or("")),
+            ),
+        None => return,
+    };
+    info!("Running the {} hook: {}", name, command);
+    let status = if cfg!(windows) {
+        process::Command::new("cmd")
+            .arg("/C")
+            .arg(&command)
+            .status()
+    } else {
+        process::Command::new("sh").arg("-c").arg(&command).status()
+    };
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => {
+            error!("The {} hook `{}` failed: {}", name, command, status);
+            process::exit(1);
+        }
+        Err(err) => {
+            error!("Couldn't run the {} hook `{}`: {}", name, command, err);
+            process::exit(1);
+        }
+    }
+}
+
+fn load_expanded_bindings(path: &str, matches: &ArgMatches) -> Result<Bindings, Error> {
+    let mut config = match matches.value_of("config") {
+        Some(c) => Config::from_file(c).unwrap(),
+        None => Config::from_root_or_default(env::current_dir().unwrap()),
+    };
+
+    apply_config_overrides(&mut config, matches);
+    run_hook("pre_generate", &config.hooks.pre_generate, matches);
+
+    let builder = Builder::new().with_config(config);
+    let builder = if path == "-" {
+        builder.with_expanded_src_reader(io::stdin())
+    } else {
+        match File::open(path) {
+            Ok(file) => builder.with_expanded_src_reader(file),
+            Err(err) => {
+                error!("Couldn't open {}: {}", path, err);
+                process::exit(1);
+            }
+        }
+    };
+    builder.generate()
+}
+
 fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error> {
     // If a file is specified then we load it as a single source
     if !input.is_dir() {
@@ -96,6 +168,7 @@ fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error>
         };
 
         apply_config_overrides(&mut config, matches);
+        run_hook("pre_generate", &config.hooks.pre_generate, matches);
 
         return Builder::new()
             .with_config(config)
@@ -130,6 +203,7 @@ fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error>
     };
 
     apply_config_overrides(&mut config, matches);
+    run_hook("pre_generate", &config.hooks.pre_generate, matches);
 
     Builder::new()
         .with_config(config)
@@ -214,6 +288,21 @@ fn main() {
                 .required(false)
                 .index(1),
         )
+        .arg(
+            Arg::new("expanded-source")
+                .long("expanded-source")
+                .value_name("PATH")
+                .takes_value(true)
+                .conflicts_with("INPUT")
+                .help(
+                    "Generate bindings for the source of a crate whose macros have \
+                    already been expanded, like the output of `cargo expand`, read \
+                    from the given Path, or from stdin with `-`. It's parsed as a \
+                    single file without running cargo, so its modules must be inline. \
+                    The config is read from --config, or from the current directory."
+                )
+                .required(false),
+        )
         .arg(
             Arg::new("crate")
                 .long("crate")
@@ -289,15 +378,92 @@ fn main() {
                     This option is ignored if `--out` is missing."
                 )
         )
+        .arg(
+            Arg::new("markdown-docs")
+                .value_name("PATH")
+                .long("markdown-docs")
+                .takes_value(true)
+                .required(false)
+                .help("Also write a Markdown API reference of the exported items, \
+                    with their declarations and documentation, to the given Path."
+                )
+        )
+        .arg(
+            Arg::new("swig")
+                .value_name("PATH")
+                .long("swig")
+                .takes_value(true)
+                .required(false)
+                .help("Also write a SWIG interface file including the bindings to the \
+                    given Path, which needs `--out`."
+                )
+        )
+        .arg(
+            Arg::new("emit")
+                .value_names(&["KIND", "PATH"])
+                .long("emit")
+                .takes_value(true)
+                .number_of_values(2)
+                .required(false)
+                .help("Also write extra files of the given KIND to the given Path. \
+                    `fuzz-stubs` writes a libFuzzer target for each function taking a \
+                    buffer to the directory at Path, passing the fuzzer input to it, \
+                    and needs `--out`. \
+                    `symbols` writes the names, kinds and signatures of the exported \
+                    functions and globals to the file at Path, as JSON if it ends \
+                    with `.json`, and as tab-separated lines otherwise. \
+                    `report` writes the items left out of the bindings or made opaque, \
+                    and the types which couldn't be found, with the reason and source \
+                    location, to the file at Path, in the same formats as `symbols`. \
+                    `layout` writes the layout of the structs, unions and enums to the \
+                    file at Path, as JSON if it ends with `.json`, with the sizes and \
+                    offsets known from `layout.probe`, and otherwise as a C or C++ source \
+                    file defining a table of them computed by the compiler, which \
+                    needs `--out`. \
+                    `pretty-printers` writes a Python file with GDB pretty-printers and \
+                    LLDB summaries for the enums with data to the file at Path. \
+                    `rust-externs` writes a Rust file declaring the functions and globals \
+                    in `extern \"C\"` blocks to the file at Path, which checks that the \
+                    crate defines them with the same types when included in a test of it, \
+                    in `unsafe extern` blocks if `rust_externs.edition` is 2024 or later."
+                )
+        )
+        .arg(
+            Arg::new("abi-snapshot")
+                .value_name("PATH")
+                .long("abi-snapshot")
+                .takes_value(true)
+                .required(false)
+                .help("Also write a JSON snapshot of the ABI of the bindings (function \
+                    signatures and type layouts) to the given Path, to be used with \
+                    `--abi-check` by later versions."
+                )
+        )
+        .arg(
+            Arg::new("abi-check")
+                .value_name("PATH")
+                .long("abi-check")
+                .takes_value(true)
+                .required(false)
+                .help("Compare the bindings against the ABI snapshot at the given Path, \
+                    and fail without writing anything if they break code built against it: \
+                    removed symbols, changed signatures, or changed struct fields or enum values."
+                )
+        )
+        .arg(
+            Arg::new("check-lib")
+                .value_name("PATH")
+                .long("check-lib")
+                .takes_value(true)
+                .required(false)
+                .help("Compare the symbols exported by the built library at the given Path \
+                    with the functions and globals the bindings declare, and fail without \
+                    writing anything if some are missing from either. The symbols are read \
+                    with `nm`, or the program in the `NM` environment variable."
+                )
+        )
         .get_matches();
 
-    if !matches.is_present("out") && matches.is_present("verify") {
-        error!(
-            "Cannot verify bindings against `stdout`, please specify a file to compare against."
-        );
-        std::process::exit(2);
-    }
-
     // Initialize logging
     if matches.is_present("quiet") {
         logging::ErrorLogger::init().unwrap();
@@ -309,21 +475,202 @@ fn main() {
         }
     }
 
+    if let Some(mut emit) = matches.values_of("emit") {
+        let kind = emit.next().unwrap();
+        if ![
+            "fuzz-stubs",
+            "symbols",
+            "report",
+            "layout",
+            "pretty-printers",
+            "rust-externs",
+        ]
+        .contains(&kind)
+        {
+            error!("Unknown kind of files to emit: {}.", kind);
+            std::process::exit(2);
+        }
+        if kind == "fuzz-stubs" && !matches.is_present("out") {
+            error!("Cannot write fuzz targets without `--out`, as they include the header.");
+            std::process::exit(2);
+        }
+        let path = emit.next().unwrap();
+        if kind == "layout" && !path.ends_with(".json") && !matches.is_present("out") {
+            error!("Cannot write a layout table without `--out`, as it includes the header.");
+            std::process::exit(2);
+        }
+    }
+
+    if matches.is_present("swig") && !matches.is_present("out") {
+        error!("Cannot write a SWIG interface without `--out`, as it includes the header.");
+        std::process::exit(2);
+    }
+
+    if !matches.is_present("out") && matches.is_present("verify") {
+        error!(
+            "Cannot verify bindings against `stdout`, please specify a file to compare against."
+        );
+        std::process::exit(2);
+    }
+
     // Find the input directory
     let input = match matches.value_of("INPUT") {
         Some(input) => PathBuf::from(input),
         None => env::current_dir().unwrap(),
     };
 
-    let bindings = match load_bindings(&input, &matches) {
+    let (bindings, source) = match matches.value_of("expanded-source") {
+        Some(path) => (
+            load_expanded_bindings(path, &matches),
+            if path == "-" { "stdin" } else { path }.to_owned(),
+        ),
+        None => (load_bindings(&input, &matches), input.display().to_string()),
+    };
+    let bindings = match bindings {
         Ok(bindings) => bindings,
         Err(msg) => {
             error!("{}", msg);
-            error!("Couldn't generate bindings for {}.", input.display());
+            error!("Couldn't generate bindings for {}.", source);
             std::process::exit(1);
         }
     };
 
+    if let Some(path) = matches.value_of("abi-check") {
+        let old = fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|json| {
+                serde_json::from_str::<AbiSnapshot>(&json).map_err(|err| err.to_string())
+            });
+        let old = match old {
+            Ok(old) => old,
+            Err(err) => {
+                error!("Couldn't read the ABI snapshot {}: {}", path, err);
+                std::process::exit(1);
+            }
+        };
+        let changes = old.breaking_changes(&bindings.abi_snapshot());
+        if !changes.is_empty() {
+            for change in &changes {
+                error!("{}", change);
+            }
+            error!("The ABI isn't compatible with {}.", path);
+            std::process::exit(2);
+        }
+    }
+
+    if let Some(path) = matches.value_of("check-lib") {
+        let check = match bindings.check_library(path) {
+            Ok(check) => check,
+            Err(err) => {
+                error!("Couldn't read the symbols of {}: {}", path, err);
+                std::process::exit(1);
+            }
+        };
+        if !check.is_ok() {
+            for symbol in &check.missing {
+                error!(
+                    "{} `{}` is declared in the bindings but not exported by {}",
+                    symbol.kind, symbol.name, path
+                );
+            }
+            for name in &check.undeclared {
+                error!(
+                    "`{}` is exported by {} but not declared in the bindings",
+                    name, path
+                );
+            }
+            error!("The bindings don't match the symbols of {}.", path);
+            std::process::exit(2);
+        }
+    }
+
+    if let Some(path) = matches.value_of("abi-snapshot") {
+        let written = File::create(path).and_then(|file| {
+            serde_json::to_writer_pretty(file, &bindings.abi_snapshot()).map_err(io::Error::from)
+        });
+        if let Err(err) = written {
+            error!("Couldn't write the ABI snapshot to {}: {}", path, err);
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(path) = matches.value_of("markdown-docs") {
+        let written = File::create(path).and_then(|file| bindings.write_markdown_docs(file));
+        if let Err(err) = written {
+            error!(
+                "Couldn't write the Markdown documentation to {}: {}",
+                path, err
+            );
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(mut emit) = matches.values_of("emit") {
+        match emit.next() {
+            Some("symbols") => {
+                let path = emit.next().unwrap();
+                let written = File::create(path).and_then(|file| {
+                    if path.ends_with(".json") {
+                        serde_json::to_writer_pretty(file, &bindings.symbols())
+                            .map_err(io::Error::from)
+                    } else {
+                        bindings.write_symbols(file)
+                    }
+                });
+                if let Err(err) = written {
+                    error!("Couldn't write the symbols to {}: {}", path, err);
+                    std::process::exit(1);
+                }
+            }
+            Some("report") => {
+                let path = emit.next().unwrap();
+                let written = File::create(path).and_then(|file| {
+                    if path.ends_with(".json") {
+                        serde_json::to_writer_pretty(file, bindings.skipped())
+                            .map_err(io::Error::from)
+                    } else {
+                        bindings.write_skipped(file)
+                    }
+                });
+                if let Err(err) = written {
+                    error!("Couldn't write the report to {}: {}", path, err);
+                    std::process::exit(1);
+                }
+            }
+            Some("layout") => {
+                let path = emit.next().unwrap();
+                if path.ends_with(".json") {
+                    let written = File::create(path).and_then(|file| {
+                        serde_json::to_writer_pretty(file, &bindings.layout_descriptor())
+                            .map_err(io::Error::from)
+                    });
+                    if let Err(err) = written {
+                        error!("Couldn't write the layout descriptor to {}: {}", path, err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Some("pretty-printers") => {
+                let path = emit.next().unwrap();
+                let written =
+                    File::create(path).and_then(|file| bindings.write_pretty_printers(file));
+                if let Err(err) = written {
+                    error!("Couldn't write the pretty-printers to {}: {}", path, err);
+                    std::process::exit(1);
+                }
+            }
+            Some("rust-externs") => {
+                let path = emit.next().unwrap();
+                let written = File::create(path).and_then(|file| bindings.write_rust_externs(file));
+                if let Err(err) = written {
+                    error!("Couldn't write the Rust declarations to {}: {}", path, err);
+                    std::process::exit(1);
+                }
+            }
+            _ => {}
+        }
+    }
+
     // Write the bindings file
     match matches.value_of("out") {
         Some(file) => {
@@ -336,6 +683,38 @@ fn main() {
             if let Some(depfile) = matches.value_of("depfile") {
                 bindings.generate_depfile(file, depfile)
             }
+            if let Some(path) = matches.value_of("swig") {
+                let written =
+                    File::create(path).and_then(|out| bindings.write_swig_interface(out, file));
+                if let Err(err) = written {
+                    error!("Couldn't write the SWIG interface to {}: {}", path, err);
+                    std::process::exit(1);
+                }
+            }
+            if let Some(mut emit) = matches.values_of("emit") {
+                let (kind, dir) = (emit.next().unwrap(), emit.next().unwrap());
+                if kind == "fuzz-stubs" {
+                    if let Err(err) = bindings.write_fuzz_stubs(dir, file) {
+                        error!("Couldn't write the fuzz targets to {}: {}", dir, err);
+                        std::process::exit(1);
+                    }
+                }
+                if kind == "layout" && !dir.ends_with(".json") {
+                    let written =
+                        File::create(dir).and_then(|out| bindings.write_layout_table(out, file));
+                    if let Err(err) = written {
+                        error!("Couldn't write the layout table to {}: {}", dir, err);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            if !matches.is_present("verify") {
+                run_hook(
+                    "post_generate",
+                    &bindings.config.hooks.post_generate,
+                    &matches,
+                );
+            }
         }
         _ => {
             bindings.write(io::stdout());
@@ -0,0 +1,44 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Fast,
+    Slow,
+    Off = 10,
+    Idle,
+}
+
+#[no_mangle]
+pub extern "C" fn point_len(p: Point) -> f32 {}
+
+#[no_mangle]
+pub extern "C" fn set_mode(mode: Mode) {}
+
+#[no_mangle]
+pub extern "C" fn reset() {}
+
+#[repr(C, packed)]
+pub struct Header {
+    len: u16,
+    kind: u8,
+}
+
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+}
+
+pub struct Session;
+
+#[no_mangle]
+pub extern "C" fn header_len(header: *const Header) -> u16 {}
+
+#[no_mangle]
+pub extern "C" fn options_apply(options: *const Options) {}
+
+#[no_mangle]
+pub extern "C" fn session_open() -> *mut Session {}
@@ -0,0 +1,39 @@
+#[repr(C)]
+pub struct Point {
+    y: i32,
+    x: i32,
+    z: i32,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Slow,
+    Fast,
+    Off = 10,
+    Turbo = 20,
+}
+
+#[no_mangle]
+pub extern "C" fn point_len(p: Point) -> f64 {}
+
+#[no_mangle]
+pub extern "C" fn set_mode(mode: Mode) {}
+
+#[no_mangle]
+pub extern "C" fn set_speed(speed: u32) {}
+
+#[repr(C, align(4))]
+pub struct Header {
+    len: u16,
+    kind: u8,
+}
+
+pub struct Options {
+    verbose: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn header_len(header: *const Header) -> u16 {}
+
+#[no_mangle]
+pub extern "C" fn options_apply(options: *const Options) {}
@@ -0,0 +1,53 @@
+use cbindgen::{AbiSnapshot, Builder, Config, Language};
+
+fn snapshot(src: &str) -> AbiSnapshot {
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.layout.packed = Some("CBINDGEN_PACKED".to_owned());
+    config.layout.aligned_n = Some("CBINDGEN_ALIGNED".to_owned());
+    Builder::new()
+        .with_config(config)
+        .with_src(src)
+        .generate()
+        .unwrap()
+        .abi_snapshot()
+}
+
+#[test]
+fn abi_snapshot_roundtrips() {
+    let v1 = snapshot("tests/abi/v1.rs");
+    let json = serde_json::to_string(&v1).unwrap();
+    assert_eq!(serde_json::from_str::<AbiSnapshot>(&json).unwrap(), v1);
+    assert!(v1.breaking_changes(&v1).is_empty());
+
+    let values: Vec<_> = v1.enums["Mode"]
+        .variants
+        .iter()
+        .map(|v| v.value.as_str())
+        .collect();
+    assert_eq!(values, ["0", "1", "10", "11"]);
+}
+
+#[test]
+fn abi_breaking_changes() {
+    let changes = snapshot("tests/abi/v1.rs").breaking_changes(&snapshot("tests/abi/v2.rs"));
+    assert_eq!(
+        changes,
+        [
+            "function `point_len` changed from `float (struct Point)` to `double (struct Point)`",
+            "function `reset` was removed",
+            "function `session_open` was removed",
+            "struct `Header` changed its alignment from `packed` to `align(4)`",
+            "struct `Options` became opaque",
+            "struct `Point` has field `y` where `x` used to be",
+            "struct `Point` has field `x` where `y` used to be",
+            "struct `Point` gained field `z`, changing its size",
+            "variant `Fast` of enum `Mode` changed its value from `0` to `1`",
+            "variant `Slow` of enum `Mode` changed its value from `1` to `0`",
+            "variant `Idle` of enum `Mode` was removed",
+            "opaque type `Session` was removed",
+        ]
+    );
+}
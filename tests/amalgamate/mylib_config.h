@@ -0,0 +1,5 @@
+#pragma once
+
+#include "mylib_types.h"
+
+#define MYLIB_MAX_FLAGS 32
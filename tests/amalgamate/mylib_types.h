@@ -0,0 +1,6 @@
+#pragma once
+
+#include "mylib_config.h"
+#include <stdint.h>
+
+typedef uint32_t MyLibFlags;
@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+
+use cbindgen::{Bindings, Builder, Language, LibraryCheck};
+
+// The tests set `NM`, which is shared by the whole process.
+static NM: Mutex<()> = Mutex::new(());
+
+fn bindings() -> Bindings {
+    Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/rust/source_locations.rs")
+        .generate()
+        .unwrap()
+}
+
+/// Checks the bindings against a library for which `nm` lists `symbols`.
+#[cfg(unix)]
+fn check_with_nm(library: &str, symbols: &str) -> LibraryCheck {
+    use std::os::unix::fs::PermissionsExt;
+
+    let _lock = NM.lock().unwrap_or_else(|e| e.into_inner());
+    let dir = tempfile::tempdir().unwrap();
+    let listing = dir.path().join("symbols.txt");
+    fs::write(&listing, symbols).unwrap();
+    let nm = dir.path().join("nm");
+    fs::write(&nm, format!("#!/bin/sh\ncat '{}'\n", listing.display())).unwrap();
+    fs::set_permissions(&nm, fs::Permissions::from_mode(0o755)).unwrap();
+
+    std::env::set_var("NM", &nm);
+    let check = bindings().check_library(Path::new(library));
+    std::env::remove_var("NM");
+    check.unwrap()
+}
+
+#[test]
+fn check_lib() {
+    let bindings = bindings();
+
+    // A library exporting `area` and a function the bindings don't know
+    // about, but not `COUNTER`.
+    let dir = tempfile::tempdir().unwrap();
+    let source = dir.path().join("lib.c");
+    let library = dir.path().join("liblib.so");
+    fs::write(
+        &source,
+        "float area(void) { return 0; }\nvoid extra(void) {}\n",
+    )
+    .unwrap();
+    let status = Command::new("cc")
+        .arg("-shared")
+        .arg("-fPIC")
+        .arg("-o")
+        .arg(&library)
+        .arg(&source)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let _lock = NM.lock().unwrap_or_else(|e| e.into_inner());
+    let check = bindings.check_library(&library).unwrap();
+    assert!(!check.is_ok());
+    let missing: Vec<_> = check.missing.iter().map(|symbol| &symbol.name).collect();
+    assert_eq!(missing, ["COUNTER"]);
+    assert_eq!(check.undeclared, ["extra"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn versioned_symbols() {
+    let check = check_with_nm(
+        "liblib.so",
+        "VERS_1 A 0 \narea@@VERS_1 T 10f9 b\nCOUNTER@VERS_0 D 4008 4\n",
+    );
+    assert!(check.is_ok(), "{:?}", check);
+}
+
+#[cfg(unix)]
+#[test]
+fn text_and_data_symbols() {
+    // A function exported as data and a static exported as a function are
+    // both missing and undeclared, and weak symbols don't count.
+    let check = check_with_nm(
+        "liblib.so",
+        "area D 4000 4\nCOUNTER T 10f9 b\nhelper W 1100 7\nextra B 4010 4\n",
+    );
+    let missing: Vec<_> = check.missing.iter().map(|symbol| &symbol.name).collect();
+    assert_eq!(missing, ["area", "COUNTER"]);
+    assert_eq!(check.undeclared, ["COUNTER", "area", "extra"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn staticlib_std_symbols() {
+    let check = check_with_nm(
+        "liblib.a",
+        "liblib.a[lib-0123456789abcdef.lib.0123456789abcdef-cgu.0.rcgu.o]:\n\
+         area T 0 4\n\
+         COUNTER B 0 4\n\
+         liblib.a[std-0123456789abcdef.std.0123456789abcdef-cgu.0.rcgu.o]:\n\
+         rust_eh_personality T 0 4\n\
+         liblib.a[compiler_builtins-0123456789abcdef.compiler_builtins.0123456789abcdef-cgu.000.rcgu.o]:\n\
+         memcpy T 0 4\n\
+         liblib.a[45c91108d938afe8-muldc3.o]:\n\
+         muldc3 T 0 4\n",
+    );
+    assert!(check.is_ok(), "{:?}", check);
+}
+
+#[cfg(unix)]
+#[test]
+fn mach_o_symbols() {
+    // Mach-O symbols start with an underscore, whatever the host is.
+    let dir = tempfile::tempdir().unwrap();
+    let library = dir.path().join("liblib.dylib");
+    fs::write(&library, [0xcf, 0xfa, 0xed, 0xfe, 0x07, 0x00, 0x00, 0x01]).unwrap();
+    let check = check_with_nm(
+        library.to_str().unwrap(),
+        "_area T 0 4\n_COUNTER D 0 4\n__ZN3lib4util17h0123456789abcdefE T 0 4\n",
+    );
+    assert!(check.is_ok(), "{:?}", check);
+}
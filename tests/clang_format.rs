@@ -0,0 +1,65 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+use cbindgen::{Builder, Config, Language};
+
+#[test]
+fn clang_format_written_file() {
+    let dir = tempfile::tempdir().unwrap();
+    // A stand-in for clang-format, which records its arguments.
+    let program = dir.path().join("fake-clang-format");
+    fs::write(
+        &program,
+        "#!/bin/sh\necho \"// $1 ${2%%=*}=${2##*/}\"\ncat\n",
+    )
+    .unwrap();
+    fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.clang_format.style = Some("file".to_owned());
+    config.clang_format.path = Some(program.to_str().unwrap().to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/cython_wrappers.rs")
+        .generate()
+        .unwrap();
+
+    let header = dir.path().join("parser.h");
+    assert!(bindings.write_to_file(&header));
+    let contents = fs::read_to_string(&header).unwrap();
+    assert!(
+        contents.starts_with("// --style=file --assume-filename=parser.h\n"),
+        "{}",
+        contents
+    );
+    assert!(contents.contains("void parser_free("));
+    // The formatted output is what's compared.
+    assert!(!bindings.write_to_file(&header));
+}
+
+#[test]
+fn clang_format_missing() {
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.clang_format.style = Some("LLVM".to_owned());
+    config.clang_format.path = Some("/nonexistent/clang-format".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/cython_wrappers.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let header = dir.path().join("parser.h");
+    assert!(bindings.write_to_file(&header));
+    let mut unformatted = Vec::new();
+    bindings.write(&mut unformatted);
+    assert_eq!(fs::read(&header).unwrap(), unformatted);
+}
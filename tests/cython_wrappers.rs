@@ -0,0 +1,67 @@
+use std::fs;
+
+use cbindgen::{Builder, Config, Language};
+
+#[test]
+fn cython_wrappers() {
+    let mut config = Config {
+        language: Language::Cython,
+        ..Default::default()
+    };
+    config.cython.wrapper_path = Some("parser.pyx".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/cython_wrappers.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    assert!(bindings.write_to_file(dir.path().join("cparser.pxd")));
+
+    let wrappers = fs::read_to_string(dir.path().join("parser.pyx")).unwrap();
+    assert!(wrappers.contains("\ncimport cparser\n"));
+    assert!(wrappers.contains(
+        "\ncdef class Parser:\n  cdef cparser.Parser *ptr\n\n  \
+         def __init__(self, uintptr_t capacity, bint strict):\n    \
+         self.ptr = cparser.parser_new(capacity, strict)\n    \
+         if self.ptr is NULL:\n      raise MemoryError()\n\n  \
+         def __dealloc__(self):\n    if self.ptr is not NULL:\n      \
+         cparser.parser_free(self.ptr)\n"
+    ));
+    assert!(wrappers.contains(
+        "\ncdef class Document:\n  \"\"\"A parsed document.\"\"\"\n  cdef cparser.Document *ptr\n"
+    ));
+    assert!(
+        wrappers.contains("\n  @staticmethod\n  cdef Document from_ptr(cparser.Document *ptr):\n")
+    );
+    // Without a constructor, the class is only created with `from_ptr`.
+    let document = wrappers.split("cdef class Parser").next().unwrap();
+    assert!(!document.contains("__init__"));
+
+    // Only the wrappers are out of date, which counts as a change.
+    assert!(!bindings.write_to_file(dir.path().join("cparser.pxd")));
+    fs::write(dir.path().join("parser.pyx"), "").unwrap();
+    assert!(bindings.write_to_file(dir.path().join("cparser.pxd")));
+    assert_eq!(
+        fs::read_to_string(dir.path().join("parser.pyx")).unwrap(),
+        wrappers
+    );
+}
+
+#[test]
+fn cython_wrappers_only_for_cython() {
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.cython.wrapper_path = Some("parser.pyx".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/cython_wrappers.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    bindings.write_to_file(dir.path().join("parser.h"));
+    assert!(!dir.path().join("parser.pyx").exists());
+}
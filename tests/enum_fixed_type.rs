@@ -0,0 +1,36 @@
+use cbindgen::{Builder, Config, EnumFixedType, Language};
+
+// C23 enums with a fixed underlying type aren't supported by the compilers
+// the expectation tests run with yet, so they're only checked here.
+#[test]
+fn enum_fixed_type_c23() {
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.enumeration.fixed_type = EnumFixedType::C23;
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/enum_fixed_type.rs")
+        .generate()
+        .unwrap();
+
+    let mut header = Vec::new();
+    bindings.write(&mut header);
+    let header = String::from_utf8(header).unwrap();
+
+    assert!(header.contains(
+        "enum Flags : uint32_t {\n  \
+           /**\n   \
+            * The first flag.\n   \
+            */\n  \
+           Low = 1,\n  \
+           High = 1073741824,\n  \
+           Next,\n\
+         };\n\
+         typedef enum Flags Flags;\n"
+    ));
+    // The annotation still takes precedence.
+    assert!(header.contains("enum {\n  Read,\n  Write,\n};\ntypedef uint16_t Mode;\n"));
+    assert!(header.contains("enum Shape_Tag : uint8_t {\n"));
+}
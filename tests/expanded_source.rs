@@ -0,0 +1,85 @@
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+
+use cbindgen::{Builder, Error, Language};
+
+// Like the output of `cargo expand`: the modules are inline and the macros
+// are gone.
+const EXPANDED: &str = r#"
+mod shapes {
+    #[repr(C)]
+    pub struct Point {
+        pub x: f32,
+        pub y: f32,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn point_length(point: *const shapes::Point) -> f32 {
+    0.0
+}
+"#;
+
+fn generate(reader: impl Read) -> Result<String, Error> {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_no_includes()
+        .with_expanded_src_reader(reader)
+        .generate()?;
+    let mut out = Vec::new();
+    bindings.write(&mut out);
+    Ok(String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn expanded_src_reader() {
+    let out = generate(EXPANDED.as_bytes()).unwrap();
+    assert!(out.contains("} Point;"), "{}", out);
+    assert!(
+        out.contains("float point_length(const struct Point *point);"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn expanded_src_reader_error() {
+    struct Failing;
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "broken pipe"))
+        }
+    }
+    assert!(matches!(
+        generate(Failing),
+        Err(Error::ParseCannotOpenFile { .. })
+    ));
+    assert!(matches!(
+        generate("pub fn (".as_bytes()),
+        Err(Error::ParseSyntaxError { .. })
+    ));
+}
+
+#[test]
+fn expanded_source_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(["--lang", "c", "--expanded-source", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(EXPANDED.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let out = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        out.contains("float point_length(const struct Point *point);"),
+        "{}",
+        out
+    );
+}
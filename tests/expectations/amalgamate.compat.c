@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+/* Begin of "mylib_types.h" */
+
+/* Begin of "mylib_config.h" */
+
+
+#define MYLIB_MAX_FLAGS 32
+/* End of "mylib_config.h" */
+#include <stdint.h>
+
+typedef uint32_t MyLibFlags;
+/* End of "mylib_types.h" */
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void mylib_set_flags(MyLibFlags flags);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
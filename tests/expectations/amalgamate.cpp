@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+/* Begin of "mylib_types.h" */
+
+/* Begin of "mylib_config.h" */
+
+
+#define MYLIB_MAX_FLAGS 32
+/* End of "mylib_config.h" */
+#include <stdint.h>
+
+typedef uint32_t MyLibFlags;
+/* End of "mylib_types.h" */
+
+extern "C" {
+
+void mylib_set_flags(MyLibFlags flags);
+
+} // extern "C"
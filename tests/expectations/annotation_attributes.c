@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Plain {
+  A,
+  B,
+};
+typedef uint8_t Plain;
+
+typedef struct Hidden Hidden;
+
+#define RENAMED_IS_DEFINED 1
+typedef struct {
+  int32_t x;
+} Renamed;
+
+typedef struct {
+  int32_t first;
+  int32_t second;
+} Pair;
+
+/* Things */
+
+void take(Renamed a, const Hidden *b, Pair c, Plain d);
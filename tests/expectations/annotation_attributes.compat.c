@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Plain
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint8_t Plain;
+#endif // __cplusplus
+
+typedef struct Hidden Hidden;
+
+#define RENAMED_IS_DEFINED 1
+typedef struct {
+  int32_t x;
+} Renamed;
+
+typedef struct {
+  int32_t first;
+  int32_t second;
+} Pair;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/* Things */
+
+void take(Renamed a, const Hidden *b, Pair c, Plain d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum Plain : uint8_t {
+  A,
+  B,
+};
+
+struct Hidden;
+
+#define RENAMED_IS_DEFINED 1
+struct Renamed {
+  int32_t x;
+};
+
+struct Pair {
+  int32_t first;
+  int32_t second;
+};
+
+extern "C" {
+
+/* Things */
+
+void take(Renamed a, const Hidden *b, Pair c, Plain d);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Plain {
+  A,
+  B,
+};
+typedef uint8_t Plain;
+
+typedef struct Hidden Hidden;
+
+#define RENAMED_IS_DEFINED 1
+typedef struct Renamed {
+  int32_t x;
+} Renamed;
+
+typedef struct Pair {
+  int32_t first;
+  int32_t second;
+} Pair;
+
+/* Things */
+
+void take(struct Renamed a, const struct Hidden *b, struct Pair c, Plain d);
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    A,
+    B,
+  ctypedef uint8_t Plain;
+
+  cdef struct Hidden:
+    pass
+
+  #define RENAMED_IS_DEFINED 1
+  cdef struct Renamed:
+    int32_t x;
+
+  cdef struct Pair:
+    int32_t first;
+    int32_t second;
+
+  # Things
+
+  void take(Renamed a, const Hidden *b, Pair c, Plain d);
@@ -116,7 +116,7 @@ struct J {
     return result;
   }
 
-  bool IsJ_Foo() const {
+  MY_ATTRS bool IsJ_Foo() const {
     return tag == Tag::J_Foo;
   }
 
@@ -139,7 +139,7 @@ struct J {
     return result;
   }
 
-  bool IsJ_Bar() const {
+  MY_ATTRS bool IsJ_Bar() const {
     return tag == Tag::J_Bar;
   }
 
@@ -159,7 +159,7 @@ struct J {
     return result;
   }
 
-  bool IsJ_Baz() const {
+  MY_ATTRS bool IsJ_Baz() const {
     return tag == Tag::J_Baz;
   }
 };
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define DEFAULT_VALUE (VALUE_BASE + 1)
+
+#define VALUE_BASE 42
+
+#define QUOTED_VALUE (VALUE_BASE + 2)
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#define legacy_get_value() get_value()
+#define LEGACY_API 1
+
+int32_t get_value(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#define DEFAULT_VALUE (VALUE_BASE + 1)
+
+constexpr static const int32_t VALUE_BASE = 42;
+
+#define QUOTED_VALUE (VALUE_BASE + 2)
+
+extern "C" {
+
+#define legacy_get_value() get_value()
+#define LEGACY_API 1
+
+int32_t get_value();
+
+} // extern "C"
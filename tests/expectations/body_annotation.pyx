@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  #define DEFAULT_VALUE (VALUE_BASE + 1)
+
+  const int32_t VALUE_BASE # = 42
+
+  #define QUOTED_VALUE (VALUE_BASE + 2)
+
+  #define legacy_get_value() get_value()
+  #define LEGACY_API 1
+
+  int32_t get_value();
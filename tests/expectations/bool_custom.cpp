@@ -0,0 +1,32 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if 0
+''' '
+#endif
+typedef int BOOL;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef int BOOL
+#endif
+
+
+constexpr static const BOOL ENABLED = 1;
+
+struct Options {
+  BOOL verbose;
+  uint8_t level;
+};
+constexpr static const Options Options_QUIET = Options{ /* .verbose = */ 0, /* .level = */ 0 };
+
+extern "C" {
+
+BOOL options_apply(const Options *options, BOOL force);
+
+} // extern "C"
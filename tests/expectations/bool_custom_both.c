@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if 0
+''' '
+#endif
+typedef int BOOL;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef int BOOL
+#endif
+
+
+#define ENABLED 1
+
+typedef struct Options {
+  BOOL verbose;
+  uint8_t level;
+} Options;
+#define Options_QUIET (Options){ .verbose = 0, .level = 0 }
+
+BOOL options_apply(const struct Options *options, BOOL force);
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if 0
+''' '
+#endif
+typedef int BOOL;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef int BOOL
+#endif
+
+
+#define ENABLED 1
+
+struct Options {
+  BOOL verbose;
+  uint8_t level;
+};
+#define Options_QUIET (Options){ .verbose = 0, .level = 0 }
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+BOOL options_apply(const struct Options *options, BOOL force);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
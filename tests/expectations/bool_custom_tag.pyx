@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef struct va_list
+#if 0
+''' '
+#endif
+typedef int BOOL;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef int BOOL
+#endif
+
+
+cdef extern from *:
+
+  const BOOL ENABLED # = True
+
+  cdef struct Options:
+    BOOL verbose;
+    uint8_t level;
+  const Options Options_QUIET # = <Options>{ False, 0 }
+
+  BOOL options_apply(const Options *options, BOOL force);
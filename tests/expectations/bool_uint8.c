@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ENABLED 1
+
+typedef struct {
+  uint8_t verbose;
+  uint8_t level;
+} Options;
+#define Options_QUIET (Options){ .verbose = 0, .level = 0 }
+
+uint8_t options_apply(const Options *options, uint8_t force);
@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint8_t ENABLED = 1;
+
+struct Options {
+  uint8_t verbose;
+  uint8_t level;
+};
+constexpr static const Options Options_QUIET = Options{ /* .verbose = */ 0, /* .level = */ 0 };
+
+extern "C" {
+
+uint8_t options_apply(const Options *options, uint8_t force);
+
+} // extern "C"
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define ENABLED 1
+
+typedef struct Options {
+  uint8_t verbose;
+  uint8_t level;
+} Options;
+#define Options_QUIET (Options){ .verbose = 0, .level = 0 }
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint8_t options_apply(const struct Options *options, uint8_t force);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,15 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint8_t ENABLED # = True
+
+  cdef struct Options:
+    uint8_t verbose;
+    uint8_t level;
+  const Options Options_QUIET # = <Options>{ False, 0 }
+
+  uint8_t options_apply(const Options *options, uint8_t force);
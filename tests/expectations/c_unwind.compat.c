@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef int32_t (*Callback)(int32_t);
+
+typedef struct {
+  void (*on_error)(uint32_t);
+  void (*on_event)(void);
+} Handlers;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Runs the callback, letting panics propagate.
+ *
+ * This function may unwind (`extern "C-unwind"`).
+ */
+int32_t run(Callback callback, Handlers handlers);
+
+void plain(void);
+
+/**
+ * This function may unwind (`extern "C-unwind"`).
+ */
+extern void imported(int32_t value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
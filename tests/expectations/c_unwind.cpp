@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Callback = int32_t(*)(int32_t);
+
+struct Handlers {
+  void (*on_error)(uint32_t);
+  void (*on_event)();
+};
+
+extern "C" {
+
+/// Runs the callback, letting panics propagate.
+///
+/// This function may unwind (`extern "C-unwind"`).
+int32_t run(Callback callback, Handlers handlers);
+
+void plain();
+
+/// This function may unwind (`extern "C-unwind"`).
+extern void imported(int32_t value);
+
+} // extern "C"
@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef int32_t (*Callback)(int32_t);
+
+  cdef struct Handlers:
+    void (*on_error)(uint32_t);
+    void (*on_event)();
+
+  # Runs the callback, letting panics propagate.
+  #
+  # This function may unwind (`extern "C-unwind"`).
+  int32_t run(Callback callback, Handlers handlers);
+
+  void plain();
+
+  # This function may unwind (`extern "C-unwind"`).
+  extern void imported(int32_t value);
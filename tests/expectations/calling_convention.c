@@ -0,0 +1,42 @@
+#if !defined(CBINDGEN_SYSTEM)
+#if defined(_WIN32)
+#define CBINDGEN_SYSTEM __stdcall
+#else
+#define CBINDGEN_SYSTEM
+#endif
+#endif
+
+#if !defined(CBINDGEN_STDCALL)
+#if defined(_WIN32)
+#define CBINDGEN_STDCALL __stdcall
+#elif defined(__GNUC__) && defined(__i386__)
+#define CBINDGEN_STDCALL __attribute__((stdcall))
+#else
+#define CBINDGEN_STDCALL
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef intptr_t (CBINDGEN_SYSTEM *WndProc)(uint8_t *hwnd, uint32_t msg);
+
+typedef struct {
+  void (CBINDGEN_SYSTEM *on_message)(uint32_t);
+  bool (CBINDGEN_STDCALL *on_close)(uint8_t*);
+  void (*on_error)(int32_t);
+  void (*on_event)(void);
+} Callbacks;
+
+typedef struct {
+  void (CBINDGEN_SYSTEM *show)(const void *self, bool visible);
+} WindowVTable;
+
+typedef struct {
+  void *data;
+  const WindowVTable *vtable;
+} DynWindow;
+
+void set_callbacks(WndProc proc_, Callbacks callbacks, DynWindow window);
@@ -0,0 +1,50 @@
+#if !defined(CBINDGEN_SYSTEM)
+#if defined(_WIN32)
+#define CBINDGEN_SYSTEM __stdcall
+#else
+#define CBINDGEN_SYSTEM
+#endif
+#endif
+
+#if !defined(CBINDGEN_STDCALL)
+#if defined(_WIN32)
+#define CBINDGEN_STDCALL __stdcall
+#elif defined(__GNUC__) && defined(__i386__)
+#define CBINDGEN_STDCALL __attribute__((stdcall))
+#else
+#define CBINDGEN_STDCALL
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef intptr_t (CBINDGEN_SYSTEM *WndProc)(uint8_t *hwnd, uint32_t msg);
+
+struct Callbacks {
+  void (CBINDGEN_SYSTEM *on_message)(uint32_t);
+  bool (CBINDGEN_STDCALL *on_close)(uint8_t*);
+  void (*on_error)(int32_t);
+  void (*on_event)(void);
+};
+
+struct WindowVTable {
+  void (CBINDGEN_SYSTEM *show)(const void *self, bool visible);
+};
+
+struct DynWindow {
+  void *data;
+  const struct WindowVTable *vtable;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void set_callbacks(WndProc proc_, struct Callbacks callbacks, struct DynWindow window);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef intptr_t (*WndProc)(uint8_t *hwnd, uint32_t msg);
+
+  cdef struct Callbacks:
+    void (*on_message)(uint32_t);
+    bool (*on_close)(uint8_t*);
+    void (*on_error)(int32_t);
+    void (*on_event)();
+
+  cdef struct WindowVTable:
+    void (*show)(const void *self, bool visible);
+
+  cdef struct DynWindow:
+    void *data;
+    const WindowVTable *vtable;
+
+  void set_callbacks(WndProc proc_, Callbacks callbacks, DynWindow window);
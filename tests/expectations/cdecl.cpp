@@ -22,7 +22,7 @@ using H = int32_t*const *;
 
 using I = const int32_t(*)[16];
 
-using J = double(**)(float);
+using J = double(*const *)(float);
 
 using K = int32_t[16];
 
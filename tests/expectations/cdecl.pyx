@@ -24,7 +24,7 @@ cdef extern from *:
 
   ctypedef const int32_t (*I)[16];
 
-  ctypedef double (**J)(float);
+  ctypedef double (*const *J)(float);
 
   ctypedef int32_t K[16];
 
@@ -0,0 +1,61 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using ArrayOfFns = void(*[4])();
+
+using PtrToArray = const int32_t(*)[4];
+
+using FnReturningFn = uint8_t(*(*)())(int32_t);
+
+using PtrToArrayOfFns = void(*const (*)[4])();
+
+using PtrToFn = void(*const *)();
+
+using Grid = uint16_t(*(*[3][2])(const uint8_t(*)[2]))[3];
+
+using Nested = void(*const (*(*)(void(*(*)(int32_t))()))[2])();
+
+using RefToArrayOfFns = void(*const (*)[2])();
+
+using PtrToPtrToArray = const int32_t(*const *)[4];
+
+using RefToArrayOfPtrsToFns = void(*const *(*)[3])();
+
+struct Table {
+  void (*fns[4])();
+  const int32_t (*row)[4];
+  uint8_t (*(*factory)())(int32_t);
+  void (*(*slots)[4])(int32_t);
+  const uint8_t (*rows[3])[2];
+  void (*const *indirect)();
+};
+
+extern "C" {
+
+extern void (*HANDLERS[4])();
+
+void handler();
+
+uint8_t (*get_handler(int32_t x))(int32_t);
+
+void (*const (*get_handlers())[4])();
+
+int32_t (*get_row())[4];
+
+void root(ArrayOfFns a,
+          PtrToArray b,
+          FnReturningFn c,
+          PtrToArrayOfFns d,
+          PtrToFn e,
+          Grid f,
+          Nested g,
+          RefToArrayOfFns h,
+          PtrToPtrToArray i,
+          RefToArrayOfPtrsToFns j,
+          Table t,
+          const uint8_t (*const *u)[2]);
+
+} // extern "C"
@@ -0,0 +1,56 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef void (*ArrayOfFns[4])(void);
+
+typedef const int32_t (*PtrToArray)[4];
+
+typedef uint8_t (*(*FnReturningFn)(void))(int32_t);
+
+typedef void (*const (*PtrToArrayOfFns)[4])(void);
+
+typedef void (*const *PtrToFn)(void);
+
+typedef uint16_t (*(*Grid[3][2])(const uint8_t(*)[2]))[3];
+
+typedef void (*const (*(*Nested)(void(*(*)(int32_t))(void)))[2])(void);
+
+typedef void (*const (*RefToArrayOfFns)[2])(void);
+
+typedef const int32_t (*const *PtrToPtrToArray)[4];
+
+typedef void (*const *(*RefToArrayOfPtrsToFns)[3])(void);
+
+typedef struct Table {
+  void (*fns[4])(void);
+  const int32_t (*row)[4];
+  uint8_t (*(*factory)(void))(int32_t);
+  void (*(*slots)[4])(int32_t);
+  const uint8_t (*rows[3])[2];
+  void (*const *indirect)(void);
+} Table;
+
+extern void (*HANDLERS[4])(void);
+
+void handler(void);
+
+uint8_t (*get_handler(int32_t x))(int32_t);
+
+void (*const (*get_handlers(void))[4])(void);
+
+int32_t (*get_row(void))[4];
+
+void root(ArrayOfFns a,
+          PtrToArray b,
+          FnReturningFn c,
+          PtrToArrayOfFns d,
+          PtrToFn e,
+          Grid f,
+          Nested g,
+          RefToArrayOfFns h,
+          PtrToPtrToArray i,
+          RefToArrayOfPtrsToFns j,
+          struct Table t,
+          const uint8_t (*const *u)[2]);
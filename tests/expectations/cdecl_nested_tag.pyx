@@ -0,0 +1,58 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef void (*ArrayOfFns[4])();
+
+  ctypedef const int32_t (*PtrToArray)[4];
+
+  ctypedef uint8_t (*(*FnReturningFn)())(int32_t);
+
+  ctypedef void (*const (*PtrToArrayOfFns)[4])();
+
+  ctypedef void (*const *PtrToFn)();
+
+  ctypedef uint16_t (*(*Grid[3][2])(const uint8_t(*)[2]))[3];
+
+  ctypedef void (*const (*(*Nested)(void(*(*)(int32_t))()))[2])();
+
+  ctypedef void (*const (*RefToArrayOfFns)[2])();
+
+  ctypedef const int32_t (*const *PtrToPtrToArray)[4];
+
+  ctypedef void (*const *(*RefToArrayOfPtrsToFns)[3])();
+
+  cdef struct Table:
+    void (*fns[4])();
+    const int32_t (*row)[4];
+    uint8_t (*(*factory)())(int32_t);
+    void (*(*slots)[4])(int32_t);
+    const uint8_t (*rows[3])[2];
+    void (*const *indirect)();
+
+  extern void (*HANDLERS[4])();
+
+  void handler();
+
+  uint8_t (*get_handler(int32_t x))(int32_t);
+
+  void (*const (*get_handlers())[4])();
+
+  int32_t (*get_row())[4];
+
+  void root(ArrayOfFns a,
+            PtrToArray b,
+            FnReturningFn c,
+            PtrToArrayOfFns d,
+            PtrToFn e,
+            Grid f,
+            Nested g,
+            RefToArrayOfFns h,
+            PtrToPtrToArray i,
+            RefToArrayOfPtrsToFns j,
+            Table t,
+            const uint8_t (*const *u)[2]);
@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if !defined(NOT_DEFINED) && (1 == 1)
+#define GPU_COUNT 2
+#endif
+
+#if defined(DEFINED)
+typedef struct {
+  uint32_t device;
+} GpuContext;
+#endif
+
+#if defined(DEFINED)
+typedef struct {
+  GpuContext ctx;
+  uint64_t frames;
+} Renderer;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(DEFINED)
+void gpu_root(GpuContext *ctx);
+#endif
+
+void root(void);
+
+#if defined(DEFINED)
+GpuContext *gpu_context_new(void);
+#endif
+
+#if (defined(MYLIB_ENABLE_VULKAN)) && (defined(DEFINED))
+void renderer_draw(Renderer *renderer);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,40 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if !defined(NOT_DEFINED) && (1 == 1)
+constexpr static const uint32_t GPU_COUNT = 2;
+#endif
+
+#if defined(DEFINED)
+struct GpuContext {
+  uint32_t device;
+};
+#endif
+
+#if defined(DEFINED)
+struct Renderer {
+  GpuContext ctx;
+  uint64_t frames;
+};
+#endif
+
+extern "C" {
+
+#if defined(DEFINED)
+void gpu_root(GpuContext *ctx);
+#endif
+
+void root();
+
+#if defined(DEFINED)
+GpuContext *gpu_context_new();
+#endif
+
+#if (defined(MYLIB_ENABLE_VULKAN)) && (defined(DEFINED))
+void renderer_draw(Renderer *renderer);
+#endif
+
+} // extern "C"
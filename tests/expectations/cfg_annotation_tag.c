@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if !defined(NOT_DEFINED) && (1 == 1)
+#define GPU_COUNT 2
+#endif
+
+#if defined(DEFINED)
+struct GpuContext {
+  uint32_t device;
+};
+#endif
+
+#if defined(DEFINED)
+struct Renderer {
+  struct GpuContext ctx;
+  uint64_t frames;
+};
+#endif
+
+#if defined(DEFINED)
+void gpu_root(struct GpuContext *ctx);
+#endif
+
+void root(void);
+
+#if defined(DEFINED)
+struct GpuContext *gpu_context_new(void);
+#endif
+
+#if (defined(MYLIB_ENABLE_VULKAN)) && (defined(DEFINED))
+void renderer_draw(struct Renderer *renderer);
+#endif
@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t GPU_COUNT # = 2
+
+  cdef struct GpuContext:
+    uint32_t device;
+
+  cdef struct Renderer:
+    GpuContext ctx;
+    uint64_t frames;
+
+  void gpu_root(GpuContext *ctx);
+
+  void root();
+
+  GpuContext *gpu_context_new();
+
+  void renderer_draw(Renderer *renderer);
@@ -0,0 +1,35 @@
+#if 0
+DEF FEATURE_GPU = 0
+DEF PLATFORM_WIN = 0
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if (!defined(FEATURE_GPU) || defined(PLATFORM_WIN))
+/**
+ * Present whenever `gpu` is off, whatever the platform.
+ */
+typedef struct {
+  uint32_t width;
+  uint32_t height;
+} Surface;
+#endif
+
+#if (!defined(FEATURE_GPU) || defined(PLATFORM_WIN))
+void surface(const Surface *surface);
+#endif
+
+#if 0
+''' '
+#endif
+
+#include "testing-helpers.h"
+static_assert(sizeof(CBINDGEN_STRUCT(Surface)) == 8, "Surface is missing without gpu");
+
+#if 0
+' '''
+#endif
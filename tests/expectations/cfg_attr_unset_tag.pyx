@@ -0,0 +1,33 @@
+#if 0
+DEF FEATURE_GPU = 0
+DEF PLATFORM_WIN = 0
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  IF (not FEATURE_GPU or PLATFORM_WIN):
+    # Present whenever `gpu` is off, whatever the platform.
+    cdef struct Surface:
+      uint32_t width;
+      uint32_t height;
+
+  IF (not FEATURE_GPU or PLATFORM_WIN):
+    void surface(const Surface *surface);
+
+#if 0
+''' '
+#endif
+
+#include "testing-helpers.h"
+static_assert(sizeof(CBINDGEN_STRUCT(Surface)) == 8, "Surface is missing without gpu");
+
+#if 0
+' '''
+#endif
@@ -0,0 +1,66 @@
+#if 0
+DEF FEATURE_GPU = 0
+DEF FEATURE_VULKAN = 0
+DEF FEATURE_METAL = 0
+DEF PLATFORM_MACOS = 0
+DEF PLATFORM_WIN = 0
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if (!defined(FEATURE_GPU) || (!defined(FEATURE_VULKAN) || defined(PLATFORM_WIN)))
+enum Backend
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Software,
+#if (!defined(FEATURE_GPU) || defined(FEATURE_VULKAN))
+  Vulkan,
+#endif
+};
+#ifndef __cplusplus
+typedef uint8_t Backend;
+#endif // __cplusplus
+#endif
+
+#if (defined(FEATURE_GPU) && !defined(PLATFORM_MACOS))
+typedef struct {
+  uint32_t device;
+#if (defined(FEATURE_VULKAN) || (defined(FEATURE_METAL) && !defined(PLATFORM_WIN)))
+  uint32_t queue
+#endif
+  ;
+} GpuContext;
+#endif
+
+#if (!defined(FEATURE_GPU) || !(defined(PLATFORM_WIN) || defined(PLATFORM_MACOS)))
+typedef struct {
+  uint32_t width;
+  uint32_t height;
+} Surface;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if (defined(FEATURE_GPU) && !defined(PLATFORM_MACOS))
+void gpu_context(const GpuContext *context);
+#endif
+
+#if (!defined(FEATURE_GPU) || !(defined(PLATFORM_WIN) || defined(PLATFORM_MACOS)))
+void surface(const Surface *surface);
+#endif
+
+#if (!defined(FEATURE_GPU) || (!defined(FEATURE_VULKAN) || defined(PLATFORM_WIN)))
+void backend(Backend backend);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
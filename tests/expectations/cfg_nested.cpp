@@ -0,0 +1,56 @@
+#if 0
+DEF FEATURE_GPU = 0
+DEF FEATURE_VULKAN = 0
+DEF FEATURE_METAL = 0
+DEF PLATFORM_MACOS = 0
+DEF PLATFORM_WIN = 0
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if (!defined(FEATURE_GPU) || (!defined(FEATURE_VULKAN) || defined(PLATFORM_WIN)))
+enum class Backend : uint8_t {
+  Software,
+#if (!defined(FEATURE_GPU) || defined(FEATURE_VULKAN))
+  Vulkan,
+#endif
+};
+#endif
+
+#if (defined(FEATURE_GPU) && !defined(PLATFORM_MACOS))
+struct GpuContext {
+  uint32_t device;
+#if (defined(FEATURE_VULKAN) || (defined(FEATURE_METAL) && !defined(PLATFORM_WIN)))
+  uint32_t queue
+#endif
+  ;
+};
+#endif
+
+#if (!defined(FEATURE_GPU) || !(defined(PLATFORM_WIN) || defined(PLATFORM_MACOS)))
+struct Surface {
+  uint32_t width;
+  uint32_t height;
+};
+#endif
+
+extern "C" {
+
+#if (defined(FEATURE_GPU) && !defined(PLATFORM_MACOS))
+void gpu_context(const GpuContext *context);
+#endif
+
+#if (!defined(FEATURE_GPU) || !(defined(PLATFORM_WIN) || defined(PLATFORM_MACOS)))
+void surface(const Surface *surface);
+#endif
+
+#if (!defined(FEATURE_GPU) || (!defined(FEATURE_VULKAN) || defined(PLATFORM_WIN)))
+void backend(Backend backend);
+#endif
+
+} // extern "C"
@@ -0,0 +1,41 @@
+#if 0
+DEF FEATURE_GPU = 0
+DEF FEATURE_VULKAN = 0
+DEF FEATURE_METAL = 0
+DEF PLATFORM_MACOS = 0
+DEF PLATFORM_WIN = 0
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  IF (not FEATURE_GPU or (not FEATURE_VULKAN or PLATFORM_WIN)):
+    cdef enum:
+      Software,
+      Vulkan,
+    ctypedef uint8_t Backend;
+
+  IF (FEATURE_GPU and not PLATFORM_MACOS):
+    ctypedef struct GpuContext:
+      uint32_t device;
+      uint32_t queue;
+
+  IF (not FEATURE_GPU or not (PLATFORM_WIN or PLATFORM_MACOS)):
+    ctypedef struct Surface:
+      uint32_t width;
+      uint32_t height;
+
+  IF (FEATURE_GPU and not PLATFORM_MACOS):
+    void gpu_context(const GpuContext *context);
+
+  IF (not FEATURE_GPU or not (PLATFORM_WIN or PLATFORM_MACOS)):
+    void surface(const Surface *surface);
+
+  IF (not FEATURE_GPU or (not FEATURE_VULKAN or PLATFORM_WIN)):
+    void backend(Backend backend);
@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Glyph {
+  char32_t code_point;
+  char32_t fallbacks[4];
+};
+
+extern "C" {
+
+extern const char32_t DEFAULT_CHAR;
+
+bool glyph_lookup(char32_t c, Glyph *out);
+
+char32_t glyph_first(const Glyph *glyph);
+
+} // extern "C"
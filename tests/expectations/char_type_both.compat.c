@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <uchar.h>
+#include <stdlib.h>
+
+typedef struct Glyph {
+  char32_t code_point;
+  char32_t fallbacks[4];
+} Glyph;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const char32_t DEFAULT_CHAR;
+
+bool glyph_lookup(char32_t c, struct Glyph *out);
+
+char32_t glyph_first(const struct Glyph *glyph);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,17 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Glyph:
+    uint32_t code_point;
+    uint32_t fallbacks[4];
+
+  extern const uint32_t DEFAULT_CHAR;
+
+  bool glyph_lookup(uint32_t c, Glyph *out);
+
+  uint32_t glyph_first(const Glyph *glyph);
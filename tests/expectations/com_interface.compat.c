@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t (*QueryInterface)(void *This, const void *riid, void **object);
+  uint32_t (*AddRef)(void *This);
+  uint32_t (*Release)(void *This);
+  /**
+   * Adds `amount` to the counter.
+   */
+  int32_t (*Increment)(void *This, uint32_t amount);
+  int32_t (*GetValue)(void *This, uint32_t *value);
+} ICounterVtbl;
+
+#define ICounter_QueryInterface(This, riid, object) ((This)->lpVtbl->QueryInterface(This, riid, object))
+#define ICounter_AddRef(This) ((This)->lpVtbl->AddRef(This))
+#define ICounter_Release(This) ((This)->lpVtbl->Release(This))
+#define ICounter_Increment(This, amount) ((This)->lpVtbl->Increment(This, amount))
+#define ICounter_GetValue(This, value) ((This)->lpVtbl->GetValue(This, value))
+
+#if defined(_WIN32)
+static const IID IID_ICounter = { 0x6b29fc40, 0xca47, 0x1067, { 0xb3, 0x1d, 0x00, 0xdd, 0x01, 0x06, 0x62, 0xda } };
+#endif
+
+/**
+ * A COM interface exposing a counter.
+ */
+typedef struct {
+  const ICounterVtbl *lpVtbl;
+} ICounter;
+
+typedef struct {
+  int32_t (*QueryInterface)(void *This, const void *riid, void **object);
+  uint32_t (*AddRef)(void *This);
+  uint32_t (*Release)(void *This);
+  int32_t (*Reset)(void *This);
+} IResetVtbl;
+
+#define IReset_QueryInterface(This, riid, object) ((This)->lpVtbl->QueryInterface(This, riid, object))
+#define IReset_AddRef(This) ((This)->lpVtbl->AddRef(This))
+#define IReset_Release(This) ((This)->lpVtbl->Release(This))
+#define IReset_Reset(This) ((This)->lpVtbl->Reset(This))
+
+typedef struct {
+  const IResetVtbl *lpVtbl;
+} IReset;
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct ICounterVtbl:
+    int32_t (*QueryInterface)(void *This, const void *riid, void **object);
+    uint32_t (*AddRef)(void *This);
+    uint32_t (*Release)(void *This);
+    # Adds `amount` to the counter.
+    int32_t (*Increment)(void *This, uint32_t amount);
+    int32_t (*GetValue)(void *This, uint32_t *value);
+
+  # A COM interface exposing a counter.
+  cdef struct ICounter:
+    const ICounterVtbl *lpVtbl;
+
+  cdef struct IResetVtbl:
+    int32_t (*QueryInterface)(void *This, const void *riid, void **object);
+    uint32_t (*AddRef)(void *This);
+    uint32_t (*Release)(void *This);
+    int32_t (*Reset)(void *This);
+
+  cdef struct IReset:
+    const IResetVtbl *lpVtbl;
@@ -0,0 +1,38 @@
+#if !defined(__cplusplus) && (!defined(__STDC_VERSION__) || __STDC_VERSION__ < 201112L)
+#error "This header needs C11 or later, for anonymous structs and unions."
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind {
+  Small,
+  Large,
+};
+typedef uint8_t Kind;
+
+typedef enum {
+  Circle,
+  Square,
+  Empty,
+} Shape_Tag;
+
+typedef struct {
+  float side;
+} Square_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    Square_Body square;
+  };
+} Shape;
+
+typedef uint32_t Id;
+
+bool area(Shape shape, Kind kind, Id id);
@@ -0,0 +1,44 @@
+#if (defined(_MSVC_LANG) ? _MSVC_LANG : __cplusplus) < 201103L
+#error "This header needs C++11 or later, for enum class and alias declarations."
+#endif
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Kind : uint8_t {
+  Small,
+  Large,
+};
+
+struct Shape {
+  enum class Tag {
+    Circle,
+    Square,
+    Empty,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Square_Body {
+    float side;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+};
+
+using Id = uint32_t;
+
+extern "C" {
+
+bool area(Shape shape, Kind kind, Id id);
+
+} // extern "C"
@@ -0,0 +1,52 @@
+#if !defined(__cplusplus) && (!defined(__STDC_VERSION__) || __STDC_VERSION__ < 201112L)
+#error "This header needs C11 or later, for anonymous structs and unions."
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Small,
+  Large,
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef enum Shape_Tag {
+  Circle,
+  Square,
+  Empty,
+} Shape_Tag;
+
+typedef struct Square_Body {
+  float side;
+} Square_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    Square_Body square;
+  };
+} Shape;
+
+typedef uint32_t Id;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool area(struct Shape shape, Kind kind, Id id);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,29 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Small,
+    Large,
+  ctypedef uint8_t Kind;
+
+  cdef enum Shape_Tag:
+    Circle,
+    Square,
+    Empty,
+
+  cdef struct Square_Body:
+    float side;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    float circle;
+    Square_Body square;
+
+  ctypedef uint32_t Id;
+
+  bool area(Shape shape, Kind kind, Id id);
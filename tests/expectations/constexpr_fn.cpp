@@ -0,0 +1,60 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t PREFIX_FLAG_A = (1 << 0);
+
+constexpr static const uint32_t PREFIX_FLAG_B = (1 << 1);
+
+/// Combines two sets of flags.
+static inline constexpr uint32_t PREFIX_combine(uint32_t a, uint32_t b) {
+  return (a | b);
+}
+
+static inline constexpr uint64_t PREFIX_mask(uint8_t bits) {
+  return ((1 << (uint64_t)bits) - 1);
+}
+
+static inline constexpr int32_t PREFIX_negate(int32_t x) {
+  return -x;
+}
+
+static inline constexpr int32_t PREFIX_answer() {
+  return 42;
+}
+
+static inline constexpr bool PREFIX_is_small(int32_t x) {
+  return ((x < 16) && (x > -16));
+}
+
+static inline constexpr bool PREFIX_is_odd_small(uint32_t a) {
+  return (!(a > 16) && (a != 3));
+}
+
+static inline constexpr bool PREFIX_either(bool a, bool b) {
+  return (!a | !b);
+}
+
+static inline constexpr uint8_t PREFIX_invert(uint8_t bits) {
+  return ~bits;
+}
+
+static inline constexpr uint8_t PREFIX_high_nibble(uint8_t bits) {
+  return (bits << 4);
+}
+
+struct PREFIX_Settings {
+  uint32_t flags;
+};
+
+static inline constexpr uint32_t PREFIX_settings_flags(PREFIX_Settings settings) {
+  return ((settings).flags | PREFIX_FLAG_A);
+}
+
+extern "C" {
+
+void root(PREFIX_Settings settings);
+
+} // extern "C"
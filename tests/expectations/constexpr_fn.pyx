@@ -0,0 +1,37 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t PREFIX_FLAG_A # = (1 << 0)
+
+  const uint32_t PREFIX_FLAG_B # = (1 << 1)
+
+  # Combines two sets of flags.
+  uint32_t PREFIX_combine(uint32_t a, uint32_t b)
+
+  uint64_t PREFIX_mask(uint8_t bits)
+
+  int32_t PREFIX_negate(int32_t x)
+
+  int32_t PREFIX_answer()
+
+  bool PREFIX_is_small(int32_t x)
+
+  bool PREFIX_is_odd_small(uint32_t a)
+
+  bool PREFIX_either(bool a, bool b)
+
+  uint8_t PREFIX_invert(uint8_t bits)
+
+  uint8_t PREFIX_high_nibble(uint8_t bits)
+
+  ctypedef struct PREFIX_Settings:
+    uint32_t flags;
+
+  uint32_t PREFIX_settings_flags(PREFIX_Settings settings)
+
+  void root(PREFIX_Settings settings);
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define PREFIX_FLAG_A (1 << 0)
+
+#define PREFIX_FLAG_B (1 << 1)
+
+/**
+ * Combines two sets of flags.
+ */
+#define PREFIX_combine(a, b) ((uint32_t)(((uint32_t)(a)) | ((uint32_t)(b))))
+
+#define PREFIX_mask(bits) ((uint64_t)((1 << (uint64_t)((uint8_t)(bits))) - 1))
+
+#define PREFIX_negate(x) ((int32_t)(-((int32_t)(x))))
+
+#define PREFIX_answer() ((int32_t)42)
+
+#define PREFIX_is_small(x) ((bool)((((int32_t)(x)) < 16) && (((int32_t)(x)) > -16)))
+
+#define PREFIX_is_odd_small(a) ((bool)(!(((uint32_t)(a)) > 16) && (((uint32_t)(a)) != 3)))
+
+#define PREFIX_either(a, b) ((bool)(!((bool)(a)) | !((bool)(b))))
+
+#define PREFIX_invert(bits) ((uint8_t)(~((uint8_t)(bits))))
+
+#define PREFIX_high_nibble(bits) ((uint8_t)(((uint8_t)(bits)) << 4))
+
+typedef struct PREFIX_Settings {
+  uint32_t flags;
+} PREFIX_Settings;
+
+#define PREFIX_settings_flags(settings) ((uint32_t)(((settings)).flags | PREFIX_FLAG_A))
+
+void root(struct PREFIX_Settings settings);
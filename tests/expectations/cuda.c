@@ -0,0 +1,45 @@
+#if !defined(CBINDGEN_CUDA_HOST_DEVICE)
+#if defined(__CUDACC__)
+#define CBINDGEN_CUDA_HOST __host__
+#define CBINDGEN_CUDA_DEVICE __device__
+#define CBINDGEN_CUDA_HOST_DEVICE __host__ __device__
+#else
+#define CBINDGEN_CUDA_HOST
+#define CBINDGEN_CUDA_DEVICE
+#define CBINDGEN_CUDA_HOST_DEVICE
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Vec2;
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    struct {
+      Vec2 rect;
+    };
+  };
+} Shape;
+
+CBINDGEN_CUDA_HOST_DEVICE float vec2_length(Vec2 v);
+
+CBINDGEN_CUDA_HOST float shape_area(const Shape *shape);
+
+void host_only(void);
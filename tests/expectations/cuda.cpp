@@ -0,0 +1,80 @@
+#if !defined(CBINDGEN_CUDA_HOST_DEVICE)
+#if defined(__CUDACC__)
+#define CBINDGEN_CUDA_HOST __host__
+#define CBINDGEN_CUDA_DEVICE __device__
+#define CBINDGEN_CUDA_HOST_DEVICE __host__ __device__
+#else
+#define CBINDGEN_CUDA_HOST
+#define CBINDGEN_CUDA_DEVICE
+#define CBINDGEN_CUDA_HOST_DEVICE
+#endif
+#endif
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Vec2 {
+  float x;
+  float y;
+
+  CBINDGEN_CUDA_HOST_DEVICE bool operator==(const Vec2& other) const {
+    return x == other.x &&
+           y == other.y;
+  }
+};
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Rect,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Rect_Body {
+    Vec2 _0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  };
+
+  CBINDGEN_CUDA_HOST_DEVICE static Shape Circle(const float &_0) {
+    Shape result;
+    ::new (&result.circle._0) (float)(_0);
+    result.tag = Tag::Circle;
+    return result;
+  }
+
+  CBINDGEN_CUDA_HOST_DEVICE bool IsCircle() const {
+    return tag == Tag::Circle;
+  }
+
+  CBINDGEN_CUDA_HOST_DEVICE static Shape Rect(const Vec2 &_0) {
+    Shape result;
+    ::new (&result.rect._0) (Vec2)(_0);
+    result.tag = Tag::Rect;
+    return result;
+  }
+
+  CBINDGEN_CUDA_HOST_DEVICE bool IsRect() const {
+    return tag == Tag::Rect;
+  }
+};
+
+extern "C" {
+
+CBINDGEN_CUDA_HOST_DEVICE float vec2_length(Vec2 v);
+
+CBINDGEN_CUDA_HOST float shape_area(const Shape *shape);
+
+void host_only();
+
+} // extern "C"
@@ -0,0 +1,59 @@
+#if !defined(CBINDGEN_CUDA_HOST_DEVICE)
+#if defined(__CUDACC__)
+#define CBINDGEN_CUDA_HOST __host__
+#define CBINDGEN_CUDA_DEVICE __device__
+#define CBINDGEN_CUDA_HOST_DEVICE __host__ __device__
+#else
+#define CBINDGEN_CUDA_HOST
+#define CBINDGEN_CUDA_DEVICE
+#define CBINDGEN_CUDA_HOST_DEVICE
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Vec2 {
+  float x;
+  float y;
+} Vec2;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Rect,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    struct {
+      struct Vec2 rect;
+    };
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+CBINDGEN_CUDA_HOST_DEVICE float vec2_length(struct Vec2 v);
+
+CBINDGEN_CUDA_HOST float shape_area(const struct Shape *shape);
+
+void host_only(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,34 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Not exposed to Python.
+///
+enum class Internal {
+  A,
+  B,
+};
+
+/// A "small" enum.
+enum class Level : uint8_t {
+  Low,
+  High,
+};
+
+/// The outcome of a request.
+///
+/// Failures can be retried.
+enum class Status {
+  Ok,
+  /// Not found.
+  NotFound,
+  Failed = 10,
+};
+
+extern "C" {
+
+void root(Status status, Level level, Internal internal);
+
+} // extern "C"
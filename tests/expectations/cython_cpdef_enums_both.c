@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Not exposed to Python.
+ *
+ */
+typedef enum Internal {
+  A,
+  B,
+} Internal;
+
+/**
+ * A "small" enum.
+ */
+enum Level {
+  Low,
+  High,
+};
+typedef uint8_t Level;
+
+/**
+ * The outcome of a request.
+ *
+ * Failures can be retried.
+ */
+typedef enum Status {
+  Ok,
+  /**
+   * Not found.
+   */
+  NotFound,
+  Failed = 10,
+} Status;
+
+void root(enum Status status, Level level, enum Internal internal);
@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Not exposed to Python.
+ *
+ */
+enum Internal {
+  A,
+  B,
+};
+
+/**
+ * A "small" enum.
+ */
+enum Level {
+  Low,
+  High,
+};
+typedef uint8_t Level;
+
+/**
+ * The outcome of a request.
+ *
+ * Failures can be retried.
+ */
+enum Status {
+  Ok,
+  /**
+   * Not found.
+   */
+  NotFound,
+  Failed = 10,
+};
+
+void root(enum Status status, Level level, enum Internal internal);
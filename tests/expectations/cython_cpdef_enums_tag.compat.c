@@ -0,0 +1,52 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Not exposed to Python.
+ *
+ */
+enum Internal {
+  A,
+  B,
+};
+
+/**
+ * A "small" enum.
+ */
+enum Level
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Low,
+  High,
+};
+#ifndef __cplusplus
+typedef uint8_t Level;
+#endif // __cplusplus
+
+/**
+ * The outcome of a request.
+ *
+ * Failures can be retried.
+ */
+enum Status {
+  Ok,
+  /**
+   * Not found.
+   */
+  NotFound,
+  Failed = 10,
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(enum Status status, Level level, enum Internal internal);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,32 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # Not exposed to Python.
+  #
+  cdef enum Internal:
+    A,
+    B,
+
+  # A "small" enum.
+  cpdef enum:
+    Low,
+    High,
+  ctypedef uint8_t Level;
+
+  cpdef enum Status:
+    """
+    The outcome of a request.
+
+    Failures can be retried.
+    """
+    Ok,
+    # Not found.
+    NotFound,
+    Failed # = 10,
+
+  void root(Status status, Level level, Internal internal);
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+void buffer_fill(Buffer *buffer, uint8_t value);
+
+/**
+ * Calls back into Python, so it needs the GIL.
+ */
+void buffer_notify(const Buffer *buffer, void (*callback)(const Buffer*));
+
+uint32_t buffer_checksum_with_a_very_long_name_to_lay_out_its_arguments_vertically(
+  const Buffer *buffer,
+  uintptr_t offset,
+  uintptr_t len);
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Buffer:
+    uint8_t *data;
+    uintptr_t len;
+
+  void buffer_fill(Buffer *buffer, uint8_t value) nogil;
+
+  # Calls back into Python, so it needs the GIL.
+  void buffer_notify(const Buffer *buffer, void (*callback)(const Buffer*));
+
+  uint32_t buffer_checksum_with_a_very_long_name_to_lay_out_its_arguments_vertically(
+    const Buffer *buffer,
+    uintptr_t offset,
+    uintptr_t len) nogil;
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void buffer_fill(struct Buffer *buffer, uint8_t value);
+
+/**
+ * Calls back into Python, so it needs the GIL.
+ */
+void buffer_notify(const struct Buffer *buffer, void (*callback)(const struct Buffer*));
+
+uint32_t buffer_checksum_with_a_very_long_name_to_lay_out_its_arguments_vertically(
+  const struct Buffer *buffer,
+  uintptr_t offset,
+  uintptr_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
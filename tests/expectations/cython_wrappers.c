@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A parsed document.
+ */
+typedef struct Document Document;
+
+typedef struct Parser Parser;
+
+Parser *parser_new(uintptr_t capacity, bool strict);
+
+Document *parser_parse(Parser *parser);
+
+void parser_free(Parser *parser);
+
+void document_free(Document *document);
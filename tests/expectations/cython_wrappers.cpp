@@ -0,0 +1,39 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <memory>
+
+/// A parsed document.
+struct Document;
+
+struct Parser;
+
+extern "C" {
+
+Parser *parser_new(uintptr_t capacity, bool strict);
+
+Document *parser_parse(Parser *parser);
+
+void parser_free(Parser *parser);
+
+void document_free(Document *document);
+
+} // extern "C"
+
+struct DocumentDeleter {
+  void operator()(Document *ptr) const {
+    document_free(ptr);
+  }
+};
+
+using DocumentPtr = std::unique_ptr<Document, DocumentDeleter>;
+
+struct ParserDeleter {
+  void operator()(Parser *ptr) const {
+    parser_free(ptr);
+  }
+};
+
+using ParserPtr = std::unique_ptr<Parser, ParserDeleter>;
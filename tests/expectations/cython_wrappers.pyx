@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # A parsed document.
+  ctypedef struct Document:
+    pass
+
+  ctypedef struct Parser:
+    pass
+
+  Parser *parser_new(uintptr_t capacity, bool strict);
+
+  Document *parser_parse(Parser *parser);
+
+  void parser_free(Parser *parser);
+
+  void document_free(Document *document);
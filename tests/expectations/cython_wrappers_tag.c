@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A parsed document.
+ */
+struct Document;
+
+struct Parser;
+
+struct Parser *parser_new(uintptr_t capacity, bool strict);
+
+struct Document *parser_parse(struct Parser *parser);
+
+void parser_free(struct Parser *parser);
+
+void document_free(struct Document *document);
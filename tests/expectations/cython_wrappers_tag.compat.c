@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A parsed document.
+ */
+struct Document;
+
+struct Parser;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Parser *parser_new(uintptr_t capacity, bool strict);
+
+struct Document *parser_parse(struct Parser *parser);
+
+void parser_free(struct Parser *parser);
+
+void document_free(struct Document *document);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
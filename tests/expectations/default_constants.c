@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t hits;
+  uint64_t misses;
+  float ratio;
+  bool enabled;
+} Counters;
+/**
+ * The default value.
+ */
+#define Counters_DEFAULT (Counters){ .hits = 0, .misses = 0, .ratio = 0.0, .enabled = false }
+
+typedef struct {
+  uint32_t retries;
+  uint64_t timeout_ms;
+  bool verbose;
+} Options;
+/**
+ * The default value.
+ */
+#define Options_DEFAULT (Options){ .retries = 3, .timeout_ms = (30 * 1000), .verbose = false }
+
+typedef struct {
+  uint32_t width;
+  uint32_t height;
+} Size;
+/**
+ * The default value.
+ */
+#define Size_DEFAULT (Size){ .width = 640, .height = 480 }
+
+typedef struct {
+  uint32_t value;
+} Computed;
+
+typedef struct {
+  const uint8_t *data;
+} WithPointer;
+
+void root(Counters counters,
+          Options options,
+          Size size,
+          Computed computed,
+          WithPointer with_pointer);
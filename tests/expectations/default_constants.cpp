@@ -0,0 +1,47 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Counters {
+  uint32_t hits;
+  uint64_t misses;
+  float ratio;
+  bool enabled;
+};
+/// The default value.
+constexpr static const Counters Counters_DEFAULT = Counters{ /* .hits = */ 0, /* .misses = */ 0, /* .ratio = */ 0.0, /* .enabled = */ false };
+
+struct Options {
+  uint32_t retries;
+  uint64_t timeout_ms;
+  bool verbose;
+};
+/// The default value.
+constexpr static const Options Options_DEFAULT = Options{ /* .retries = */ 3, /* .timeout_ms = */ (30 * 1000), /* .verbose = */ false };
+
+struct Size {
+  uint32_t width;
+  uint32_t height;
+};
+/// The default value.
+constexpr static const Size Size_DEFAULT = Size{ /* .width = */ 640, /* .height = */ 480 };
+
+struct Computed {
+  uint32_t value;
+};
+
+struct WithPointer {
+  const uint8_t *data;
+};
+
+extern "C" {
+
+void root(Counters counters,
+          Options options,
+          Size size,
+          Computed computed,
+          WithPointer with_pointer);
+
+} // extern "C"
@@ -0,0 +1,56 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Counters {
+  uint32_t hits;
+  uint64_t misses;
+  float ratio;
+  bool enabled;
+};
+/**
+ * The default value.
+ */
+#define Counters_DEFAULT (Counters){ .hits = 0, .misses = 0, .ratio = 0.0, .enabled = false }
+
+struct Options {
+  uint32_t retries;
+  uint64_t timeout_ms;
+  bool verbose;
+};
+/**
+ * The default value.
+ */
+#define Options_DEFAULT (Options){ .retries = 3, .timeout_ms = (30 * 1000), .verbose = false }
+
+struct Size {
+  uint32_t width;
+  uint32_t height;
+};
+/**
+ * The default value.
+ */
+#define Size_DEFAULT (Size){ .width = 640, .height = 480 }
+
+struct Computed {
+  uint32_t value;
+};
+
+struct WithPointer {
+  const uint8_t *data;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Counters counters,
+          struct Options options,
+          struct Size size,
+          struct Computed computed,
+          struct WithPointer with_pointer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,40 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Counters:
+    uint32_t hits;
+    uint64_t misses;
+    float ratio;
+    bool enabled;
+  # The default value.
+  const Counters Counters_DEFAULT # = <Counters>{ 0, 0, 0.0, False }
+
+  cdef struct Options:
+    uint32_t retries;
+    uint64_t timeout_ms;
+    bool verbose;
+  # The default value.
+  const Options Options_DEFAULT # = <Options>{ 3, (30 * 1000), False }
+
+  cdef struct Size:
+    uint32_t width;
+    uint32_t height;
+  # The default value.
+  const Size Size_DEFAULT # = <Size>{ 640, 480 }
+
+  cdef struct Computed:
+    uint32_t value;
+
+  cdef struct WithPointer:
+    const uint8_t *data;
+
+  void root(Counters counters,
+            Options options,
+            Size size,
+            Computed computed,
+            WithPointer with_pointer);
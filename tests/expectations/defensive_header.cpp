@@ -0,0 +1,50 @@
+#ifndef DEFENSIVE_HEADER_H
+#define DEFENSIVE_HEADER_H
+
+#pragma once
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C++" {
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Shape {
+  enum class Tag {
+    Circle,
+    Square,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Square_Body {
+    Point _0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+};
+
+extern "C" {
+
+extern const uint32_t COUNTER;
+
+float area(Shape s);
+
+} // extern "C"
+
+} // extern "C++"
+
+#endif // DEFENSIVE_HEADER_H
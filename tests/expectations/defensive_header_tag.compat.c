@@ -0,0 +1,53 @@
+#ifndef DEFENSIVE_HEADER_H
+#define DEFENSIVE_HEADER_H
+
+#pragma once
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C++" {
+#endif // __cplusplus
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+enum Shape_Tag {
+  Circle,
+  Square,
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    struct {
+      struct Point square;
+    };
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t COUNTER;
+
+float area(struct Shape s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // extern "C++"
+#endif // __cplusplus
+
+#endif /* DEFENSIVE_HEADER_H */
@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+
+  cdef enum Shape_Tag:
+    Circle,
+    Square,
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    float circle;
+    Point square;
+
+  extern const uint32_t COUNTER;
+
+  float area(Shape s);
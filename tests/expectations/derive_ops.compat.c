@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+  float z;
+} Vec3;
+
+typedef struct {
+  float x;
+  float y;
+} Vec2;
+
+typedef struct {
+  uint8_t bits;
+} Permissions;
+
+typedef struct {
+  uint64_t value;
+} Id;
+
+typedef struct {
+  uint32_t value;
+} Opaque;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Vec3 vec3_sub(const Vec3 *a, const Vec3 *b);
+
+Vec2 vec2_add(Vec2 a, Vec2 b);
+
+void root(Permissions p, Id i, Opaque o, Vec3 v);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
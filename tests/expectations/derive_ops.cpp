@@ -0,0 +1,112 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Vec3 {
+  float x;
+  float y;
+  float z;
+
+  constexpr Vec3 operator+(const Vec3& other) const {
+    return Vec3 { static_cast<decltype(x)>(x + other.x),
+                  static_cast<decltype(y)>(y + other.y),
+                  static_cast<decltype(z)>(z + other.z) };
+  }
+  Vec3& operator+=(const Vec3& other) {
+    *this = (*this + other);
+    return *this;
+  }
+};
+
+struct Vec2 {
+  float x;
+  float y;
+
+  constexpr Vec2 operator-(const Vec2& other) const {
+    return Vec2 { static_cast<decltype(x)>(x - other.x),
+                  static_cast<decltype(y)>(y - other.y) };
+  }
+  Vec2& operator-=(const Vec2& other) {
+    *this = (*this - other);
+    return *this;
+  }
+  constexpr Vec2 operator-() const {
+    return Vec2 { static_cast<decltype(x)>(-x),
+                  static_cast<decltype(y)>(-y) };
+  }
+};
+
+struct Permissions {
+  uint8_t bits;
+
+  constexpr Permissions operator|(const Permissions& other) const {
+    return Permissions { static_cast<decltype(bits)>(bits | other.bits) };
+  }
+  Permissions& operator|=(const Permissions& other) {
+    *this = (*this | other);
+    return *this;
+  }
+  constexpr Permissions operator&(const Permissions& other) const {
+    return Permissions { static_cast<decltype(bits)>(bits & other.bits) };
+  }
+  Permissions& operator&=(const Permissions& other) {
+    *this = (*this & other);
+    return *this;
+  }
+  constexpr Permissions operator~() const {
+    return Permissions { static_cast<decltype(bits)>(~bits) };
+  }
+};
+
+struct Id {
+  uint64_t value;
+
+  constexpr Id operator+(const Id& other) const {
+    return Id { static_cast<decltype(value)>(value + other.value) };
+  }
+  Id& operator+=(const Id& other) {
+    *this = (*this + other);
+    return *this;
+  }
+  constexpr Id operator-(const Id& other) const {
+    return Id { static_cast<decltype(value)>(value - other.value) };
+  }
+  Id& operator-=(const Id& other) {
+    *this = (*this - other);
+    return *this;
+  }
+};
+
+struct Opaque {
+  uint32_t value;
+};
+
+extern "C" {
+
+Vec3 vec3_sub(const Vec3 *a, const Vec3 *b);
+
+Vec2 vec2_add(Vec2 a, Vec2 b);
+
+void root(Permissions p, Id i, Opaque o, Vec3 v);
+
+} // extern "C"
+
+inline Vec3 operator-(const Vec3& lhs, const Vec3& rhs) {
+  return vec3_sub(&lhs, &rhs);
+}
+
+inline Vec3& operator-=(Vec3& lhs, const Vec3& rhs) {
+  lhs = lhs - rhs;
+  return lhs;
+}
+
+inline Vec2 operator+(const Vec2& lhs, const Vec2& rhs) {
+  return vec2_add(lhs, rhs);
+}
+
+inline Vec2& operator+=(Vec2& lhs, const Vec2& rhs) {
+  lhs = lhs + rhs;
+  return lhs;
+}
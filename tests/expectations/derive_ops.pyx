@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Vec3:
+    float x;
+    float y;
+    float z;
+
+  ctypedef struct Vec2:
+    float x;
+    float y;
+
+  ctypedef struct Permissions:
+    uint8_t bits;
+
+  ctypedef struct Id:
+    uint64_t value;
+
+  ctypedef struct Opaque:
+    uint32_t value;
+
+  Vec3 vec3_sub(const Vec3 *a, const Vec3 *b);
+
+  Vec2 vec2_add(Vec2 a, Vec2 b);
+
+  void root(Permissions p, Id i, Opaque o, Vec3 v);
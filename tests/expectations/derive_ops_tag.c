@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Vec3 {
+  float x;
+  float y;
+  float z;
+};
+
+struct Vec2 {
+  float x;
+  float y;
+};
+
+struct Permissions {
+  uint8_t bits;
+};
+
+struct Id {
+  uint64_t value;
+};
+
+struct Opaque {
+  uint32_t value;
+};
+
+struct Vec3 vec3_sub(const struct Vec3 *a, const struct Vec3 *b);
+
+struct Vec2 vec2_add(struct Vec2 a, struct Vec2 b);
+
+void root(struct Permissions p, struct Id i, struct Opaque o, struct Vec3 v);
@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Vec3:
+    float x;
+    float y;
+    float z;
+
+  cdef struct Vec2:
+    float x;
+    float y;
+
+  cdef struct Permissions:
+    uint8_t bits;
+
+  cdef struct Id:
+    uint64_t value;
+
+  cdef struct Opaque:
+    uint32_t value;
+
+  Vec3 vec3_sub(const Vec3 *a, const Vec3 *b);
+
+  Vec2 vec2_add(Vec2 a, Vec2 b);
+
+  void root(Permissions p, Id i, Opaque o, Vec3 v);
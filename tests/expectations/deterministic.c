@@ -0,0 +1,26 @@
+#include <errno.h>
+#include <limits.h>
+#include <stdint.h>
+#include <stdio.h>
+
+/* from deterministic.rs:13 */
+#define LEGS 4
+
+/* from deterministic.rs:12 */
+#define MAX_STRIPES 100
+
+/* from deterministic.rs:3 */
+typedef struct {
+  uint32_t stripes;
+} Zebra;
+
+/* from deterministic.rs:8 */
+typedef struct {
+  Zebra zebra;
+} Aardvark;
+
+/* from deterministic.rs:19 */
+void aardvark_new(Aardvark aardvark);
+
+/* from deterministic.rs:16 */
+void zoo_open(Zebra zebra);
@@ -0,0 +1,30 @@
+#include <errno.h>
+#include <limits.h>
+#include <stdint.h>
+#include <stdio.h>
+
+/* from deterministic.rs:13 */
+constexpr static const uint32_t LEGS = 4;
+
+/* from deterministic.rs:12 */
+constexpr static const uint32_t MAX_STRIPES = 100;
+
+/* from deterministic.rs:3 */
+struct Zebra {
+  uint32_t stripes;
+};
+
+/* from deterministic.rs:8 */
+struct Aardvark {
+  Zebra zebra;
+};
+
+extern "C" {
+
+/* from deterministic.rs:19 */
+void aardvark_new(Aardvark aardvark);
+
+/* from deterministic.rs:16 */
+void zoo_open(Zebra zebra);
+
+} // extern "C"
@@ -0,0 +1,34 @@
+#include <errno.h>
+#include <limits.h>
+#include <stdint.h>
+#include <stdio.h>
+
+/* from deterministic.rs:13 */
+#define LEGS 4
+
+/* from deterministic.rs:12 */
+#define MAX_STRIPES 100
+
+/* from deterministic.rs:3 */
+struct Zebra {
+  uint32_t stripes;
+};
+
+/* from deterministic.rs:8 */
+struct Aardvark {
+  struct Zebra zebra;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/* from deterministic.rs:19 */
+void aardvark_new(struct Aardvark aardvark);
+
+/* from deterministic.rs:16 */
+void zoo_open(struct Zebra zebra);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
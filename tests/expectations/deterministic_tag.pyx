@@ -0,0 +1,22 @@
+
+cdef extern from *:
+
+  # from deterministic.rs:13
+  const uint32_t LEGS # = 4
+
+  # from deterministic.rs:12
+  const uint32_t MAX_STRIPES # = 100
+
+  # from deterministic.rs:3
+  cdef struct Zebra:
+    uint32_t stripes;
+
+  # from deterministic.rs:8
+  cdef struct Aardvark:
+    Zebra zebra;
+
+  # from deterministic.rs:19
+  void aardvark_new(Aardvark aardvark);
+
+  # from deterministic.rs:16
+  void zoo_open(Zebra zebra);
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A buffer of samples, see buffer_new and Buffer::len.
+ *
+ * Samples are stored in Sample order, and `samples[i]`
+ * is the i-th one. More on [the format](https://example.com/format).
+ */
+typedef struct {
+  uintptr_t len;
+} Buffer;
+
+/**
+ * Creates a buffer.
+ *
+ * @param len The number of samples, see Buffer.
+ * @param capacity The number of samples to reserve room for.
+ *
+ * @return
+ * A new buffer, to be freed by buffer_free().
+ *
+ * @par Examples
+ * @code
+ * Buffer *buffer = buffer_new(16, 32);
+ * @endcode
+ */
+Buffer *buffer_new(uintptr_t len, uintptr_t capacity);
+
+/**
+ * Frees a buffer.
+ *
+ * @warning Safety:
+ * `buffer` must come from buffer_new.
+ *
+ * @warning Panics:
+ * If `buffer` is null.
+ */
+void buffer_free(Buffer *buffer);
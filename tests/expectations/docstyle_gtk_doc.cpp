@@ -0,0 +1,68 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T = void>
+struct Box;
+
+/**
+ * Buffer:
+ *
+ * An opaque buffer.
+ */
+struct Buffer;
+
+template<typename T = void>
+struct Option;
+
+/**
+ * Point:
+ *
+ * A point in space.
+ */
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+/**
+ * buffer_new:
+ * @bytes: (nullable) (array length=len): The bytes to copy.
+ * @len: How many bytes there are.
+ *
+ * Creates a buffer holding a copy of the given bytes.
+ *
+ * Returns: A new buffer, or null if allocation failed.
+ */
+Option<Box<Buffer>> buffer_new(const uint8_t *bytes, uintptr_t len);
+
+/**
+ * buffer_free:
+ * @buffer: (nullable) (transfer full):
+ *
+ * Frees a buffer.
+ */
+void buffer_free(Buffer *buffer);
+
+/**
+ * buffer_data:
+ * @buffer:
+ *
+ * Returns the data of a buffer.
+ *
+ * Returns: (transfer none) (nullable):
+ */
+const uint8_t *buffer_data(const Buffer *buffer);
+
+/**
+ * point_distance:
+ * @a:
+ * @b: (nullable):
+ */
+float point_distance(const Point *a, const Point *b);
+
+} // extern "C"
@@ -0,0 +1,38 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # An opaque buffer.
+  ctypedef struct Buffer:
+    pass
+
+  # A point in space.
+  ctypedef struct Point:
+    float x;
+    float y;
+
+  # Creates a buffer holding a copy of the given bytes.
+  #
+  # # Arguments
+  #
+  # * `bytes` - The bytes to copy.
+  # * `len` - How many bytes there are.
+  #
+  # # Returns
+  #
+  # A new buffer, or null if allocation failed.
+  #
+  Buffer *buffer_new(const uint8_t *bytes, uintptr_t len);
+
+  # Frees a buffer.
+  #
+  void buffer_free(Buffer *buffer);
+
+  # Returns the data of a buffer.
+  const uint8_t *buffer_data(const Buffer *buffer);
+
+  float point_distance(const Point *a, const Point *b);
@@ -0,0 +1,65 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Buffer:
+ *
+ * An opaque buffer.
+ */
+typedef struct Buffer Buffer;
+
+/**
+ * Point:
+ *
+ * A point in space.
+ */
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * buffer_new:
+ * @bytes: (nullable) (array length=len): The bytes to copy.
+ * @len: How many bytes there are.
+ *
+ * Creates a buffer holding a copy of the given bytes.
+ *
+ * Returns: (transfer full) (nullable): A new buffer, or null if allocation failed.
+ */
+struct Buffer *buffer_new(const uint8_t *bytes, uintptr_t len);
+
+/**
+ * buffer_free:
+ * @buffer: (nullable) (transfer full):
+ *
+ * Frees a buffer.
+ */
+void buffer_free(struct Buffer *buffer);
+
+/**
+ * buffer_data:
+ * @buffer:
+ *
+ * Returns the data of a buffer.
+ *
+ * Returns: (transfer none) (nullable):
+ */
+const uint8_t *buffer_data(const struct Buffer *buffer);
+
+/**
+ * point_distance:
+ * @a:
+ * @b: (nullable):
+ */
+float point_distance(const struct Point *a, const struct Point *b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
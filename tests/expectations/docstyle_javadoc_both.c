@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A buffer of samples, see buffer_new and Buffer::len.
+ *
+ * Samples are stored in Sample order, and `samples[i]`
+ * is the i-th one. More on [the format](https://example.com/format).
+ */
+typedef struct Buffer {
+  uintptr_t len;
+} Buffer;
+
+/**
+ * Creates a buffer.
+ *
+ * <p><b>Examples</b>
+ * <pre>{@code
+ * Buffer *buffer = buffer_new(16, 32);
+ * }</pre>
+ *
+ * @param len The number of samples, see Buffer.
+ * @param capacity The number of samples to reserve room for.
+ * @return A new buffer, to be freed by buffer_free().
+ */
+struct Buffer *buffer_new(uintptr_t len, uintptr_t capacity);
+
+/**
+ * Frees a buffer.
+ *
+ * <p><b>Safety:</b>
+ * `buffer` must come from buffer_new.
+ *
+ * <p><b>Panics:</b>
+ * If `buffer` is null.
+ */
+void buffer_free(struct Buffer *buffer);
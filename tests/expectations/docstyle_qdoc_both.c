@@ -0,0 +1,47 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A buffer of samples, see [`buffer_new`] and [Buffer::len](crate::Buffer).
+ *
+ * Samples are stored in [`crate::audio::Sample`] order, and `samples[i]`
+ * is the i-th one. More on [the format](https://example.com/format).
+ */
+typedef struct Buffer {
+  uintptr_t len;
+} Buffer;
+
+/**
+ * Creates a buffer.
+ *
+ * # Arguments
+ *
+ * * `len` - The number of samples, see [`Buffer`].
+ * * capacity - The number of samples to reserve room for.
+ *
+ * # Returns
+ *
+ * A new buffer, to be freed by [`buffer_free()`].
+ *
+ * # Examples
+ *
+ * ```c
+ * Buffer *buffer = buffer_new(16, 32);
+ * ```
+ */
+struct Buffer *buffer_new(uintptr_t len, uintptr_t capacity);
+
+/**
+ * Frees a buffer.
+ *
+ * # Safety
+ *
+ * `buffer` must come from [`buffer_new`].
+ *
+ * # Panics
+ *
+ * If `buffer` is null.
+ */
+void buffer_free(struct Buffer *buffer);
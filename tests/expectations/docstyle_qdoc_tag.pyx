@@ -0,0 +1,43 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # A buffer of samples, see [`buffer_new`] and [Buffer::len](crate::Buffer).
+  #
+  # Samples are stored in [`crate::audio::Sample`] order, and `samples[i]`
+  # is the i-th one. More on [the format](https://example.com/format).
+  cdef struct Buffer:
+    uintptr_t len;
+
+  # Creates a buffer.
+  #
+  # # Arguments
+  #
+  # * `len` - The number of samples, see [`Buffer`].
+  # * capacity - The number of samples to reserve room for.
+  #
+  # # Returns
+  #
+  # A new buffer, to be freed by [`buffer_free()`].
+  #
+  # # Examples
+  #
+  # ```c
+  # Buffer *buffer = buffer_new(16, 32);
+  # ```
+  Buffer *buffer_new(uintptr_t len, uintptr_t capacity);
+
+  # Frees a buffer.
+  #
+  # # Safety
+  #
+  # `buffer` must come from [`buffer_new`].
+  #
+  # # Panics
+  #
+  # If `buffer` is null.
+  void buffer_free(Buffer *buffer);
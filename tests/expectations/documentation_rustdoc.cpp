@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Item {
+  uint32_t value;
+};
+
+/// A buffer holding Items, see buffer_push or [the
+/// guide](https://example.com/guide).
+///
+/// # Preconditions
+///
+/// The `data` of a Buffer must be valid for `len` items.
+///
+/// ```c
+/// # include <stdlib.h>
+/// ```
+struct Buffer {
+  Item *data;
+  uintptr_t len;
+};
+
+extern "C" {
+
+/// Pushes an Item to a Buffer.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// buffer_push(&mut buffer, item);
+/// ```
+///
+/// # Panics
+///
+/// If the buffer is full.
+void buffer_push(Buffer *buffer, Item item);
+
+} // extern "C"
@@ -0,0 +1,37 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Item:
+    uint32_t value;
+
+  # A buffer holding Items, see buffer_push or [the
+  # guide](https://example.com/guide).
+  #
+  # # Preconditions
+  #
+  # The `data` of a Buffer must be valid for `len` items.
+  #
+  # ```c
+  # # include <stdlib.h>
+  # ```
+  ctypedef struct Buffer:
+    Item *data;
+    uintptr_t len;
+
+  # Pushes an Item to a Buffer.
+  #
+  # # Example
+  #
+  # ```rust,no_run
+  # buffer_push(&mut buffer, item);
+  # ```
+  #
+  # # Panics
+  #
+  # If the buffer is full.
+  void buffer_push(Buffer *buffer, Item item);
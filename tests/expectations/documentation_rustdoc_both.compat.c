@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Item {
+  uint32_t value;
+} Item;
+
+/**
+ * A buffer holding Items, see buffer_push or [the
+ * guide](https://example.com/guide).
+ *
+ * # Preconditions
+ *
+ * The `data` of a Buffer must be valid for `len` items.
+ *
+ * ```c
+ * # include <stdlib.h>
+ * ```
+ */
+typedef struct Buffer {
+  struct Item *data;
+  uintptr_t len;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Pushes an Item to a Buffer.
+ *
+ * # Example
+ *
+ * ```rust,no_run
+ * buffer_push(&mut buffer, item);
+ * ```
+ *
+ * # Panics
+ *
+ * If the buffer is full.
+ */
+void buffer_push(struct Buffer *buffer, struct Item item);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
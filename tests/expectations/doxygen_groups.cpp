@@ -0,0 +1,38 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/** \defgroup geometry Geometry */
+/** \defgroup Drawing Drawing */
+/** \defgroup Audio_output Audio output */
+
+/// A point.
+///
+/// \ingroup geometry
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+void ungrouped();
+
+/* Drawing */
+
+/// \ingroup Drawing
+void draw_point(Point point);
+
+/// Draws a line.
+///
+/// \ingroup Drawing
+void draw_line(Point from, Point to);
+
+/* Audio output */
+
+/// \ingroup Audio_output
+void play_sound(uint32_t id);
+
+} // extern "C"
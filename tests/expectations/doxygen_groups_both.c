@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/** \defgroup geometry Geometry */
+/** \defgroup Drawing Drawing */
+/** \defgroup Audio_output Audio output */
+
+/**
+ * A point.
+ *
+ * \ingroup geometry
+ */
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+void ungrouped(void);
+
+/* Drawing */
+
+/**
+ * \ingroup Drawing
+ */
+void draw_point(struct Point point);
+
+/**
+ * Draws a line.
+ *
+ * \ingroup Drawing
+ */
+void draw_line(struct Point from, struct Point to);
+
+/* Audio output */
+
+/**
+ * \ingroup Audio_output
+ */
+void play_sound(uint32_t id);
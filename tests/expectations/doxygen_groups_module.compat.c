@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/** \defgroup net_socket Sockets */
+/** \defgroup net Networking */
+/** \defgroup audio audio */
+/** \defgroup media media */
+
+/**
+ * \ingroup net_socket
+ */
+#define MAX_SOCKETS 64
+
+/**
+ * An IPv4 address.
+ *
+ * \ingroup net
+ */
+typedef struct {
+  uint8_t octets[4];
+  uint16_t port;
+} Address;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Initializes the library.
+ */
+void library_init(void);
+
+/**
+ * \ingroup audio
+ */
+void audio_play(void);
+
+/**
+ * \ingroup media
+ */
+void video_play(void);
+
+/**
+ * \ingroup net
+ */
+bool address_is_loopback(const Address *address);
+
+/**
+ * Opens a socket connected to `address`.
+ *
+ * \ingroup net_socket
+ */
+int32_t socket_open(const Address *address);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
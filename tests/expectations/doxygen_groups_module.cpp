@@ -0,0 +1,42 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/** \defgroup net_socket Sockets */
+/** \defgroup net Networking */
+/** \defgroup audio audio */
+/** \defgroup media media */
+
+/// \ingroup net_socket
+constexpr static const uint32_t MAX_SOCKETS = 64;
+
+/// An IPv4 address.
+///
+/// \ingroup net
+struct Address {
+  uint8_t octets[4];
+  uint16_t port;
+};
+
+extern "C" {
+
+/// Initializes the library.
+void library_init();
+
+/// \ingroup audio
+void audio_play();
+
+/// \ingroup media
+void video_play();
+
+/// \ingroup net
+bool address_is_loopback(const Address *address);
+
+/// Opens a socket connected to `address`.
+///
+/// \ingroup net_socket
+int32_t socket_open(const Address *address);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t MAX_SOCKETS # = 64
+
+  # An IPv4 address.
+  ctypedef struct Address:
+    uint8_t octets[4];
+    uint16_t port;
+
+  # Initializes the library.
+  void library_init();
+
+  void audio_play();
+
+  void video_play();
+
+  bool address_is_loopback(const Address *address);
+
+  # Opens a socket connected to `address`.
+  int32_t socket_open(const Address *address);
@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/** \defgroup net_socket Sockets */
+/** \defgroup net Networking */
+/** \defgroup audio audio */
+/** \defgroup media media */
+
+/**
+ * \ingroup net_socket
+ */
+#define MAX_SOCKETS 64
+
+/**
+ * An IPv4 address.
+ *
+ * \ingroup net
+ */
+struct Address {
+  uint8_t octets[4];
+  uint16_t port;
+};
+
+/**
+ * Initializes the library.
+ */
+void library_init(void);
+
+/**
+ * \ingroup audio
+ */
+void audio_play(void);
+
+/**
+ * \ingroup media
+ */
+void video_play(void);
+
+/**
+ * \ingroup net
+ */
+bool address_is_loopback(const struct Address *address);
+
+/**
+ * Opens a socket connected to `address`.
+ *
+ * \ingroup net_socket
+ */
+int32_t socket_open(const struct Address *address);
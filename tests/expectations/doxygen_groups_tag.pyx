@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # A point.
+  #
+  cdef struct Point:
+    float x;
+    float y;
+
+  void ungrouped();
+
+  # Drawing
+
+  void draw_point(Point point);
+
+  # Draws a line.
+  #
+  void draw_line(Point from, Point to);
+
+  # Audio output
+
+  void play_sound(uint32_t id);
@@ -0,0 +1,88 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Point,
+  Circle,
+  Rect,
+  Scaled,
+} Shape_Tag;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    struct {
+      float radius;
+    } circle;
+    struct {
+      float _0;
+      float _1;
+    } rect;
+    struct {
+      float scaled;
+    };
+  };
+} Shape;
+
+enum Event_Tag {
+  Quit,
+  Key,
+  Move,
+};
+typedef uint8_t Event_Tag;
+
+typedef union {
+  Event_Tag tag;
+  struct {
+    Event_Tag tag;
+    uint32_t code;
+    bool pressed;
+  } key;
+  struct {
+    Event_Tag tag;
+    int32_t _0;
+    int32_t _1;
+  } move;
+} Event;
+
+enum Value_Tag {
+  Nothing,
+  Pair,
+  Triple,
+};
+typedef uint8_t Value_Tag;
+
+typedef struct {
+  Value_Tag tag;
+  union {
+    struct {
+      int32_t first;
+      int32_t second;
+    } pair;
+    struct {
+      int32_t _0;
+      int32_t _1;
+      int32_t _2;
+    } triple;
+  };
+} Value;
+
+typedef enum {
+  None,
+  Some,
+} Named_Tag;
+
+typedef struct {
+  int32_t value;
+} Some_Body;
+
+typedef struct {
+  Named_Tag tag;
+  union {
+    Some_Body some;
+  };
+} Named;
+
+void root(Shape shape, Event event, Value value, Named named);
@@ -0,0 +1,430 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <cassert>
+
+struct Shape {
+  enum class Tag {
+    Point,
+    Circle,
+    Rect,
+    Scaled,
+  };
+
+  Tag tag;
+  union {
+    struct {
+      float radius;
+    } circle;
+    struct {
+      float _0;
+      float _1;
+    } rect;
+    struct {
+      float _0;
+    } scaled;
+  };
+
+  static Shape Point() {
+    Shape result;
+    result.tag = Tag::Point;
+    return result;
+  }
+
+  bool IsPoint() const {
+    return tag == Tag::Point;
+  }
+
+  static Shape Circle(const float &radius) {
+    Shape result;
+    ::new (&result.circle.radius) (float)(radius);
+    result.tag = Tag::Circle;
+    return result;
+  }
+
+  bool IsCircle() const {
+    return tag == Tag::Circle;
+  }
+
+  const decltype(circle)& AsCircle() const {
+    assert(IsCircle());
+    return circle;
+  }
+
+  decltype(circle)& AsCircle() {
+    assert(IsCircle());
+    return circle;
+  }
+
+  static Shape Rect(const float &_0,
+                    const float &_1) {
+    Shape result;
+    ::new (&result.rect._0) (float)(_0);
+    ::new (&result.rect._1) (float)(_1);
+    result.tag = Tag::Rect;
+    return result;
+  }
+
+  bool IsRect() const {
+    return tag == Tag::Rect;
+  }
+
+  const decltype(rect)& AsRect() const {
+    assert(IsRect());
+    return rect;
+  }
+
+  decltype(rect)& AsRect() {
+    assert(IsRect());
+    return rect;
+  }
+
+  static Shape Scaled(const float &_0) {
+    Shape result;
+    ::new (&result.scaled._0) (float)(_0);
+    result.tag = Tag::Scaled;
+    return result;
+  }
+
+  bool IsScaled() const {
+    return tag == Tag::Scaled;
+  }
+
+  const float& AsScaled() const {
+    assert(IsScaled());
+    return scaled._0;
+  }
+
+  float& AsScaled() {
+    assert(IsScaled());
+    return scaled._0;
+  }
+
+  private:
+  Shape() {
+
+  }
+  public:
+
+
+  ~Shape() {
+    switch (tag) {
+      case Tag::Circle: { using Body = decltype(circle); circle.~Body(); } break;
+      case Tag::Rect: { using Body = decltype(rect); rect.~Body(); } break;
+      case Tag::Scaled: { using Body = decltype(scaled); scaled.~Body(); } break;
+      default: break;
+    }
+  }
+
+  Shape(const Shape& other)
+   : tag(other.tag) {
+    switch (tag) {
+      case Tag::Circle: ::new (&circle) (decltype(circle))(other.circle); break;
+      case Tag::Rect: ::new (&rect) (decltype(rect))(other.rect); break;
+      case Tag::Scaled: ::new (&scaled) (decltype(scaled))(other.scaled); break;
+      default: break;
+    }
+  }
+};
+
+union Event {
+  enum class Tag : uint8_t {
+    Quit,
+    Key,
+    Move,
+  };
+
+  struct {
+    Tag tag;
+  };
+  struct {
+    Tag tag;
+    uint32_t code;
+    bool pressed;
+  } key;
+  struct {
+    Tag tag;
+    int32_t _0;
+    int32_t _1;
+  } move;
+
+  static Event Quit() {
+    Event result;
+    result.tag = Tag::Quit;
+    return result;
+  }
+
+  bool IsQuit() const {
+    return tag == Tag::Quit;
+  }
+
+  static Event Key(const uint32_t &code,
+                   const bool &pressed) {
+    Event result;
+    ::new (&result.key.code) (uint32_t)(code);
+    ::new (&result.key.pressed) (bool)(pressed);
+    result.tag = Tag::Key;
+    return result;
+  }
+
+  bool IsKey() const {
+    return tag == Tag::Key;
+  }
+
+  const decltype(key)& AsKey() const {
+    assert(IsKey());
+    return key;
+  }
+
+  decltype(key)& AsKey() {
+    assert(IsKey());
+    return key;
+  }
+
+  static Event Move(const int32_t &_0,
+                    const int32_t &_1) {
+    Event result;
+    ::new (&result.move._0) (int32_t)(_0);
+    ::new (&result.move._1) (int32_t)(_1);
+    result.tag = Tag::Move;
+    return result;
+  }
+
+  bool IsMove() const {
+    return tag == Tag::Move;
+  }
+
+  const decltype(move)& AsMove() const {
+    assert(IsMove());
+    return move;
+  }
+
+  decltype(move)& AsMove() {
+    assert(IsMove());
+    return move;
+  }
+
+  private:
+  Event() {
+
+  }
+  public:
+
+
+  ~Event() {
+    switch (tag) {
+      case Tag::Key: { using Body = decltype(key); key.~Body(); } break;
+      case Tag::Move: { using Body = decltype(move); move.~Body(); } break;
+      default: break;
+    }
+  }
+
+  Event(const Event& other)
+   : tag(other.tag) {
+    switch (tag) {
+      case Tag::Key: ::new (&key) (decltype(key))(other.key); break;
+      case Tag::Move: ::new (&move) (decltype(move))(other.move); break;
+      default: break;
+    }
+  }
+};
+
+struct Value {
+  enum class Tag : uint8_t {
+    Nothing,
+    Pair,
+    Triple,
+  };
+
+  friend std::ostream& operator<<(std::ostream& stream, const Tag& instance) {
+    using Tag = Value::Tag;
+    switch (instance) {
+      case Tag::Nothing: stream << "Nothing"; break;
+      case Tag::Pair: stream << "Pair"; break;
+      case Tag::Triple: stream << "Triple"; break;
+    }
+    return stream;
+  }
+
+  friend std::ostream& operator<<(std::ostream& stream, const Value& instance) {
+    using Tag = Value::Tag;
+    switch (instance.tag) {
+      case Tag::Nothing: stream << "Nothing"; break;
+      case Tag::Pair: stream << "Pair"; break;
+      case Tag::Triple: stream << "Triple"; break;
+    }
+    return stream;
+  }
+
+  Tag tag;
+  union {
+    struct {
+      int32_t first;
+      int32_t second;
+    } pair;
+    struct {
+      int32_t _0;
+      int32_t _1;
+      int32_t _2;
+    } triple;
+  };
+
+  static Value Nothing() {
+    Value result;
+    result.tag = Tag::Nothing;
+    return result;
+  }
+
+  bool IsNothing() const {
+    return tag == Tag::Nothing;
+  }
+
+  static Value Pair(const int32_t &first,
+                    const int32_t &second) {
+    Value result;
+    ::new (&result.pair.first) (int32_t)(first);
+    ::new (&result.pair.second) (int32_t)(second);
+    result.tag = Tag::Pair;
+    return result;
+  }
+
+  bool IsPair() const {
+    return tag == Tag::Pair;
+  }
+
+  const decltype(pair)& AsPair() const {
+    assert(IsPair());
+    return pair;
+  }
+
+  decltype(pair)& AsPair() {
+    assert(IsPair());
+    return pair;
+  }
+
+  static Value Triple(const int32_t &_0,
+                      const int32_t &_1,
+                      const int32_t &_2) {
+    Value result;
+    ::new (&result.triple._0) (int32_t)(_0);
+    ::new (&result.triple._1) (int32_t)(_1);
+    ::new (&result.triple._2) (int32_t)(_2);
+    result.tag = Tag::Triple;
+    return result;
+  }
+
+  bool IsTriple() const {
+    return tag == Tag::Triple;
+  }
+
+  const decltype(triple)& AsTriple() const {
+    assert(IsTriple());
+    return triple;
+  }
+
+  decltype(triple)& AsTriple() {
+    assert(IsTriple());
+    return triple;
+  }
+
+  private:
+  Value() {
+
+  }
+  public:
+
+
+  ~Value() {
+    switch (tag) {
+      case Tag::Pair: { using Body = decltype(pair); pair.~Body(); } break;
+      case Tag::Triple: { using Body = decltype(triple); triple.~Body(); } break;
+      default: break;
+    }
+  }
+
+  Value(const Value& other)
+   : tag(other.tag) {
+    switch (tag) {
+      case Tag::Pair: ::new (&pair) (decltype(pair))(other.pair); break;
+      case Tag::Triple: ::new (&triple) (decltype(triple))(other.triple); break;
+      default: break;
+    }
+  }
+};
+
+struct Named {
+  enum class Tag {
+    None,
+    Some,
+  };
+
+  struct Some_Body {
+    int32_t value;
+  };
+
+  Tag tag;
+  union {
+    Some_Body some;
+  };
+
+  static Named None() {
+    Named result;
+    result.tag = Tag::None;
+    return result;
+  }
+
+  bool IsNone() const {
+    return tag == Tag::None;
+  }
+
+  static Named Some(const int32_t &value) {
+    Named result;
+    ::new (&result.some.value) (int32_t)(value);
+    result.tag = Tag::Some;
+    return result;
+  }
+
+  bool IsSome() const {
+    return tag == Tag::Some;
+  }
+
+  const Some_Body& AsSome() const {
+    assert(IsSome());
+    return some;
+  }
+
+  Some_Body& AsSome() {
+    assert(IsSome());
+    return some;
+  }
+
+  private:
+  Named() {
+
+  }
+  public:
+
+
+  ~Named() {
+    switch (tag) {
+      case Tag::Some: some.~Some_Body(); break;
+      default: break;
+    }
+  }
+
+  Named(const Named& other)
+   : tag(other.tag) {
+    switch (tag) {
+      case Tag::Some: ::new (&some) (Some_Body)(other.some); break;
+      default: break;
+    }
+  }
+};
+
+extern "C" {
+
+void root(Shape shape, Event event, Value value, Named named);
+
+} // extern "C"
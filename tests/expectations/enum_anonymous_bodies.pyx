@@ -0,0 +1,80 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef enum Shape_Tag:
+    Point,
+    Circle,
+    Rect,
+    Scaled,
+
+  ctypedef struct Circle_Body:
+    float radius;
+
+  ctypedef struct Rect_Body:
+    float _0;
+    float _1;
+
+  ctypedef struct Shape:
+    Shape_Tag tag;
+    Circle_Body circle;
+    Rect_Body rect;
+    float scaled;
+
+  cdef enum:
+    Quit,
+    Key,
+    Move,
+  ctypedef uint8_t Event_Tag;
+
+  ctypedef struct Key_Body:
+    Event_Tag tag;
+    uint32_t code;
+    bool pressed;
+
+  ctypedef struct Move_Body:
+    Event_Tag tag;
+    int32_t _0;
+    int32_t _1;
+
+  ctypedef union Event:
+    Event_Tag tag;
+    Key_Body key;
+    Move_Body move;
+
+  cdef enum:
+    Nothing,
+    Pair,
+    Triple,
+  ctypedef uint8_t Value_Tag;
+
+  ctypedef struct Pair_Body:
+    int32_t first;
+    int32_t second;
+
+  ctypedef struct Triple_Body:
+    int32_t _0;
+    int32_t _1;
+    int32_t _2;
+
+  ctypedef struct Value:
+    Value_Tag tag;
+    Pair_Body pair;
+    Triple_Body triple;
+
+  ctypedef enum Named_Tag:
+    None,
+    Some,
+
+  ctypedef struct Some_Body:
+    int32_t value;
+
+  ctypedef struct Named:
+    Named_Tag tag;
+    Some_Body some;
+
+  void root(Shape shape, Event event, Value value, Named named);
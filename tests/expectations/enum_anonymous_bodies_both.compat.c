@@ -0,0 +1,108 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Shape_Tag {
+  Point,
+  Circle,
+  Rect,
+  Scaled,
+} Shape_Tag;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      float radius;
+    } circle;
+    struct {
+      float _0;
+      float _1;
+    } rect;
+    struct {
+      float scaled;
+    };
+  };
+} Shape;
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Quit,
+  Key,
+  Move,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+typedef union Event {
+  Event_Tag tag;
+  struct {
+    Event_Tag tag;
+    uint32_t code;
+    bool pressed;
+  } key;
+  struct {
+    Event_Tag tag;
+    int32_t _0;
+    int32_t _1;
+  } move;
+} Event;
+
+enum Value_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Nothing,
+  Pair,
+  Triple,
+};
+#ifndef __cplusplus
+typedef uint8_t Value_Tag;
+#endif // __cplusplus
+
+typedef struct Value {
+  Value_Tag tag;
+  union {
+    struct {
+      int32_t first;
+      int32_t second;
+    } pair;
+    struct {
+      int32_t _0;
+      int32_t _1;
+      int32_t _2;
+    } triple;
+  };
+} Value;
+
+typedef enum Named_Tag {
+  None,
+  Some,
+} Named_Tag;
+
+typedef struct Some_Body {
+  int32_t value;
+} Some_Body;
+
+typedef struct Named {
+  Named_Tag tag;
+  union {
+    Some_Body some;
+  };
+} Named;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Shape shape, union Event event, struct Value value, struct Named named);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,88 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Shape_Tag {
+  Point,
+  Circle,
+  Rect,
+  Scaled,
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct {
+      float radius;
+    } circle;
+    struct {
+      float _0;
+      float _1;
+    } rect;
+    struct {
+      float scaled;
+    };
+  };
+};
+
+enum Event_Tag {
+  Quit,
+  Key,
+  Move,
+};
+typedef uint8_t Event_Tag;
+
+union Event {
+  Event_Tag tag;
+  struct {
+    Event_Tag tag;
+    uint32_t code;
+    bool pressed;
+  } key;
+  struct {
+    Event_Tag tag;
+    int32_t _0;
+    int32_t _1;
+  } move;
+};
+
+enum Value_Tag {
+  Nothing,
+  Pair,
+  Triple,
+};
+typedef uint8_t Value_Tag;
+
+struct Value {
+  Value_Tag tag;
+  union {
+    struct {
+      int32_t first;
+      int32_t second;
+    } pair;
+    struct {
+      int32_t _0;
+      int32_t _1;
+      int32_t _2;
+    } triple;
+  };
+};
+
+enum Named_Tag {
+  None,
+  Some,
+};
+
+struct Some_Body {
+  int32_t value;
+};
+
+struct Named {
+  enum Named_Tag tag;
+  union {
+    struct Some_Body some;
+  };
+};
+
+void root(struct Shape shape, union Event event, struct Value value, struct Named named);
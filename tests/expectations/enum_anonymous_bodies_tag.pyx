@@ -0,0 +1,80 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum Shape_Tag:
+    Point,
+    Circle,
+    Rect,
+    Scaled,
+
+  cdef struct Circle_Body:
+    float radius;
+
+  cdef struct Rect_Body:
+    float _0;
+    float _1;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    Circle_Body circle;
+    Rect_Body rect;
+    float scaled;
+
+  cdef enum:
+    Quit,
+    Key,
+    Move,
+  ctypedef uint8_t Event_Tag;
+
+  cdef struct Key_Body:
+    Event_Tag tag;
+    uint32_t code;
+    bool pressed;
+
+  cdef struct Move_Body:
+    Event_Tag tag;
+    int32_t _0;
+    int32_t _1;
+
+  cdef union Event:
+    Event_Tag tag;
+    Key_Body key;
+    Move_Body move;
+
+  cdef enum:
+    Nothing,
+    Pair,
+    Triple,
+  ctypedef uint8_t Value_Tag;
+
+  cdef struct Pair_Body:
+    int32_t first;
+    int32_t second;
+
+  cdef struct Triple_Body:
+    int32_t _0;
+    int32_t _1;
+    int32_t _2;
+
+  cdef struct Value:
+    Value_Tag tag;
+    Pair_Body pair;
+    Triple_Body triple;
+
+  cdef enum Named_Tag:
+    None,
+    Some,
+
+  cdef struct Some_Body:
+    int32_t value;
+
+  cdef struct Named:
+    Named_Tag tag;
+    Some_Body some;
+
+  void root(Shape shape, Event event, Value value, Named named);
@@ -0,0 +1,60 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint8_t LEVEL_BASE = 4;
+
+enum class Direction : int8_t {
+  Back = -1,
+  Still,
+  Forward,
+};
+
+/// Some flags.
+enum class Flags : uint32_t {
+  /// The first flag.
+  Low = 1,
+  High = 1073741824,
+  Next,
+};
+
+enum class Level : uint8_t {
+  Base = LEVEL_BASE,
+  Up,
+};
+
+enum class Mode : uint16_t {
+  Read,
+  Write,
+};
+
+union Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Square,
+  };
+
+  struct Circle_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct Square_Body {
+    Tag tag;
+    float side;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Circle_Body circle;
+  Square_Body square;
+};
+
+extern "C" {
+
+void root(Flags flags, Direction direction, Level level, Mode mode, Shape shape);
+
+} // extern "C"
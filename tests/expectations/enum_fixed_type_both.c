@@ -0,0 +1,52 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LEVEL_BASE 4
+
+typedef int8_t Direction;
+#define Back (-1)
+#define Still 0
+#define Forward 1
+
+/**
+ * Some flags.
+ */
+typedef uint32_t Flags;
+/**
+ * The first flag.
+ */
+#define Low 1
+#define High 1073741824
+#define Next 1073741825
+
+typedef uint8_t Level;
+#define Base (LEVEL_BASE)
+#define Up (LEVEL_BASE + 1)
+
+enum {
+  Read,
+  Write,
+};
+typedef uint16_t Mode;
+
+typedef uint8_t Shape_Tag;
+#define Circle 0
+#define Square 1
+
+typedef struct Square_Body {
+  Shape_Tag tag;
+  float side;
+} Square_Body;
+
+typedef union Shape {
+  Shape_Tag tag;
+  struct {
+    Shape_Tag circle_tag;
+    float circle;
+  };
+  Square_Body square;
+} Shape;
+
+void root(Flags flags, Direction direction, Level level, Mode mode, union Shape shape);
@@ -0,0 +1,49 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint8_t LEVEL_BASE # = 4
+
+  cdef enum:
+    Back # = -1,
+    Still,
+    Forward,
+  ctypedef int8_t Direction;
+
+  # Some flags.
+  cdef enum:
+    # The first flag.
+    Low # = 1,
+    High # = 1073741824,
+    Next,
+  ctypedef uint32_t Flags;
+
+  cdef enum:
+    Base # = LEVEL_BASE,
+    Up,
+  ctypedef uint8_t Level;
+
+  cdef enum:
+    Read,
+    Write,
+  ctypedef uint16_t Mode;
+
+  cdef enum:
+    Circle,
+    Square,
+  ctypedef uint8_t Shape_Tag;
+
+  cdef struct Square_Body:
+    Shape_Tag tag;
+    float side;
+
+  cdef union Shape:
+    Shape_Tag tag;
+    float circle;
+    Square_Body square;
+
+  void root(Flags flags, Direction direction, Level level, Mode mode, Shape shape);
@@ -0,0 +1,68 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Shape_Tag {
+  Circle,
+  Polygon,
+  Empty,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct {
+  float radius;
+} Circle_Body;
+
+typedef struct {
+  float *points;
+  uintptr_t len;
+} Polygon_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Polygon_Body polygon;
+  };
+} Shape;
+
+void shape_destroy(Shape *ptr);
+void shape_clone(const Shape *src, Shape *dst);
+
+enum Message_Tag {
+  Text,
+  Quit,
+};
+typedef uint8_t Message_Tag;
+
+typedef union {
+  Message_Tag tag;
+  struct {
+    Message_Tag text_tag;
+    uint8_t *text;
+  };
+} Message;
+
+void message_drop(Message *ptr);
+
+enum Token_Tag {
+  Word,
+  End,
+};
+typedef uint8_t Token_Tag;
+
+typedef struct {
+  Token_Tag tag;
+  union {
+    struct {
+      uint8_t *word;
+    };
+  };
+} Token;
+
+Token token_clone(const Token *src);
+
+void root(const Shape *shape, Message *message);
+
+void tokenize(Token *token);
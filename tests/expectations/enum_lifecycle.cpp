@@ -0,0 +1,94 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Shape;
+extern "C" {
+void shape_destroy(Shape *ptr);
+void shape_clone(const Shape *src, Shape *dst);
+} // extern "C"
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Polygon,
+    Empty,
+  };
+
+  struct Circle_Body {
+    float radius;
+  };
+
+  struct Polygon_Body {
+    float *points;
+    uintptr_t len;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Polygon_Body polygon;
+  };
+
+  ~Shape() {
+    shape_destroy(this);
+  }
+
+  Shape(const Shape& other) {
+    shape_clone(&other, this);
+  }
+};
+
+union Message;
+extern "C" {
+void message_drop(Message *ptr);
+} // extern "C"
+
+union Message {
+  enum class Tag : uint8_t {
+    Text,
+    Quit,
+  };
+
+  struct Text_Body {
+    Tag tag;
+    uint8_t *_0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Text_Body text;
+
+  ~Message() {
+    message_drop(this);
+  }
+};
+
+struct Token {
+  enum class Tag : uint8_t {
+    Word,
+    End,
+  };
+
+  struct Word_Body {
+    uint8_t *_0;
+  };
+
+  Tag tag;
+  union {
+    Word_Body word;
+  };
+};
+
+extern "C" {
+
+void root(const Shape *shape, Message *message);
+
+Token token_clone(const Token *src);
+
+void tokenize(Token *token);
+
+} // extern "C"
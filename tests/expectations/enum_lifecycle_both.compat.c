@@ -0,0 +1,112 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Polygon,
+  Empty,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Circle_Body {
+  float radius;
+} Circle_Body;
+
+typedef struct Polygon_Body {
+  float *points;
+  uintptr_t len;
+} Polygon_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Polygon_Body polygon;
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+void shape_destroy(struct Shape *ptr);
+void shape_clone(const struct Shape *src, struct Shape *dst);
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+enum Message_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Text,
+  Quit,
+};
+#ifndef __cplusplus
+typedef uint8_t Message_Tag;
+#endif // __cplusplus
+
+typedef union Message {
+  Message_Tag tag;
+  struct {
+    Message_Tag text_tag;
+    uint8_t *text;
+  };
+} Message;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+void message_drop(union Message *ptr);
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+enum Token_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Word,
+  End,
+};
+#ifndef __cplusplus
+typedef uint8_t Token_Tag;
+#endif // __cplusplus
+
+typedef struct Token {
+  Token_Tag tag;
+  union {
+    struct {
+      uint8_t *word;
+    };
+  };
+} Token;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+struct Token token_clone(const struct Token *src);
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(const struct Shape *shape, union Message *message);
+
+void tokenize(struct Token *token);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
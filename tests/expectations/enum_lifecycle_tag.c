@@ -0,0 +1,68 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Shape_Tag {
+  Circle,
+  Polygon,
+  Empty,
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  float radius;
+};
+
+struct Polygon_Body {
+  float *points;
+  uintptr_t len;
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Polygon_Body polygon;
+  };
+};
+
+void shape_destroy(struct Shape *ptr);
+void shape_clone(const struct Shape *src, struct Shape *dst);
+
+enum Message_Tag {
+  Text,
+  Quit,
+};
+typedef uint8_t Message_Tag;
+
+union Message {
+  Message_Tag tag;
+  struct {
+    Message_Tag text_tag;
+    uint8_t *text;
+  };
+};
+
+void message_drop(union Message *ptr);
+
+enum Token_Tag {
+  Word,
+  End,
+};
+typedef uint8_t Token_Tag;
+
+struct Token {
+  Token_Tag tag;
+  union {
+    struct {
+      uint8_t *word;
+    };
+  };
+};
+
+struct Token token_clone(const struct Token *src);
+
+void root(const struct Shape *shape, union Message *message);
+
+void tokenize(struct Token *token);
@@ -0,0 +1,54 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Circle,
+    Polygon,
+    Empty,
+  ctypedef uint8_t Shape_Tag;
+
+  cdef struct Circle_Body:
+    float radius;
+
+  cdef struct Polygon_Body:
+    float *points;
+    uintptr_t len;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    Circle_Body circle;
+    Polygon_Body polygon;
+
+  void shape_destroy(Shape *ptr);
+  void shape_clone(const Shape *src, Shape *dst);
+
+  cdef enum:
+    Text,
+    Quit,
+  ctypedef uint8_t Message_Tag;
+
+  cdef union Message:
+    Message_Tag tag;
+    uint8_t *text;
+
+  void message_drop(Message *ptr);
+
+  cdef enum:
+    Word,
+    End,
+  ctypedef uint8_t Token_Tag;
+
+  cdef struct Token:
+    Token_Tag tag;
+    uint8_t *word;
+
+  Token token_clone(const Token *src);
+
+  void root(const Shape *shape, Message *message);
+
+  void tokenize(Token *token);
@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Red,
+  Green,
+  Blue,
+#if defined(DEFINE_ALPHA)
+  Transparent,
+#endif
+} Color;
+
+typedef enum {
+  A,
+  B,
+} Hidden;
+
+enum Level {
+  Low = 1,
+  High = 10,
+};
+typedef uint8_t Level;
+
+void root(Color c, Level l, Hidden h);
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+#if defined(DEFINE_ALPHA)
+    case Transparent: return "Transparent";
+#endif
+    default: return NULL;
+  }
+}
+
+static inline const char *Level_to_string(Level value) {
+  switch (value) {
+    case Low: return "Low";
+    case High: return "High";
+    default: return NULL;
+  }
+}
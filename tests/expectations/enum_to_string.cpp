@@ -0,0 +1,50 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Color {
+  Red,
+  Green,
+  Blue,
+#if defined(DEFINE_ALPHA)
+  Transparent,
+#endif
+};
+
+enum class Hidden {
+  A,
+  B,
+};
+
+enum class Level : uint8_t {
+  Low = 1,
+  High = 10,
+};
+
+extern "C" {
+
+void root(Color c, Level l, Hidden h);
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Color::Red: return "Red";
+    case Color::Green: return "Green";
+    case Color::Blue: return "Blue";
+#if defined(DEFINE_ALPHA)
+    case Color::Transparent: return "Transparent";
+#endif
+    default: return NULL;
+  }
+}
+
+static inline const char *Level_to_string(Level value) {
+  switch (value) {
+    case Level::Low: return "Low";
+    case Level::High: return "High";
+    default: return NULL;
+  }
+}
+
+} // extern "C"
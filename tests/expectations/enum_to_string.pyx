@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef enum Color:
+    Red,
+    Green,
+    Blue,
+    Transparent,
+
+  ctypedef enum Hidden:
+    A,
+    B,
+
+  cdef enum:
+    Low # = 1,
+    High # = 10,
+  ctypedef uint8_t Level;
+
+  void root(Color c, Level l, Hidden h);
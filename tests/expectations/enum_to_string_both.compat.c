@@ -0,0 +1,60 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue,
+#if defined(DEFINE_ALPHA)
+  Transparent,
+#endif
+} Color;
+
+typedef enum Hidden {
+  A,
+  B,
+} Hidden;
+
+enum Level
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Low = 1,
+  High = 10,
+};
+#ifndef __cplusplus
+typedef uint8_t Level;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(enum Color c, Level l, enum Hidden h);
+
+static inline const char *Color_to_string(Color value) {
+  switch (value) {
+    case Red: return "Red";
+    case Green: return "Green";
+    case Blue: return "Blue";
+#if defined(DEFINE_ALPHA)
+    case Transparent: return "Transparent";
+#endif
+    default: return NULL;
+  }
+}
+
+static inline const char *Level_to_string(Level value) {
+  switch (value) {
+    case Low: return "Low";
+    case High: return "High";
+    default: return NULL;
+  }
+}
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
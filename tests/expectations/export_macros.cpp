@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace ns {
+
+constexpr static const uint32_t my_DEFAULT_FLAGS = 3;
+
+struct my_widget_t {
+  uint32_t flags;
+};
+
+extern "C" {
+
+extern const uint32_t WIDGET_COUNT;
+
+void widget_init(my_widget_t *widget, uint32_t flags);
+
+} // extern "C"
+
+} // namespace ns
+
+#define WIDGET_INIT(w) widget_init((w), ns::my_DEFAULT_FLAGS)
+#define WIDGET_RESET(w) do { \
+  widget_init((w), 0); \
+} while (0)
+#define WIDGET_SIZE sizeof(ns::my_widget_t)
+#define WIDGET_TOTAL (WIDGET_COUNT + {Unknown})
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace ns {
+#endif // __cplusplus
+
+#define my_DEFAULT_FLAGS 3
+
+typedef struct my_widget_t {
+  uint32_t flags;
+} my_widget_t;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t WIDGET_COUNT;
+
+void widget_init(struct my_widget_t *widget, uint32_t flags);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace ns
+#endif // __cplusplus
+
+#define WIDGET_INIT(w) widget_init((w), my_DEFAULT_FLAGS)
+#define WIDGET_RESET(w) do { \
+  widget_init((w), 0); \
+} while (0)
+#define WIDGET_SIZE sizeof(my_widget_t)
+#define WIDGET_TOTAL (WIDGET_COUNT + {Unknown})
@@ -0,0 +1,16 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t my_DEFAULT_FLAGS # = 3
+
+  cdef struct my_widget_t:
+    uint32_t flags;
+
+  extern const uint32_t WIDGET_COUNT;
+
+  void widget_init(my_widget_t *widget, uint32_t flags);
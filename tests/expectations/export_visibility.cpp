@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Public {
+  int32_t x;
+};
+
+extern "C" {
+
+void public_function(Public p);
+
+extern void public_import();
+
+} // extern "C"
@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Public {
+  int32_t x;
+} Public;
+
+void public_function(struct Public p);
+
+extern void public_import(void);
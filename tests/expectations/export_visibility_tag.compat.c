@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Public {
+  int32_t x;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void public_function(struct Public p);
+
+extern void public_import(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
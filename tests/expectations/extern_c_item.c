@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+extern Point ORIGIN;
+
+extern const uint32_t COUNT;
+
+Point point_new(int32_t x, int32_t y);
+
+void point_distance(const Point *a, const Point *b, double *out);
+
+extern void point_log(const Point *point);
@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" Point ORIGIN;
+
+extern "C" const uint32_t COUNT;
+
+extern "C" Point point_new(int32_t x, int32_t y);
+
+extern "C" void point_distance(const Point *a, const Point *b, double *out);
+
+extern "C" void point_log(const Point *point);
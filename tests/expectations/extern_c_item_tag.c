@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern struct Point ORIGIN;
+
+extern const uint32_t COUNT;
+
+struct Point point_new(int32_t x, int32_t y);
+
+void point_distance(const struct Point *a, const struct Point *b, double *out);
+
+extern void point_log(const struct Point *point);
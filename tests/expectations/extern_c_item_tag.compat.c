@@ -0,0 +1,27 @@
+#if !defined(CBINDGEN_EXTERN_C)
+#if defined(__cplusplus)
+#define CBINDGEN_EXTERN_C extern "C"
+#else
+#define CBINDGEN_EXTERN_C extern
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+CBINDGEN_EXTERN_C struct Point ORIGIN;
+
+CBINDGEN_EXTERN_C const uint32_t COUNT;
+
+CBINDGEN_EXTERN_C struct Point point_new(int32_t x, int32_t y);
+
+CBINDGEN_EXTERN_C void point_distance(const struct Point *a, const struct Point *b, double *out);
+
+CBINDGEN_EXTERN_C void point_log(const struct Point *point);
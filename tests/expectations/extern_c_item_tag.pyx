@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+
+  extern Point ORIGIN;
+
+  extern const uint32_t COUNT;
+
+  Point point_new(int32_t x, int32_t y);
+
+  void point_distance(const Point *a, const Point *b, double *out);
+
+  extern void point_log(const Point *point);
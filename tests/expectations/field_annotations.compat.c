@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t mId;
+  /**
+   * The engine state.
+   */
+  uint64_t internals[3];
+  uint16_t counts[4];
+  void *engine;
+  /**
+   * Must be released with `handle_free`.
+   */
+  uint8_t *data;
+} Handle;
+
+typedef union {
+  int32_t asInt;
+  /**
+   * Single precision.
+   * Only valid after `value_set_float`.
+   */
+  float real;
+} Value;
+
+typedef struct {
+  int32_t first;
+  int32_t _1;
+} Pair;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Handle handle, Value value, Pair pair);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
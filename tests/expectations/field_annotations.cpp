@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Handle {
+  uint32_t mId;
+  /// The engine state.
+  uint64_t internals[3];
+  uint16_t counts[4];
+  void *engine;
+  /// Must be released with `handle_free`.
+  uint8_t *data;
+};
+
+union Value {
+  int32_t asInt;
+  /// Single precision.
+  /// Only valid after `value_set_float`.
+  float real;
+};
+
+struct Pair {
+  int32_t first;
+  int32_t _1;
+};
+
+extern "C" {
+
+void root(Handle handle, Value value, Pair pair);
+
+} // extern "C"
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Handle:
+    uint32_t mId;
+    # The engine state.
+    uint64_t internals[3];
+    uint16_t counts[4];
+    void *engine;
+    # Must be released with `handle_free`.
+    uint8_t *data;
+
+  cdef union Value:
+    int32_t asInt;
+    # Single precision.
+    # Only valid after `value_set_float`.
+    float real;
+
+  cdef struct Pair:
+    int32_t first;
+    int32_t _1;
+
+  void root(Handle handle, Value value, Pair pair);
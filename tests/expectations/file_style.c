@@ -0,0 +1,19 @@
+﻿#if 0
+License text
+spanning lines
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A point.
+ */
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+Point origin(void);
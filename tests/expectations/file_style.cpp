@@ -0,0 +1,22 @@
+﻿#if 0
+License text
+spanning lines
+#endif
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A point.
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+Point origin();
+
+} // extern "C"
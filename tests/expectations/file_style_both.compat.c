@@ -0,0 +1,27 @@
+﻿#if 0
+License text
+spanning lines
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A point.
+ */
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Point origin(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,19 @@
+﻿#if 0
+License text
+spanning lines
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A point.
+ */
+struct Point {
+  float x;
+  float y;
+};
+
+struct Point origin(void);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t (*on_something_happened)(
+    uint32_t first_argument,
+    const uint8_t *second_argument,
+    uintptr_t third_argument_name);
+} Callbacks;
+
+typedef int32_t (*Callback)(uint32_t first_argument,
+                            const uint8_t *second_argument,
+                            uintptr_t third_argument_name);
+
+void register_callbacks(const Callbacks *callbacks,
+                        int32_t (*fallback)(
+                          uint32_t first_argument,
+                          const uint8_t *second_argument,
+                          uintptr_t third_argument_name),
+                        uint8_t *user_data);
+
+void set_callback(Callback callback);
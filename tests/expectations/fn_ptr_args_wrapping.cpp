@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Callbacks {
+  int32_t (*on_something_happened)(
+    uint32_t first_argument,
+    const uint8_t *second_argument,
+    uintptr_t third_argument_name);
+};
+
+using Callback = int32_t(*)(uint32_t first_argument,
+                            const uint8_t *second_argument,
+                            uintptr_t third_argument_name);
+
+extern "C" {
+
+void register_callbacks(const Callbacks *callbacks,
+                        int32_t (*fallback)(
+                          uint32_t first_argument,
+                          const uint8_t *second_argument,
+                          uintptr_t third_argument_name),
+                        uint8_t *user_data);
+
+void set_callback(Callback callback);
+
+} // extern "C"
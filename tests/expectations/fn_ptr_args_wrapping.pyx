@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Callbacks:
+    int32_t (*on_something_happened)(
+      uint32_t first_argument,
+      const uint8_t *second_argument,
+      uintptr_t third_argument_name);
+
+  ctypedef int32_t (*Callback)(
+    uint32_t first_argument,
+    const uint8_t *second_argument,
+    uintptr_t third_argument_name);
+
+  void register_callbacks(const Callbacks *callbacks,
+                          int32_t (*fallback)(
+                            uint32_t first_argument,
+                            const uint8_t *second_argument,
+                            uintptr_t third_argument_name),
+                          uint8_t *user_data);
+
+  void set_callback(Callback callback);
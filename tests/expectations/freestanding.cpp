@@ -0,0 +1,31 @@
+#ifndef CBINDGEN_FREESTANDING_TYPES
+#define CBINDGEN_FREESTANDING_TYPES
+typedef signed char int8_t;
+typedef short int16_t;
+typedef int int32_t;
+typedef long long int64_t;
+typedef unsigned char uint8_t;
+typedef unsigned short uint16_t;
+typedef unsigned int uint32_t;
+typedef unsigned long long uint64_t;
+typedef long intptr_t;
+typedef unsigned int uintptr_t;
+typedef unsigned int size_t;
+typedef long ptrdiff_t;
+typedef __builtin_va_list va_list;
+#endif
+
+constexpr static const uintptr_t MAX_LEN = 256;
+
+struct Packet {
+  uint32_t id;
+  uintptr_t len;
+  bool valid;
+  const uint8_t *data;
+};
+
+extern "C" {
+
+bool packet_send(const Packet *packet, int64_t timeout_ms);
+
+} // extern "C"
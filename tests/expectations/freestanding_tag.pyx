@@ -0,0 +1,17 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uintptr_t MAX_LEN # = 256
+
+  cdef struct Packet:
+    uint32_t id;
+    uintptr_t len;
+    bool valid;
+    const uint8_t *data;
+
+  bool packet_send(const Packet *packet, int64_t timeout_ms);
@@ -0,0 +1,66 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum WidgetError {
+  Success = 0,
+  NotFound,
+  InvalidSize,
+};
+typedef uint8_t WidgetError;
+
+typedef struct {
+  uint32_t id;
+  float width;
+} Widget;
+
+/**
+ * The result of `widget_resize`.
+ */
+enum WidgetResizeResult_Tag {
+  WidgetResizeResult_Ok,
+  WidgetResizeResult_Err,
+};
+typedef uint8_t WidgetResizeResult_Tag;
+
+typedef struct {
+  WidgetResizeResult_Tag tag;
+  union {
+    struct {
+      Widget ok;
+    };
+    struct {
+      WidgetError err;
+    };
+  };
+} WidgetResizeResult;
+
+/**
+ * The result of `widget_show`.
+ */
+enum WidgetShowResult_Tag {
+  WidgetShowResult_Ok,
+  WidgetShowResult_Err,
+};
+typedef uint8_t WidgetShowResult_Tag;
+
+typedef struct {
+  WidgetShowResult_Tag tag;
+  union {
+    struct {
+      WidgetError err;
+    };
+  };
+} WidgetShowResult;
+
+/**
+ * Opens the widget `id`.
+ */
+WidgetError widget_open(uint32_t id, Widget *result);
+
+WidgetError widget_close(Widget *widget);
+
+WidgetResizeResult widget_resize(Widget *widget, float width);
+
+WidgetShowResult widget_show(const Widget *widget);
@@ -0,0 +1,92 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum WidgetError
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Success = 0,
+  NotFound,
+  InvalidSize,
+};
+#ifndef __cplusplus
+typedef uint8_t WidgetError;
+#endif // __cplusplus
+
+typedef struct {
+  uint32_t id;
+  float width;
+} Widget;
+
+/**
+ * The result of `widget_resize`.
+ */
+enum WidgetResizeResult_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  WidgetResizeResult_Ok,
+  WidgetResizeResult_Err,
+};
+#ifndef __cplusplus
+typedef uint8_t WidgetResizeResult_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  WidgetResizeResult_Tag tag;
+  union {
+    struct {
+      Widget ok;
+    };
+    struct {
+      WidgetError err;
+    };
+  };
+} WidgetResizeResult;
+
+/**
+ * The result of `widget_show`.
+ */
+enum WidgetShowResult_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  WidgetShowResult_Ok,
+  WidgetShowResult_Err,
+};
+#ifndef __cplusplus
+typedef uint8_t WidgetShowResult_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  WidgetShowResult_Tag tag;
+  union {
+    struct {
+      WidgetError err;
+    };
+  };
+} WidgetShowResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Opens the widget `id`.
+ */
+WidgetError widget_open(uint32_t id, Widget *result);
+
+WidgetError widget_close(Widget *widget);
+
+WidgetResizeResult widget_resize(Widget *widget, float width);
+
+WidgetShowResult widget_show(const Widget *widget);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,68 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class WidgetError : uint8_t {
+  Success = 0,
+  NotFound,
+  InvalidSize,
+};
+
+struct Widget {
+  uint32_t id;
+  float width;
+};
+
+/// The result of `widget_resize`.
+struct WidgetResizeResult {
+  enum class Tag : uint8_t {
+    Ok,
+    Err,
+  };
+
+  struct Ok_Body {
+    Widget _0;
+  };
+
+  struct Err_Body {
+    WidgetError _0;
+  };
+
+  Tag tag;
+  union {
+    Ok_Body ok;
+    Err_Body err;
+  };
+};
+
+/// The result of `widget_show`.
+struct WidgetShowResult {
+  enum class Tag : uint8_t {
+    Ok,
+    Err,
+  };
+
+  struct Err_Body {
+    WidgetError _0;
+  };
+
+  Tag tag;
+  union {
+    Err_Body err;
+  };
+};
+
+extern "C" {
+
+/// Opens the widget `id`.
+WidgetError widget_open(uint32_t id, Widget *result);
+
+WidgetError widget_close(Widget *widget);
+
+WidgetResizeResult widget_resize(Widget *widget, float width);
+
+WidgetShowResult widget_show(const Widget *widget);
+
+} // extern "C"
@@ -0,0 +1,47 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Success # = 0,
+    NotFound,
+    InvalidSize,
+  ctypedef uint8_t WidgetError;
+
+  ctypedef struct Widget:
+    uint32_t id;
+    float width;
+
+  # The result of `widget_resize`.
+  cdef enum:
+    WidgetResizeResult_Ok,
+    WidgetResizeResult_Err,
+  ctypedef uint8_t WidgetResizeResult_Tag;
+
+  ctypedef struct WidgetResizeResult:
+    WidgetResizeResult_Tag tag;
+    Widget ok;
+    WidgetError err;
+
+  # The result of `widget_show`.
+  cdef enum:
+    WidgetShowResult_Ok,
+    WidgetShowResult_Err,
+  ctypedef uint8_t WidgetShowResult_Tag;
+
+  ctypedef struct WidgetShowResult:
+    WidgetShowResult_Tag tag;
+    WidgetError err;
+
+  # Opens the widget `id`.
+  WidgetError widget_open(uint32_t id, Widget *result);
+
+  WidgetError widget_close(Widget *widget);
+
+  WidgetResizeResult widget_resize(Widget *widget, float width);
+
+  WidgetShowResult widget_show(const Widget *widget);
@@ -0,0 +1,92 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum WidgetError
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Success = 0,
+  NotFound,
+  InvalidSize,
+};
+#ifndef __cplusplus
+typedef uint8_t WidgetError;
+#endif // __cplusplus
+
+struct Widget {
+  uint32_t id;
+  float width;
+};
+
+/**
+ * The result of `widget_resize`.
+ */
+enum WidgetResizeResult_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  WidgetResizeResult_Ok,
+  WidgetResizeResult_Err,
+};
+#ifndef __cplusplus
+typedef uint8_t WidgetResizeResult_Tag;
+#endif // __cplusplus
+
+struct WidgetResizeResult {
+  WidgetResizeResult_Tag tag;
+  union {
+    struct {
+      struct Widget ok;
+    };
+    struct {
+      WidgetError err;
+    };
+  };
+};
+
+/**
+ * The result of `widget_show`.
+ */
+enum WidgetShowResult_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  WidgetShowResult_Ok,
+  WidgetShowResult_Err,
+};
+#ifndef __cplusplus
+typedef uint8_t WidgetShowResult_Tag;
+#endif // __cplusplus
+
+struct WidgetShowResult {
+  WidgetShowResult_Tag tag;
+  union {
+    struct {
+      WidgetError err;
+    };
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Opens the widget `id`.
+ */
+WidgetError widget_open(uint32_t id, struct Widget *result);
+
+WidgetError widget_close(struct Widget *widget);
+
+struct WidgetResizeResult widget_resize(struct Widget *widget, float width);
+
+struct WidgetShowResult widget_show(const struct Widget *widget);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Parser Parser;
+
+bool parser_feed(Parser *parser, const char *data, uintptr_t len);
+
+void decode_in_place(uint8_t *buf, uint32_t len, uint32_t flags);
+
+void parser_reset(Parser *parser);
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Parser Parser;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool parser_feed(Parser *parser, const char *data, uintptr_t len);
+
+void decode_in_place(uint8_t *buf, uint32_t len, uint32_t flags);
+
+void parser_reset(Parser *parser);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
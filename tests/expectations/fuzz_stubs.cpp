@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Parser;
+
+extern "C" {
+
+bool parser_feed(Parser *parser, const char *data, uintptr_t len);
+
+void decode_in_place(uint8_t *buf, uint32_t len, uint32_t flags);
+
+void parser_reset(Parser *parser);
+
+} // extern "C"
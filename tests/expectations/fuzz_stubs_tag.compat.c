@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Parser;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool parser_feed(struct Parser *parser, const char *data, uintptr_t len);
+
+void decode_in_place(uint8_t *buf, uint32_t len, uint32_t flags);
+
+void parser_reset(struct Parser *parser);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,16 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Parser:
+    pass
+
+  bool parser_feed(Parser *parser, const char *data, uintptr_t len);
+
+  void decode_in_place(uint8_t *buf, uint32_t len, uint32_t flags);
+
+  void parser_reset(Parser *parser);
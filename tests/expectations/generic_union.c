@@ -0,0 +1,64 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union {
+  uint64_t a;
+  float b;
+} Either_u64__f32;
+
+typedef struct {
+  Either_u64__f32 value;
+  uint8_t tag;
+} Holder_u64;
+
+typedef union {
+  int32_t a;
+  uint32_t b;
+} Either_i32__u32;
+
+typedef union {
+  uint8_t items[4];
+  const uint8_t *raw;
+} Buffer_u8__4;
+
+typedef enum {
+  Number,
+  Bytes,
+} Message_Tag;
+
+typedef struct {
+  Buffer_u8__4 data;
+} Bytes_Body;
+
+typedef struct {
+  Message_Tag tag;
+  union {
+    struct {
+      Either_i32__u32 number;
+    };
+    Bytes_Body bytes;
+  };
+} Message;
+
+typedef union {
+  uint16_t value;
+} Borrowed_u16;
+
+typedef union {
+  int16_t a;
+  float b;
+} Either_i16__f32;
+
+typedef struct {
+  Either_i16__f32 value;
+  uint8_t tag;
+} Holder_i16;
+
+typedef union {
+  const uint8_t *a;
+  Holder_i16 b;
+} Either______u8__Holder_i16;
+
+void root(Holder_u64 a, Message b, Borrowed_u16 c, const Either______u8__Holder_i16 *d);
@@ -0,0 +1,58 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename A, typename B>
+union Either {
+  A a;
+  B b;
+};
+
+template<typename T>
+struct Holder {
+  Either<T, float> value;
+  uint8_t tag;
+};
+
+template<typename T, uintptr_t N>
+union Buffer {
+  T items[N];
+  const T *raw;
+};
+
+struct Message {
+  enum class Tag {
+    Number,
+    Bytes,
+  };
+
+  struct Number_Body {
+    Either<int32_t, uint32_t> _0;
+  };
+
+  struct Bytes_Body {
+    Buffer<uint8_t, 4> data;
+  };
+
+  Tag tag;
+  union {
+    Number_Body number;
+    Bytes_Body bytes;
+  };
+};
+
+template<typename T>
+union Borrowed {
+  T value;
+};
+
+extern "C" {
+
+void root(Holder<uint64_t> a,
+          Message b,
+          Borrowed<uint16_t> c,
+          const Either<const uint8_t*, Holder<int16_t>> *d);
+
+} // extern "C"
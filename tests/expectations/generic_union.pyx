@@ -0,0 +1,52 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef union Either_u64__f32:
+    uint64_t a;
+    float b;
+
+  ctypedef struct Holder_u64:
+    Either_u64__f32 value;
+    uint8_t tag;
+
+  ctypedef union Either_i32__u32:
+    int32_t a;
+    uint32_t b;
+
+  ctypedef union Buffer_u8__4:
+    uint8_t items[4];
+    const uint8_t *raw;
+
+  ctypedef enum Message_Tag:
+    Number,
+    Bytes,
+
+  ctypedef struct Bytes_Body:
+    Buffer_u8__4 data;
+
+  ctypedef struct Message:
+    Message_Tag tag;
+    Either_i32__u32 number;
+    Bytes_Body bytes;
+
+  ctypedef union Borrowed_u16:
+    uint16_t value;
+
+  ctypedef union Either_i16__f32:
+    int16_t a;
+    float b;
+
+  ctypedef struct Holder_i16:
+    Either_i16__f32 value;
+    uint8_t tag;
+
+  ctypedef union Either______u8__Holder_i16:
+    const uint8_t *a;
+    Holder_i16 b;
+
+  void root(Holder_u64 a, Message b, Borrowed_u16 c, const Either______u8__Holder_i16 *d);
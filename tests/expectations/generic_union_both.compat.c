@@ -0,0 +1,75 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union Either_u64__f32 {
+  uint64_t a;
+  float b;
+} Either_u64__f32;
+
+typedef struct Holder_u64 {
+  union Either_u64__f32 value;
+  uint8_t tag;
+} Holder_u64;
+
+typedef union Either_i32__u32 {
+  int32_t a;
+  uint32_t b;
+} Either_i32__u32;
+
+typedef union Buffer_u8__4 {
+  uint8_t items[4];
+  const uint8_t *raw;
+} Buffer_u8__4;
+
+typedef enum Message_Tag {
+  Number,
+  Bytes,
+} Message_Tag;
+
+typedef struct Bytes_Body {
+  union Buffer_u8__4 data;
+} Bytes_Body;
+
+typedef struct Message {
+  Message_Tag tag;
+  union {
+    struct {
+      union Either_i32__u32 number;
+    };
+    Bytes_Body bytes;
+  };
+} Message;
+
+typedef union Borrowed_u16 {
+  uint16_t value;
+} Borrowed_u16;
+
+typedef union Either_i16__f32 {
+  int16_t a;
+  float b;
+} Either_i16__f32;
+
+typedef struct Holder_i16 {
+  union Either_i16__f32 value;
+  uint8_t tag;
+} Holder_i16;
+
+typedef union Either______u8__Holder_i16 {
+  const uint8_t *a;
+  struct Holder_i16 b;
+} Either______u8__Holder_i16;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Holder_u64 a,
+          struct Message b,
+          union Borrowed_u16 c,
+          const union Either______u8__Holder_i16 *d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,67 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+union Either_u64__f32 {
+  uint64_t a;
+  float b;
+};
+
+struct Holder_u64 {
+  union Either_u64__f32 value;
+  uint8_t tag;
+};
+
+union Either_i32__u32 {
+  int32_t a;
+  uint32_t b;
+};
+
+union Buffer_u8__4 {
+  uint8_t items[4];
+  const uint8_t *raw;
+};
+
+enum Message_Tag {
+  Number,
+  Bytes,
+};
+
+struct Bytes_Body {
+  union Buffer_u8__4 data;
+};
+
+struct Message {
+  enum Message_Tag tag;
+  union {
+    struct {
+      union Either_i32__u32 number;
+    };
+    struct Bytes_Body bytes;
+  };
+};
+
+union Borrowed_u16 {
+  uint16_t value;
+};
+
+union Either_i16__f32 {
+  int16_t a;
+  float b;
+};
+
+struct Holder_i16 {
+  union Either_i16__f32 value;
+  uint8_t tag;
+};
+
+union Either______u8__Holder_i16 {
+  const uint8_t *a;
+  struct Holder_i16 b;
+};
+
+void root(struct Holder_u64 a,
+          struct Message b,
+          union Borrowed_u16 c,
+          const union Either______u8__Holder_i16 *d);
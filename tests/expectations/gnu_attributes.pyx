@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Buffer:
+    uint8_t *data;
+    uintptr_t len;
+
+  Buffer *buffer_new(uintptr_t capacity);
+
+  void buffer_append(Buffer *buffer, const uint8_t *data, uintptr_t len);
+
+  uintptr_t buffer_copy(const Buffer *buffer, uint8_t *out, uintptr_t out_len);
+
+  void buffer_set_user_data(Buffer *buffer, void *user_data);
+
+  void buffer_free(Buffer *buffer);
@@ -0,0 +1,51 @@
+#if !defined(CBINDGEN_NONNULL)
+#if defined(__GNUC__)
+#define CBINDGEN_NONNULL(...) __attribute__((nonnull(__VA_ARGS__)))
+#define CBINDGEN_WARN_UNUSED_RESULT __attribute__((warn_unused_result))
+#define CBINDGEN_MALLOC __attribute__((malloc))
+#define CBINDGEN_ALLOC_SIZE(...) __attribute__((alloc_size(__VA_ARGS__)))
+#else
+#define CBINDGEN_NONNULL(...)
+#define CBINDGEN_WARN_UNUSED_RESULT
+#define CBINDGEN_MALLOC
+#define CBINDGEN_ALLOC_SIZE(...)
+#endif
+#if defined(__GNUC__) && !defined(__clang__) && __GNUC__ >= 10
+#define CBINDGEN_ACCESS(...) __attribute__((access(__VA_ARGS__)))
+#else
+#define CBINDGEN_ACCESS(...)
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Buffer *buffer_new(uintptr_t capacity) CBINDGEN_MALLOC CBINDGEN_ALLOC_SIZE(1);
+
+void buffer_append(struct Buffer *buffer,
+                   const uint8_t *data,
+                   uintptr_t len) CBINDGEN_NONNULL(1) CBINDGEN_ACCESS(read_write, 1) CBINDGEN_ACCESS(read_only, 2, 3);
+
+uintptr_t buffer_copy(const struct Buffer *buffer,
+                      uint8_t *out,
+                      uintptr_t out_len) CBINDGEN_WARN_UNUSED_RESULT CBINDGEN_NONNULL(1) CBINDGEN_ACCESS(read_only, 1) CBINDGEN_ACCESS(write_only, 2, 3);
+
+void buffer_set_user_data(struct Buffer *buffer,
+                          void *user_data) CBINDGEN_NONNULL(1) CBINDGEN_ACCESS(read_write, 1);
+
+void buffer_free(struct Buffer *buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,68 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint16_t major;
+  uint16_t minor;
+} Version;
+
+/* ===== module: audio ===== */
+
+typedef struct Mixer Mixer;
+
+typedef struct {
+  Mixer *mixer;
+  uint32_t sample_rate;
+} Stream;
+
+/* ===== module: net ===== */
+
+/**
+ * An IPv4 address.
+ */
+typedef struct {
+  uint8_t octets[4];
+  uint16_t port;
+} Address;
+
+/* ===== module: net::dns ===== */
+
+typedef struct {
+  uint16_t id;
+  uint16_t flags;
+} Query;
+
+/* ===== module: net::socket ===== */
+
+typedef struct {
+  Address peer;
+  int32_t fd;
+} Socket;
+
+Version library_version(void);
+
+void library_init(void);
+
+/* ===== module: audio ===== */
+
+void audio_play(Stream *stream);
+
+void audio_stop(Stream *stream);
+
+/* Volume */
+
+void audio_set_volume(Stream *stream, float volume);
+
+/* ===== module: net ===== */
+
+bool address_is_loopback(const Address *address);
+
+/* ===== module: net::dns ===== */
+
+int32_t dns_resolve(const Query *query);
+
+/* ===== module: net::socket ===== */
+
+Socket socket_open(const Address *address);
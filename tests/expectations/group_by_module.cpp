@@ -0,0 +1,71 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Version {
+  uint16_t major;
+  uint16_t minor;
+};
+
+/* ===== module: audio ===== */
+
+struct Mixer;
+
+struct Stream {
+  Mixer *mixer;
+  uint32_t sample_rate;
+};
+
+/* ===== module: net ===== */
+
+/// An IPv4 address.
+struct Address {
+  uint8_t octets[4];
+  uint16_t port;
+};
+
+/* ===== module: net::dns ===== */
+
+struct Query {
+  uint16_t id;
+  uint16_t flags;
+};
+
+/* ===== module: net::socket ===== */
+
+struct Socket {
+  Address peer;
+  int32_t fd;
+};
+
+extern "C" {
+
+Version library_version();
+
+void library_init();
+
+/* ===== module: audio ===== */
+
+void audio_play(Stream *stream);
+
+void audio_stop(Stream *stream);
+
+/* Volume */
+
+void audio_set_volume(Stream *stream, float volume);
+
+/* ===== module: net ===== */
+
+bool address_is_loopback(const Address *address);
+
+/* ===== module: net::dns ===== */
+
+int32_t dns_resolve(const Query *query);
+
+/* ===== module: net::socket ===== */
+
+Socket socket_open(const Address *address);
+
+} // extern "C"
@@ -0,0 +1,76 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Version {
+  uint16_t major;
+  uint16_t minor;
+} Version;
+
+/* ===== module: audio ===== */
+
+typedef struct Mixer Mixer;
+
+typedef struct Stream {
+  struct Mixer *mixer;
+  uint32_t sample_rate;
+} Stream;
+
+/* ===== module: net ===== */
+
+/**
+ * An IPv4 address.
+ */
+typedef struct Address {
+  uint8_t octets[4];
+  uint16_t port;
+} Address;
+
+/* ===== module: net::dns ===== */
+
+typedef struct Query {
+  uint16_t id;
+  uint16_t flags;
+} Query;
+
+/* ===== module: net::socket ===== */
+
+typedef struct Socket {
+  struct Address peer;
+  int32_t fd;
+} Socket;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Version library_version(void);
+
+void library_init(void);
+
+/* ===== module: audio ===== */
+
+void audio_play(struct Stream *stream);
+
+void audio_stop(struct Stream *stream);
+
+/* Volume */
+
+void audio_set_volume(struct Stream *stream, float volume);
+
+/* ===== module: net ===== */
+
+bool address_is_loopback(const struct Address *address);
+
+/* ===== module: net::dns ===== */
+
+int32_t dns_resolve(const struct Query *query);
+
+/* ===== module: net::socket ===== */
+
+struct Socket socket_open(const struct Address *address);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,65 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Version:
+    uint16_t major;
+    uint16_t minor;
+
+  # ===== module: audio =====
+
+  cdef struct Mixer:
+    pass
+
+  cdef struct Stream:
+    Mixer *mixer;
+    uint32_t sample_rate;
+
+  # ===== module: net =====
+
+  # An IPv4 address.
+  cdef struct Address:
+    uint8_t octets[4];
+    uint16_t port;
+
+  # ===== module: net::dns =====
+
+  cdef struct Query:
+    uint16_t id;
+    uint16_t flags;
+
+  # ===== module: net::socket =====
+
+  cdef struct Socket:
+    Address peer;
+    int32_t fd;
+
+  Version library_version();
+
+  void library_init();
+
+  # ===== module: audio =====
+
+  void audio_play(Stream *stream);
+
+  void audio_stop(Stream *stream);
+
+  # Volume
+
+  void audio_set_volume(Stream *stream, float volume);
+
+  # ===== module: net =====
+
+  bool address_is_loopback(const Address *address);
+
+  # ===== module: net::dns =====
+
+  int32_t dns_resolve(const Query *query);
+
+  # ===== module: net::socket =====
+
+  Socket socket_open(const Address *address);
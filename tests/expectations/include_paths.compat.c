@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Point ffi_origin(void);
+
+void ffi_nested(Point point);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define VERSION 1
+
+typedef struct {
+  bool verbose;
+  uint8_t level;
+} Options;
+
+void configure(const Options *options);
@@ -0,0 +1,24 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace example {
+inline namespace v1 {
+
+constexpr static const uint32_t VERSION = 1;
+
+struct Options {
+  bool verbose;
+  uint8_t level;
+};
+
+extern "C" {
+
+void configure(const Options *options);
+
+} // extern "C"
+
+} // inline namespace v1
+} // namespace example
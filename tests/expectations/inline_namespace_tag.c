@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define VERSION 1
+
+struct Options {
+  bool verbose;
+  uint8_t level;
+};
+
+void configure(const struct Options *options);
@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace example {
+inline namespace v1 {
+#endif // __cplusplus
+
+#define VERSION 1
+
+struct Options {
+  bool verbose;
+  uint8_t level;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(const struct Options *options);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // inline namespace v1
+} // namespace example
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define TEST_SEED 42
+
+typedef struct {
+  uint32_t id;
+} Handle;
+
+/**
+ * Used by a public function, so it can't be internal.
+ */
+typedef struct {
+  bool verbose;
+} Options;
+
+/**
+ * Only used by the internal functions.
+ */
+typedef struct {
+  uint64_t allocations;
+} Stats;
+
+extern uint32_t INTERNAL_COUNTER;
+
+Handle handle_open(const Options *options);
+
+Stats handle_stats(Handle handle);
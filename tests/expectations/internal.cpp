@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t TEST_SEED = 42;
+
+struct Handle {
+  uint32_t id;
+};
+
+/// Used by a public function, so it can't be internal.
+struct Options {
+  bool verbose;
+};
+
+/// Only used by the internal functions.
+struct Stats {
+  uint64_t allocations;
+};
+
+extern "C" {
+
+extern uint32_t INTERNAL_COUNTER;
+
+Handle handle_open(const Options *options);
+
+Stats handle_stats(Handle handle);
+
+} // extern "C"
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define TEST_SEED 42
+
+typedef struct Handle {
+  uint32_t id;
+} Handle;
+
+/**
+ * Used by a public function, so it can't be internal.
+ */
+typedef struct Options {
+  bool verbose;
+} Options;
+
+/**
+ * Only used by the internal functions.
+ */
+typedef struct Stats {
+  uint64_t allocations;
+} Stats;
+
+extern uint32_t INTERNAL_COUNTER;
+
+struct Handle handle_open(const struct Options *options);
+
+struct Stats handle_stats(struct Handle handle);
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define TEST_SEED 42
+
+struct Handle {
+  uint32_t id;
+};
+
+/**
+ * Used by a public function, so it can't be internal.
+ */
+struct Options {
+  bool verbose;
+};
+
+/**
+ * Only used by the internal functions.
+ */
+struct Stats {
+  uint64_t allocations;
+};
+
+extern uint32_t INTERNAL_COUNTER;
+
+struct Handle handle_open(const struct Options *options);
+
+struct Stats handle_stats(struct Handle handle);
@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t TEST_SEED # = 42
+
+  cdef struct Handle:
+    uint32_t id;
+
+  # Used by a public function, so it can't be internal.
+  cdef struct Options:
+    bool verbose;
+
+  # Only used by the internal functions.
+  cdef struct Stats:
+    uint64_t allocations;
+
+  extern uint32_t INTERNAL_COUNTER;
+
+  Handle handle_open(const Options *options);
+
+  Stats handle_stats(Handle handle);
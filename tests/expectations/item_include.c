@@ -0,0 +1,30 @@
+#if 0
+DEF HAVE_THREADS = 0
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stdio.h>
+#if defined(HAVE_THREADS)
+#include <pthread.h>
+#endif
+#include "testing-helpers.h"
+#if defined(HAVE_THREADS)
+#include "threads-helpers.h"
+#endif
+
+typedef struct {
+  int32_t level;
+} Logger;
+
+void root(Logger logger);
+
+#if defined(HAVE_THREADS)
+/**
+ * Only included along with the function that needs it.
+ */
+void lock(Logger logger);
+#endif
@@ -0,0 +1,33 @@
+#if 0
+DEF HAVE_THREADS = 0
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <stdio.h>
+#if defined(HAVE_THREADS)
+#include <pthread.h>
+#endif
+#include "testing-helpers.h"
+#if defined(HAVE_THREADS)
+#include "threads-helpers.h"
+#endif
+
+struct Logger {
+  int32_t level;
+};
+
+extern "C" {
+
+void root(Logger logger);
+
+#if defined(HAVE_THREADS)
+/// Only included along with the function that needs it.
+void lock(Logger logger);
+#endif
+
+} // extern "C"
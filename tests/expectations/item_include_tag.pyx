@@ -0,0 +1,21 @@
+#if 0
+DEF HAVE_THREADS = 0
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Logger:
+    int32_t level;
+
+  void root(Logger logger);
+
+  IF HAVE_THREADS:
+    # Only included along with the function that needs it.
+    void lock(Logger logger);
@@ -0,0 +1,133 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <assert.h>
+#include <stdalign.h>
+
+typedef enum {
+  First,
+  Second,
+} Simple;
+static_assert(sizeof(Simple) == 4, "size of Simple");
+static_assert(alignof(Simple) == 4, "alignment of Simple");
+
+/**
+ * Declared with the size and alignment of a `Vec<u32>` and a `u16`.
+ */
+typedef struct Handle {
+  uint64_t _opaque[4];
+} Handle;
+static_assert(sizeof(Handle) == 32, "size of Handle");
+static_assert(alignof(Handle) == 8, "alignment of Handle");
+
+typedef struct {
+  uint8_t a;
+  uint64_t b;
+  uint16_t c;
+} Mixed;
+static_assert(sizeof(Mixed) == 24, "size of Mixed");
+static_assert(alignof(Mixed) == 8, "alignment of Mixed");
+static_assert(offsetof(Mixed, a) == 0, "offset of Mixed::a");
+static_assert(offsetof(Mixed, b) == 8, "offset of Mixed::b");
+static_assert(offsetof(Mixed, c) == 16, "offset of Mixed::c");
+
+typedef struct CBINDGEN_PACKED {
+  uint8_t a;
+  uint32_t b;
+} Packed;
+static_assert(sizeof(Packed) == 5, "size of Packed");
+static_assert(alignof(Packed) == 1, "alignment of Packed");
+static_assert(offsetof(Packed, a) == 0, "offset of Packed::a");
+static_assert(offsetof(Packed, b) == 1, "offset of Packed::b");
+
+typedef struct CBINDGEN_ALIGNED(16) {
+  uint8_t a;
+} Aligned;
+static_assert(sizeof(Aligned) == 16, "size of Aligned");
+static_assert(alignof(Aligned) == 16, "alignment of Aligned");
+static_assert(offsetof(Aligned, a) == 0, "offset of Aligned::a");
+
+typedef union {
+  uint8_t a;
+  uint32_t b;
+} Either;
+static_assert(sizeof(Either) == 4, "size of Either");
+static_assert(alignof(Either) == 4, "alignment of Either");
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Value,
+  Empty,
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_Tag;
+#endif // __cplusplus
+
+typedef union {
+  Tagged_Tag tag;
+  struct {
+    Tagged_Tag value_tag;
+    uint32_t value;
+  };
+} Tagged;
+static_assert(sizeof(Tagged) == 8, "size of Tagged");
+static_assert(alignof(Tagged) == 4, "alignment of Tagged");
+
+/**
+ * cbindgen doesn't know about the `PhantomData` field, so only the size and
+ * alignment can be checked.
+ */
+typedef struct {
+  uint32_t a;
+} Marked;
+static_assert(sizeof(Marked) == 4, "size of Marked");
+static_assert(alignof(Marked) == 4, "alignment of Marked");
+
+typedef uint32_t Id;
+static_assert(sizeof(Id) == 4, "size of Id");
+static_assert(alignof(Id) == 4, "alignment of Id");
+
+#if defined(WIDE)
+typedef struct {
+  uint64_t a;
+} Wide;
+#endif
+#if defined(WIDE)
+static_assert(sizeof(Wide) == 8, "size of Wide");
+static_assert(alignof(Wide) == 8, "alignment of Wide");
+static_assert(offsetof(Wide, a) == 0, "offset of Wide::a");
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void process(Mixed _mixed,
+             Packed _packed,
+             Aligned _aligned,
+             Either _either,
+             Tagged _tagged,
+             Simple _simple,
+             Marked _marked,
+             Id _id);
+
+#if defined(WIDE)
+void wide(Wide _wide);
+#endif
+
+Handle *handle_new(void);
+
+void handle_free(Handle *_handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
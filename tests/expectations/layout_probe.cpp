@@ -0,0 +1,124 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#include <cstdarg>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Simple {
+  First,
+  Second,
+};
+static_assert(sizeof(Simple) == 4, "size of Simple");
+static_assert(alignof(Simple) == 4, "alignment of Simple");
+
+template<typename T = void>
+struct Box;
+
+/// Declared with the size and alignment of a `Vec<u32>` and a `u16`.
+struct Handle {
+  uint64_t _opaque[4];
+};
+static_assert(sizeof(Handle) == 32, "size of Handle");
+static_assert(alignof(Handle) == 8, "alignment of Handle");
+
+struct Mixed {
+  uint8_t a;
+  uint64_t b;
+  uint16_t c;
+};
+static_assert(sizeof(Mixed) == 24, "size of Mixed");
+static_assert(alignof(Mixed) == 8, "alignment of Mixed");
+static_assert(offsetof(Mixed, a) == 0, "offset of Mixed::a");
+static_assert(offsetof(Mixed, b) == 8, "offset of Mixed::b");
+static_assert(offsetof(Mixed, c) == 16, "offset of Mixed::c");
+
+struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint32_t b;
+};
+static_assert(sizeof(Packed) == 5, "size of Packed");
+static_assert(alignof(Packed) == 1, "alignment of Packed");
+static_assert(offsetof(Packed, a) == 0, "offset of Packed::a");
+static_assert(offsetof(Packed, b) == 1, "offset of Packed::b");
+
+struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+};
+static_assert(sizeof(Aligned) == 16, "size of Aligned");
+static_assert(alignof(Aligned) == 16, "alignment of Aligned");
+static_assert(offsetof(Aligned, a) == 0, "offset of Aligned::a");
+
+union Either {
+  uint8_t a;
+  uint32_t b;
+};
+static_assert(sizeof(Either) == 4, "size of Either");
+static_assert(alignof(Either) == 4, "alignment of Either");
+
+union Tagged {
+  enum class Tag : uint8_t {
+    Value,
+    Empty,
+  };
+
+  struct Value_Body {
+    Tag tag;
+    uint32_t _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Value_Body value;
+};
+static_assert(sizeof(Tagged) == 8, "size of Tagged");
+static_assert(alignof(Tagged) == 4, "alignment of Tagged");
+
+/// cbindgen doesn't know about the `PhantomData` field, so only the size and
+/// alignment can be checked.
+struct Marked {
+  uint32_t a;
+};
+static_assert(sizeof(Marked) == 4, "size of Marked");
+static_assert(alignof(Marked) == 4, "alignment of Marked");
+
+using Id = uint32_t;
+static_assert(sizeof(Id) == 4, "size of Id");
+static_assert(alignof(Id) == 4, "alignment of Id");
+
+#if defined(WIDE)
+struct Wide {
+  uint64_t a;
+};
+#endif
+#if defined(WIDE)
+static_assert(sizeof(Wide) == 8, "size of Wide");
+static_assert(alignof(Wide) == 8, "alignment of Wide");
+static_assert(offsetof(Wide, a) == 0, "offset of Wide::a");
+#endif
+
+extern "C" {
+
+void process(Mixed _mixed,
+             Packed _packed,
+             Aligned _aligned,
+             Either _either,
+             Tagged _tagged,
+             Simple _simple,
+             Marked _marked,
+             Id _id);
+
+#if defined(WIDE)
+void wide(Wide _wide);
+#endif
+
+Box<Handle> handle_new();
+
+void handle_free(Box<Handle> _handle);
+
+} // extern "C"
@@ -0,0 +1,119 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <assert.h>
+#include <stdalign.h>
+
+enum Simple {
+  First,
+  Second,
+};
+static_assert(sizeof(enum Simple) == 4, "size of Simple");
+static_assert(alignof(enum Simple) == 4, "alignment of Simple");
+
+/**
+ * Declared with the size and alignment of a `Vec<u32>` and a `u16`.
+ */
+struct Handle {
+  uint64_t _opaque[4];
+};
+static_assert(sizeof(struct Handle) == 32, "size of Handle");
+static_assert(alignof(struct Handle) == 8, "alignment of Handle");
+
+struct Mixed {
+  uint8_t a;
+  uint64_t b;
+  uint16_t c;
+};
+static_assert(sizeof(struct Mixed) == 24, "size of Mixed");
+static_assert(alignof(struct Mixed) == 8, "alignment of Mixed");
+static_assert(offsetof(struct Mixed, a) == 0, "offset of Mixed::a");
+static_assert(offsetof(struct Mixed, b) == 8, "offset of Mixed::b");
+static_assert(offsetof(struct Mixed, c) == 16, "offset of Mixed::c");
+
+struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint32_t b;
+};
+static_assert(sizeof(struct Packed) == 5, "size of Packed");
+static_assert(alignof(struct Packed) == 1, "alignment of Packed");
+static_assert(offsetof(struct Packed, a) == 0, "offset of Packed::a");
+static_assert(offsetof(struct Packed, b) == 1, "offset of Packed::b");
+
+struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+};
+static_assert(sizeof(struct Aligned) == 16, "size of Aligned");
+static_assert(alignof(struct Aligned) == 16, "alignment of Aligned");
+static_assert(offsetof(struct Aligned, a) == 0, "offset of Aligned::a");
+
+union Either {
+  uint8_t a;
+  uint32_t b;
+};
+static_assert(sizeof(union Either) == 4, "size of Either");
+static_assert(alignof(union Either) == 4, "alignment of Either");
+
+enum Tagged_Tag {
+  Value,
+  Empty,
+};
+typedef uint8_t Tagged_Tag;
+
+union Tagged {
+  Tagged_Tag tag;
+  struct {
+    Tagged_Tag value_tag;
+    uint32_t value;
+  };
+};
+static_assert(sizeof(union Tagged) == 8, "size of Tagged");
+static_assert(alignof(union Tagged) == 4, "alignment of Tagged");
+
+/**
+ * cbindgen doesn't know about the `PhantomData` field, so only the size and
+ * alignment can be checked.
+ */
+struct Marked {
+  uint32_t a;
+};
+static_assert(sizeof(struct Marked) == 4, "size of Marked");
+static_assert(alignof(struct Marked) == 4, "alignment of Marked");
+
+typedef uint32_t Id;
+static_assert(sizeof(Id) == 4, "size of Id");
+static_assert(alignof(Id) == 4, "alignment of Id");
+
+#if defined(WIDE)
+struct Wide {
+  uint64_t a;
+};
+#endif
+#if defined(WIDE)
+static_assert(sizeof(struct Wide) == 8, "size of Wide");
+static_assert(alignof(struct Wide) == 8, "alignment of Wide");
+static_assert(offsetof(struct Wide, a) == 0, "offset of Wide::a");
+#endif
+
+void process(struct Mixed _mixed,
+             struct Packed _packed,
+             struct Aligned _aligned,
+             union Either _either,
+             union Tagged _tagged,
+             enum Simple _simple,
+             struct Marked _marked,
+             Id _id);
+
+#if defined(WIDE)
+void wide(struct Wide _wide);
+#endif
+
+struct Handle *handle_new(void);
+
+void handle_free(struct Handle *_handle);
@@ -0,0 +1,71 @@
+#define CBINDGEN_PACKED        __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n)    __attribute__ ((aligned(n)))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum Simple:
+    First,
+    Second,
+
+  # Declared with the size and alignment of a `Vec<u32>` and a `u16`.
+  cdef struct Handle:
+    uint64_t _opaque[4]
+
+  cdef struct Mixed:
+    uint8_t a;
+    uint64_t b;
+    uint16_t c;
+
+  cdef packed struct Packed:
+    uint8_t a;
+    uint32_t b;
+
+  cdef struct Aligned:
+    uint8_t a;
+
+  cdef union Either:
+    uint8_t a;
+    uint32_t b;
+
+  cdef enum:
+    Value,
+    Empty,
+  ctypedef uint8_t Tagged_Tag;
+
+  cdef union Tagged:
+    Tagged_Tag tag;
+    uint32_t value;
+
+  # cbindgen doesn't know about the `PhantomData` field, so only the size and
+  # alignment can be checked.
+  cdef struct Marked:
+    uint32_t a;
+
+  ctypedef uint32_t Id;
+
+  IF WIDE:
+    cdef struct Wide:
+      uint64_t a;
+
+  void process(Mixed _mixed,
+               Packed _packed,
+               Aligned _aligned,
+               Either _either,
+               Tagged _tagged,
+               Simple _simple,
+               Marked _marked,
+               Id _id);
+
+  IF WIDE:
+    void wide(Wide _wide);
+
+  Handle *handle_new();
+
+  void handle_free(Handle *_handle);
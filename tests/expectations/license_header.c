@@ -0,0 +1,13 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0
+ * Authors: hitbear, Jane Doe <jane@example.com> */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint64_t bar;
+} Foo;
+
+void doit(const Foo*);
@@ -0,0 +1,21 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0
+ * Authors: hitbear, Jane Doe <jane@example.com> */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint64_t bar;
+} Foo;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void doit(const Foo*);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
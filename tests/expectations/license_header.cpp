@@ -0,0 +1,18 @@
+/* SPDX-License-Identifier: MIT OR Apache-2.0
+ * Authors: hitbear, Jane Doe <jane@example.com> */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Foo {
+  uint64_t bar;
+};
+
+extern "C" {
+
+void doit(const Foo*);
+
+} // extern "C"
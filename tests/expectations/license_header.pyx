@@ -0,0 +1,15 @@
+# SPDX-License-Identifier: MIT OR Apache-2.0
+# Authors: hitbear, Jane Doe <jane@example.com>
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Foo:
+    uint64_t bar;
+
+  void doit(const Foo*);
@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Callbacks:
+    int32_t (*on_something_happened)(uint32_t first_argument,
+                                     const uint8_t *second_argument,
+                                     uintptr_t third_argument_name);
+
+  ctypedef int32_t (*VeryLongCallbackName)(uint32_t first_argument,
+                                           const uint8_t *second_argument,
+                                           uintptr_t third_argument_name);
+
+  extern int32_t (*GLOBAL_HANDLER_WITH_LONG_NAME)(
+    uint32_t first_argument,
+    const uint8_t *second_argument,
+    uintptr_t third_argument_name);
+
+  void register_callbacks(const Callbacks *callbacks,
+                          int32_t (*fallback)(uint32_t first_argument,
+                                              const uint8_t *second_argument,
+                                              uintptr_t third_argument_name),
+                          uint8_t *user_data);
+
+  void an_extremely_long_function_name_for_registering_the_callbacks_of_a_context(
+    uint8_t *context_with_long_name,
+    VeryLongCallbackName fallback);
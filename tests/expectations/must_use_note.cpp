@@ -0,0 +1,39 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+#define MUST_USE_FUNC_WITH_NOTE(...) __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT_WITH_NOTE(...) __attribute__((warn_unused))
+#define MUST_USE_ENUM_WITH_NOTE(...) /* nothing */
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class MUST_USE_ENUM_WITH_NOTE("errors must be handled") Status : int32_t {
+  Ok = 0,
+  Failed = 1,
+};
+
+struct MUST_USE_STRUCT_WITH_NOTE("the handle must be closed") Handle {
+  int32_t fd;
+};
+
+extern "C" {
+
+MUST_USE_FUNC int32_t must_use_without_note();
+
+MUST_USE_FUNC_WITH_NOTE("check the error code") Status must_use_with_note();
+
+MUST_USE_FUNC_WITH_NOTE("This quote \" requires to be quoted")
+int32_t must_use_with_note_which_requires_to_be_escaped();
+
+MUST_USE_FUNC_WITH_NOTE("the handle leaks otherwise") Handle open_handle();
+
+MUST_USE_FUNC int32_t forced_must_use();
+
+int32_t suppressed_must_use();
+
+} // extern "C"
@@ -0,0 +1,35 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+#define MUST_USE_FUNC_WITH_NOTE(...) __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT_WITH_NOTE(...) __attribute__((warn_unused))
+#define MUST_USE_ENUM_WITH_NOTE(...) /* nothing */
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Ok # = 0,
+    Failed # = 1,
+  ctypedef int32_t Status;
+
+  ctypedef struct Handle:
+    int32_t fd;
+
+  int32_t must_use_without_note();
+
+  Status must_use_with_note();
+
+  int32_t must_use_with_note_which_requires_to_be_escaped();
+
+  Handle open_handle();
+
+  int32_t forced_must_use();
+
+  int32_t suppressed_must_use();
@@ -0,0 +1,49 @@
+#define MUST_USE_FUNC __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT __attribute__((warn_unused))
+#define MUST_USE_ENUM /* nothing */
+#define MUST_USE_FUNC_WITH_NOTE(...) __attribute__((warn_unused_result))
+#define MUST_USE_STRUCT_WITH_NOTE(...) __attribute__((warn_unused))
+#define MUST_USE_ENUM_WITH_NOTE(...) /* nothing */
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  Ok = 0,
+  Failed = 1,
+};
+#ifndef __cplusplus
+typedef int32_t Status;
+#endif // __cplusplus
+
+typedef struct MUST_USE_STRUCT_WITH_NOTE("the handle must be closed") Handle {
+  int32_t fd;
+} Handle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+MUST_USE_FUNC int32_t must_use_without_note(void);
+
+MUST_USE_FUNC_WITH_NOTE("check the error code") Status must_use_with_note(void);
+
+MUST_USE_FUNC_WITH_NOTE("This quote \" requires to be quoted")
+int32_t must_use_with_note_which_requires_to_be_escaped(void);
+
+MUST_USE_FUNC_WITH_NOTE("the handle leaks otherwise") struct Handle open_handle(void);
+
+MUST_USE_FUNC int32_t forced_must_use(void);
+
+int32_t suppressed_must_use(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
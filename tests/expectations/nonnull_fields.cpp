@@ -0,0 +1,41 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Opaque;
+
+using OpaquePtr = Opaque*CBINDGEN_NONNULL ;
+
+using OpaqueHandle = Opaque*CBINDGEN_NONNULL ;
+
+struct Node {
+  int32_t value;
+  Opaque *next;
+};
+
+struct Handles {
+  Opaque *CBINDGEN_NONNULL a;
+  Opaque *CBINDGEN_NONNULL b;
+  OpaquePtr c;
+  OpaqueHandle d;
+  Opaque *CBINDGEN_NONNULL e[2];
+  uint8_t (*CBINDGEN_NONNULL f)[4];
+  Node *CBINDGEN_NONNULL g;
+  uint8_t *h;
+  int32_t (**CBINDGEN_NONNULL i)(int32_t);
+};
+
+extern "C" {
+
+void root(Handles handles, OpaqueHandle handle, Node *CBINDGEN_NONNULL node);
+
+} // extern "C"
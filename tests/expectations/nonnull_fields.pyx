@@ -0,0 +1,38 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Opaque:
+    pass
+
+  ctypedef Opaque *OpaquePtr;
+
+  ctypedef Opaque *OpaqueHandle;
+
+  ctypedef struct Node:
+    int32_t value;
+    Opaque *next;
+
+  ctypedef struct Handles:
+    Opaque *a;
+    Opaque *b;
+    OpaquePtr c;
+    OpaqueHandle d;
+    Opaque *e[2];
+    uint8_t (*f)[4];
+    Node *g;
+    uint8_t *h;
+    int32_t (**i)(int32_t);
+
+  void root(Handles handles, OpaqueHandle handle, Node *node);
@@ -0,0 +1,44 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Opaque Opaque;
+
+typedef struct Opaque *CBINDGEN_NONNULL OpaquePtr;
+
+typedef struct Opaque *CBINDGEN_NONNULL OpaqueHandle;
+
+typedef struct Node {
+  int32_t value;
+  struct Opaque *next;
+} Node;
+
+typedef struct Handles {
+  struct Opaque *CBINDGEN_NONNULL a;
+  struct Opaque *CBINDGEN_NONNULL b;
+  OpaquePtr c;
+  OpaqueHandle d;
+  struct Opaque *CBINDGEN_NONNULL e[2];
+  uint8_t (*CBINDGEN_NONNULL f)[4];
+  struct Node *CBINDGEN_NONNULL g;
+  uint8_t *h;
+  int32_t (**CBINDGEN_NONNULL i)(int32_t);
+} Handles;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Handles handles, OpaqueHandle handle, struct Node *CBINDGEN_NONNULL node);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <assert.h>
+
+typedef struct {
+  int32_t x;
+} Foo;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t foo_get(const Foo *foo, uintptr_t index);
+
+int32_t foo_copy(Foo *dst, const Foo *src);
+
+void foo_reset(Foo *foo);
+
+#if defined(DEFINE_EXTRA)
+void foo_free(Foo *foo);
+#endif
+
+Foo *foo_new(void);
+
+static inline int32_t foo_get_checked(const Foo *foo, uintptr_t index) {
+  assert(foo != NULL);
+  return foo_get(foo, index);
+}
+
+static inline int32_t foo_copy_checked(Foo *dst, const Foo *src) {
+  if (dst == NULL || src == NULL) {
+    return -1;
+  }
+  return foo_copy(dst, src);
+}
+
+static inline void foo_reset_checked(Foo *foo) {
+  if (foo == NULL) {
+    return;
+  }
+  foo_reset(foo);
+}
+
+#if defined(DEFINE_EXTRA)
+static inline void foo_free_checked(Foo *foo) {
+  assert(foo != NULL);
+  foo_free(foo);
+}
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Foo:
+    int32_t x;
+
+  int32_t foo_get(const Foo *foo, uintptr_t index);
+
+  int32_t foo_copy(Foo *dst, const Foo *src);
+
+  void foo_reset(Foo *foo);
+
+  IF DEFINE_EXTRA:
+    void foo_free(Foo *foo);
+
+  Foo *foo_new();
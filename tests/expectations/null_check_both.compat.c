@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <assert.h>
+
+typedef struct Foo {
+  int32_t x;
+} Foo;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t foo_get(const struct Foo *foo, uintptr_t index);
+
+int32_t foo_copy(struct Foo *dst, const struct Foo *src);
+
+void foo_reset(struct Foo *foo);
+
+#if defined(DEFINE_EXTRA)
+void foo_free(struct Foo *foo);
+#endif
+
+struct Foo *foo_new(void);
+
+static inline int32_t foo_get_checked(const struct Foo *foo, uintptr_t index) {
+  assert(foo != NULL);
+  return foo_get(foo, index);
+}
+
+static inline int32_t foo_copy_checked(struct Foo *dst, const struct Foo *src) {
+  if (dst == NULL || src == NULL) {
+    return -1;
+  }
+  return foo_copy(dst, src);
+}
+
+static inline void foo_reset_checked(struct Foo *foo) {
+  if (foo == NULL) {
+    return;
+  }
+  foo_reset(foo);
+}
+
+#if defined(DEFINE_EXTRA)
+static inline void foo_free_checked(struct Foo *foo) {
+  assert(foo != NULL);
+  foo_free(foo);
+}
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
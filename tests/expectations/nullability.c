@@ -0,0 +1,25 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+} Opaque;
+
+void copy_into(Opaque *CBINDGEN_NONNULL dst,
+               const Opaque *CBINDGEN_NULLABLE hint,
+               const Opaque *CBINDGEN_NONNULL src) __attribute__((nonnull(1, 3)));
+
+const Opaque *CBINDGEN_NULLABLE lookup(const Opaque *CBINDGEN_NONNULL table,
+                                       const uint8_t *CBINDGEN_NONNULL name,
+                                       const Opaque *CBINDGEN_NULLABLE fallback) __attribute__((nonnull(1, 2)));
@@ -0,0 +1,23 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Opaque:
+    int32_t x;
+
+  void copy_into(Opaque *dst, const Opaque *hint, const Opaque *src);
+
+  const Opaque *lookup(const Opaque *table, const uint8_t *name, const Opaque *fallback);
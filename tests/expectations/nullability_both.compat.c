@@ -0,0 +1,33 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#define CBINDGEN_NULLABLE _Nullable
+#else
+#define CBINDGEN_NONNULL
+#define CBINDGEN_NULLABLE
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Opaque {
+  int32_t x;
+} Opaque;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void copy_into(struct Opaque *CBINDGEN_NONNULL dst,
+               const struct Opaque *CBINDGEN_NULLABLE hint,
+               const struct Opaque *CBINDGEN_NONNULL src) __attribute__((nonnull(1, 3)));
+
+const struct Opaque *CBINDGEN_NULLABLE lookup(const struct Opaque *CBINDGEN_NONNULL table,
+                                              const uint8_t *CBINDGEN_NONNULL name,
+                                              const struct Opaque *CBINDGEN_NULLABLE fallback) __attribute__((nonnull(1, 2)));
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind {
+  A,
+  B,
+};
+typedef uint32_t Kind;
+
+typedef Kind *KindOwned;
+
+typedef struct Opaque Opaque;
+
+typedef Opaque *OpaqueOwned;
+
+typedef struct {
+  int32_t value;
+} Leaf;
+
+typedef Leaf *LeafOwned;
+
+typedef struct {
+  LeafOwned root;
+  uintptr_t size;
+} Tree;
+
+typedef Tree *TreeOwned;
+
+typedef uint64_t Id;
+
+typedef Id *IdOwned;
+
+TreeOwned tree_new(void);
+
+void tree_free(TreeOwned tree);
+
+OpaqueOwned opaque_new(void);
+
+void boxes(KindOwned kind, IdOwned id, int32_t /* owned */ *value);
+
+Leaf /* owned */ *leaf_new(void);
+
+void leaf_free(Leaf *leaf);
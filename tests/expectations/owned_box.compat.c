@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint32_t Kind;
+#endif // __cplusplus
+
+typedef Kind *KindOwned;
+
+typedef struct Opaque Opaque;
+
+typedef Opaque *OpaqueOwned;
+
+typedef struct {
+  int32_t value;
+} Leaf;
+
+typedef Leaf *LeafOwned;
+
+typedef struct {
+  LeafOwned root;
+  uintptr_t size;
+} Tree;
+
+typedef Tree *TreeOwned;
+
+typedef uint64_t Id;
+
+typedef Id *IdOwned;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+TreeOwned tree_new(void);
+
+void tree_free(TreeOwned tree);
+
+OpaqueOwned opaque_new(void);
+
+void boxes(KindOwned kind, IdOwned id, int32_t /* owned */ *value);
+
+Leaf /* owned */ *leaf_new(void);
+
+void leaf_free(Leaf *leaf);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
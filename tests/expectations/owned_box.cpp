@@ -0,0 +1,47 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using LeafOwned = struct Leaf*;
+using OpaqueOwned = struct Opaque*;
+using TreeOwned = struct Tree*;
+
+enum class Kind : uint32_t {
+  A,
+  B,
+};
+
+using KindOwned = Kind*;
+
+struct Opaque;
+
+struct Leaf {
+  int32_t value;
+};
+
+struct Tree {
+  LeafOwned root;
+  uintptr_t size;
+};
+
+using Id = uint64_t;
+
+using IdOwned = Id*;
+
+extern "C" {
+
+TreeOwned tree_new();
+
+void tree_free(TreeOwned tree);
+
+OpaqueOwned opaque_new();
+
+void boxes(KindOwned kind, IdOwned id, int32_t /* owned */ *value);
+
+Leaf /* owned */ *leaf_new();
+
+void leaf_free(Leaf *leaf);
+
+} // extern "C"
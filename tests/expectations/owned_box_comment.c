@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind {
+  A,
+  B,
+};
+typedef uint32_t Kind;
+
+typedef struct Opaque Opaque;
+
+typedef struct {
+  int32_t value;
+} Leaf;
+
+typedef Leaf *LeafOwned;
+
+typedef struct {
+  Leaf /* owned */ *root;
+  uintptr_t size;
+} Tree;
+
+typedef uint64_t Id;
+
+Tree /* owned */ *tree_new(void);
+
+void tree_free(Tree /* owned */ *tree);
+
+Opaque /* owned */ *opaque_new(void);
+
+void boxes(Kind /* owned */ *kind, Id /* owned */ *id, int32_t /* owned */ *value);
+
+LeafOwned leaf_new(void);
+
+void leaf_free(Leaf *leaf);
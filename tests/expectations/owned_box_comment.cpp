@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using LeafOwned = struct Leaf*;
+
+enum class Kind : uint32_t {
+  A,
+  B,
+};
+
+struct Opaque;
+
+struct Leaf {
+  int32_t value;
+};
+
+struct Tree {
+  Leaf /* owned */ *root;
+  uintptr_t size;
+};
+
+using Id = uint64_t;
+
+extern "C" {
+
+Tree /* owned */ *tree_new();
+
+void tree_free(Tree /* owned */ *tree);
+
+Opaque /* owned */ *opaque_new();
+
+void boxes(Kind /* owned */ *kind, Id /* owned */ *id, int32_t /* owned */ *value);
+
+LeafOwned leaf_new();
+
+void leaf_free(Leaf *leaf);
+
+} // extern "C"
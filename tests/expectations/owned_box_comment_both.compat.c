@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Leaf *LeafOwned;
+
+enum Kind
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint32_t Kind;
+#endif // __cplusplus
+
+typedef struct Opaque Opaque;
+
+typedef struct Leaf {
+  int32_t value;
+} Leaf;
+
+typedef struct Tree {
+  struct Leaf /* owned */ *root;
+  uintptr_t size;
+} Tree;
+
+typedef uint64_t Id;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Tree /* owned */ *tree_new(void);
+
+void tree_free(struct Tree /* owned */ *tree);
+
+struct Opaque /* owned */ *opaque_new(void);
+
+void boxes(Kind /* owned */ *kind, Id /* owned */ *id, int32_t /* owned */ *value);
+
+LeafOwned leaf_new(void);
+
+void leaf_free(struct Leaf *leaf);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
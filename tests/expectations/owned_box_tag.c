@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Leaf *LeafOwned;
+typedef struct Opaque *OpaqueOwned;
+typedef struct Tree *TreeOwned;
+
+enum Kind {
+  A,
+  B,
+};
+typedef uint32_t Kind;
+
+typedef Kind *KindOwned;
+
+struct Opaque;
+
+struct Leaf {
+  int32_t value;
+};
+
+struct Tree {
+  LeafOwned root;
+  uintptr_t size;
+};
+
+typedef uint64_t Id;
+
+typedef Id *IdOwned;
+
+TreeOwned tree_new(void);
+
+void tree_free(TreeOwned tree);
+
+OpaqueOwned opaque_new(void);
+
+void boxes(KindOwned kind, IdOwned id, int32_t /* owned */ *value);
+
+struct Leaf /* owned */ *leaf_new(void);
+
+void leaf_free(struct Leaf *leaf);
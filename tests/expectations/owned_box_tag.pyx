@@ -0,0 +1,36 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    A,
+    B,
+  ctypedef uint32_t Kind;
+
+  cdef struct Opaque:
+    pass
+
+  cdef struct Leaf:
+    int32_t value;
+
+  cdef struct Tree:
+    Leaf *root;
+    uintptr_t size;
+
+  ctypedef uint64_t Id;
+
+  Tree *tree_new();
+
+  void tree_free(Tree *tree);
+
+  Opaque *opaque_new();
+
+  void boxes(Kind *kind, Id *id, int32_t *value);
+
+  Leaf *leaf_new();
+
+  void leaf_free(Leaf *leaf);
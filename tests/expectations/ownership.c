@@ -0,0 +1,35 @@
+#define OWNED(dealloc)
+#define CONSUMES(index)
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer Buffer;
+
+/**
+ * Creates a new buffer.
+ *
+ * Ownership of the returned value is transferred to the caller.
+ * It must be released with `buffer_free`.
+ */
+Buffer *buffer_new(void) OWNED(buffer_free);
+
+/**
+ * Ownership of the returned value is transferred to the caller.
+ */
+Buffer *buffer_clone(const Buffer *buffer);
+
+/**
+ * Takes ownership of `buffer`.
+ */
+void buffer_free(Buffer *buffer) CONSUMES(1);
+
+/**
+ * Appends `other` to `buffer`.
+ *
+ * Takes ownership of `other`.
+ */
+void buffer_append(Buffer *buffer, Buffer *other) CONSUMES(2);
@@ -0,0 +1,35 @@
+#define OWNED(dealloc)
+#define CONSUMES(index)
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer;
+
+/**
+ * Creates a new buffer.
+ *
+ * Ownership of the returned value is transferred to the caller.
+ * It must be released with `buffer_free`.
+ */
+struct Buffer *buffer_new(void) OWNED(buffer_free);
+
+/**
+ * Ownership of the returned value is transferred to the caller.
+ */
+struct Buffer *buffer_clone(const struct Buffer *buffer);
+
+/**
+ * Takes ownership of `buffer`.
+ */
+void buffer_free(struct Buffer *buffer) CONSUMES(1);
+
+/**
+ * Appends `other` to `buffer`.
+ *
+ * Takes ownership of `other`.
+ */
+void buffer_append(struct Buffer *buffer, struct Buffer *other) CONSUMES(2);
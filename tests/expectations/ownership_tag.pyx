@@ -0,0 +1,31 @@
+#define OWNED(dealloc)
+#define CONSUMES(index)
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Buffer:
+    pass
+
+  # Creates a new buffer.
+  #
+  # Ownership of the returned value is transferred to the caller.
+  # It must be released with `buffer_free`.
+  Buffer *buffer_new();
+
+  # Ownership of the returned value is transferred to the caller.
+  Buffer *buffer_clone(const Buffer *buffer);
+
+  # Takes ownership of `buffer`.
+  void buffer_free(Buffer *buffer);
+
+  # Appends `other` to `buffer`.
+  #
+  # Takes ownership of `other`.
+  void buffer_append(Buffer *buffer, Buffer *other);
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Read,
+  Write,
+} Mode;
+
+typedef struct {
+  int32_t fd;
+  Mode mode;
+} Handle;
+
+Handle open(Mode mode);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Read,
+  Write,
+} Mode;
+
+typedef struct {
+  int32_t fd;
+  Mode mode;
+} Handle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Handle open(Mode mode);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Mode {
+  Read,
+  Write,
+};
+
+struct Handle {
+  int32_t fd;
+  Mode mode;
+};
+
+extern "C" {
+
+Handle open(Mode mode);
+
+} // extern "C"
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Mode {
+  Read,
+  Write,
+} Mode;
+
+typedef struct Handle {
+  int32_t fd;
+  enum Mode mode;
+} Handle;
+
+struct Handle open(enum Mode mode);
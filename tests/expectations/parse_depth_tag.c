@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode {
+  Read,
+  Write,
+};
+
+struct Handle {
+  int32_t fd;
+  enum Mode mode;
+};
+
+struct Handle open(enum Mode mode);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode {
+  Read,
+  Write,
+};
+
+struct Handle {
+  int32_t fd;
+  enum Mode mode;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Handle open(enum Mode mode);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
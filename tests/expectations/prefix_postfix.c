@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define BEFORE_CONSTANT 1
+#define CONSTANT 4
+
+#if 1
+typedef enum {
+  A,
+  B,
+} Bar;
+#endif
+
+#define FOO_IS_DEFINED 1
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Foo;
+#define FOO_SIZE (sizeof(int32_t) * 2)
+#define FOO_ALIGN sizeof(int32_t)
+
+extern const uint32_t GLOBAL;
+#define AFTER_GLOBAL 1
+
+void root(Foo foo, Bar bar);
+
+#if defined(HAVE_USE_IT)
+// before
+void use_it(Foo foo);
+#endif
@@ -0,0 +1,37 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#define BEFORE_CONSTANT 1
+constexpr static const uint32_t CONSTANT = 4;
+
+#if 1
+enum class Bar {
+  A,
+  B,
+};
+#endif
+
+#define FOO_IS_DEFINED 1
+struct Foo {
+  int32_t x;
+  int32_t y;
+};
+#define FOO_SIZE (sizeof(int32_t) * 2)
+#define FOO_ALIGN sizeof(int32_t)
+
+extern "C" {
+
+extern const uint32_t GLOBAL;
+#define AFTER_GLOBAL 1
+
+void root(Foo foo, Bar bar);
+
+#if defined(HAVE_USE_IT)
+// before
+void use_it(Foo foo);
+#endif
+
+} // extern "C"
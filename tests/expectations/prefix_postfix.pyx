@@ -0,0 +1,33 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  #define BEFORE_CONSTANT 1
+  const uint32_t CONSTANT # = 4
+
+  #if 1
+  ctypedef enum Bar:
+    A,
+    B,
+  #endif
+
+  #define FOO_IS_DEFINED 1
+  ctypedef struct Foo:
+    int32_t x;
+    int32_t y;
+  #define FOO_SIZE (sizeof(int32_t) * 2)
+  #define FOO_ALIGN sizeof(int32_t)
+
+  extern const uint32_t GLOBAL;
+  #define AFTER_GLOBAL 1
+
+  void root(Foo foo, Bar bar);
+
+  #if defined(HAVE_USE_IT)
+  // before
+  void use_it(Foo foo);
+  #endif
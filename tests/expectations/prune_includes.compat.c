@@ -0,0 +1,19 @@
+#include <stdbool.h>
+#include <stdint.h>
+
+typedef struct {
+  bool verbose;
+  int32_t level;
+} Options;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool configure(const Options *options);
+
+void reset(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,14 @@
+#include <cstdint>
+
+struct Options {
+  bool verbose;
+  int32_t level;
+};
+
+extern "C" {
+
+bool configure(const Options *options);
+
+void reset();
+
+} // extern "C"
@@ -0,0 +1,11 @@
+#include <stdbool.h>
+#include <stdint.h>
+
+struct Options {
+  bool verbose;
+  int32_t level;
+};
+
+bool configure(const struct Options *options);
+
+void reset(void);
@@ -0,0 +1,19 @@
+#include <stdbool.h>
+#include <stdint.h>
+
+struct Options {
+  bool verbose;
+  int32_t level;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+bool configure(const struct Options *options);
+
+void reset(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,14 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+
+cdef extern from *:
+
+  cdef struct Options:
+    bool verbose;
+    int32_t level;
+
+  bool configure(const Options *options);
+
+  void reset();
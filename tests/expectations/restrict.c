@@ -0,0 +1,8 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+void copy(uint8_t *restrict dst, const uint8_t *restrict src, uintptr_t len);
+
+void transform(const float (*restrict input)[4], float (*restrict out)[4]);
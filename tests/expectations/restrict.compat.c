@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void copy(uint8_t *__restrict dst, const uint8_t *__restrict src, uintptr_t len);
+
+void transform(const float (*__restrict input)[4], float (*__restrict out)[4]);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,13 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+void copy(uint8_t *__restrict dst, const uint8_t *__restrict src, uintptr_t len);
+
+void transform(const float (*__restrict input)[4], float (*__restrict out)[4]);
+
+} // extern "C"
@@ -0,0 +1,54 @@
+#if defined(_MSC_VER)
+#include <sal.h>
+#else
+#if !defined(_In_)
+#define _In_
+#endif
+#if !defined(_In_opt_)
+#define _In_opt_
+#endif
+#if !defined(_In_reads_opt_)
+#define _In_reads_opt_(size)
+#endif
+#if !defined(_Inout_)
+#define _Inout_
+#endif
+#if !defined(_Out_)
+#define _Out_
+#endif
+#if !defined(_Out_writes_opt_)
+#define _Out_writes_opt_(size)
+#endif
+#if !defined(_Ret_maybenull_)
+#define _Ret_maybenull_
+#endif
+#if !defined(_Ret_notnull_)
+#define _Ret_notnull_
+#endif
+#endif
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+_Ret_maybenull_ struct Buffer *buffer_new(uintptr_t capacity);
+
+void buffer_append(_Inout_ struct Buffer *buffer,
+                   _In_reads_opt_(len) const uint8_t *data,
+                   uintptr_t len);
+
+uintptr_t buffer_copy(_In_ const struct Buffer *buffer,
+                      _Out_writes_opt_(out_len) uint8_t *out,
+                      uintptr_t out_len);
+
+void buffer_len(_In_opt_ const struct Buffer *buffer, _Out_ uintptr_t *count);
+
+_Ret_notnull_ uint8_t *buffer_get(_Inout_ struct Buffer *buffer);
+
+void buffer_free(struct Buffer *buffer);
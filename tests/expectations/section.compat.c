@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void init(void);
+
+void shutdown(void);
+
+/* Audio API */
+
+void audio_play(void);
+
+void audio_stop(void);
+
+/* Video API */
+
+void video_play(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+void init();
+
+void shutdown();
+
+/* Audio API */
+
+void audio_play();
+
+void audio_stop();
+
+/* Video API */
+
+void video_play();
+
+} // extern "C"
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+void init(void);
+
+/******************************************************************************
+ * Audio API
+ ******************************************************************************/
+
+void audio_play(void);
+
+/******************************************************************************
+ * Video API
+ ******************************************************************************/
+
+void video_play(void);
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+void init();
+
+/******************************************************************************
+ * Audio API
+ ******************************************************************************/
+
+void audio_play();
+
+/******************************************************************************
+ * Video API
+ ******************************************************************************/
+
+void video_play();
+
+} // extern "C"
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if 0
+''' '
+#endif
+typedef long ssize_type;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef long ssize_type
+#endif
+
+
+enum IE {
+  IV,
+};
+typedef ssize_type IE;
+
+enum UE {
+  UV,
+};
+typedef size_t UE;
+
+typedef size_t Usize;
+
+typedef ssize_type Isize;
+
+void root(Usize, Isize, UE, IE);
@@ -0,0 +1,54 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if 0
+''' '
+#endif
+typedef long ssize_type;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef long ssize_type
+#endif
+
+
+enum IE
+#ifdef __cplusplus
+  : ssize_type
+#endif // __cplusplus
+ {
+  IV,
+};
+#ifndef __cplusplus
+typedef ssize_type IE;
+#endif // __cplusplus
+
+enum UE
+#ifdef __cplusplus
+  : size_t
+#endif // __cplusplus
+ {
+  UV,
+};
+#ifndef __cplusplus
+typedef size_t UE;
+#endif // __cplusplus
+
+typedef size_t Usize;
+
+typedef ssize_type Isize;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Usize, Isize, UE, IE);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
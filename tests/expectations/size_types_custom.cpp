@@ -0,0 +1,37 @@
+#include <cstdarg>
+#include <cstddef>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if 0
+''' '
+#endif
+typedef long ssize_type;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef long ssize_type
+#endif
+
+
+enum class IE : ssize_type {
+  IV,
+};
+
+enum class UE : size_t {
+  UV,
+};
+
+using Usize = size_t;
+
+using Isize = ssize_type;
+
+extern "C" {
+
+void root(Usize, Isize, UE, IE);
+
+} // extern "C"
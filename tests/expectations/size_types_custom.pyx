@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+#if 0
+''' '
+#endif
+typedef long ssize_type;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef long ssize_type
+#endif
+
+
+cdef extern from *:
+
+  cdef enum:
+    IV,
+  ctypedef ssize_type IE;
+
+  cdef enum:
+    UV,
+  ctypedef size_t UE;
+
+  ctypedef size_t Usize;
+
+  ctypedef ssize_type Isize;
+
+  void root(Usize, Isize, UE, IE);
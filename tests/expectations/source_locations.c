@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* from source_locations.rs:1 */
+#define LIMIT 16
+
+/* from source_locations.rs:11 */
+/**
+ * A shape.
+ */
+enum Shape {
+  Circle,
+  Square,
+};
+typedef uint8_t Shape;
+
+/* from source_locations.rs:4 */
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+/* from source_locations.rs:16 */
+typedef Point Points[4];
+
+/* from source_locations.rs:19 */
+extern uint32_t COUNTER;
+
+/* from source_locations.rs:22 */
+float area(Shape shape, const Points *points);
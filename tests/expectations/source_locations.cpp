@@ -0,0 +1,34 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/* from source_locations.rs:1 */
+constexpr static const uint32_t LIMIT = 16;
+
+/* from source_locations.rs:11 */
+/// A shape.
+enum class Shape : uint8_t {
+  Circle,
+  Square,
+};
+
+/* from source_locations.rs:4 */
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+/* from source_locations.rs:16 */
+using Points = Point[4];
+
+extern "C" {
+
+/* from source_locations.rs:19 */
+extern uint32_t COUNTER;
+
+/* from source_locations.rs:22 */
+float area(Shape shape, const Points *points);
+
+} // extern "C"
@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/* from source_locations.rs:1 */
+#define LIMIT 16
+
+/* from source_locations.rs:11 */
+/**
+ * A shape.
+ */
+enum Shape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape;
+#endif // __cplusplus
+
+/* from source_locations.rs:4 */
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+/* from source_locations.rs:16 */
+typedef struct Point Points[4];
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/* from source_locations.rs:19 */
+extern uint32_t COUNTER;
+
+/* from source_locations.rs:22 */
+float area(Shape shape, const Points *points);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
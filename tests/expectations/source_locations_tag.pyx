@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # from source_locations.rs:1
+  const uint32_t LIMIT # = 16
+
+  # from source_locations.rs:11
+  # A shape.
+  cdef enum:
+    Circle,
+    Square,
+  ctypedef uint8_t Shape;
+
+  # from source_locations.rs:4
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+
+  # from source_locations.rs:16
+  ctypedef Point Points[4];
+
+  # from source_locations.rs:19
+  extern uint32_t COUNTER;
+
+  # from source_locations.rs:22
+  float area(Shape shape, const Points *points);
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define COUNT 4
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t TABLE[256];
+#define TABLE_LEN 256
+
+extern uint8_t NAMES[COUNT];
+#define NAMES_LEN COUNT
+
+extern const uint8_t (*BYTES)[3];
+#define BYTES_LEN 3
+
+extern const uint16_t UNLISTED[8];
+
+extern const uint64_t *FIRST;
+#define FIRST_LEN 5
+
+extern const uint32_t SCALAR;
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uintptr_t COUNT = 4;
+
+extern "C" {
+
+extern const uint32_t TABLE[256];
+#define TABLE_LEN 256
+
+extern uint8_t NAMES[COUNT];
+#define NAMES_LEN COUNT
+
+extern const uint8_t (*BYTES)[3];
+#define BYTES_LEN 3
+
+extern const uint16_t UNLISTED[8];
+
+extern const uint64_t *FIRST;
+#define FIRST_LEN 5
+
+extern const uint32_t SCALAR;
+
+} // extern "C"
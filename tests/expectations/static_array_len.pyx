@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uintptr_t COUNT # = 4
+
+  extern const uint32_t TABLE[256];
+  const size_t TABLE_LEN # = 256
+
+  extern uint8_t NAMES[COUNT];
+  const size_t NAMES_LEN # = COUNT
+
+  extern const uint8_t (*BYTES)[3];
+  const size_t BYTES_LEN # = 3
+
+  extern const uint16_t UNLISTED[8];
+
+  extern const uint64_t *FIRST;
+  const size_t FIRST_LEN # = 5
+
+  extern const uint32_t SCALAR;
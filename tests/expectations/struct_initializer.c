@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+static inline Point Point_new(int32_t x, int32_t y) {
+  Point result = { .x = x, .y = y };
+  return result;
+}
+
+typedef struct {
+  Point origin;
+  Point size;
+  bool (*callback)(int32_t);
+} Rect;
+
+static inline Rect Rect_new(Point origin, Point size, bool (*callback)(int32_t)) {
+  Rect result = { .origin = origin, .size = size, .callback = callback };
+  return result;
+}
+
+typedef struct {
+  uintptr_t len;
+  uintptr_t len_;
+  uint8_t data[4];
+} Buffer;
+
+#define Buffer_INIT(_0, _1, ...) { .len = _0, .len_ = _1, .data = __VA_ARGS__ }
+
+typedef struct {
+  uint32_t value;
+} Skipped;
+
+typedef struct {
+
+} Empty;
+
+void root(Rect rect, Buffer buffer, Skipped skipped, Empty empty);
@@ -0,0 +1,36 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Rect {
+  Point origin;
+  Point size;
+  bool (*callback)(int32_t);
+};
+
+struct Buffer {
+  uintptr_t len;
+  uintptr_t len_;
+  uint8_t data[4];
+};
+
+struct Skipped {
+  uint32_t value;
+};
+
+struct Empty {
+
+};
+
+extern "C" {
+
+void root(Rect rect, Buffer buffer, Skipped skipped, Empty empty);
+
+} // extern "C"
@@ -0,0 +1,29 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Point:
+    int32_t x;
+    int32_t y;
+
+  ctypedef struct Rect:
+    Point origin;
+    Point size;
+    bool (*callback)(int32_t);
+
+  ctypedef struct Buffer:
+    uintptr_t len;
+    uintptr_t len_;
+    uint8_t data[4];
+
+  ctypedef struct Skipped:
+    uint32_t value;
+
+  ctypedef struct Empty:
+    pass
+
+  void root(Rect rect, Buffer buffer, Skipped skipped, Empty empty);
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#define Point_INIT(x_, y_) { .x = x_, .y = y_ }
+
+typedef struct {
+  Point start;
+  Point end;
+} Line;
+
+#define Line_INIT(start_, end_) { .start = start_, .end = end_ }
+
+typedef struct {
+  uint32_t len;
+  uint8_t data[4];
+} Buffer;
+
+#define Buffer_INIT(len_, ...) { .len = len_, .data = __VA_ARGS__ }
+
+/**
+ * The array isn't last, so there is no macro.
+ */
+typedef struct {
+  int16_t values[2];
+  uint8_t count;
+} Samples;
+
+extern const Point ORIGIN;
+
+void root(Line line, Buffer buffer, Samples samples);
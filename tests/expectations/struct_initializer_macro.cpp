@@ -0,0 +1,34 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Line {
+  Point start;
+  Point end;
+};
+
+struct Buffer {
+  uint32_t len;
+  uint8_t data[4];
+};
+
+/// The array isn't last, so there is no macro.
+struct Samples {
+  int16_t values[2];
+  uint8_t count;
+};
+
+extern "C" {
+
+extern const Point ORIGIN;
+
+void root(Line line, Buffer buffer, Samples samples);
+
+} // extern "C"
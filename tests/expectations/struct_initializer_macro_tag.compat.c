@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+#define Point_INIT(x_, y_) { .x = x_, .y = y_ }
+
+struct Line {
+  struct Point start;
+  struct Point end;
+};
+
+#define Line_INIT(start_, end_) { .start = start_, .end = end_ }
+
+struct Buffer {
+  uint32_t len;
+  uint8_t data[4];
+};
+
+#define Buffer_INIT(len_, ...) { .len = len_, .data = __VA_ARGS__ }
+
+/**
+ * The array isn't last, so there is no macro.
+ */
+struct Samples {
+  int16_t values[2];
+  uint8_t count;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const struct Point ORIGIN;
+
+void root(struct Line line, struct Buffer buffer, struct Samples samples);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
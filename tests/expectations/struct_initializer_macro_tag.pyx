@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+
+  cdef struct Line:
+    Point start;
+    Point end;
+
+  cdef struct Buffer:
+    uint32_t len;
+    uint8_t data[4];
+
+  # The array isn't last, so there is no macro.
+  cdef struct Samples:
+    int16_t values[2];
+    uint8_t count;
+
+  extern const Point ORIGIN;
+
+  void root(Line line, Buffer buffer, Samples samples);
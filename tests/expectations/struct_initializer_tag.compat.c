@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+static inline struct Point Point_new(int32_t x, int32_t y) {
+  struct Point result = { .x = x, .y = y };
+  return result;
+}
+
+struct Rect {
+  struct Point origin;
+  struct Point size;
+  bool (*callback)(int32_t);
+};
+
+static inline struct Rect Rect_new(struct Point origin, struct Point size, bool (*callback)(int32_t)) {
+  struct Rect result = { .origin = origin, .size = size, .callback = callback };
+  return result;
+}
+
+struct Buffer {
+  uintptr_t len;
+  uintptr_t len_;
+  uint8_t data[4];
+};
+
+#define Buffer_INIT(_0, _1, ...) { .len = _0, .len_ = _1, .data = __VA_ARGS__ }
+
+struct Skipped {
+  uint32_t value;
+};
+
+struct Empty {
+
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Rect rect, struct Buffer buffer, struct Skipped skipped, struct Empty empty);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
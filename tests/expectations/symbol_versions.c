@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+int32_t open_file(const uint8_t *path);
+
+int32_t open_file_v2(const uint8_t *path, uint32_t flags);
+
+int32_t open_file_v10(const uint8_t *path, uint32_t flags, uint32_t mode);
+
+void close_file_v2(int32_t fd);
+
+#define open_file open_file_v10
+#define close_file close_file_v2
+
+#if defined(__ELF__)
+__asm__(".symver open_file_v2, open_file@MYLIB_2");
+__asm__(".symver open_file_v10, open_file@MYLIB_10");
+__asm__(".symver close_file_v2, close_file@MYLIB_2");
+#endif
@@ -0,0 +1,15 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  int32_t open_file(const uint8_t *path);
+
+  int32_t open_file_v2(const uint8_t *path, uint32_t flags);
+
+  int32_t open_file_v10(const uint8_t *path, uint32_t flags, uint32_t mode);
+
+  void close_file_v2(int32_t fd);
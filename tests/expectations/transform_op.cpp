@@ -156,7 +156,7 @@ struct StyleBar {
   static StyleBar Bar1(const int32_t &x,
                        const StylePoint<T> &y,
                        const StylePoint<float> &z,
-                       int32_t (*&u)(int32_t)) {
+                       int32_t (*const &u)(int32_t)) {
     StyleBar result;
     ::new (&result.bar1.x) (int32_t)(x);
     ::new (&result.bar1.y) (StylePoint<T>)(y);
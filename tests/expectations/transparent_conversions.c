@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef double Meters;
+
+typedef uint8_t *Handle;
+
+typedef uint32_t Id;
+#define Id_INVALID 0
+
+typedef uint32_t Plain;
+
+void root(Meters a, Handle b, Id c, Plain d);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef double Meters;
+
+typedef uint8_t *Handle;
+
+typedef uint32_t Id;
+#define Id_INVALID 0
+
+typedef uint32_t Plain;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Meters a, Handle b, Id c, Plain d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,47 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Meters {
+  double _0;
+
+  Meters() = default;
+  constexpr explicit Meters(double const& value) : _0(value) {}
+  constexpr explicit operator double() const {
+    return _0;
+  }
+};
+
+struct Handle {
+  uint8_t *raw;
+
+  Handle() = default;
+  constexpr explicit Handle(uint8_t *const& value) : raw(value) {}
+  constexpr explicit operator uint8_t*() const {
+    return raw;
+  }
+};
+
+struct Id {
+  uint32_t _0;
+
+  Id() = default;
+  constexpr explicit Id(uint32_t const& value) : _0(value) {}
+  constexpr explicit operator uint32_t() const {
+    return _0;
+  }
+  bool operator==(const Id& other) const {
+    return _0 == other._0;
+  }
+};
+constexpr static const Id Id_INVALID = Id{ /* ._0 = */ 0 };
+
+using Plain = uint32_t;
+
+extern "C" {
+
+void root(Meters a, Handle b, Id c, Plain d);
+
+} // extern "C"
@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef double Meters;
+
+  ctypedef uint8_t *Handle;
+
+  ctypedef uint32_t Id;
+  const Id Id_INVALID # = 0
+
+  ctypedef uint32_t Plain;
+
+  void root(Meters a, Handle b, Id c, Plain d);
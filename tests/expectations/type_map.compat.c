@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if 0
+''' '
+#endif
+typedef uint32_t DWORD;
+typedef uintptr_t SIZE_T;
+typedef int BOOL;
+typedef uint16_t WCHAR;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef uint32_t DWORD
+  ctypedef uintptr_t SIZE_T
+  ctypedef int BOOL
+  ctypedef uint16_t WCHAR
+#endif
+
+
+#define MAX_WINDOWS 16
+
+#define DEFAULT_ID (DWORD)1
+
+typedef struct {
+  DWORD id;
+  SIZE_T size;
+  BOOL visible;
+  const WCHAR *title;
+} Window;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+BOOL window_show(Window *window, DWORD flags, SIZE_T count);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
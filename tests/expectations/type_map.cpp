@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if 0
+''' '
+#endif
+typedef uint32_t DWORD;
+typedef uintptr_t SIZE_T;
+typedef int BOOL;
+typedef uint16_t WCHAR;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef uint32_t DWORD
+  ctypedef uintptr_t SIZE_T
+  ctypedef int BOOL
+  ctypedef uint16_t WCHAR
+#endif
+
+
+constexpr static const DWORD MAX_WINDOWS = 16;
+
+constexpr static const DWORD DEFAULT_ID = (DWORD)1;
+
+struct Window {
+  DWORD id;
+  SIZE_T size;
+  BOOL visible;
+  const WCHAR *title;
+};
+
+extern "C" {
+
+BOOL window_show(Window *window, DWORD flags, SIZE_T count);
+
+} // extern "C"
@@ -0,0 +1,37 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef struct va_list
+#if 0
+''' '
+#endif
+typedef uint32_t DWORD;
+typedef uintptr_t SIZE_T;
+typedef int BOOL;
+typedef uint16_t WCHAR;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef uint32_t DWORD
+  ctypedef uintptr_t SIZE_T
+  ctypedef int BOOL
+  ctypedef uint16_t WCHAR
+#endif
+
+
+cdef extern from *:
+
+  const DWORD MAX_WINDOWS # = 16
+
+  const DWORD DEFAULT_ID # = <DWORD>1
+
+  ctypedef struct Window:
+    DWORD id;
+    SIZE_T size;
+    BOOL visible;
+    const WCHAR *title;
+
+  BOOL window_show(Window *window, DWORD flags, SIZE_T count);
@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+#if 0
+''' '
+#endif
+typedef uint32_t DWORD;
+typedef uintptr_t SIZE_T;
+typedef int BOOL;
+typedef uint16_t WCHAR;
+#if 0
+' '''
+#endif
+
+#if 0
+cdef extern from *:
+  ctypedef uint32_t DWORD
+  ctypedef uintptr_t SIZE_T
+  ctypedef int BOOL
+  ctypedef uint16_t WCHAR
+#endif
+
+
+#define MAX_WINDOWS 16
+
+#define DEFAULT_ID (DWORD)1
+
+typedef struct Window {
+  DWORD id;
+  SIZE_T size;
+  BOOL visible;
+  const WCHAR *title;
+} Window;
+
+BOOL window_show(struct Window *window, DWORD flags, SIZE_T count);
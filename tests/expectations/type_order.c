@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union {
+  int32_t integer;
+  float real;
+} Data;
+
+enum Kind {
+  Integer,
+  Real,
+};
+typedef uint8_t Kind;
+
+typedef struct {
+  Kind kind;
+  Data data;
+} Value;
+
+typedef struct {
+  Value value;
+  const Data *data;
+} Node;
+
+typedef struct {
+  Node *head;
+  uintptr_t len;
+} List;
+
+typedef void (*Callback)(List *list, const Data *data);
+
+void root(List list, Node *node, Callback callback);
@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union {
+  int32_t integer;
+  float real;
+} Data;
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Integer,
+  Real,
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef struct {
+  Kind kind;
+  Data data;
+} Value;
+
+typedef struct {
+  Value value;
+  const Data *data;
+} Node;
+
+typedef struct {
+  Node *head;
+  uintptr_t len;
+} List;
+
+typedef void (*Callback)(List *list, const Data *data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(List list, Node *node, Callback callback);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,40 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Node;
+
+struct List {
+  Node *head;
+  uintptr_t len;
+};
+
+union Data {
+  int32_t integer;
+  float real;
+};
+
+enum class Kind : uint8_t {
+  Integer,
+  Real,
+};
+
+struct Value {
+  Kind kind;
+  Data data;
+};
+
+struct Node {
+  Value value;
+  const Data *data;
+};
+
+using Callback = void(*)(List *list, const Data *data);
+
+extern "C" {
+
+void root(List list, Node *node, Callback callback);
+
+} // extern "C"
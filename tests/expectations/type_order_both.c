@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Node Node;
+
+typedef struct List {
+  struct Node *head;
+  uintptr_t len;
+} List;
+
+typedef union Data {
+  int32_t integer;
+  float real;
+} Data;
+
+enum Kind {
+  Integer,
+  Real,
+};
+typedef uint8_t Kind;
+
+typedef struct Value {
+  Kind kind;
+  union Data data;
+} Value;
+
+typedef struct Node {
+  struct Value value;
+  const union Data *data;
+} Node;
+
+typedef void (*Callback)(struct List *list, const union Data *data);
+
+void root(struct List list, struct Node *node, Callback callback);
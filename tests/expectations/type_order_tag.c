@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node;
+
+struct List {
+  struct Node *head;
+  uintptr_t len;
+};
+
+union Data {
+  int32_t integer;
+  float real;
+};
+
+enum Kind {
+  Integer,
+  Real,
+};
+typedef uint8_t Kind;
+
+struct Value {
+  Kind kind;
+  union Data data;
+};
+
+struct Node {
+  struct Value value;
+  const union Data *data;
+};
+
+typedef void (*Callback)(struct List *list, const union Data *data);
+
+void root(struct List list, struct Node *node, Callback callback);
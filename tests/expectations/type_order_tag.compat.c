@@ -0,0 +1,50 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node;
+
+struct List {
+  struct Node *head;
+  uintptr_t len;
+};
+
+union Data {
+  int32_t integer;
+  float real;
+};
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Integer,
+  Real,
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+struct Value {
+  Kind kind;
+  union Data data;
+};
+
+struct Node {
+  struct Value value;
+  const union Data *data;
+};
+
+typedef void (*Callback)(struct List *list, const union Data *data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct List list, struct Node *node, Callback callback);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
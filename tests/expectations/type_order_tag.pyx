@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Node
+
+  cdef struct List:
+    Node *head;
+    uintptr_t len;
+
+  cdef union Data:
+    int32_t integer;
+    float real;
+
+  cdef enum:
+    Integer,
+    Real,
+  ctypedef uint8_t Kind;
+
+  cdef struct Value:
+    Kind kind;
+    Data data;
+
+  cdef struct Node:
+    Value value;
+    const Data *data;
+
+  ctypedef void (*Callback)(List *list, const Data *data);
+
+  void root(List list, Node *node, Callback callback);
@@ -0,0 +1,47 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <memory>
+
+#if defined(DEFINE_DOCUMENTS)
+struct Document;
+#endif
+
+struct Parser;
+
+/// No destructor, so no `std::unique_ptr`.
+struct Token;
+
+extern "C" {
+
+Parser *parser_new();
+
+const Token *parser_next(Parser *parser);
+
+void parser_free(Parser *parser);
+
+#if defined(DEFINE_DOCUMENTS)
+void document_destroy(Document *document);
+#endif
+
+} // extern "C"
+
+#if defined(DEFINE_DOCUMENTS)
+struct DocumentDeleter {
+  void operator()(Document *ptr) const {
+    document_destroy(ptr);
+  }
+};
+
+using DocumentPtr = std::unique_ptr<Document, DocumentDeleter>;
+#endif
+
+struct ParserDeleter {
+  void operator()(Parser *ptr) const {
+    parser_free(ptr);
+  }
+};
+
+using ParserPtr = std::unique_ptr<Parser, ParserDeleter>;
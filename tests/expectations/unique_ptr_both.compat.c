@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(DEFINE_DOCUMENTS)
+typedef struct Document Document;
+#endif
+
+typedef struct Parser Parser;
+
+/**
+ * No destructor, so no `std::unique_ptr`.
+ */
+typedef struct Token Token;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Parser *parser_new(void);
+
+const struct Token *parser_next(struct Parser *parser);
+
+void parser_free(struct Parser *parser);
+
+#if defined(DEFINE_DOCUMENTS)
+void document_destroy(struct Document *document);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(DEFINE_DOCUMENTS)
+struct Document;
+#endif
+
+struct Parser;
+
+/**
+ * No destructor, so no `std::unique_ptr`.
+ */
+struct Token;
+
+struct Parser *parser_new(void);
+
+const struct Token *parser_next(struct Parser *parser);
+
+void parser_free(struct Parser *parser);
+
+#if defined(DEFINE_DOCUMENTS)
+void document_destroy(struct Document *document);
+#endif
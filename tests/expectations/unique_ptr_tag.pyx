@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  IF DEFINE_DOCUMENTS:
+    cdef struct Document:
+      pass
+
+  cdef struct Parser:
+    pass
+
+  # No destructor, so no `std::unique_ptr`.
+  cdef struct Token:
+    pass
+
+  Parser *parser_new();
+
+  const Token *parser_next(Parser *parser);
+
+  void parser_free(Parser *parser);
+
+  IF DEFINE_DOCUMENTS:
+    void document_destroy(Document *document);
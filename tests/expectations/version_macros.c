@@ -0,0 +1,15 @@
+#define MYLIB_VERSION_MAJOR 1
+#define MYLIB_VERSION_MINOR 12
+#define MYLIB_VERSION_PATCH 3
+#define MYLIB_VERSION "1.12.3-beta.1"
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint64_t bar;
+} Foo;
+
+void doit(const Foo*);
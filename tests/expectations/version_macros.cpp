@@ -0,0 +1,20 @@
+#define MYLIB_VERSION_MAJOR 1
+#define MYLIB_VERSION_MINOR 12
+#define MYLIB_VERSION_PATCH 3
+#define MYLIB_VERSION "1.12.3-beta.1"
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Foo {
+  uint64_t bar;
+};
+
+extern "C" {
+
+void doit(const Foo*);
+
+} // extern "C"
@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct ShapeVTable {
+  /// Returns the area of the shape.
+  double (*area)(const void *self);
+  void (*scale)(void *self, double factor);
+  const uint8_t *(*name)(const void *self);
+  void (*destroy)(void *self);
+};
+
+/// A shape that can be drawn by the host.
+struct DynShape {
+  void *data;
+  const ShapeVTable *vtable;
+};
+
+struct ListenerVTable {
+  bool (*notify)(const void *self, DynShape shape, int32_t code);
+};
+
+struct DynListener {
+  void *data;
+  const ListenerVTable *vtable;
+};
@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct ShapeVTable:
+    # Returns the area of the shape.
+    double (*area)(const void *self);
+    void (*scale)(void *self, double factor);
+    const uint8_t *(*name)(const void *self);
+    void (*destroy)(void *self);
+
+  # A shape that can be drawn by the host.
+  ctypedef struct DynShape:
+    void *data;
+    const ShapeVTable *vtable;
+
+  ctypedef struct ListenerVTable:
+    bool (*notify)(const void *self, DynShape shape, int32_t code);
+
+  ctypedef struct DynListener:
+    void *data;
+    const ListenerVTable *vtable;
@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct ShapeVTable {
+  /**
+   * Returns the area of the shape.
+   */
+  double (*area)(const void *self);
+  void (*scale)(void *self, double factor);
+  const uint8_t *(*name)(const void *self);
+  void (*destroy)(void *self);
+} ShapeVTable;
+
+/**
+ * A shape that can be drawn by the host.
+ */
+typedef struct DynShape {
+  void *data;
+  const struct ShapeVTable *vtable;
+} DynShape;
+
+typedef struct ListenerVTable {
+  bool (*notify)(const void *self, struct DynShape shape, int32_t code);
+} ListenerVTable;
+
+typedef struct DynListener {
+  void *data;
+  const struct ListenerVTable *vtable;
+} DynListener;
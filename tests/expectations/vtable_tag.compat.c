@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct ShapeVTable {
+  /**
+   * Returns the area of the shape.
+   */
+  double (*area)(const void *self);
+  void (*scale)(void *self, double factor);
+  const uint8_t *(*name)(const void *self);
+  void (*destroy)(void *self);
+};
+
+/**
+ * A shape that can be drawn by the host.
+ */
+struct DynShape {
+  void *data;
+  const struct ShapeVTable *vtable;
+};
+
+struct ListenerVTable {
+  bool (*notify)(const void *self, struct DynShape shape, int32_t code);
+};
+
+struct DynListener {
+  void *data;
+  const struct ListenerVTable *vtable;
+};
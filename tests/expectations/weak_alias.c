@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Called when the plugin is loaded, does nothing by default.
+ */
+void plugin_on_load(uint32_t version);
+#pragma weak plugin_on_load
+
+uint32_t plugin_get_version(void);
+
+#if defined(PLUGIN_ALLOC)
+void plugin_destroy(uint8_t *data);
+#pragma weak plugin_destroy
+#endif
+
+#define plugin_version plugin_get_version
+#if defined(PLUGIN_ALLOC)
+#define plugin_free plugin_destroy
+#define plugin_release plugin_destroy
+#endif
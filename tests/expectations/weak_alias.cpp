@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+/// Called when the plugin is loaded, does nothing by default.
+void plugin_on_load(uint32_t version);
+#pragma weak plugin_on_load
+
+uint32_t plugin_get_version();
+
+#if defined(PLUGIN_ALLOC)
+void plugin_destroy(uint8_t *data);
+#pragma weak plugin_destroy
+#endif
+
+} // extern "C"
+
+#define plugin_version plugin_get_version
+#if defined(PLUGIN_ALLOC)
+#define plugin_free plugin_destroy
+#define plugin_release plugin_destroy
+#endif
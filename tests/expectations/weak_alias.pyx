@@ -0,0 +1,15 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # Called when the plugin is loaded, does nothing by default.
+  void plugin_on_load(uint32_t version);
+
+  uint32_t plugin_get_version();
+
+  IF PLUGIN_ALLOC:
+    void plugin_destroy(uint8_t *data);
@@ -0,0 +1,8 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+void plugin_on_unload(void) __attribute__((weak));
+
+void plugin_init(void);
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void plugin_on_unload(void) __attribute__((weak));
+
+void plugin_init(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
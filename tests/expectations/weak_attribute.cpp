@@ -0,0 +1,13 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+void plugin_on_unload() __attribute__((weak));
+
+void plugin_init();
+
+} // extern "C"
@@ -0,0 +1,46 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    None,
+    Urgent,
+  ctypedef uint16_t Flags;
+
+  cdef enum:
+    Ping,
+    Pong,
+  ctypedef uint8_t Kind;
+
+  ctypedef uint32_t Length;
+
+  ctypedef struct Header:
+    Kind kind;
+    Flags flags;
+    Length length;
+
+  ctypedef struct Packet:
+    Header header;
+    uint16_t ids[4];
+    int32_t matrix[2][2];
+    Header hops[2];
+    uint8_t ttl;
+    uint8_t payload[16];
+
+  ctypedef struct Bytes:
+    uint8_t data[4];
+    bool valid;
+
+  IF NET:
+    ctypedef struct Route:
+      uint16_t hops[4];
+      uint32_t metric;
+
+  void send(const Packet *packet, Bytes bytes);
+
+  IF NET:
+    void route(const Route *route);
@@ -0,0 +1,95 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_SWAP_WIRE_BYTES
+#define CBINDGEN_SWAP_WIRE_BYTES
+/* Converts the `count` integers of `size` bytes at `values` between the byte
+ * order of the target and big-endian (network) byte order, in place. */
+static inline void cbindgen_swap_wire_bytes(void *values, size_t size, size_t count) {
+  const unsigned int one = 1;
+  unsigned char *bytes = (unsigned char *)values;
+  size_t i, j;
+  if (*(const unsigned char *)&one == 0) {
+    return;
+  }
+  for (i = 0; i < count; i++, bytes += size) {
+    for (j = 0; j < size / 2; j++) {
+      unsigned char byte = bytes[j];
+      bytes[j] = bytes[size - 1 - j];
+      bytes[size - 1 - j] = byte;
+    }
+  }
+}
+#endif
+
+enum Flags {
+  None,
+  Urgent,
+};
+typedef uint16_t Flags;
+
+enum Kind {
+  Ping,
+  Pong,
+};
+typedef uint8_t Kind;
+
+typedef uint32_t Length;
+
+typedef struct Header {
+  Kind kind;
+  Flags flags;
+  Length length;
+} Header;
+
+static inline void Header_swap_wire_bytes(Header *value) {
+  cbindgen_swap_wire_bytes(&value->flags, sizeof(value->flags), 1);
+  cbindgen_swap_wire_bytes(&value->length, sizeof(value->length), 1);
+}
+
+typedef struct Packet {
+  struct Header header;
+  uint16_t ids[4];
+  int32_t matrix[2][2];
+  struct Header hops[2];
+  uint8_t ttl;
+  uint8_t payload[16];
+} Packet;
+
+static inline void Packet_swap_wire_bytes(Packet *value) {
+  size_t i;
+  Header_swap_wire_bytes(&value->header);
+  cbindgen_swap_wire_bytes(&value->ids, sizeof(value->ids[0]), sizeof(value->ids) / sizeof(value->ids[0]));
+  cbindgen_swap_wire_bytes(&value->matrix, sizeof(value->matrix[0][0]), sizeof(value->matrix) / sizeof(value->matrix[0][0]));
+  for (i = 0; i < sizeof(value->hops) / sizeof(value->hops[0]); i++) {
+    Header_swap_wire_bytes(&(&value->hops[0])[i]);
+  }
+}
+
+typedef struct Bytes {
+  uint8_t data[4];
+  bool valid;
+} Bytes;
+
+#if defined(NET)
+typedef struct Route {
+  uint16_t hops[4];
+  uint32_t metric;
+} Route;
+#endif
+
+#if defined(NET)
+static inline void Route_swap_wire_bytes(Route *value) {
+  cbindgen_swap_wire_bytes(&value->hops, sizeof(value->hops[0]), sizeof(value->hops) / sizeof(value->hops[0]));
+  cbindgen_swap_wire_bytes(&value->metric, sizeof(value->metric), 1);
+}
+#endif
+
+void send(const struct Packet *packet, struct Bytes bytes);
+
+#if defined(NET)
+void route(const struct Route *route);
+#endif
@@ -0,0 +1,86 @@
+use std::num::NonZeroU32;
+use std::os::raw::c_char;
+use std::ptr::NonNull;
+
+pub struct Opaque;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+pub struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+pub type Callback = extern "C" fn(*mut Opaque, u32) -> bool;
+
+#[repr(C)]
+pub enum Error {
+    Ok,
+    Failed,
+}
+
+impl Point {
+    #[no_mangle]
+    pub extern "C" fn point_len(&self) -> f64 {
+        0.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn opaque_new(name: *const c_char, len: usize) -> Box<Opaque> {
+    Box::new(Opaque)
+}
+
+#[no_mangle]
+pub extern "C" fn opaque_free(opaque: Option<Box<Opaque>>) {}
+
+#[no_mangle]
+pub extern "C" fn opaque_visit(
+    opaque: NonNull<Opaque>,
+    points: &mut [Point; 4],
+    callback: Callback,
+) -> Error {
+    Error::Ok
+}
+
+#[no_mangle]
+pub extern "C" fn pair_sum(pair: &Pair<i32>, scale: Option<&f32>) -> Pair<i64> {
+    Pair {
+        first: 0,
+        second: 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn next_char(c: char, step: NonZeroU32) -> char {
+    c
+}
+
+#[no_mangle]
+pub extern "C-unwind" fn may_unwind(value: u8) {}
+
+#[no_mangle]
+pub extern "C" fn abort_now() -> ! {
+    std::process::abort()
+}
+
+#[cfg(feature = "extra")]
+#[no_mangle]
+pub extern "C" fn extra() {}
+
+/// cbindgen:result
+#[no_mangle]
+pub extern "C" fn checked_div(a: i32, b: i32) -> Result<i32, Error> {
+    Ok(a / b)
+}
+
+#[no_mangle]
+pub static mut COUNTER: u64 = 0;
+
+#[no_mangle]
+pub static ORIGIN: Point = Point { x: 0, y: 0 };
@@ -0,0 +1,61 @@
+use std::fs;
+use std::process::Command;
+
+use cbindgen::{Builder, Config, Language};
+
+#[test]
+fn fuzz_stubs() {
+    let config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/fuzz_stubs.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let paths = bindings
+        .write_fuzz_stubs(dir.path(), "include/bindings.h")
+        .unwrap();
+    assert_eq!(
+        paths,
+        vec![
+            dir.path().join("fuzz_parser_feed.c"),
+            dir.path().join("fuzz_decode_in_place.c"),
+        ]
+    );
+
+    let stub = fs::read_to_string(dir.path().join("fuzz_parser_feed.c")).unwrap();
+    assert!(stub.contains("#include \"bindings.h\"\n"));
+    assert!(stub.ends_with(
+        "int LLVMFuzzerTestOneInput(const uint8_t *data, size_t size) {\n  \
+           struct Parser *parser;\n  \
+           memset(&parser, 0, sizeof(parser));\n  \
+           parser_feed(parser, (const char*)data, size);\n  \
+           return 0;\n\
+         }\n"
+    ));
+
+    let stub = fs::read_to_string(dir.path().join("fuzz_decode_in_place.c")).unwrap();
+    assert!(stub.contains("  memcpy(copy, data, size);\n"));
+    assert!(stub.contains("  decode_in_place((uint8_t*)copy, size, flags);\n  free(copy);\n"));
+}
+
+#[test]
+fn fuzz_stubs_need_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let stubs = dir.path().join("fuzz");
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .arg("--emit")
+        .arg("fuzz-stubs")
+        .arg(&stubs)
+        .arg("tests/rust/fuzz_stubs.rs")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("without `--out`"));
+    assert!(output.stdout.is_empty());
+    assert!(!stubs.exists());
+}
@@ -0,0 +1,49 @@
+use std::fs;
+
+use cbindgen::{Builder, Config};
+
+#[test]
+fn header_and_trailer_files() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join("banner.h.in"),
+        "/*\n * {crate} {version}\n *\n * Licensed under the MPL.\n */\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("trailer.h.in"), "/* End of {crate}. */\n").unwrap();
+    fs::write(
+        dir.path().join("cbindgen.toml"),
+        "language = \"C\"\nheader_file = \"banner.h.in\"\ntrailer_file = \"trailer.h.in\"\n",
+    )
+    .unwrap();
+
+    let config = Config::from_file(dir.path().join("cbindgen.toml")).unwrap();
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_crate("tests/rust/package_version")
+        .generate()
+        .unwrap();
+    let mut header = Vec::new();
+    bindings.write(&mut header);
+    let header = String::from_utf8(header).unwrap();
+    assert!(
+        header.starts_with("/*\n * package_version 0.1.0\n *\n * Licensed under the MPL.\n */\n")
+    );
+    assert!(header.ends_with("\n/* End of package_version. */\n"));
+}
+
+#[test]
+fn missing_header_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        header_file: Some(dir.path().join("missing.h.in")),
+        ..Default::default()
+    };
+    let err = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/cython_wrappers.rs")
+        .generate()
+        .err()
+        .unwrap();
+    assert!(err.to_string().starts_with("Couldn't read "), "{}", err);
+}
@@ -0,0 +1,95 @@
+#![cfg(unix)]
+
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn hooks() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("cbindgen.toml");
+    let output = dir.path().join("bindings.h");
+    let depfile = dir.path().join("bindings.d");
+    let log = dir.path().join("hooks.log");
+    fs::write(
+        &config,
+        format!(
+            "language = \"C\"\n\
+             [hooks]\n\
+             pre_generate = \"test ! -e {{output}} && echo pre >> {log}\"\n\
+             post_generate = \"test -e {{depfile}} && echo '/* post */' >> {{output}} && echo post >> {log}\"\n",
+            log = log.display()
+        ),
+    )
+    .unwrap();
+
+    let cbindgen = |extra_args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+            .arg("--config")
+            .arg(&config)
+            .arg("--output")
+            .arg(&output)
+            .arg("--depfile")
+            .arg(&depfile)
+            .args(extra_args)
+            .arg("tests/rust/fns.rs")
+            .status()
+            .unwrap()
+    };
+
+    assert!(cbindgen(&[]).success());
+    assert_eq!(fs::read_to_string(&log).unwrap(), "pre\npost\n");
+    assert!(fs::read_to_string(&output)
+        .unwrap()
+        .ends_with("/* post */\n"));
+
+    // `--verify` doesn't write anything, so there's nothing to post-process.
+    fs::remove_file(&output).unwrap();
+    assert!(!cbindgen(&["--verify"]).success());
+    assert_eq!(fs::read_to_string(&log).unwrap(), "pre\npost\npre\n");
+}
+
+#[test]
+fn failing_hook() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("cbindgen.toml");
+    let output = dir.path().join("bindings.h");
+    fs::write(&config, "[hooks]\npre_generate = \"exit 3\"\n").unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .arg("--config")
+        .arg(&config)
+        .arg("--output")
+        .arg(&output)
+        .arg("tests/rust/fns.rs")
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(1));
+    assert!(!output.exists());
+}
+
+#[test]
+fn hook_paths_are_quoted() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("cbindgen.toml");
+    let output = dir.path().join("it's $(touch injected); a header.h");
+    fs::write(
+        &config,
+        "[hooks]\npost_generate = \"echo '/* post */' >> {output}\"\n",
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .current_dir(dir.path())
+        .arg("--config")
+        .arg(&config)
+        .arg("--output")
+        .arg(&output)
+        .arg(fs::canonicalize("tests/rust/fns.rs").unwrap())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert!(fs::read_to_string(&output)
+        .unwrap()
+        .ends_with("/* post */\n"));
+    assert!(!dir.path().join("injected").exists());
+}
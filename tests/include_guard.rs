@@ -0,0 +1,50 @@
+use std::fs;
+
+use cbindgen::{Builder, Config, Language};
+
+#[test]
+fn include_guard_per_file() {
+    let config = Config {
+        language: Language::C,
+        include_guard: Some("{file}_INCLUDED".to_owned()),
+        ..Default::default()
+    };
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/cython_wrappers.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    bindings.write_to_file(dir.path().join("parser.h"));
+    bindings.write_to_file(dir.path().join("parser-compat.h"));
+
+    let header = fs::read_to_string(dir.path().join("parser.h")).unwrap();
+    assert!(header.starts_with("#ifndef PARSER_H_INCLUDED\n#define PARSER_H_INCLUDED\n"));
+    assert!(header.ends_with("#endif /* PARSER_H_INCLUDED */\n"));
+    let compat = fs::read_to_string(dir.path().join("parser-compat.h")).unwrap();
+    assert!(compat.starts_with("#ifndef PARSER_COMPAT_H_INCLUDED\n"));
+}
+
+#[test]
+fn include_guard_with_crate_version() {
+    let config = Config {
+        language: Language::Cxx,
+        include_guard: Some("{crate}_{version}_H".to_owned()),
+        ..Default::default()
+    };
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_crate("tests/rust/package_version")
+        .generate()
+        .unwrap();
+
+    let mut header = Vec::new();
+    bindings.write(&mut header);
+    let header = String::from_utf8(header).unwrap();
+    assert!(
+        header.contains("#ifndef PACKAGE_VERSION_0_1_0_H\n"),
+        "{}",
+        header
+    );
+}
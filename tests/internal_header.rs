@@ -0,0 +1,65 @@
+use std::fs;
+
+use cbindgen::{Builder, Config, Language};
+
+#[test]
+fn internal_header() {
+    let mut config = Config {
+        language: Language::C,
+        include_guard: Some("BINDINGS_H".to_owned()),
+        ..Default::default()
+    };
+    config.internal.path = Some("bindings_internal.h".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/internal.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    assert!(bindings.write_to_file(dir.path().join("bindings.h")));
+
+    let header = fs::read_to_string(dir.path().join("bindings.h")).unwrap();
+    assert!(header.contains("} Options;\n"));
+    assert!(header.contains("\nstruct Handle handle_open(const struct Options *options);\n"));
+    assert!(!header.contains("Stats"));
+    assert!(!header.contains("TEST_SEED"));
+    assert!(!header.contains("INTERNAL_COUNTER"));
+
+    let internal = fs::read_to_string(dir.path().join("bindings_internal.h")).unwrap();
+    assert!(internal.starts_with(
+        "#ifndef BINDINGS_H_INTERNAL\n#define BINDINGS_H_INTERNAL\n\n#include \"bindings.h\"\n"
+    ));
+    assert!(internal.contains("\n#define TEST_SEED 42\n"));
+    assert!(internal.contains("} Stats;\n"));
+    assert!(internal.contains("\nstruct Stats handle_stats(struct Handle handle);\n"));
+    assert!(internal.contains("\nextern uint32_t INTERNAL_COUNTER;\n"));
+    assert!(!internal.contains("Options"));
+    assert!(!internal.contains("#include <"));
+    assert!(internal.ends_with("#endif /* BINDINGS_H_INTERNAL */\n"));
+
+    // Only the internal header is out of date, which counts as a change.
+    assert!(!bindings.write_to_file(dir.path().join("bindings.h")));
+    fs::write(dir.path().join("bindings_internal.h"), "").unwrap();
+    assert!(bindings.write_to_file(dir.path().join("bindings.h")));
+    assert_eq!(
+        fs::read_to_string(dir.path().join("bindings_internal.h")).unwrap(),
+        internal
+    );
+
+    // Without a file to split them into, the internal declarations stay.
+    let mut written = Vec::new();
+    bindings.write(&mut written);
+    let written = String::from_utf8(written).unwrap();
+    assert!(written.contains("} Options;\n"));
+    assert!(written.contains("} Stats;\n"));
+    assert!(written.contains("\n#define TEST_SEED 42\n"));
+    assert!(written.contains("\nstruct Stats handle_stats(struct Handle handle);\n"));
+    assert!(written.contains("\nextern uint32_t INTERNAL_COUNTER;\n"));
+
+    // The internal functions are exported by the library all the same.
+    assert!(bindings
+        .symbols()
+        .iter()
+        .any(|symbol| symbol.name == "handle_stats"));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "serialize-ir")]
+
+use cbindgen::ir::ItemContainer;
+use cbindgen::{Builder, Language};
+
+#[test]
+fn ir_serializes_to_json() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/rust/function_noreturn.rs")
+        .generate()
+        .unwrap();
+
+    let ir = bindings.ir();
+    let functions: Vec<_> = ir.functions.iter().map(|f| f.path.name()).collect();
+    assert_eq!(functions, ["loop_forever", "normal_return"]);
+    assert!(ir
+        .items
+        .iter()
+        .any(|item| matches!(item, ItemContainer::Struct(s) if s.export_name == "Example")));
+
+    let json = serde_json::to_value(&ir).unwrap();
+    assert_eq!(json["functions"][0]["path"], "loop_forever");
+    assert_eq!(json["functions"][0]["never_return"], true);
+    // Without `layout.probe`, no layouts are known.
+    assert_eq!(json["layouts"], serde_json::json!({}));
+}
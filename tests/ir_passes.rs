@@ -0,0 +1,65 @@
+use cbindgen::{
+    AnnotationValue, BackendWriter, Builder, Config, Function, Language, LanguageBackend, Library,
+    Path,
+};
+
+fn generate(builder: Builder) -> String {
+    let mut out = Vec::new();
+    builder
+        .with_language(Language::C)
+        .with_src("tests/rust/function_noreturn.rs")
+        .generate()
+        .unwrap()
+        .write(&mut out);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn with_ir_pass() {
+    let out = generate(Builder::new().with_ir_pass(|library: &mut Library| {
+        library
+            .functions_mut()
+            .retain(|f| f.path.name() != "normal_return");
+        library.retain_items(|item| item.name() != "Example");
+    }));
+    assert!(out.contains("loop_forever"));
+    assert!(!out.contains("normal_return"));
+    assert!(!out.contains("Example"));
+}
+
+#[test]
+fn map_functions() {
+    let out = generate(Builder::new().map_functions(|f| {
+        f.path = Path::new(format!("renamed_{}", f.path.name()));
+    }));
+    assert!(out.contains("renamed_loop_forever"));
+    assert!(out.contains("renamed_normal_return"));
+}
+
+#[test]
+fn map_items() {
+    let out = generate(Builder::new().map_items(|item| {
+        let prefix = format!("#define HAS_{} 1", item.name().to_uppercase());
+        item.annotations_mut()
+            .set("prefix", AnnotationValue::Atom(Some(prefix)));
+    }));
+    assert!(out.contains("#define HAS_EXAMPLE 1\ntypedef struct Example {"));
+    // Functions aren't items.
+    assert!(!out.contains("HAS_LOOP_FOREVER"));
+}
+
+#[test]
+fn with_backend() {
+    struct CommentBackend;
+
+    impl LanguageBackend for CommentBackend {
+        fn write_function(&self, _: &Config, out: &mut BackendWriter, f: &Function) {
+            write!(out, "/* {} */", f.path.name());
+        }
+    }
+
+    let out = generate(Builder::new().with_backend(Box::new(CommentBackend)));
+    assert!(out.contains("/* loop_forever */"));
+    assert!(out.contains("/* normal_return */"));
+    assert!(out.contains("Example"));
+}
@@ -0,0 +1,12 @@
+#[repr(C)]
+pub struct Point { x: i32, y: i32, tags: [u8; 3] }
+#[repr(C)]
+pub union Bits { f: f32, u: u32 }
+#[repr(u8)]
+pub enum Kind { A = 1, B, C = 10 }
+#[repr(C)]
+pub enum Shape { Circle(f32), Square(Point) }
+#[repr(C)]
+pub struct Holder { p: *const Point, k: Kind, b: Bits, s: Shape }
+#[no_mangle]
+pub extern "C" fn root(h: Holder) {}
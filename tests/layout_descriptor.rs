@@ -0,0 +1,69 @@
+use std::process::Command;
+
+use cbindgen::{Builder, Language, TypeKind};
+
+#[test]
+fn layout_descriptor() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/layout/types.rs")
+        .generate()
+        .unwrap();
+
+    let descriptor = bindings.layout_descriptor();
+    assert_eq!(descriptor.byte_order, "native");
+    let names: Vec<_> = descriptor.types.iter().map(|x| &*x.name).collect();
+    assert_eq!(names, ["Kind", "Point", "Bits", "Shape", "Holder"]);
+
+    let kind = &descriptor.types[0];
+    assert_eq!(kind.kind, TypeKind::Enum);
+    let values: Vec<_> = kind.variants.iter().map(|x| &*x.value).collect();
+    assert_eq!(values, ["1", "2", "10"]);
+
+    // Without `layout.probe`, only the sizes of fixed-width fields are known.
+    let point = &descriptor.types[1];
+    assert_eq!(point.kind, TypeKind::Struct);
+    assert_eq!(point.size, None);
+    assert_eq!(point.fields[2].name, "tags");
+    assert_eq!(point.fields[2].ty, "uint8_t[3]");
+    assert_eq!(point.fields[2].offset, None);
+    assert_eq!(point.fields[2].size, Some(3));
+
+    let holder = &descriptor.types[4];
+    assert_eq!(holder.fields[0].ty, "const struct Point*");
+    assert_eq!(holder.fields[0].size, None);
+
+    let mut table = Vec::new();
+    bindings
+        .write_layout_table(&mut table, "include/types.h")
+        .unwrap();
+    let table = String::from_utf8(table).unwrap();
+    assert!(table.contains("#include \"types.h\"\n"));
+    assert!(table.contains(
+        "static const struct cbindgen_field_descriptor types_Point_fields[] = {\n  \
+         { \"x\", \"int32_t\", offsetof(Point, x), sizeof(((Point *)0)->x) },\n"
+    ));
+    assert!(table.contains(
+        "const struct cbindgen_type_descriptor types_type_descriptors[] = {\n  \
+         { \"Kind\", sizeof(Kind), _Alignof(Kind), NULL, 0 },\n  \
+         { \"Point\", sizeof(Point), _Alignof(Point), types_Point_fields, 3 },\n"
+    ));
+    assert!(table.ends_with("const size_t types_type_descriptor_count = 5;\n"));
+}
+
+#[test]
+fn layout_table_needs_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let table = dir.path().join("layout.c");
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .arg("--emit")
+        .arg("layout")
+        .arg(&table)
+        .arg("tests/layout/types.rs")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("without `--out`"));
+    assert!(output.stdout.is_empty());
+    assert!(!table.exists());
+}
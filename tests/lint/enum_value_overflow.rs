@@ -0,0 +1,8 @@
+#[repr(i64)]
+pub enum Big {
+    Small = 1,
+    Huge = 0x1_0000_0000,
+}
+
+#[no_mangle]
+pub extern "C" fn big_value(value: Big) {}
@@ -0,0 +1,44 @@
+#[repr(u8)]
+pub enum Duplicated {
+    A = 1,
+    B,
+    #[cfg(feature = "legacy")]
+    C = 2,
+}
+
+#[repr(u8)]
+pub enum Gappy {
+    A,
+    B,
+    C = 5,
+    D = 7,
+}
+
+/// Flags aren't dense.
+/// cbindgen:value-gaps=allow
+#[repr(u8)]
+pub enum Flags {
+    A = 1,
+    B = 2,
+    C = 4,
+}
+
+#[repr(u8)]
+pub enum Platform {
+    #[cfg(unix)]
+    Unix = 1,
+    #[cfg(windows)]
+    Windows = 1,
+}
+
+/// cbindgen:duplicate-values=allow
+#[repr(u8)]
+pub enum Aliased {
+    First = 0,
+    #[cfg(feature = "legacy")]
+    Default = 0,
+    Second,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Duplicated, b: Gappy, c: Flags, d: Platform, e: Aliased) {}
@@ -0,0 +1,22 @@
+pub struct Inner {
+    value: u32,
+}
+
+#[repr(C)]
+pub struct Outer {
+    inner: Inner,
+    count: u32,
+}
+
+/// cbindgen:field-names=[x, y, z]
+#[repr(C)]
+pub struct Point(f32, f32);
+
+#[repr(C)]
+pub struct Fine {
+    inner: *const Inner,
+    count: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn use_outer(outer: *const Outer, point: Point, fine: Fine) {}
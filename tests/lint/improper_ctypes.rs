@@ -0,0 +1,24 @@
+pub struct Handle {
+    value: String,
+}
+
+pub type Name = String;
+
+#[no_mangle]
+pub extern "C" fn handle_new(name: String) -> Box<Handle> {
+    Box::new(Handle { value: name })
+}
+
+#[no_mangle]
+pub extern "C" fn handle_rename(handle: &mut Handle, name: &str) {}
+
+#[no_mangle]
+pub extern "C" fn handle_take(handle: Handle) {}
+
+#[no_mangle]
+pub extern "C" fn handle_name(handle: &Handle) -> Name {
+    handle.value.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn handle_free(handle: Box<Handle>) {}
@@ -0,0 +1,180 @@
+//! The checks of the bindings, which fail with `Error::Diagnostics` when
+//! they're denied, and log a warning per diagnostic otherwise.
+
+use std::cell::RefCell;
+use std::sync::Once;
+
+use cbindgen::{Builder, CharType, Config, Error, Language, LintLevel};
+use log::{Level, Log, Metadata, Record};
+
+thread_local! {
+    /// The warnings logged by the current test, which runs on its own thread.
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+struct WarningLogger;
+
+impl Log for WarningLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Warn
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            WARNINGS.with(|warnings| warnings.borrow_mut().push(record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Generates the bindings of `src`, returning the warnings logged along the
+/// way, or the diagnostics of the error, without their source location.
+fn check(config: Config, src: &str) -> Result<Vec<String>, Vec<String>> {
+    static LOGGER: Once = Once::new();
+    LOGGER.call_once(|| {
+        log::set_logger(&WarningLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+    });
+    WARNINGS.with(|warnings| warnings.borrow_mut().clear());
+
+    let without_location = |diagnostic: &String| match diagnostic.split_once(": ") {
+        Some((_, diagnostic)) => diagnostic.to_owned(),
+        None => diagnostic.clone(),
+    };
+    match Builder::new().with_config(config).with_src(src).generate() {
+        Ok(_) => {
+            Ok(WARNINGS.with(|warnings| warnings.borrow().iter().map(without_location).collect()))
+        }
+        Err(Error::Diagnostics(_, diagnostics)) => {
+            Err(diagnostics.iter().map(without_location).collect())
+        }
+        Err(err) => panic!("expected diagnostics, got {}", err),
+    }
+}
+
+/// Checks that `lint` fails with `diagnostics` when denied, logs them when it
+/// warns, and stays quiet when allowed.
+fn check_lint(config: impl Fn(LintLevel) -> Config, src: &str, lint: &str, diagnostics: &[&str]) {
+    let suffix = format!(" ({})", lint);
+    let warnings = |level| {
+        check(config(level), src).map(|warnings| {
+            warnings
+                .iter()
+                .filter_map(|warning| warning.strip_suffix(&suffix))
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+        })
+    };
+    assert_eq!(warnings(LintLevel::Deny), Err(to_strings(diagnostics)));
+    assert_eq!(warnings(LintLevel::Warn), Ok(to_strings(diagnostics)));
+    assert_eq!(warnings(LintLevel::Allow), Ok(vec![]));
+}
+
+fn to_strings(diagnostics: &[&str]) -> Vec<String> {
+    diagnostics.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn improper_ctypes() {
+    check_lint(
+        |improper_ctypes| Config {
+            improper_ctypes,
+            ..Config::default()
+        },
+        "tests/lint/improper_ctypes.rs",
+        "improper_ctypes",
+        &[
+            "argument `name` of `handle_new`: `String` has no C layout and can only be passed behind a pointer",
+            "argument `name` of `handle_rename`: `&str` is a reference to an unsized type; use `*const c_char` instead",
+            "argument `handle` of `handle_take`: `Handle` has no C layout and can only be passed behind a pointer",
+            "return type of `handle_name`: `String` has no C layout and can only be passed behind a pointer",
+        ],
+    );
+}
+
+#[test]
+fn char_type() {
+    let config = |char_type| Config {
+        char_type,
+        ..Config::default()
+    };
+    assert_eq!(
+        check(config(CharType::Error), "tests/rust/char_type.rs"),
+        Err(to_strings(&[
+            "`Glyph`",
+            "`DEFAULT_CHAR`",
+            "`glyph_lookup`",
+            "`glyph_first`",
+        ]))
+    );
+    assert_eq!(
+        check(config(CharType::Uint32), "tests/rust/char_type.rs"),
+        Ok(vec![])
+    );
+    assert_eq!(
+        check(config(CharType::Char32), "tests/rust/char_type.rs"),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn enum_value_overflow() {
+    check_lint(
+        |value_range| {
+            let mut config = Config {
+                language: Language::C,
+                ..Config::default()
+            };
+            config.enumeration.value_range = value_range;
+            config
+        },
+        "tests/lint/enum_value_overflow.rs",
+        "enum.value_range",
+        &["`Big::Huge` is 4294967296, out of the range of `int`"],
+    );
+}
+
+#[test]
+fn field_layout() {
+    check_lint(
+        |field_layout| Config {
+            field_layout,
+            ..Config::default()
+        },
+        "tests/lint/field_layout.rs",
+        "field_layout",
+        &[
+            "field `Outer::inner`: `Inner` has no C layout and can only be passed behind a pointer",
+            "`Point` has 2 fields, but its `field-names` annotation has 3 names, which are given to the fields by position",
+        ],
+    );
+}
+
+#[test]
+fn duplicate_values() {
+    check_lint(
+        |duplicate_values| {
+            let mut config = Config::default();
+            config.enumeration.duplicate_values = duplicate_values;
+            config
+        },
+        "tests/lint/enum_values.rs",
+        "enum.duplicate_values",
+        &["`Duplicated::C` has the same value as `Duplicated::B`, 2"],
+    );
+}
+
+#[test]
+fn value_gaps() {
+    check_lint(
+        |value_gaps| {
+            let mut config = Config::default();
+            config.enumeration.value_gaps = value_gaps;
+            config
+        },
+        "tests/lint/enum_values.rs",
+        "enum.value_gaps",
+        &["`Gappy` has no variant for 2 to 4, 6"],
+    );
+}
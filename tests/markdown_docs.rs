@@ -0,0 +1,37 @@
+use cbindgen::{Builder, Language};
+
+#[test]
+fn markdown_docs() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/markdown_docs/lib.rs")
+        .generate()
+        .unwrap();
+
+    let mut docs = Vec::new();
+    bindings.write_markdown_docs(&mut docs).unwrap();
+    let docs = String::from_utf8(docs).unwrap();
+
+    assert!(docs.starts_with("# API reference\n"));
+    assert!(docs.contains(
+        "## Constants\n\n### `LIMIT`\n\n```c\n#define LIMIT 16\n```\n\n\
+         The most points a shape can have.\n"
+    ));
+    assert!(docs.contains("### `Shape`\n\n```c\nenum Shape {\n"));
+    assert!(docs.contains("typedef uint8_t Shape;\n```\n\nA shape.\n"));
+    assert!(docs.contains("A shape.\n\n- `Circle`: A round shape.\n- `Square`\n"));
+    // The documentation of the fields is only listed after the declaration.
+    assert!(docs.contains(
+        "```c\ntypedef struct Point {\n  int32_t x;\n  int32_t y;\n} Point;\n```\n\n\
+         A point on the canvas.\n\n- `x`: The horizontal coordinate.\n- `y`\n"
+    ));
+    assert!(docs.contains("### `Points`\n\n```c\ntypedef struct Point Points[4];\n```\n"));
+    assert!(docs.contains(
+        "## Globals\n\n### `COUNTER`\n\n```c\nextern uint32_t COUNTER;\n```\n\n\
+         The number of shapes drawn so far.\n"
+    ));
+    assert!(docs.ends_with(
+        "## Functions\n\n### `area`\n\n```c\nfloat area(Shape shape, const Points *points);\n```\n\n\
+         The area of the shape with the given points.\n"
+    ));
+}
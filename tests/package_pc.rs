@@ -0,0 +1,89 @@
+use std::fs;
+
+use cbindgen::{Builder, Config};
+
+#[test]
+fn pc_file() {
+    let mut config = Config::default();
+    config.package.emit_pc = true;
+    config.package.requires = vec!["zlib".to_owned()];
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_crate("tests/rust/package_version")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    assert!(bindings.write_to_file(dir.path().join("bindings.h")));
+
+    let pc = fs::read_to_string(dir.path().join("package_version.pc")).unwrap();
+    assert_eq!(
+        pc,
+        "prefix=/usr/local\n\
+         exec_prefix=${prefix}\n\
+         libdir=${exec_prefix}/lib\n\
+         includedir=${prefix}/include\n\
+         \n\
+         Name: package_version\n\
+         Description: package_version\n\
+         Version: 0.1.0\n\
+         Requires: zlib\n\
+         Cflags: -I${includedir}\n\
+         Libs: -L${libdir} -lpackage_version\n"
+    );
+
+    // Only the pkg-config file is out of date, which counts as a change.
+    assert!(!bindings.write_to_file(dir.path().join("bindings.h")));
+    fs::write(dir.path().join("package_version.pc"), "").unwrap();
+    assert!(bindings.write_to_file(dir.path().join("bindings.h")));
+    assert_eq!(
+        fs::read_to_string(dir.path().join("package_version.pc")).unwrap(),
+        pc
+    );
+}
+
+#[test]
+fn pc_file_config() {
+    let mut config = Config::default();
+    config.package.emit_pc = true;
+    config.package.name = Some("mylib".to_owned());
+    config.package.description = Some("My library".to_owned());
+    config.package.prefix = "/usr".to_owned();
+    config.package.libs = Some("-L${libdir} -lmy".to_owned());
+    config.package.libs_private = Some("-lpthread".to_owned());
+    config.package.cflags = Some("-DMYLIB".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/function_noreturn.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    bindings.write_to_file(dir.path().join("bindings.h"));
+
+    let pc = fs::read_to_string(dir.path().join("mylib.pc")).unwrap();
+    assert!(pc.starts_with("prefix=/usr\n"));
+    assert!(pc.contains("Name: mylib\nDescription: My library\nVersion: 0.0.0\n"));
+    assert!(pc.contains("Cflags: -I${includedir} -DMYLIB\n"));
+    assert!(pc.ends_with("Libs: -L${libdir} -lmy\nLibs.private: -lpthread\n"));
+}
+
+#[test]
+fn pc_file_lib_name() {
+    let mut config = Config::default();
+    config.package.emit_pc = true;
+    config.package.name = Some("expand-features-sdk".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_crate("tests/rust/expand_features")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    bindings.write_to_file(dir.path().join("bindings.h"));
+
+    // The library is named after the `[lib]` target, not the package.
+    let pc = fs::read_to_string(dir.path().join("expand-features-sdk.pc")).unwrap();
+    assert!(pc.contains("Name: expand-features-sdk\n"));
+    assert!(pc.ends_with("Libs: -L${libdir} -lexpand\n"));
+}
@@ -0,0 +1,34 @@
+use cbindgen::{Builder, Language};
+
+fn pretty_printers(language: Language) -> String {
+    let bindings = Builder::new()
+        .with_language(language)
+        .with_src("tests/printers/enums.rs")
+        .generate()
+        .unwrap();
+
+    let mut printers = Vec::new();
+    bindings.write_pretty_printers(&mut printers).unwrap();
+    String::from_utf8(printers).unwrap()
+}
+
+#[test]
+fn pretty_printers_c() {
+    let printers = pretty_printers(Language::C);
+    // Single field tuple variants are inlined in C, with their own tag with
+    // `repr(u8)`, which is left out.
+    let shape = "        0: (\"Circle\", [(\"_0\", (\"circle\",))]),\n        \
+                 1: (\"Square\", [(\"_0\", (\"square\",))]),\n        \
+                 2: (\"Rect\", [(\"w\", (\"rect\", \"w\")), (\"h\", (\"rect\", \"h\"))]),\n        \
+                 3: (\"Empty\", []),\n";
+    assert!(printers.contains(&format!("    \"Shape\": {{\n{}", shape)));
+    assert!(printers.contains(&format!("    \"Shape2\": {{\n{}", shape)));
+    assert!(printers.contains("OPTION_LIKE = set([\"Opt\"])\n"));
+    assert!(!printers.contains("\"Point\""));
+}
+
+#[test]
+fn pretty_printers_cxx() {
+    let printers = pretty_printers(Language::Cxx);
+    assert!(printers.contains("        0: (\"Circle\", [(\"_0\", (\"circle\", \"_0\"))]),\n"));
+}
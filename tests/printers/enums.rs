@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Point { x: i32, y: i32 }
+#[repr(C)]
+pub enum Shape { Circle(f32), Square(Point), Rect { w: i32, h: i32 }, Empty }
+#[repr(u8)]
+pub enum Shape2 { Circle(f32), Square(Point), Rect { w: i32, h: i32 }, Empty }
+#[repr(C, u8)]
+pub enum Opt { None, Some(Point) }
+#[no_mangle]
+pub extern "C" fn root(a: Shape, b: Shape2, c: Opt) {}
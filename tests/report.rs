@@ -0,0 +1,30 @@
+use cbindgen::{Builder, Language, SkippedKind};
+
+#[test]
+fn report() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/report/skipped.rs")
+        .generate()
+        .unwrap();
+
+    let skipped = bindings.skipped();
+    assert_eq!(skipped.len(), 4);
+    assert_eq!(skipped[0].name, "skipped::Buffer");
+    assert_eq!(skipped[0].kind, SkippedKind::Opaque);
+    assert_eq!(skipped[0].location.as_deref(), Some("skipped.rs:1"));
+
+    assert_eq!(skipped[3].name, "Source");
+    assert_eq!(skipped[3].kind, SkippedKind::Type);
+    assert_eq!(skipped[3].location, None);
+
+    let mut list = Vec::new();
+    bindings.write_skipped(&mut list).unwrap();
+    assert_eq!(
+        String::from_utf8(list).unwrap(),
+        "skipped.rs:1\topaque\tskipped::Buffer\tStruct is not marked #[repr(C)] or #[repr(transparent)].\n\
+         skipped.rs:17\tstatic\tskipped::COUNTER\tnot `no_mangle`\n\
+         skipped.rs:19\tfunction\tskipped::reader_reset\tnot `no_mangle`, and has no `export_name` attribute\n\
+         -\ttype\tSource\tnot found, or not supported\n"
+    );
+}
@@ -0,0 +1,22 @@
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+pub struct Unused {
+    data: Vec<u8>,
+}
+
+#[repr(C)]
+pub struct Reader {
+    buffer: *mut Buffer,
+    source: *const Source,
+}
+
+const CAPACITY: usize = 64;
+
+pub static COUNTER: u32 = 0;
+
+pub extern "C" fn reader_reset(reader: *mut Reader) {}
+
+#[no_mangle]
+pub extern "C" fn reader_open(reader: *mut Reader) {}
@@ -0,0 +1,31 @@
+#[cbindgen::rename("Renamed")]
+#[cbindgen::prefix("#define RENAMED_IS_DEFINED 1")]
+#[repr(C)]
+pub struct Original {
+    x: i32,
+}
+
+#[cbindgen::opaque]
+#[repr(C)]
+pub struct Hidden {
+    secret: i32,
+}
+
+#[cbindgen::field_names(first, second)]
+#[repr(C)]
+pub struct Pair(i32, i32);
+
+#[cbindgen::enum_class(false)]
+#[repr(u8)]
+pub enum Plain {
+    A,
+    B,
+}
+
+#[cbindgen::section("Things")]
+#[no_mangle]
+pub extern "C" fn take(a: Original, b: *const Hidden, c: Pair, d: Plain) {}
+
+#[cbindgen_macros::ignore]
+#[no_mangle]
+pub extern "C" fn ignored() {}
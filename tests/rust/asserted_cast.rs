@@ -20,6 +20,7 @@ pub enum I {
 }
 
 /// cbindgen:prefix-with-name
+/// cbindgen:variant-is-attributes=MY_ATTRS
 #[repr(C, u8)]
 pub enum J {
     Foo(i16),
@@ -0,0 +1,19 @@
+/// cbindgen:body=#define DEFAULT_VALUE (VALUE_BASE + 1)
+pub const DEFAULT_VALUE: i32 = 43;
+
+pub const VALUE_BASE: i32 = 42;
+
+/// cbindgen:body="#define QUOTED_VALUE (VALUE_BASE + 2)"
+pub const QUOTED_VALUE: i32 = 44;
+
+/// cbindgen:body=#define legacy_get_value() get_value()
+/// cbindgen:body=#define LEGACY_API 1
+#[no_mangle]
+pub extern "C" fn legacy_get_value() -> i32 {
+    get_value()
+}
+
+#[no_mangle]
+pub extern "C" fn get_value() -> i32 {
+    VALUE_BASE
+}
@@ -0,0 +1,19 @@
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+    level: u8,
+}
+
+impl Options {
+    pub const QUIET: Options = Options {
+        verbose: false,
+        level: 0,
+    };
+}
+
+pub const ENABLED: bool = true;
+
+#[no_mangle]
+pub extern "C" fn options_apply(options: *const Options, force: bool) -> bool {
+    true
+}
@@ -0,0 +1,20 @@
+pub type Callback = extern "C-unwind" fn(i32) -> i32;
+
+#[repr(C)]
+pub struct Handlers {
+    on_error: extern "C-unwind" fn(u32),
+    on_event: Option<extern "C-unwind" fn()>,
+}
+
+/// Runs the callback, letting panics propagate.
+#[no_mangle]
+pub extern "C-unwind" fn run(callback: Callback, handlers: Handlers) -> i32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn plain() {}
+
+extern "C-unwind" {
+    pub fn imported(value: i32);
+}
@@ -0,0 +1,17 @@
+pub type WndProc = extern "system" fn(hwnd: *mut u8, msg: u32) -> isize;
+
+#[repr(C)]
+pub struct Callbacks {
+    on_message: extern "system" fn(u32),
+    on_close: Option<extern "stdcall" fn(*mut u8) -> bool>,
+    on_error: extern "C" fn(i32),
+    on_event: fn(),
+}
+
+/// cbindgen:vtable
+pub trait Window {
+    extern "system" fn show(&self, visible: bool);
+}
+
+#[no_mangle]
+pub extern "C" fn set_callbacks(proc_: WndProc, callbacks: Callbacks, window: DynWindow) {}
@@ -0,0 +1,59 @@
+pub type ArrayOfFns = [extern "C" fn(); 4];
+pub type PtrToArray = *const [i32; 4];
+pub type FnReturningFn = extern "C" fn() -> extern "C" fn(i32) -> u8;
+pub type PtrToArrayOfFns = *const [extern "C" fn(); 4];
+pub type PtrToFn = *const extern "C" fn();
+pub type Grid = [[Option<extern "C" fn(*const [u8; 2]) -> *mut [u16; 3]>; 2]; 3];
+pub type Nested =
+    extern "C" fn(extern "C" fn(i32) -> extern "C" fn()) -> *const [extern "C" fn(); 2];
+pub type RefToArrayOfFns = Option<&'static [extern "C" fn(); 2]>;
+pub type PtrToPtrToArray = *const *const [i32; 4];
+pub type RefToArrayOfPtrsToFns = &'static mut [*const extern "C" fn(); 3];
+
+#[repr(C)]
+pub struct Table {
+    fns: [extern "C" fn(); 4],
+    row: *const [i32; 4],
+    factory: extern "C" fn() -> extern "C" fn(i32) -> u8,
+    slots: *mut [Option<extern "C" fn(i32)>; 4],
+    rows: [*const [u8; 2]; 3],
+    indirect: *const extern "C" fn(),
+}
+
+#[no_mangle]
+pub static mut HANDLERS: [extern "C" fn(); 4] = [handler; 4];
+
+#[no_mangle]
+pub extern "C" fn handler() {}
+
+#[no_mangle]
+pub extern "C" fn get_handler(x: i32) -> extern "C" fn(i32) -> u8 {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn get_handlers() -> *const [extern "C" fn(); 4] {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn get_row() -> *mut [i32; 4] {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: ArrayOfFns,
+    b: PtrToArray,
+    c: FnReturningFn,
+    d: PtrToArrayOfFns,
+    e: PtrToFn,
+    f: Grid,
+    g: Nested,
+    h: RefToArrayOfFns,
+    i: PtrToPtrToArray,
+    j: RefToArrayOfPtrsToFns,
+    t: Table,
+    u: *const *const [u8; 2],
+) {
+}
@@ -0,0 +1,30 @@
+/// cbindgen:cfg=defined(DEFINED)
+#[repr(C)]
+pub struct GpuContext {
+    device: u32,
+}
+
+/// cbindgen:cfg=!defined(NOT_DEFINED) && (1 == 1)
+pub const GPU_COUNT: u32 = 2;
+
+/// cbindgen:cfg=defined(DEFINED)
+#[no_mangle]
+pub extern "C" fn gpu_root(ctx: *mut GpuContext) {}
+
+#[no_mangle]
+pub extern "C" fn root() {}
+
+#[repr(C)]
+pub struct Renderer {
+    ctx: GpuContext,
+    frames: u64,
+}
+
+#[no_mangle]
+pub extern "C" fn gpu_context_new() -> *mut GpuContext {
+    loop {}
+}
+
+/// cbindgen:cfg=defined(MYLIB_ENABLE_VULKAN)
+#[no_mangle]
+pub extern "C" fn renderer_draw(renderer: *mut Renderer) {}
@@ -0,0 +1,11 @@
+/// Present whenever `gpu` is off, whatever the platform.
+#[cfg_attr(feature = "gpu", cfg(windows))]
+#[repr(C)]
+pub struct Surface {
+    width: u32,
+    height: u32,
+}
+
+#[no_mangle]
+#[cfg_attr(feature = "gpu", cfg(windows))]
+pub extern "C" fn surface(surface: &Surface) {}
@@ -0,0 +1,34 @@
+#[cfg(all(feature = "gpu", not(target_os = "macos")))]
+#[repr(C)]
+pub struct GpuContext {
+    device: u32,
+    #[cfg(any(feature = "vulkan", all(feature = "metal", not(windows))))]
+    queue: u32,
+}
+
+#[cfg_attr(feature = "gpu", cfg(not(any(windows, target_os = "macos"))))]
+#[repr(C)]
+pub struct Surface {
+    width: u32,
+    height: u32,
+}
+
+#[cfg_attr(feature = "gpu", cfg_attr(feature = "vulkan", cfg(windows), derive(Clone)))]
+#[repr(u8)]
+pub enum Backend {
+    Software,
+    #[cfg_attr(feature = "gpu", cfg(feature = "vulkan"))]
+    Vulkan,
+}
+
+#[no_mangle]
+#[cfg(all(feature = "gpu", not(target_os = "macos")))]
+pub extern "C" fn gpu_context(context: &GpuContext) {}
+
+#[no_mangle]
+#[cfg_attr(feature = "gpu", cfg(not(any(windows, target_os = "macos"))))]
+pub extern "C" fn surface(surface: &Surface) {}
+
+#[no_mangle]
+#[cfg_attr(feature = "gpu", cfg_attr(feature = "vulkan", cfg(windows)))]
+pub extern "C" fn backend(backend: Backend) {}
@@ -0,0 +1,18 @@
+#[repr(C)]
+pub struct Glyph {
+    code_point: char,
+    fallbacks: [char; 4],
+}
+
+#[no_mangle]
+pub static DEFAULT_CHAR: char = '?';
+
+#[no_mangle]
+pub extern "C" fn glyph_lookup(c: char, out: *mut Glyph) -> bool {
+    false
+}
+
+#[no_mangle]
+pub extern "C" fn glyph_first(glyph: &Glyph) -> char {
+    glyph.code_point
+}
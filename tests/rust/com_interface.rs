@@ -0,0 +1,13 @@
+/// A COM interface exposing a counter.
+/// cbindgen:com
+/// cbindgen:iid=6B29FC40-CA47-1067-B31D-00DD010662DA
+pub trait ICounter {
+    /// Adds `amount` to the counter.
+    fn Increment(&mut self, amount: u32) -> i32;
+    fn GetValue(&self, value: *mut u32) -> i32;
+}
+
+/// cbindgen:com
+pub trait IReset {
+    fn Reset(&mut self) -> i32;
+}
@@ -0,0 +1,17 @@
+#[repr(C)]
+pub enum Shape {
+    Circle(f32),
+    Square { side: f32 },
+    Empty,
+}
+
+#[repr(u8)]
+pub enum Kind {
+    Small,
+    Large,
+}
+
+pub type Id = u32;
+
+#[no_mangle]
+pub extern "C" fn area(shape: Shape, kind: Kind, id: Id) -> bool {}
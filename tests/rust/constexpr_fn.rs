@@ -0,0 +1,73 @@
+pub const FLAG_A: u32 = 1 << 0;
+pub const FLAG_B: u32 = 1 << 1;
+
+/// Combines two sets of flags.
+/// cbindgen:constexpr
+pub const fn combine(a: u32, b: u32) -> u32 {
+    a | b
+}
+
+/// cbindgen:constexpr
+pub const fn mask(bits: u8) -> u64 {
+    (1 << bits as u64) - 1
+}
+
+/// cbindgen:constexpr
+pub const fn negate(x: i32) -> i32 {
+    -x
+}
+
+/// cbindgen:constexpr
+pub const fn answer() -> i32 {
+    42
+}
+
+/// cbindgen:constexpr
+pub const fn is_small(x: i32) -> bool {
+    x < 16 && x > -16
+}
+
+/// cbindgen:constexpr
+pub const fn is_odd_small(a: u32) -> bool {
+    !(a > 16) && a != 3
+}
+
+/// cbindgen:constexpr
+pub const fn either(a: bool, b: bool) -> bool {
+    !a | !b
+}
+
+/// cbindgen:constexpr
+pub const fn invert(bits: u8) -> u8 {
+    !bits
+}
+
+/// cbindgen:constexpr
+pub const fn high_nibble(bits: u8) -> u8 {
+    bits << 4
+}
+
+#[repr(C)]
+pub struct Settings {
+    flags: u32,
+}
+
+/// cbindgen:constexpr
+pub const fn settings_flags(settings: Settings) -> u32 {
+    settings.flags | FLAG_A
+}
+
+// Not annotated, so not exported.
+pub const fn not_exported(a: u32) -> u32 {
+    a + 1
+}
+
+/// Doesn't have a single expression as its body.
+/// cbindgen:constexpr
+pub const fn unsupported(a: u32) -> u32 {
+    let b = a + 1;
+    b * 2
+}
+
+#[no_mangle]
+pub extern "C" fn root(settings: Settings) {}
@@ -0,0 +1,30 @@
+/// cbindgen:derive-eq
+/// cbindgen:cuda=hostdevice
+#[repr(C)]
+pub struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+/// cbindgen:derive-helper-methods
+/// cbindgen:cuda=hostdevice
+#[repr(C, u8)]
+pub enum Shape {
+    Circle(f32),
+    Rect(Vec2),
+}
+
+/// cbindgen:cuda=hostdevice
+#[no_mangle]
+pub extern "C" fn vec2_length(v: Vec2) -> f32 {
+    0.0
+}
+
+#[no_mangle]
+pub extern "C" fn shape_area(shape: &Shape) -> f32 {
+    0.0
+}
+
+/// cbindgen:cuda=none
+#[no_mangle]
+pub extern "C" fn host_only() {}
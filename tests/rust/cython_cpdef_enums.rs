@@ -0,0 +1,29 @@
+/// The outcome of a request.
+///
+/// Failures can be retried.
+#[repr(C)]
+pub enum Status {
+    Ok,
+    /// Not found.
+    NotFound,
+    Failed = 10,
+}
+
+/// A "small" enum.
+#[repr(u8)]
+pub enum Level {
+    Low,
+    High,
+}
+
+/// Not exposed to Python.
+///
+/// cbindgen:cpdef=false
+#[repr(C)]
+pub enum Internal {
+    A,
+    B,
+}
+
+#[no_mangle]
+pub extern "C" fn root(status: Status, level: Level, internal: Internal) {}
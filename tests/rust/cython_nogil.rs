@@ -0,0 +1,22 @@
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_fill(buffer: *mut Buffer, value: u8) {}
+
+/// cbindgen:nogil=false
+/// Calls back into Python, so it needs the GIL.
+#[no_mangle]
+pub extern "C" fn buffer_notify(buffer: *const Buffer, callback: extern "C" fn(*const Buffer)) {}
+
+#[no_mangle]
+pub extern "C" fn buffer_checksum_with_a_very_long_name_to_lay_out_its_arguments_vertically(
+    buffer: *const Buffer,
+    offset: usize,
+    len: usize,
+) -> u32 {
+    0
+}
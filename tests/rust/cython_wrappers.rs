@@ -0,0 +1,27 @@
+/// cbindgen:constructor=parser_new
+/// cbindgen:destructor=parser_free
+pub struct Parser {
+    input: Vec<u8>,
+}
+
+/// A parsed document.
+/// cbindgen:destructor=document_free
+pub struct Document {
+    root: Box<u32>,
+}
+
+#[no_mangle]
+pub extern "C" fn parser_new(capacity: usize, strict: bool) -> *mut Parser {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn parser_parse(parser: *mut Parser) -> *mut Document {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn parser_free(parser: *mut Parser) {}
+
+#[no_mangle]
+pub extern "C" fn document_free(document: *mut Document) {}
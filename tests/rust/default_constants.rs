@@ -0,0 +1,63 @@
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Counters {
+    hits: u32,
+    misses: u64,
+    ratio: f32,
+    enabled: bool,
+}
+
+#[repr(C)]
+pub struct Options {
+    retries: u32,
+    timeout_ms: u64,
+    verbose: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            retries: 3,
+            timeout_ms: 30 * 1000,
+            verbose: false,
+        }
+    }
+}
+
+/// cbindgen:default=Self { width: 640, height: 480 }
+#[repr(C)]
+pub struct Size {
+    width: u32,
+    height: u32,
+}
+
+#[repr(C)]
+pub struct Computed {
+    value: u32,
+}
+
+impl Default for Computed {
+    fn default() -> Self {
+        Computed { value: compute() }
+    }
+}
+
+fn compute() -> u32 {
+    1
+}
+
+#[repr(C)]
+#[derive(Default)]
+pub struct WithPointer {
+    data: Option<&'static u8>,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    counters: Counters,
+    options: Options,
+    size: Size,
+    computed: Computed,
+    with_pointer: WithPointer,
+) {
+}
@@ -0,0 +1,8 @@
+#[repr(C)]
+pub struct Point { x: i32, y: i32 }
+#[repr(C)]
+pub enum Shape { Circle(f32), Square(Point) }
+#[no_mangle]
+pub static COUNTER: u32 = 0;
+#[no_mangle]
+pub extern "C" fn area(s: Shape) -> f32 { 0.0 }
@@ -0,0 +1,126 @@
+use std::ops::{Add, BitAnd, BitOr, Mul, Neg, Not, Rem, Sub};
+
+/// cbindgen:add-function=vec2_add
+#[repr(C)]
+pub struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, other: Vec2) -> Vec2 {
+        vec2_add(self, other)
+    }
+}
+
+impl Sub<Vec2> for Vec2 {
+    type Output = Vec2;
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Vec2 {
+        Vec2 { x: -self.x, y: -self.y }
+    }
+}
+
+// Not `Self` on the right-hand side, so not an operator of `Vec2`.
+impl Mul<f32> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, factor: f32) -> Vec2 {
+        Vec2 { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+#[repr(C)]
+pub struct Permissions {
+    bits: u8,
+}
+
+impl BitOr for Permissions {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Permissions { bits: self.bits | other.bits }
+    }
+}
+
+impl BitAnd for Permissions {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        Permissions { bits: self.bits & other.bits }
+    }
+}
+
+impl Not for Permissions {
+    type Output = Self;
+    fn not(self) -> Self {
+        Permissions { bits: !self.bits }
+    }
+}
+
+/// cbindgen:derive-ops=[add, sub]
+#[repr(C)]
+pub struct Id {
+    value: u64,
+}
+
+/// cbindgen:derive-ops=false
+#[repr(C)]
+pub struct Opaque {
+    value: u32,
+}
+
+impl Add for Opaque {
+    type Output = Opaque;
+    fn add(self, other: Opaque) -> Opaque {
+        Opaque { value: self.value + other.value }
+    }
+}
+
+// C++ has no `%` for floats, so `Rem` isn't derived, and `vec3_sub` takes its
+// operands by pointer.
+/// cbindgen:sub-function=vec3_sub
+#[repr(C)]
+pub struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+    fn add(self, other: Vec3) -> Vec3 {
+        Vec3 { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+    fn sub(self, other: Vec3) -> Vec3 {
+        unsafe { vec3_sub(&self, &other) }
+    }
+}
+
+impl Rem for Vec3 {
+    type Output = Vec3;
+    fn rem(self, other: Vec3) -> Vec3 {
+        Vec3 { x: self.x % other.x, y: self.y % other.y, z: self.z % other.z }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vec3_sub(a: *const Vec3, b: *const Vec3) -> Vec3 {
+    Vec3 { x: (*a).x - (*b).x, y: (*a).y - (*b).y, z: (*a).z - (*b).z }
+}
+
+#[no_mangle]
+pub extern "C" fn vec2_add(a: Vec2, b: Vec2) -> Vec2 {
+    Vec2 { x: a.x + b.x, y: a.y + b.y }
+}
+
+#[no_mangle]
+pub extern "C" fn root(p: Permissions, i: Id, o: Opaque, v: Vec3) {}
@@ -0,0 +1,19 @@
+/// cbindgen:include=<stdint.h>
+#[repr(C)]
+pub struct Zebra {
+    stripes: u32,
+}
+
+#[repr(C)]
+pub struct Aardvark {
+    zebra: Zebra,
+}
+
+pub const MAX_STRIPES: u32 = 100;
+pub const LEGS: u32 = 4;
+
+#[no_mangle]
+pub extern "C" fn zoo_open(zebra: Zebra) {}
+
+#[no_mangle]
+pub extern "C" fn aardvark_new(aardvark: Aardvark) {}
@@ -0,0 +1,45 @@
+/// An opaque buffer.
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+/// A point in space.
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// Creates a buffer holding a copy of the given bytes.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to copy.
+/// * `len` - How many bytes there are.
+///
+/// # Returns
+///
+/// A new buffer, or null if allocation failed.
+///
+/// cbindgen:returns-ownership=buffer_free
+#[no_mangle]
+pub extern "C" fn buffer_new(bytes: *const u8, len: usize) -> Option<Box<Buffer>> {
+    None
+}
+
+/// Frees a buffer.
+///
+/// cbindgen:consumes=buffer
+#[no_mangle]
+pub extern "C" fn buffer_free(buffer: *mut Buffer) {}
+
+/// Returns the data of a buffer.
+#[no_mangle]
+pub extern "C" fn buffer_data(buffer: &Buffer) -> *const u8 {
+    std::ptr::null()
+}
+
+#[no_mangle]
+pub extern "C" fn point_distance(a: &Point, b: Option<&Point>) -> f32 {
+    0.0
+}
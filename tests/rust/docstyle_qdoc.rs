@@ -0,0 +1,39 @@
+/// A buffer of samples, see [`buffer_new`] and [Buffer::len](crate::Buffer).
+///
+/// Samples are stored in [`crate::audio::Sample`] order, and `samples[i]`
+/// is the i-th one. More on [the format](https://example.com/format).
+#[repr(C)]
+pub struct Buffer {
+    len: usize,
+}
+
+/// Creates a buffer.
+///
+/// # Arguments
+///
+/// * `len` - The number of samples, see [`Buffer`].
+/// * capacity - The number of samples to reserve room for.
+///
+/// # Returns
+///
+/// A new buffer, to be freed by [`buffer_free()`].
+///
+/// # Examples
+///
+/// ```c
+/// Buffer *buffer = buffer_new(16, 32);
+/// ```
+#[no_mangle]
+pub extern "C" fn buffer_new(len: usize, capacity: usize) -> *mut Buffer {}
+
+/// Frees a buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from [`buffer_new`].
+///
+/// # Panics
+///
+/// If `buffer` is null.
+#[no_mangle]
+pub unsafe extern "C" fn buffer_free(buffer: *mut Buffer) {}
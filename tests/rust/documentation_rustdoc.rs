@@ -0,0 +1,47 @@
+/// A buffer holding [`Item`]s, see [`buffer_push`] or [the
+/// guide](https://example.com/guide).
+///
+/// # Examples
+///
+/// ```
+/// # let mut buffer = Buffer::default();
+/// buffer.len = 0;
+/// ## Not hidden
+/// ```
+///
+/// ## More examples
+///
+/// Removed along with the examples.
+///
+/// # Safety
+///
+/// The `data` of a [`Buffer`] must be valid for `len` items.
+///
+/// ```c
+/// # include <stdlib.h>
+/// ```
+#[repr(C)]
+pub struct Buffer {
+    data: *mut Item,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct Item {
+    value: u32,
+}
+
+/// Pushes an [`Item`] to a [`Buffer`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use crate::Buffer;
+/// buffer_push(&mut buffer, item);
+/// ```
+///
+/// # Panics
+///
+/// If the buffer is full.
+#[no_mangle]
+pub extern "C" fn buffer_push(buffer: &mut Buffer, item: Item) {}
@@ -0,0 +1,25 @@
+/// A point.
+///
+/// cbindgen:group=geometry
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// cbindgen:section=Drawing
+#[no_mangle]
+pub extern "C" fn draw_point(point: Point) {}
+
+/// Draws a line.
+///
+/// cbindgen:section=Drawing
+#[no_mangle]
+pub extern "C" fn draw_line(from: Point, to: Point) {}
+
+/// cbindgen:section=Audio output
+#[no_mangle]
+pub extern "C" fn play_sound(id: u32) {}
+
+#[no_mangle]
+pub extern "C" fn ungrouped() {}
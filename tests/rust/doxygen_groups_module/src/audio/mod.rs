@@ -0,0 +1,6 @@
+#[no_mangle]
+pub extern "C" fn audio_play() {}
+
+/// cbindgen:group=media
+#[no_mangle]
+pub extern "C" fn video_play() {}
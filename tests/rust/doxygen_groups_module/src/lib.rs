@@ -0,0 +1,6 @@
+pub mod audio;
+pub mod net;
+
+/// Initializes the library.
+#[no_mangle]
+pub extern "C" fn library_init() {}
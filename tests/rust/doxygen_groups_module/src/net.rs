@@ -0,0 +1,13 @@
+pub mod socket;
+
+/// An IPv4 address.
+#[repr(C)]
+pub struct Address {
+    octets: [u8; 4],
+    port: u16,
+}
+
+#[no_mangle]
+pub extern "C" fn address_is_loopback(address: &Address) -> bool {
+    address.octets[0] == 127
+}
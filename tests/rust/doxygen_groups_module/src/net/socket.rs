@@ -0,0 +1,9 @@
+use crate::net::Address;
+
+pub const MAX_SOCKETS: u32 = 64;
+
+/// Opens a socket connected to `address`.
+#[no_mangle]
+pub extern "C" fn socket_open(address: &Address) -> i32 {
+    0
+}
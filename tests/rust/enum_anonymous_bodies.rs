@@ -0,0 +1,32 @@
+#[repr(C)]
+pub enum Shape {
+    Point,
+    Circle { radius: f32 },
+    Rect(f32, f32),
+    Scaled(f32),
+}
+
+#[repr(u8)]
+pub enum Event {
+    Quit,
+    Key { code: u32, pressed: bool },
+    Move(i32, i32),
+}
+
+/// cbindgen:derive-ostream
+#[repr(C, u8)]
+pub enum Value {
+    Nothing,
+    Pair { first: i32, second: i32 },
+    Triple(i32, i32, i32),
+}
+
+/// cbindgen:anonymous-variant-bodies=false
+#[repr(C)]
+pub enum Named {
+    None,
+    Some { value: i32 },
+}
+
+#[no_mangle]
+pub extern "C" fn root(shape: Shape, event: Event, value: Value, named: Named) {}
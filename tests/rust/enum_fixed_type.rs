@@ -0,0 +1,39 @@
+/// Some flags.
+#[repr(u32)]
+pub enum Flags {
+    /// The first flag.
+    Low = 1,
+    High = 0x4000_0000,
+    Next,
+}
+
+#[repr(i8)]
+pub enum Direction {
+    Back = -1,
+    Still,
+    Forward,
+}
+
+pub const LEVEL_BASE: u8 = 4;
+
+#[repr(u8)]
+pub enum Level {
+    Base = LEVEL_BASE,
+    Up,
+}
+
+/// cbindgen:fixed-type=anonymous-enum
+#[repr(u16)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle(f32),
+    Square { side: f32 },
+}
+
+#[no_mangle]
+pub extern "C" fn root(flags: Flags, direction: Direction, level: Level, mode: Mode, shape: Shape) {}
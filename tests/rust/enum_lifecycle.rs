@@ -0,0 +1,43 @@
+/// cbindgen:destructor=shape_destroy
+/// cbindgen:clone=shape_clone
+#[repr(C, u8)]
+pub enum Shape {
+    Circle { radius: f32 },
+    Polygon { points: *mut f32, len: usize },
+    Empty,
+}
+
+/// cbindgen:destructor=message_drop
+#[repr(u8)]
+pub enum Message {
+    Text(*mut u8),
+    Quit,
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shape_destroy(ptr: *mut Shape) {
+    std::ptr::drop_in_place(ptr)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn shape_clone(src: *const Shape, dst: *mut Shape) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn message_drop(ptr: *mut Message) {}
+
+#[no_mangle]
+pub extern "C" fn root(shape: *const Shape, message: *mut Message) {}
+
+/// cbindgen:destructor=token_free
+/// cbindgen:clone=token_clone
+#[repr(C, u8)]
+pub enum Token {
+    Word(*mut u8),
+    End,
+}
+
+#[no_mangle]
+pub extern "C" fn token_clone(src: &Token) -> Token {}
+
+#[no_mangle]
+pub extern "C" fn tokenize(token: *mut Token) {}
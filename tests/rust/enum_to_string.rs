@@ -0,0 +1,24 @@
+#[repr(C)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+    #[cfg(feature = "alpha")]
+    Transparent,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low = 1,
+    High = 10,
+}
+
+/// cbindgen:derive-to-string=false
+#[repr(C)]
+pub enum Hidden {
+    A,
+    B,
+}
+
+#[no_mangle]
+pub extern "C" fn root(c: Color, l: Level, h: Hidden) {}
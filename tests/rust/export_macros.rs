@@ -0,0 +1,12 @@
+pub const DEFAULT_FLAGS: u32 = 3;
+
+#[repr(C)]
+pub struct Widget {
+    flags: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn widget_init(widget: *mut Widget, flags: u32) {}
+
+#[no_mangle]
+pub static WIDGET_COUNT: u32 = 0;
@@ -0,0 +1,40 @@
+#[repr(C)]
+pub struct Public {
+    x: i32,
+}
+
+#[repr(C)]
+pub(crate) struct Crate {
+    x: i32,
+}
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct Hidden {
+    x: i32,
+}
+
+#[doc(hidden)]
+pub mod internal {
+    #[no_mangle]
+    pub extern "C" fn internal_plumbing() {}
+}
+
+#[no_mangle]
+pub extern "C" fn public_function(p: Public) {}
+
+#[no_mangle]
+pub(crate) extern "C" fn crate_function(c: Crate) {}
+
+#[no_mangle]
+extern "C" fn private_function() {}
+
+#[doc(hidden)]
+#[no_mangle]
+pub extern "C" fn hidden_function(h: Hidden) {}
+
+extern "C" {
+    pub fn public_import();
+
+    fn private_import();
+}
@@ -0,0 +1,23 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub static mut ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[no_mangle]
+pub static COUNT: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn point_new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[no_mangle]
+pub extern "C" fn point_distance(a: *const Point, b: *const Point, out: *mut f64) {}
+
+extern "C" {
+    pub fn point_log(point: *const Point);
+}
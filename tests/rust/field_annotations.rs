@@ -0,0 +1,37 @@
+pub struct Internals {
+    state: Vec<u8>,
+}
+
+#[repr(C)]
+pub struct Handle {
+    /// cbindgen:rename=mId
+    id: u32,
+    /// The engine state.
+    /// cbindgen:hide=[24, 8]
+    internals: Internals,
+    /// cbindgen:hide=8
+    counts: [u16; 4],
+    /// cbindgen:hide
+    engine: *mut Internals,
+    /// cbindgen:doc=Must be released with `handle_free`.
+    data: *mut u8,
+}
+
+#[repr(C)]
+pub union Value {
+    /// cbindgen:rename=asInt
+    int: i32,
+    /// Single precision.
+    /// cbindgen:doc=Only valid after `value_set_float`.
+    real: f32,
+}
+
+#[repr(C)]
+pub struct Pair(
+    /// cbindgen:rename=first
+    i32,
+    i32,
+);
+
+#[no_mangle]
+pub extern "C" fn root(handle: Handle, value: Value, pair: Pair) {}
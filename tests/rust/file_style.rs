@@ -0,0 +1,11 @@
+/// A point.
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn origin() -> Point {
+    Point { x: 0.0, y: 0.0 }
+}
@@ -0,0 +1,35 @@
+pub type Callback = Option<
+    unsafe extern "C" fn(
+        first_argument: u32,
+        second_argument: *const u8,
+        third_argument_name: usize,
+    ) -> i32,
+>;
+
+#[repr(C)]
+pub struct Callbacks {
+    pub on_something_happened: Option<
+        unsafe extern "C" fn(
+            first_argument: u32,
+            second_argument: *const u8,
+            third_argument_name: usize,
+        ) -> i32,
+    >,
+}
+
+#[no_mangle]
+pub extern "C" fn register_callbacks(
+    callbacks: *const Callbacks,
+    fallback: Option<
+        unsafe extern "C" fn(
+            first_argument: u32,
+            second_argument: *const u8,
+            third_argument_name: usize,
+        ) -> i32,
+    >,
+    user_data: *mut u8,
+) {
+}
+
+#[no_mangle]
+pub extern "C" fn set_callback(callback: Callback) {}
@@ -0,0 +1,14 @@
+#[repr(C)]
+pub struct Packet {
+    id: u32,
+    len: usize,
+    valid: bool,
+    data: *const u8,
+}
+
+pub const MAX_LEN: usize = 256;
+
+#[no_mangle]
+pub extern "C" fn packet_send(packet: &Packet, timeout_ms: i64) -> bool {
+    true
+}
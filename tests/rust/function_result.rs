@@ -0,0 +1,37 @@
+#[repr(C)]
+pub struct Widget {
+    id: u32,
+    width: f32,
+}
+
+#[repr(u8)]
+pub enum WidgetError {
+    Success = 0,
+    NotFound,
+    InvalidSize,
+}
+
+/// cbindgen:result
+/// Opens the widget `id`.
+#[no_mangle]
+pub extern "C" fn widget_open(id: u32) -> Result<Widget, WidgetError> {
+    todo!()
+}
+
+/// cbindgen:result=out-param
+#[no_mangle]
+pub extern "C" fn widget_close(widget: *mut Widget) -> Result<(), WidgetError> {
+    todo!()
+}
+
+/// cbindgen:result=tagged
+#[no_mangle]
+pub extern "C" fn widget_resize(widget: *mut Widget, width: f32) -> Result<Widget, WidgetError> {
+    todo!()
+}
+
+/// cbindgen:result=tagged
+#[no_mangle]
+pub extern "C" fn widget_show(widget: *const Widget) -> Result<(), WidgetError> {
+    todo!()
+}
@@ -0,0 +1,14 @@
+use std::os::raw::c_char;
+
+pub struct Parser;
+
+#[no_mangle]
+pub extern "C" fn parser_feed(parser: *mut Parser, data: *const c_char, len: usize) -> bool {
+    false
+}
+
+#[no_mangle]
+pub extern "C" fn decode_in_place(buf: *mut u8, len: u32, flags: u32) {}
+
+#[no_mangle]
+pub extern "C" fn parser_reset(parser: *mut Parser) {}
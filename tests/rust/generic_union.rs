@@ -0,0 +1,40 @@
+use std::marker::PhantomData;
+
+#[repr(C)]
+pub union Either<A, B> {
+    a: A,
+    b: B,
+}
+
+#[repr(C)]
+pub union Buffer<T, const N: usize> {
+    items: [T; N],
+    raw: *const T,
+}
+
+#[repr(C)]
+pub union Borrowed<'a, T: Copy> {
+    value: T,
+    marker: PhantomData<&'a T>,
+}
+
+#[repr(C)]
+pub struct Holder<T> {
+    value: Either<T, f32>,
+    tag: u8,
+}
+
+#[repr(C)]
+pub enum Message {
+    Number(Either<i32, u32>),
+    Bytes { data: Buffer<u8, 4> },
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Holder<u64>,
+    b: Message,
+    c: Borrowed<'static, u16>,
+    d: *const Either<*const u8, Holder<i16>>,
+) {
+}
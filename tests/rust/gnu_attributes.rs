@@ -0,0 +1,29 @@
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+/// cbindgen:returns-ownership=buffer_free
+/// cbindgen:alloc-size=[capacity]
+#[no_mangle]
+pub extern "C" fn buffer_new(capacity: usize) -> *mut Buffer {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_append(buffer: &mut Buffer, data: *const u8, len: usize) {}
+
+/// cbindgen:sal-out=[out]
+#[must_use]
+#[no_mangle]
+pub extern "C" fn buffer_copy(buffer: &Buffer, out: *mut u8, out_len: usize) -> usize {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_set_user_data(buffer: &mut Buffer, user_data: *mut std::ffi::c_void) {}
+
+/// cbindgen:gnu-attributes=false
+#[no_mangle]
+pub extern "C" fn buffer_free(buffer: &mut Buffer) {}
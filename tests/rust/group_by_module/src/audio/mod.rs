@@ -0,0 +1,19 @@
+pub struct Mixer {
+    channels: Vec<f32>,
+}
+
+#[repr(C)]
+pub struct Stream {
+    mixer: *mut Mixer,
+    sample_rate: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn audio_play(stream: *mut Stream) {}
+
+/// cbindgen:section=Volume
+#[no_mangle]
+pub extern "C" fn audio_set_volume(stream: *mut Stream, volume: f32) {}
+
+#[no_mangle]
+pub extern "C" fn audio_stop(stream: *mut Stream) {}
@@ -0,0 +1,16 @@
+pub mod audio;
+pub mod net;
+
+#[repr(C)]
+pub struct Version {
+    major: u16,
+    minor: u16,
+}
+
+#[no_mangle]
+pub extern "C" fn library_version() -> Version {
+    Version { major: 1, minor: 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn library_init() {}
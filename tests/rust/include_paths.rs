@@ -0,0 +1,25 @@
+mod ffi {
+    #[repr(C)]
+    pub struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[no_mangle]
+    pub extern "C" fn ffi_origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+
+    pub mod nested {
+        #[no_mangle]
+        pub extern "C" fn ffi_nested(point: super::Point) {}
+    }
+}
+
+mod internal {
+    #[no_mangle]
+    pub extern "C" fn internal_helper() {}
+}
+
+#[no_mangle]
+pub extern "C" fn root_helper() {}
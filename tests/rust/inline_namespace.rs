@@ -0,0 +1,10 @@
+pub const VERSION: u32 = 1;
+
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+    level: u8,
+}
+
+#[no_mangle]
+pub extern "C" fn configure(options: *const Options) {}
@@ -0,0 +1,36 @@
+#[repr(C)]
+pub struct Handle {
+    id: u32,
+}
+
+/// Only used by the internal functions.
+/// cbindgen:internal
+#[repr(C)]
+pub struct Stats {
+    allocations: u64,
+}
+
+/// Used by a public function, so it can't be internal.
+/// cbindgen:internal
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+}
+
+/// cbindgen:internal
+pub const TEST_SEED: u32 = 42;
+
+#[no_mangle]
+pub extern "C" fn handle_open(options: *const Options) -> Handle {
+    loop {}
+}
+
+/// cbindgen:internal
+#[no_mangle]
+pub extern "C" fn handle_stats(handle: Handle) -> Stats {
+    loop {}
+}
+
+/// cbindgen:internal
+#[no_mangle]
+pub static mut INTERNAL_COUNTER: u32 = 0;
@@ -0,0 +1,22 @@
+/// cbindgen:include=<stdio.h>
+#[repr(C)]
+pub struct Logger {
+    level: i32,
+}
+
+/// Not referenced by any function, so its include isn't needed either.
+/// cbindgen:include=<time.h>
+#[repr(C)]
+pub struct Unused {
+    t: i64,
+}
+
+/// cbindgen:include=[<stdio.h>, "testing-helpers.h"]
+#[no_mangle]
+pub extern "C" fn root(logger: Logger) {}
+
+/// Only included along with the function that needs it.
+/// cbindgen:include=[<pthread.h>, "threads-helpers.h"]
+#[cfg(feature = "threads")]
+#[no_mangle]
+pub extern "C" fn lock(logger: Logger) {}
@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+
+#[repr(C)]
+pub struct Mixed {
+    pub a: u8,
+    pub b: u64,
+    pub c: u16,
+}
+
+#[repr(C, packed)]
+pub struct Packed {
+    pub a: u8,
+    pub b: u32,
+}
+
+#[repr(C, align(16))]
+pub struct Aligned {
+    pub a: u8,
+}
+
+#[repr(C)]
+pub union Either {
+    pub a: u8,
+    pub b: u32,
+}
+
+#[repr(u8)]
+pub enum Tagged {
+    Value(u32),
+    Empty,
+}
+
+#[repr(C)]
+pub enum Simple {
+    First,
+    Second,
+}
+
+/// cbindgen doesn't know about the `PhantomData` field, so only the size and
+/// alignment can be checked.
+#[repr(C)]
+pub struct Marked {
+    pub a: u32,
+    pub marker: PhantomData<u8>,
+}
+
+#[repr(transparent)]
+pub struct Id(pub u32);
+
+/// Declared with the size and alignment of a `Vec<u32>` and a `u16`.
+pub struct Handle {
+    items: Vec<u32>,
+    id: u16,
+}
+
+#[no_mangle]
+pub extern "C" fn process(
+    _mixed: Mixed,
+    _packed: Packed,
+    _aligned: Aligned,
+    _either: Either,
+    _tagged: Tagged,
+    _simple: Simple,
+    _marked: Marked,
+    _id: Id,
+) {
+}
+
+#[cfg(target_pointer_width = "64")]
+#[repr(C)]
+pub struct Wide {
+    pub a: u64,
+}
+
+#[cfg(target_pointer_width = "64")]
+#[no_mangle]
+pub extern "C" fn wide(_wide: Wide) {}
+
+#[no_mangle]
+pub extern "C" fn handle_new() -> Box<Handle> {
+    Box::new(Handle {
+        items: Vec::new(),
+        id: 0,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn handle_free(_handle: Box<Handle>) {}
@@ -0,0 +1,7 @@
+#[repr(C)]
+pub struct Foo {
+    bar: u64,
+}
+
+#[no_mangle]
+pub extern "C" fn doit(_: &Foo) {}
@@ -0,0 +1,48 @@
+pub type VeryLongCallbackName = Option<
+    unsafe extern "C" fn(
+        first_argument: u32,
+        second_argument: *const u8,
+        third_argument_name: usize,
+    ) -> i32,
+>;
+
+#[repr(C)]
+pub struct Callbacks {
+    pub on_something_happened: Option<
+        unsafe extern "C" fn(
+            first_argument: u32,
+            second_argument: *const u8,
+            third_argument_name: usize,
+        ) -> i32,
+    >,
+}
+
+#[no_mangle]
+pub extern "C" fn register_callbacks(
+    callbacks: *const Callbacks,
+    fallback: Option<
+        unsafe extern "C" fn(
+            first_argument: u32,
+            second_argument: *const u8,
+            third_argument_name: usize,
+        ) -> i32,
+    >,
+    user_data: *mut u8,
+) {
+}
+
+#[no_mangle]
+pub static mut GLOBAL_HANDLER_WITH_LONG_NAME: Option<
+    unsafe extern "C" fn(
+        first_argument: u32,
+        second_argument: *const u8,
+        third_argument_name: usize,
+    ) -> i32,
+> = None;
+
+#[no_mangle]
+pub extern "C" fn an_extremely_long_function_name_for_registering_the_callbacks_of_a_context(
+    context_with_long_name: *mut u8,
+    fallback: VeryLongCallbackName,
+) {
+}
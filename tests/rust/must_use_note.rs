@@ -0,0 +1,49 @@
+#[repr(C)]
+#[must_use = "the handle must be closed"]
+pub struct Handle {
+    fd: i32,
+}
+
+#[repr(i32)]
+#[must_use = "errors must be handled"]
+pub enum Status {
+    Ok = 0,
+    Failed = 1,
+}
+
+#[no_mangle]
+#[must_use]
+pub extern "C" fn must_use_without_note() -> i32 {
+    0
+}
+
+#[no_mangle]
+#[must_use = "check the error code"]
+pub extern "C" fn must_use_with_note() -> Status {
+    Status::Ok
+}
+
+#[no_mangle]
+#[must_use = "This quote \" requires to be quoted"]
+pub extern "C" fn must_use_with_note_which_requires_to_be_escaped() -> i32 {
+    0
+}
+
+/// cbindgen:must-use=the handle leaks otherwise
+#[no_mangle]
+pub extern "C" fn open_handle() -> Handle {
+    Handle { fd: 0 }
+}
+
+/// cbindgen:must-use
+#[no_mangle]
+pub extern "C" fn forced_must_use() -> i32 {
+    0
+}
+
+/// cbindgen:must-use=false
+#[no_mangle]
+#[must_use]
+pub extern "C" fn suppressed_must_use() -> i32 {
+    0
+}
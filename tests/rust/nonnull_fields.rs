@@ -0,0 +1,31 @@
+use core::ptr;
+use std::ptr::NonNull;
+
+pub struct Opaque;
+
+pub type OpaquePtr = NonNull<Opaque>;
+
+#[repr(transparent)]
+pub struct OpaqueHandle(NonNull<Opaque>);
+
+#[repr(C)]
+pub struct Node {
+    value: i32,
+    next: Option<NonNull<Opaque>>,
+}
+
+#[repr(C)]
+pub struct Handles {
+    a: ptr::NonNull<Opaque>,
+    b: core::ptr::NonNull<Opaque>,
+    c: OpaquePtr,
+    d: OpaqueHandle,
+    e: [NonNull<Opaque>; 2],
+    f: NonNull<[u8; 4]>,
+    g: NonNull<Node>,
+    h: Option<NonNull<u8>>,
+    i: NonNull<extern "C" fn(i32) -> i32>,
+}
+
+#[no_mangle]
+pub extern "C" fn root(handles: Handles, handle: OpaqueHandle, node: NonNull<Node>) {}
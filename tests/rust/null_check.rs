@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Foo {
+    x: i32,
+}
+
+/// cbindgen:null-check
+#[no_mangle]
+pub extern "C" fn foo_get(foo: *const Foo, index: usize) -> i32 {
+    0
+}
+
+/// cbindgen:null-check=-1
+#[no_mangle]
+pub unsafe extern "C" fn foo_copy(dst: *mut Foo, src: &Foo) -> i32 {
+    0
+}
+
+/// cbindgen:null-check=0
+#[no_mangle]
+pub extern "C" fn foo_reset(foo: Option<&mut Foo>) {}
+
+#[cfg(feature = "extra")]
+/// cbindgen:null-check
+#[no_mangle]
+pub extern "C" fn foo_free(foo: *mut Foo) {}
+
+#[no_mangle]
+pub extern "C" fn foo_new() -> *mut Foo {
+    std::ptr::null_mut()
+}
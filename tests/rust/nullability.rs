@@ -0,0 +1,14 @@
+#[repr(C)]
+pub struct Opaque {
+    x: i32,
+}
+
+/// cbindgen:nonnull=1,3
+#[no_mangle]
+pub extern "C" fn copy_into(dst: *mut Opaque, hint: *const Opaque, src: *const Opaque) {}
+
+/// cbindgen:nonnull=[name]
+#[no_mangle]
+pub extern "C" fn lookup(table: &Opaque, name: *const u8, fallback: Option<&Opaque>) -> *const Opaque {
+    table
+}
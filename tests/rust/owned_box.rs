@@ -0,0 +1,40 @@
+#[repr(C)]
+pub struct Tree {
+    root: Option<Box<Leaf>>,
+    size: usize,
+}
+
+#[repr(C)]
+pub struct Leaf {
+    value: i32,
+}
+
+pub struct Opaque;
+
+#[repr(u32)]
+pub enum Kind {
+    A,
+    B,
+}
+
+pub type Id = u64;
+
+#[no_mangle]
+pub extern "C" fn tree_new() -> Box<Tree> {}
+
+#[no_mangle]
+pub extern "C" fn tree_free(tree: Option<Box<Tree>>) {}
+
+#[no_mangle]
+pub extern "C" fn opaque_new() -> Box<Opaque> {}
+
+#[no_mangle]
+pub extern "C" fn boxes(kind: Box<Kind>, id: Box<Id>, value: Box<i32>) {}
+
+/// cbindgen:owned=comment
+#[no_mangle]
+pub extern "C" fn leaf_new() -> Box<Leaf> {}
+
+/// cbindgen:owned=pointer
+#[no_mangle]
+pub extern "C" fn leaf_free(leaf: Box<Leaf>) {}
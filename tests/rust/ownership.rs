@@ -0,0 +1,25 @@
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+/// Creates a new buffer.
+/// cbindgen:returns-ownership=buffer_free
+#[no_mangle]
+pub extern "C" fn buffer_new() -> *mut Buffer {
+    Box::into_raw(Box::new(Buffer { data: Vec::new() }))
+}
+
+/// cbindgen:returns-ownership
+#[no_mangle]
+pub extern "C" fn buffer_clone(buffer: *const Buffer) -> *mut Buffer {
+    std::ptr::null_mut()
+}
+
+/// cbindgen:consumes=1
+#[no_mangle]
+pub extern "C" fn buffer_free(buffer: *mut Buffer) {}
+
+/// Appends `other` to `buffer`.
+/// cbindgen:consumes=[other]
+#[no_mangle]
+pub extern "C" fn buffer_append(buffer: *mut Buffer, other: *mut Buffer) {}
@@ -0,0 +1,2 @@
+// Not included, so never parsed.
+this isn't valid Rust
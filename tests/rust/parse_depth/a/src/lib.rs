@@ -0,0 +1,8 @@
+pub mod sys;
+pub mod heavy;
+
+/// Not loaded, as only the `sys` modules are.
+#[repr(C)]
+pub struct Handle {
+    pub id: u64,
+}
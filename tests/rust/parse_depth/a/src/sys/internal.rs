@@ -0,0 +1,2 @@
+// Excluded, so never parsed.
+this isn't valid Rust
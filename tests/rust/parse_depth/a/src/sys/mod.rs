@@ -0,0 +1,17 @@
+mod internal;
+pub mod mode;
+
+pub use self::mode::Mode;
+
+#[repr(C)]
+pub struct Handle {
+    pub fd: i32,
+    pub mode: Mode,
+}
+
+pub fn open(mode: Mode) -> Handle {
+    Handle {
+        fd: internal::open(),
+        mode,
+    }
+}
@@ -0,0 +1,5 @@
+#[repr(C)]
+pub enum Mode {
+    Read,
+    Write,
+}
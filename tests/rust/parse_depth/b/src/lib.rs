@@ -0,0 +1,2 @@
+// Deeper than `max_depth`, so never parsed.
+this isn't valid Rust
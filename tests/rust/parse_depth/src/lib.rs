@@ -0,0 +1,6 @@
+use parse_depth_a::sys::{Handle, Mode};
+
+#[no_mangle]
+pub extern "C" fn open(mode: Mode) -> Handle {
+    parse_depth_a::sys::open(mode)
+}
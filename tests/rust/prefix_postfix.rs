@@ -0,0 +1,32 @@
+/// cbindgen:prefix=#define FOO_IS_DEFINED 1
+/// cbindgen:postfix=#define FOO_SIZE (sizeof(int32_t) * 2)
+/// cbindgen:postfix=#define FOO_ALIGN sizeof(int32_t)
+#[repr(C)]
+pub struct Foo {
+    x: i32,
+    y: i32,
+}
+
+/// cbindgen:prefix=#if 1
+/// cbindgen:postfix=#endif
+#[repr(C)]
+pub enum Bar {
+    A,
+    B,
+}
+
+/// cbindgen:prefix=#define BEFORE_CONSTANT 1
+pub const CONSTANT: u32 = 4;
+
+/// cbindgen:postfix=#define AFTER_GLOBAL 1
+#[no_mangle]
+pub static GLOBAL: u32 = 5;
+
+#[no_mangle]
+pub extern "C" fn root(foo: Foo, bar: Bar) {}
+
+/// cbindgen:prefix=#if defined(HAVE_USE_IT)
+/// cbindgen:prefix=// before
+/// cbindgen:postfix=#endif
+#[no_mangle]
+pub extern "C" fn use_it(foo: Foo) {}
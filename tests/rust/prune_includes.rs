@@ -0,0 +1,13 @@
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+    level: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn configure(options: *const Options) -> bool {
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn reset() {}
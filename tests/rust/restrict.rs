@@ -0,0 +1,7 @@
+/// cbindgen:restrict=1,2
+#[no_mangle]
+pub extern "C" fn copy(dst: *mut u8, src: *const u8, len: usize) {}
+
+/// cbindgen:restrict=[out, input]
+#[no_mangle]
+pub extern "C" fn transform(input: &[f32; 4], out: &mut [f32; 4]) {}
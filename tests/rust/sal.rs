@@ -0,0 +1,33 @@
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_new(capacity: usize) -> *mut Buffer {
+    todo!()
+}
+
+/// cbindgen:nonnull=buffer
+#[no_mangle]
+pub extern "C" fn buffer_append(buffer: *mut Buffer, data: *const u8, len: usize) {}
+
+/// cbindgen:sal-out=[out]
+#[no_mangle]
+pub extern "C" fn buffer_copy(buffer: &Buffer, out: *mut u8, out_len: usize) -> usize {
+    0
+}
+
+/// cbindgen:sal-out=[count]
+#[no_mangle]
+pub extern "C" fn buffer_len(buffer: Option<&Buffer>, count: &mut usize) {}
+
+#[no_mangle]
+pub extern "C" fn buffer_get(buffer: &mut Buffer) -> &mut u8 {
+    todo!()
+}
+
+/// cbindgen:sal=false
+#[no_mangle]
+pub extern "C" fn buffer_free(buffer: *mut Buffer) {}
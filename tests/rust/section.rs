@@ -0,0 +1,17 @@
+/// cbindgen:section=Audio API
+#[no_mangle]
+pub extern "C" fn audio_play() {}
+
+#[no_mangle]
+pub extern "C" fn init() {}
+
+/// cbindgen:section=Video API
+#[no_mangle]
+pub extern "C" fn video_play() {}
+
+/// cbindgen:section=Audio API
+#[no_mangle]
+pub extern "C" fn audio_stop() {}
+
+#[no_mangle]
+pub extern "C" fn shutdown() {}
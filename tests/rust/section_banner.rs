@@ -0,0 +1,10 @@
+/// cbindgen:section=Audio API
+#[no_mangle]
+pub extern "C" fn audio_play() {}
+
+/// cbindgen:section=Video API
+#[no_mangle]
+pub extern "C" fn video_play() {}
+
+#[no_mangle]
+pub extern "C" fn init() {}
@@ -0,0 +1,2 @@
+// A dev-dependency, so never parsed.
+this isn't valid Rust
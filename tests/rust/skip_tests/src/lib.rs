@@ -0,0 +1,25 @@
+#[path = "../tests/common.rs"]
+mod common;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn point_new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[cfg(all(test, unix))]
+#[no_mangle]
+pub extern "C" fn point_check(point: Point) -> bool {
+    point.x == point.y
+}
+
+#[cfg(test)]
+mod tests {
+    #[no_mangle]
+    pub extern "C" fn point_test() {}
+}
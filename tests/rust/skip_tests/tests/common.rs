@@ -0,0 +1,2 @@
+// In the `tests` directory, so never parsed.
+this isn't valid Rust
@@ -0,0 +1,24 @@
+pub const LIMIT: u32 = 16;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// A shape.
+#[repr(u8)]
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+pub type Points = [Point; 4];
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn area(shape: Shape, points: &Points) -> f32 {
+    0.0
+}
@@ -0,0 +1,23 @@
+pub const COUNT: usize = 4;
+
+#[no_mangle]
+pub static TABLE: [u32; 256] = [0; 256];
+
+#[no_mangle]
+pub static mut NAMES: [u8; COUNT] = [0; COUNT];
+
+#[no_mangle]
+pub static BYTES: &[u8; 3] = &[1, 2, 3];
+
+/// cbindgen:len=false
+#[no_mangle]
+pub static UNLISTED: [u16; 8] = [0; 8];
+
+static DATA: [u64; 5] = [1, 2, 3, 4, 5];
+
+/// cbindgen:len=5
+#[no_mangle]
+pub static FIRST: &u64 = &DATA[0];
+
+#[no_mangle]
+pub static SCALAR: u32 = 0;
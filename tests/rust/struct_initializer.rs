@@ -0,0 +1,32 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+pub struct Rect {
+    origin: Point,
+    size: Point,
+    callback: Option<extern "C" fn(i32) -> bool>,
+}
+
+/// cbindgen:initializer=macro
+#[repr(C)]
+pub struct Buffer {
+    len: usize,
+    len_: usize,
+    data: [u8; 4],
+}
+
+/// cbindgen:initializer=none
+#[repr(C)]
+pub struct Skipped {
+    value: u32,
+}
+
+#[repr(C)]
+pub struct Empty {}
+
+#[no_mangle]
+pub extern "C" fn root(rect: Rect, buffer: Buffer, skipped: Skipped, empty: Empty) {}
@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+#[repr(C)]
+pub struct Buffer {
+    len: u32,
+    data: [u8; 4],
+}
+
+/// The array isn't last, so there is no macro.
+#[repr(C)]
+pub struct Samples {
+    values: [i16; 2],
+    count: u8,
+}
+
+#[no_mangle]
+pub static ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[no_mangle]
+pub extern "C" fn root(line: Line, buffer: Buffer, samples: Samples) {}
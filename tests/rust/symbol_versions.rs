@@ -0,0 +1,14 @@
+#[no_mangle]
+pub extern "C" fn open_file(path: *const u8) -> i32 {}
+
+/// cbindgen:version=2
+#[no_mangle]
+pub extern "C" fn open_file_v2(path: *const u8, flags: u32) -> i32 {}
+
+/// cbindgen:version=10
+#[no_mangle]
+pub extern "C" fn open_file_v10(path: *const u8, flags: u32, mode: u32) -> i32 {}
+
+/// cbindgen:version=2
+#[no_mangle]
+pub extern "C" fn close_file_v2(fd: i32) {}
@@ -0,0 +1,24 @@
+/// cbindgen:derive-transparent-conversions
+#[repr(transparent)]
+pub struct Meters(f64);
+
+/// cbindgen:derive-transparent-conversions
+#[repr(transparent)]
+pub struct Handle {
+    raw: *mut u8,
+}
+
+/// cbindgen:derive-transparent-conversions
+/// cbindgen:derive-eq
+#[repr(transparent)]
+pub struct Id(u32);
+
+impl Id {
+    pub const INVALID: Id = Id(0);
+}
+
+#[repr(transparent)]
+pub struct Plain(u32);
+
+#[no_mangle]
+pub extern "C" fn root(a: Meters, b: Handle, c: Id, d: Plain) {}
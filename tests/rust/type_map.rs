@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Window {
+    id: u32,
+    size: usize,
+    visible: bool,
+    title: *const u16,
+}
+
+pub const MAX_WINDOWS: u32 = 16;
+
+pub const DEFAULT_ID: u32 = 1 as u32;
+
+#[no_mangle]
+pub extern "C" fn window_show(window: *mut Window, flags: u32, count: usize) -> bool {
+    true
+}
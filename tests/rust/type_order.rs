@@ -0,0 +1,34 @@
+#[repr(C)]
+pub struct List {
+    head: *mut Node,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct Node {
+    value: Value,
+    data: *const Data,
+}
+
+#[repr(C)]
+pub struct Value {
+    kind: Kind,
+    data: Data,
+}
+
+#[repr(C)]
+pub union Data {
+    integer: i32,
+    real: f32,
+}
+
+#[repr(u8)]
+pub enum Kind {
+    Integer,
+    Real,
+}
+
+pub type Callback = extern "C" fn(list: *mut List, data: *const Data);
+
+#[no_mangle]
+pub extern "C" fn root(list: List, node: *mut Node, callback: Callback) {}
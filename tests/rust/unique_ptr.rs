@@ -0,0 +1,32 @@
+/// cbindgen:destructor=parser_free
+pub struct Parser {
+    input: Vec<u8>,
+}
+
+/// cbindgen:destructor=document_destroy
+#[cfg(feature = "documents")]
+pub struct Document {
+    root: Box<u32>,
+}
+
+/// No destructor, so no `std::unique_ptr`.
+pub struct Token {
+    len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn parser_new() -> *mut Parser {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn parser_next(parser: *mut Parser) -> *const Token {
+    todo!()
+}
+
+#[no_mangle]
+pub extern "C" fn parser_free(parser: *mut Parser) {}
+
+#[cfg(feature = "documents")]
+#[no_mangle]
+pub extern "C" fn document_destroy(document: *mut Document) {}
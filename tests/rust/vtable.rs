@@ -0,0 +1,18 @@
+/// A shape that can be drawn by the host.
+/// cbindgen:vtable
+pub trait Shape {
+    /// Returns the area of the shape.
+    fn area(&self) -> f64;
+    fn scale(&mut self, factor: f64);
+    fn name(&self) -> *const u8;
+    fn destroy(&mut self);
+}
+
+/// cbindgen:vtable
+pub trait Listener {
+    fn notify(&self, shape: DynShape, code: i32) -> bool;
+}
+
+pub trait NotExported {
+    fn ignored(&self);
+}
@@ -0,0 +1,16 @@
+/// Called when the plugin is loaded, does nothing by default.
+/// cbindgen:weak
+#[no_mangle]
+pub extern "C" fn plugin_on_load(version: u32) {}
+
+/// cbindgen:alias=plugin_version
+#[no_mangle]
+pub extern "C" fn plugin_get_version() -> u32 {
+    1
+}
+
+/// cbindgen:weak
+/// cbindgen:alias=[plugin_free, plugin_release]
+#[cfg(feature = "alloc")]
+#[no_mangle]
+pub extern "C" fn plugin_destroy(data: *mut u8) {}
@@ -0,0 +1,6 @@
+/// cbindgen:weak
+#[no_mangle]
+pub extern "C" fn plugin_on_unload() {}
+
+#[no_mangle]
+pub extern "C" fn plugin_init() {}
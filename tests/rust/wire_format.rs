@@ -0,0 +1,50 @@
+#[repr(u8)]
+pub enum Kind {
+    Ping,
+    Pong,
+}
+
+#[repr(u16)]
+pub enum Flags {
+    None,
+    Urgent,
+}
+
+pub type Length = u32;
+
+#[repr(C)]
+pub struct Header {
+    kind: Kind,
+    flags: Flags,
+    length: Length,
+}
+
+#[repr(C)]
+pub struct Packet {
+    header: Header,
+    ids: [u16; 4],
+    matrix: [[i32; 2]; 2],
+    hops: [Header; 2],
+    ttl: u8,
+    payload: [u8; 16],
+}
+
+#[repr(C)]
+pub struct Bytes {
+    data: [u8; 4],
+    valid: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn send(packet: *const Packet, bytes: Bytes) {}
+
+#[cfg(feature = "net")]
+#[repr(C)]
+pub struct Route {
+    hops: [u16; 4],
+    metric: u32,
+}
+
+#[cfg(feature = "net")]
+#[no_mangle]
+pub extern "C" fn route(route: *const Route) {}
@@ -0,0 +1,122 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use cbindgen::{Builder, Config, Language};
+
+fn rust_externs(language: Language) -> String {
+    rust_externs_with(language, None)
+}
+
+fn rust_externs_with(language: Language, edition: Option<&str>) -> String {
+    let mut config = Config {
+        language,
+        ..Config::default()
+    };
+    config.rust_externs.edition = edition.map(str::to_owned);
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/externs/lib.rs")
+        .generate()
+        .unwrap();
+
+    let mut externs = Vec::new();
+    bindings.write_rust_externs(&mut externs).unwrap();
+    String::from_utf8(externs).unwrap()
+}
+
+/// Compiles the Rust declarations along with the crate they were generated
+/// from, with `edit` applied to it.
+fn compile(externs: &str, edit: impl Fn(String) -> String) -> Output {
+    compile_with_edition(externs, "2018", edit)
+}
+
+fn compile_with_edition(externs: &str, edition: &str, edit: impl Fn(String) -> String) -> Output {
+    let dir = tempfile::tempdir().unwrap();
+    let crate_source = fs::read_to_string("tests/externs/lib.rs").unwrap();
+    fs::write(dir.path().join("lib.rs"), edit(crate_source)).unwrap();
+    fs::write(dir.path().join("externs.rs"), externs).unwrap();
+    let check = dir.path().join("check.rs");
+    fs::write(
+        &check,
+        "mod fixture {\n    include!(\"lib.rs\");\n}\nuse fixture::*;\ninclude!(\"externs.rs\");\n",
+    )
+    .unwrap();
+    Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned()))
+        .args([
+            "--edition",
+            edition,
+            "--crate-type",
+            "lib",
+            "--cap-lints",
+            "allow",
+        ])
+        .arg("--out-dir")
+        .arg(dir.path())
+        .arg(Path::new(&check))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn rust_externs_declarations() {
+    let externs = rust_externs(Language::C);
+    assert!(externs
+        .contains("    extern \"C\" {\n        pub fn point_len(this: *const Point) -> f64;\n"));
+    assert!(externs.contains(
+        "        pub fn pair_sum(pair: *const Pair<i32>, scale: *const f32) -> Pair<i64>;\n"
+    ));
+    assert!(externs.contains("        #[cfg(feature = \"extra\")]\n        pub fn extra();\n"));
+    assert!(externs.contains("        pub static mut COUNTER: u64;\n"));
+    assert!(externs.contains("    extern \"C-unwind\" {\n        pub fn may_unwind(value: u8);\n"));
+    assert!(!externs.contains("unsafe extern \"C\" {"));
+    // The signature of `checked_div` is rewritten by its `result` annotation.
+    assert!(!externs.contains("checked_div"));
+
+    // Boxes are kept as they are in C++.
+    let externs = rust_externs(Language::Cxx);
+    assert!(externs.contains("        pub fn opaque_free(opaque: Option<Box<Opaque>>);\n"));
+}
+
+#[test]
+fn rust_externs_compile() {
+    for language in [Language::C, Language::Cxx] {
+        let output = compile(&rust_externs(language), |source| source);
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let externs = rust_externs(Language::C);
+
+    let output = compile(&externs, |source| source.replace("len: usize", "len: u32"));
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("`u32: AbiEq<usize>` is not satisfied"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn rust_externs_unsafe_blocks() {
+    let externs = rust_externs_with(Language::C, Some("2024"));
+    assert!(externs.contains(
+        "    unsafe extern \"C\" {\n        pub fn point_len(this: *const Point) -> f64;\n"
+    ));
+    assert!(externs.contains("    unsafe extern \"C-unwind\" {\n"));
+
+    // The 2024 edition rejects plain `extern` blocks.
+    let output = compile_with_edition(&externs, "2024", |source| {
+        source.replace("#[no_mangle]", "#[unsafe(no_mangle)]")
+    });
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
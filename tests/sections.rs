@@ -0,0 +1,40 @@
+use std::process::Command;
+
+#[test]
+fn sections_of_types_are_ignored() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .arg("--lang")
+        .arg("c")
+        .arg("tests/sections/lib.rs")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Ignoring the section annotation of AudioConfig"));
+    assert!(stderr.contains("Ignoring the section annotation of AUDIO_CHANNELS"));
+    assert!(!stderr.contains("audio_play"));
+
+    let header = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        header.contains("/* Audio API */\n\nvoid audio_play(const struct AudioConfig *config);\n")
+    );
+}
+
+#[test]
+fn quoted_sections() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .arg("--lang")
+        .arg("c")
+        .arg("tests/sections/lib.rs")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Quoted names are the same sections as unquoted ones.
+    let header = String::from_utf8_lossy(&output.stdout);
+    assert!(header.contains(
+        "void audio_play(const struct AudioConfig *config);\n\nvoid audio_stop(void);\n"
+    ));
+    assert!(header.contains("/* Video Playback */\n\nvoid video_play(void);\n"));
+    assert!(!header.contains("/* \""));
+}
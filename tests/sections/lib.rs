@@ -0,0 +1,20 @@
+/// cbindgen:section=Audio API
+#[repr(C)]
+pub struct AudioConfig {
+    rate: u32,
+}
+
+/// cbindgen:section=Audio API
+pub const AUDIO_CHANNELS: u32 = 2;
+
+/// cbindgen:section=Audio API
+#[no_mangle]
+pub extern "C" fn audio_play(config: &AudioConfig) {}
+
+/// cbindgen:section="Audio API"
+#[no_mangle]
+pub extern "C" fn audio_stop() {}
+
+/// cbindgen:section="Video Playback"
+#[no_mangle]
+pub extern "C" fn video_play() {}
@@ -0,0 +1,65 @@
+use std::fs;
+
+use cbindgen::{Builder, Config, Language};
+
+#[test]
+fn shim_file() {
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.enumeration.derive_to_string = true;
+    config.shim.path = Some("src/bindings.c".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/enum_to_string.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    assert!(bindings.write_to_file(dir.path().join("bindings.h")));
+
+    let header = fs::read_to_string(dir.path().join("bindings.h")).unwrap();
+    assert!(header.contains("\nconst char *Color_to_string(Color value);\n"));
+    assert!(!header.contains("switch"));
+
+    let shim = fs::read_to_string(dir.path().join("src/bindings.c")).unwrap();
+    assert!(shim.starts_with("#include \"bindings.h\"\n"));
+    assert!(shim.contains("\nconst char *Color_to_string(Color value) {\n  switch (value) {\n"));
+    assert!(shim.contains("    case Low: return \"Low\";\n"));
+    assert!(!shim.contains("Hidden"));
+
+    // Only the shim is out of date, which counts as a change.
+    assert!(!bindings.write_to_file(dir.path().join("bindings.h")));
+    fs::write(dir.path().join("src/bindings.c"), "").unwrap();
+    assert!(bindings.write_to_file(dir.path().join("bindings.h")));
+    assert_eq!(
+        fs::read_to_string(dir.path().join("src/bindings.c")).unwrap(),
+        shim
+    );
+}
+
+#[test]
+fn shim_file_null_check() {
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.shim.path = Some("bindings.c".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/null_check.rs")
+        .generate()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    bindings.write_to_file(dir.path().join("bindings.h"));
+
+    let header = fs::read_to_string(dir.path().join("bindings.h")).unwrap();
+    assert!(header.contains("\nint32_t foo_get_checked(const struct Foo *foo, uintptr_t index);\n"));
+    assert!(!header.contains("assert"));
+
+    let shim = fs::read_to_string(dir.path().join("bindings.c")).unwrap();
+    assert!(shim.starts_with("#include <assert.h>\n#include \"bindings.h\"\n"));
+    assert!(shim.contains("\nint32_t foo_get_checked(const struct Foo *foo, uintptr_t index) {\n"));
+}
@@ -0,0 +1,49 @@
+use std::fs;
+
+use cbindgen::{Builder, Config, Language, SourceLocations};
+
+#[test]
+fn line_directives() {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("shapes \"v2\".rs");
+    fs::copy("tests/rust/source_locations.rs", &src).unwrap();
+    let header_dir = dir.path().join("include\\\"generated\"");
+
+    let config = Config {
+        language: Language::C,
+        source_locations: SourceLocations::Line,
+        ..Default::default()
+    };
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src(&src)
+        .generate()
+        .unwrap();
+    let header = header_dir.join("shapes.h");
+    bindings.write_to_file(&header);
+    let header_contents = fs::read_to_string(&header).unwrap();
+
+    let escaped_header = header
+        .to_str()
+        .unwrap()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    let lines: Vec<_> = header_contents.lines().collect();
+    let directive = lines
+        .iter()
+        .position(|line| *line == "#line 4 \"shapes \\\"v2\\\".rs\"")
+        .unwrap_or_else(|| panic!("{}", header_contents));
+    assert_eq!(lines[directive + 1], "typedef struct Point {");
+
+    // The lines following the declaration point back at the header, by the
+    // same path it was written to.
+    let reset = directive
+        + lines[directive..]
+            .iter()
+            .position(|line| line.starts_with("#line ") && line.contains("shapes.h"))
+            .unwrap();
+    assert_eq!(
+        lines[reset],
+        format!("#line {} \"{}\"", reset + 2, escaped_header)
+    );
+}
@@ -0,0 +1,92 @@
+use std::process::Command;
+
+use cbindgen::{Builder, Language};
+
+#[test]
+fn swig_interface() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/rust/opaque.rs")
+        .generate()
+        .unwrap();
+
+    let mut interface = Vec::new();
+    bindings
+        .write_swig_interface(&mut interface, "include/opaque.h")
+        .unwrap();
+    let interface = String::from_utf8(interface).unwrap();
+
+    assert!(interface.starts_with("%module opaque\n\n%{\n#include \"opaque.h\"\n%}\n"));
+    assert!(interface.contains("%include <stdint.i>\n"));
+    assert!(
+        interface.contains("%nodefaultctor HashMap_i32__i32__BuildHasherDefault_DefaultHasher;\n")
+    );
+    assert!(interface.ends_with("\n%include \"opaque.h\"\n"));
+}
+
+#[test]
+fn swig_interface_config() {
+    let mut config = cbindgen::Config::default();
+    config.swig.module = Some("mymodule".to_owned());
+    config.swig.header = Some("<my/opaque.h>".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src("tests/rust/opaque.rs")
+        .generate()
+        .unwrap();
+
+    let mut interface = Vec::new();
+    bindings
+        .write_swig_interface(&mut interface, "opaque.h")
+        .unwrap();
+    let interface = String::from_utf8(interface).unwrap();
+
+    assert!(interface.starts_with("%module mymodule\n\n%{\n#include <my/opaque.h>\n%}\n"));
+    assert!(interface.ends_with("\n%include <my/opaque.h>\n"));
+}
+
+#[test]
+fn swig_interface_ownership() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/swig/lib.rs")
+        .generate()
+        .unwrap();
+
+    let mut interface = Vec::new();
+    bindings
+        .write_swig_interface(&mut interface, "buffer.h")
+        .unwrap();
+    let interface = String::from_utf8(interface).unwrap();
+
+    assert!(interface.contains(
+        "%nodefaultctor Buffer;\n%extend Buffer {\n  ~Buffer() {\n    buffer_free($self);\n  }\n}\n"
+    ));
+    assert!(interface.contains("%nodefaultctor Token;\n%nodefaultdtor Token;\n"));
+    // The values returned by `buffer_new` are released with the function its
+    // annotation names, while `buffer_copy` doesn't name any.
+    assert!(interface.contains(
+        "\n%newobject buffer_new;\n\
+         %typemap(newfree) struct Buffer *buffer_new \"buffer_free($1);\";\n\
+         %newobject buffer_copy;\n\
+         %delobject buffer_free;\n\n"
+    ));
+    // Only the first argument can be disowned.
+    assert!(!interface.contains("buffer_append"));
+}
+
+#[test]
+fn swig_needs_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let interface = dir.path().join("buffer.i");
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .arg("--swig")
+        .arg(&interface)
+        .arg("tests/swig/lib.rs")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("without `--out`"));
+    assert!(output.stdout.is_empty());
+    assert!(!interface.exists());
+}
@@ -0,0 +1,36 @@
+/// cbindgen:destructor=buffer_free
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+/// No destructor, so SWIG can't destroy it.
+pub struct Token {
+    len: usize,
+}
+
+/// cbindgen:returns-ownership=buffer_free
+#[no_mangle]
+pub extern "C" fn buffer_new() -> *mut Buffer {
+    Box::into_raw(Box::new(Buffer { data: Vec::new() }))
+}
+
+/// Released by the destructor of `Buffer`.
+///
+/// cbindgen:returns-ownership
+#[no_mangle]
+pub extern "C" fn buffer_copy(buffer: *const Buffer) -> *mut Buffer {
+    std::ptr::null_mut()
+}
+
+/// cbindgen:consumes=1
+#[no_mangle]
+pub extern "C" fn buffer_free(buffer: *mut Buffer) {}
+
+/// cbindgen:consumes=[other]
+#[no_mangle]
+pub extern "C" fn buffer_append(buffer: *mut Buffer, other: *mut Buffer) {}
+
+#[no_mangle]
+pub extern "C" fn buffer_token(buffer: *const Buffer) -> *const Token {
+    std::ptr::null()
+}
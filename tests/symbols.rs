@@ -0,0 +1,31 @@
+use cbindgen::{Builder, Language, SymbolKind};
+
+#[test]
+fn symbols() {
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/rust/source_locations.rs")
+        .generate()
+        .unwrap();
+
+    let symbols = bindings.symbols();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols[0].name, "area");
+    assert_eq!(symbols[0].kind, SymbolKind::Function);
+    assert_eq!(
+        symbols[0].signature,
+        "float area(Shape shape, const Points *points)"
+    );
+
+    assert_eq!(symbols[1].name, "COUNTER");
+    assert_eq!(symbols[1].kind, SymbolKind::Static);
+    assert_eq!(symbols[1].signature, "extern uint32_t COUNTER");
+
+    let mut list = Vec::new();
+    bindings.write_symbols(&mut list).unwrap();
+    assert_eq!(
+        String::from_utf8(list).unwrap(),
+        "function\tarea\tfloat area(Shape shape, const Points *points)\n\
+         static\tCOUNTER\textern uint32_t COUNTER\n"
+    );
+}
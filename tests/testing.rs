@@ -0,0 +1,32 @@
+use std::fs;
+
+use cbindgen::testing::assert_bindings_match;
+use cbindgen::{Builder, Language, Style};
+
+fn builder() -> Builder {
+    Builder::new()
+        .with_language(Language::C)
+        .with_style(Style::Type)
+        .with_src("tests/rust/fuzz_stubs.rs")
+}
+
+#[test]
+fn matches_expectation() {
+    assert_bindings_match(builder(), "tests/expectations/fuzz_stubs.c");
+}
+
+#[test]
+#[should_panic(expected = "Run with CBINDGEN_BLESS=1 to update it.")]
+fn doesnt_match_expectation() {
+    let dir = tempfile::tempdir().unwrap();
+    let expectation = dir.path().join("bindings.h");
+    fs::write(&expectation, "#include <stdint.h>\n").unwrap();
+    assert_bindings_match(builder(), expectation);
+}
+
+#[test]
+#[should_panic(expected = "Run with CBINDGEN_BLESS=1 to create it.")]
+fn missing_expectation() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_bindings_match(builder(), dir.path().join("bindings.h"));
+}
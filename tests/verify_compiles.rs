@@ -0,0 +1,30 @@
+#![cfg(feature = "cc")]
+
+use cbindgen::{Builder, Error, Language};
+
+#[test]
+fn compiles() {
+    for language in [Language::C, Language::Cxx] {
+        Builder::new()
+            .with_language(language)
+            .with_src("tests/rust/fuzz_stubs.rs")
+            .verify_compiles(true)
+            .generate()
+            .unwrap();
+    }
+}
+
+#[test]
+fn doesnt_compile() {
+    let result = Builder::new()
+        .with_language(Language::C)
+        .with_src("tests/rust/fuzz_stubs.rs")
+        .with_after_include("#error broken header")
+        .verify_compiles(true)
+        .generate();
+    match result {
+        Err(Error::CompileCheck(output)) => assert!(output.contains("broken header")),
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("the header compiled"),
+    }
+}